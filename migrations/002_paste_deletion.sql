@@ -0,0 +1,3 @@
+ALTER TABLE pastes
+    -- When the paste was (soft) deleted.
+    ADD COLUMN IF NOT EXISTS "deleted_at" TIMESTAMPTZ;
@@ -0,0 +1,3 @@
+ALTER TABLE pastes
+    -- The argon2 hash of the password required to read the paste.
+    ADD COLUMN IF NOT EXISTS "password_hash" TEXT;
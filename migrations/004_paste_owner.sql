@@ -0,0 +1,3 @@
+ALTER TABLE pastes
+    -- The paste ID whose token owns this paste (null if the paste owns itself).
+    ADD COLUMN IF NOT EXISTS "owner_id" BIGINT;
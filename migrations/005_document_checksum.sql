@@ -0,0 +1,3 @@
+ALTER TABLE documents
+    -- The SHA-256 hex digest of the document contents (null for documents created before checksums).
+    ADD COLUMN IF NOT EXISTS "checksum" TEXT;
@@ -0,0 +1,7 @@
+ALTER TABLE pastes
+    -- The version of the paste, incremented on every edit.
+    ADD COLUMN IF NOT EXISTS "version" BIGINT NOT NULL DEFAULT 0;
+
+ALTER TABLE documents
+    -- The version of the document, incremented on every edit.
+    ADD COLUMN IF NOT EXISTS "version" BIGINT NOT NULL DEFAULT 0;
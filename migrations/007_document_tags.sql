@@ -0,0 +1,3 @@
+ALTER TABLE documents
+    -- The tags used to filter the documents within a paste.
+    ADD COLUMN IF NOT EXISTS "tags" TEXT[] NOT NULL DEFAULT '{}';
@@ -0,0 +1,2 @@
+-- Used to look up pastes by name, when unique paste names are enabled.
+CREATE INDEX IF NOT EXISTS "pastes_name_idx" ON pastes ("name") WHERE "deleted_at" IS NULL;
@@ -0,0 +1,22 @@
+CREATE TABLE IF NOT EXISTS uploads (
+    -- The ID of the upload, which becomes the ID of the document once finalized.
+    "id" BIGINT NOT NULL PRIMARY KEY,
+    -- The paste the document is being uploaded to.
+    "paste_id" BIGINT NOT NULL,
+    -- The type of the documents contents.
+    "type" TEXT NOT NULL,
+    -- The name of the document.
+    "name" TEXT NOT NULL,
+    -- The total size of the document being uploaded.
+    "length" BIGINT NOT NULL,
+    -- The amount of bytes received so far.
+    "upload_offset" BIGINT NOT NULL DEFAULT 0,
+    -- The amount of parts uploaded to the object store so far.
+    "part_count" BIGINT NOT NULL DEFAULT 0,
+    -- The ID of the object stores multipart upload.
+    "multipart_id" TEXT NOT NULL,
+    -- When the upload was created.
+    "creation" TIMESTAMPTZ NOT NULL,
+    -- Foreign key that deletes the upload when the paste gets deleted.
+    FOREIGN KEY ("paste_id") REFERENCES pastes("id") ON DELETE CASCADE
+);
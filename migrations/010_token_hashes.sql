@@ -0,0 +1,5 @@
+-- Tokens are stored as the hex encoded SHA-256 digest of the token, so they are not exposed by the database.
+ALTER TABLE paste_tokens RENAME COLUMN "token" TO "token_hash";
+
+-- Hash the existing (plaintext) tokens.
+UPDATE paste_tokens SET "token_hash" = encode(sha256(convert_to("token_hash", 'UTF8')), 'hex');
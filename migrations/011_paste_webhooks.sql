@@ -0,0 +1,2 @@
+-- The URL a paste sends view milestone events to.
+ALTER TABLE pastes ADD COLUMN IF NOT EXISTS "webhook_url" TEXT;
@@ -0,0 +1,12 @@
+CREATE TABLE IF NOT EXISTS paste_views (
+    -- The paste that was viewed.
+    "paste_id" BIGINT NOT NULL,
+    -- When the view was counted.
+    "viewed_at" TIMESTAMPTZ NOT NULL,
+    -- The hash of the address the view came from (if known), so the address itself is not stored.
+    "ip_hash" TEXT,
+    -- Foreign key that deletes the views when the paste gets deleted.
+    FOREIGN KEY ("paste_id") REFERENCES pastes("id") ON DELETE CASCADE
+);
+-- Used to bucket the views of a paste over time.
+CREATE INDEX IF NOT EXISTS "paste_views_paste_id_viewed_at_idx" ON paste_views ("paste_id", "viewed_at");
@@ -1,15 +1,24 @@
 //! The application state for holding references to all server related items.
-use std::sync::Arc;
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::Duration,
+};
 
 #[cfg(test)]
 use sqlx::PgPool;
 
 #[cfg(test)]
-use crate::app::object_store::TestObjectStore;
+use crate::app::object_store::MemoryObjectStore;
 use crate::{
     app::{
+        creation_limiter::CreationLimiter,
         handler::Handler,
-        object_store::{ObjectStore, ObjectStoreExt as _},
+        object_store::{ObjectStore, create_buckets_with_retry},
+        view_tracker::ViewTracker,
+        write_limiter::WriteLimiter,
     },
     models::errors::ApplicationError,
 };
@@ -27,6 +36,10 @@ pub struct ApplicationState {
     database: Database,
     object_store: ObjectStore,
     handler: Handler,
+    view_tracker: ViewTracker,
+    creation_limiter: CreationLimiter,
+    write_limiter: WriteLimiter,
+    maintenance_mode: AtomicBool,
 }
 
 impl ApplicationState {
@@ -51,6 +64,13 @@ impl ApplicationState {
             database: Database::new(),
             object_store: ObjectStore::from_config(config.object_store())?,
             handler: Handler::new(),
+            view_tracker: ViewTracker::new(Duration::from_secs(config.view_dedup_seconds())),
+            creation_limiter: CreationLimiter::new(config.max_pastes_per_ip_per_day()),
+            write_limiter: WriteLimiter::new(
+                config.max_concurrent_paste_writes(),
+                Duration::from_millis(config.paste_write_wait_ms()),
+            ),
+            maintenance_mode: AtomicBool::new(config.maintenance_mode()),
         };
 
         state.init().await?;
@@ -69,20 +89,35 @@ impl ApplicationState {
     pub async fn new_tests(
         config: Config,
         pool: PgPool,
-        object_store: TestObjectStore,
+        object_store: MemoryObjectStore,
     ) -> Result<Arc<Self>, ApplicationError> {
         let database = Database::from_pool(pool);
-        let object_store = ObjectStore::Test(object_store);
+        let object_store = ObjectStore::Memory(object_store);
 
         let mut handler = Handler::new();
 
         handler.start(database.clone(), object_store.clone(), config.clone())?;
 
+        let view_tracker = ViewTracker::new(Duration::from_secs(config.view_dedup_seconds()));
+
+        let creation_limiter = CreationLimiter::new(config.max_pastes_per_ip_per_day());
+
+        let write_limiter = WriteLimiter::new(
+            config.max_concurrent_paste_writes(),
+            Duration::from_millis(config.paste_write_wait_ms()),
+        );
+
+        let maintenance_mode = AtomicBool::new(config.maintenance_mode());
+
         Ok(Arc::new(Self {
             config,
             database,
             object_store,
             handler,
+            view_tracker,
+            creation_limiter,
+            write_limiter,
+            maintenance_mode,
         }))
     }
 
@@ -110,10 +145,50 @@ impl ApplicationState {
         &self.handler
     }
 
-    async fn init(&mut self) -> Result<(), ApplicationError> {
-        self.database.connect(self.config.database_url()).await?;
+    /// The view tracker used to debounce repeated views.
+    #[inline]
+    pub const fn view_tracker(&self) -> &ViewTracker {
+        &self.view_tracker
+    }
+
+    /// The creation limiter used to cap the pastes created by each client.
+    #[inline]
+    pub const fn creation_limiter(&self) -> &CreationLimiter {
+        &self.creation_limiter
+    }
+
+    /// The write limiter used to cap the concurrent writes to each paste.
+    #[inline]
+    pub const fn write_limiter(&self) -> &WriteLimiter {
+        &self.write_limiter
+    }
 
-        self.object_store.create_buckets().await?;
+    /// Whether the server is in maintenance mode, where write endpoints are unavailable.
+    #[inline]
+    pub fn maintenance_mode(&self) -> bool {
+        self.maintenance_mode.load(Ordering::Relaxed)
+    }
+
+    /// Set whether the server is in maintenance mode.
+    #[inline]
+    pub fn set_maintenance_mode(&self, enabled: bool) {
+        self.maintenance_mode.store(enabled, Ordering::Relaxed);
+    }
+
+    async fn init(&mut self) -> Result<(), ApplicationError> {
+        self.database
+            .connect(
+                self.config.database_url(),
+                self.config.db_statement_timeout_ms(),
+            )
+            .await?;
+
+        create_buckets_with_retry(
+            &self.object_store,
+            self.config.object_store_retry_attempts(),
+            Duration::from_secs(self.config.object_store_retry_interval_seconds()),
+        )
+        .await?;
 
         self.handler.start(
             self.database.clone(),
@@ -1,8 +1,18 @@
 //! The configuration objects for the server.
 
+use chrono::Utc;
 #[cfg(test)]
 use derive_builder::Builder;
+use ipnet::IpNet;
 use secrecy::SecretString;
+use tracing_appender::rolling::{InitError, RollingFileAppender, Rotation};
+use tracing_subscriber::{EnvFilter, filter::ParseError};
+
+use crate::models::{
+    document::{DEFAULT_EXTENSIONLESS_NAMES, UNSUPPORTED_MIMES, contains_mime},
+    paste::expand_paste_name,
+    snowflake::{MAXIMUM_WORKER_ID, Snowflake},
+};
 
 /// ## Config
 ///
@@ -10,6 +20,7 @@ use secrecy::SecretString;
 #[cfg_attr(test, derive(Builder, Default))]
 #[cfg_attr(test, builder(default))]
 #[derive(Debug, Clone)]
+#[expect(clippy::struct_excessive_bools)]
 pub struct Config {
     /// The host to run on.
     host: String,
@@ -17,12 +28,109 @@ pub struct Config {
     port: u16,
     /// The database URL.
     database_url: String,
+    /// The maximum amount of milliseconds a single database statement can take (none disables the limit).
+    db_statement_timeout_ms: Option<u64>,
     /// The domain to use for cors.
     domain: String,
     /// Object store information.
     object_store: ObjectStoreConfig,
     /// Size limits.
     size_limits: SizeLimitConfig,
+    /// Cors information.
+    cors: CorsConfig,
+    /// The amount of hours a deleted paste can be restored for (0 disables soft deletion).
+    deletion_grace_hours: usize,
+    /// The maximum amount of hours any paste can exist for, regardless of its expiry (none disables the limit).
+    absolute_max_age_hours: Option<usize>,
+    /// Whether deleting the last document of a paste deletes the paste, instead of being rejected.
+    delete_paste_on_last_document: bool,
+    /// Whether views from the pastes owner (using its token) are counted.
+    count_owner_views: bool,
+    /// Whether this instance sweeps (deletes) expired and purged pastes.
+    #[cfg_attr(test, builder(default = "true"))]
+    enable_expiry_sweep: bool,
+    /// The amount of seconds repeated views of a paste from the same address are counted once (0 disables this).
+    view_dedup_seconds: u64,
+    /// Whether the time of each counted view is recorded, for the analytics of each paste.
+    record_view_analytics: bool,
+    /// The maximum amount of pastes a single address can create each day (none disables the limit).
+    max_pastes_per_ip_per_day: Option<usize>,
+    /// The maximum amount of concurrent writes to the documents of a single paste (none disables the limit).
+    max_concurrent_paste_writes: Option<usize>,
+    /// The amount of milliseconds a write waits for another write to the same paste to finish, before being rejected.
+    #[cfg_attr(test, builder(default = "1000"))]
+    paste_write_wait_ms: u64,
+    /// The maximum amount of documents a single owner can have across all of its pastes (none disables the limit).
+    max_documents_per_owner: Option<usize>,
+    /// The maximum amount of tokens a single paste can have (none disables the limit).
+    max_tokens_per_paste: Option<usize>,
+    /// The proxies trusted to provide the clients address via forwarded headers.
+    trusted_proxies: Vec<IpNet>,
+    /// Whether new pastes require at least one text document.
+    require_text_document: bool,
+    /// The maximum amount of attempts made at reaching the object store on startup.
+    object_store_retry_attempts: usize,
+    /// The seconds waited after the first failed attempt at reaching the object store, doubling after each attempt.
+    object_store_retry_interval_seconds: u64,
+    /// The amount of seconds a snowflake can be created ahead of the current time (none disables the check).
+    snowflake_skew_seconds: Option<u64>,
+    /// The ID of this worker, mixed into generated snowflakes (0-1023).
+    worker_id: u16,
+    /// The maximum amount of attempts made at generating a snowflake that is not already in use.
+    #[cfg_attr(test, builder(default = "3"))]
+    snowflake_generate_attempts: usize,
+    /// The token required for admin endpoints (none disables them).
+    admin_token: Option<SecretString>,
+    /// The precision timestamps provided and generated for pastes are stored with.
+    timestamp_precision: TimestampPrecision,
+    /// How the mimes of documents are checked.
+    mime_policy: MimePolicy,
+    /// The mimes documents are allowed to have, when using the allowlist policy.
+    allowed_mimes: Vec<String>,
+    /// Whether responses are compressed for clients that accept it.
+    response_compression: bool,
+    /// The minimum size of a response before it is compressed (bytes).
+    response_compression_minimum_size: u16,
+    /// Whether trailing slashes are trimmed from request paths before routing.
+    #[cfg_attr(test, builder(default = "true"))]
+    trim_trailing_slash: bool,
+    /// Whether document names that only differ by case are treated as the same name.
+    document_name_case: DocumentNameCase,
+    /// How the contents of text documents are normalized before being stored.
+    normalize_text: TextNormalization,
+    /// Whether document names require a file extension.
+    require_file_extension: bool,
+    /// The document names allowed without a file extension, when one is required.
+    #[cfg_attr(
+        test,
+        builder(default = "DEFAULT_EXTENSIONLESS_NAMES.iter().map(ToString::to_string).collect()")
+    )]
+    extensionless_names: Vec<String>,
+    /// Whether each (non deleted) paste must have a unique name.
+    unique_paste_names: bool,
+    /// The maximum amount of seconds in-flight requests are waited on when shutting down.
+    shutdown_drain_seconds: u64,
+    /// Whether the server starts in maintenance mode, where write endpoints are unavailable.
+    maintenance_mode: bool,
+    /// The amount of seconds clients are told to wait before retrying, while in maintenance mode.
+    #[cfg_attr(test, builder(default = "60"))]
+    maintenance_retry_after_seconds: u64,
+    /// Whether the server is read only, where write endpoints are always rejected.
+    read_only: bool,
+    /// The status write requests are rejected with, while read only.
+    read_only_status: ReadOnlyStatus,
+    /// The hosts documents can be fetched from by URL (`*` allows any host, empty disables fetching).
+    source_url_allowed_hosts: Vec<String>,
+    /// The private networks documents can be fetched from by URL, which are otherwise rejected.
+    source_url_allowed_networks: Vec<IpNet>,
+    /// The maximum amount of seconds fetching a document by URL can take.
+    #[cfg_attr(test, builder(default = "10"))]
+    source_url_timeout_seconds: u64,
+    /// The hosts paste webhooks can be sent to (`*` allows any host, empty disables paste webhooks).
+    webhook_allowed_hosts: Vec<String>,
+    /// The amount of views between each view milestone a paste webhook is sent for (0 only sends it at the maximum views).
+    #[cfg_attr(test, builder(default = "100"))]
+    webhook_view_milestone: usize,
 }
 
 impl Config {
@@ -42,6 +150,7 @@ impl Config {
     ///
     /// ## Returns
     /// Returns the [`Config`] object.
+    #[expect(clippy::too_many_lines)]
     pub fn from_env() -> Self {
         Self {
             host: std::env::var("HOST").expect("HOST environment variable must be set."),
@@ -51,9 +160,279 @@ impl Config {
                 .expect("PORT requires an integer."),
             database_url: std::env::var("DATABASE_URL")
                 .expect("DATABASE_URL environment variable must be set."),
+            db_statement_timeout_ms: std::env::var("DB_STATEMENT_TIMEOUT_MS").ok().map(|v| {
+                v.parse()
+                    .expect("DB_STATEMENT_TIMEOUT_MS requires an integer.")
+            }),
             domain: std::env::var("DOMAIN").expect("DOMAIN environment variable must be set."),
             object_store: ObjectStoreConfig::from_env(),
             size_limits: SizeLimitConfig::from_env(),
+            cors: CorsConfig::from_env(),
+            deletion_grace_hours: std::env::var("DELETION_GRACE_HOURS").ok().map_or(24, |v| {
+                v.parse()
+                    .expect("DELETION_GRACE_HOURS requires an integer.")
+            }),
+            absolute_max_age_hours: std::env::var("ABSOLUTE_MAX_AGE_HOURS").ok().map(|v| {
+                v.parse()
+                    .expect("ABSOLUTE_MAX_AGE_HOURS requires an integer.")
+            }),
+            delete_paste_on_last_document: std::env::var("DELETE_PASTE_ON_LAST_DOCUMENT")
+                .ok()
+                .is_some_and(|v| {
+                    v.parse()
+                        .expect("DELETE_PASTE_ON_LAST_DOCUMENT requires a boolean.")
+                }),
+            count_owner_views: std::env::var("COUNT_OWNER_VIEWS")
+                .ok()
+                .is_some_and(|v| v.parse().expect("COUNT_OWNER_VIEWS requires a boolean.")),
+            enable_expiry_sweep: std::env::var("ENABLE_EXPIRY_SWEEP")
+                .ok()
+                .is_none_or(|v| v.parse().expect("ENABLE_EXPIRY_SWEEP requires a boolean.")),
+            view_dedup_seconds: std::env::var("VIEW_DEDUP_SECONDS").ok().map_or(0, |v| {
+                v.parse().expect("VIEW_DEDUP_SECONDS requires an integer.")
+            }),
+            record_view_analytics: std::env::var("RECORD_VIEW_ANALYTICS")
+                .ok()
+                .is_some_and(|v| v.parse().expect("RECORD_VIEW_ANALYTICS requires a boolean.")),
+            max_pastes_per_ip_per_day: std::env::var("MAX_PASTES_PER_IP_PER_DAY").ok().map(|v| {
+                v.parse()
+                    .expect("MAX_PASTES_PER_IP_PER_DAY requires an integer.")
+            }),
+            max_concurrent_paste_writes: std::env::var("MAX_CONCURRENT_PASTE_WRITES").ok().map(
+                |v| {
+                    v.parse()
+                        .expect("MAX_CONCURRENT_PASTE_WRITES requires an integer.")
+                },
+            ),
+            paste_write_wait_ms: std::env::var("PASTE_WRITE_WAIT_MS").ok().map_or(1000, |v| {
+                v.parse().expect("PASTE_WRITE_WAIT_MS requires an integer.")
+            }),
+            max_documents_per_owner: std::env::var("MAX_DOCUMENTS_PER_OWNER").ok().map(|v| {
+                v.parse()
+                    .expect("MAX_DOCUMENTS_PER_OWNER requires an integer.")
+            }),
+            max_tokens_per_paste: std::env::var("MAX_TOKENS_PER_PASTE").ok().map(|v| {
+                v.parse()
+                    .ok()
+                    .filter(|maximum| *maximum > 0)
+                    .expect("MAX_TOKENS_PER_PASTE requires an integer above 0.")
+            }),
+            trusted_proxies: std::env::var("TRUSTED_PROXIES")
+                .ok()
+                .map(|v| {
+                    v.split(',')
+                        .map(str::trim)
+                        .filter(|proxy| !proxy.is_empty())
+                        .map(|proxy| {
+                            proxy
+                                .parse()
+                                .expect("TRUSTED_PROXIES requires a list of CIDRs.")
+                        })
+                        .collect()
+                })
+                .unwrap_or_default(),
+            require_text_document: std::env::var("REQUIRE_TEXT_DOCUMENT")
+                .ok()
+                .is_some_and(|v| {
+                    v.parse()
+                        .expect("REQUIRE_TEXT_DOCUMENT requires a boolean.")
+                }),
+            object_store_retry_attempts: std::env::var("OBJECT_STORE_RETRY_ATTEMPTS").ok().map_or(
+                5,
+                |v| {
+                    v.parse()
+                        .expect("OBJECT_STORE_RETRY_ATTEMPTS requires an integer.")
+                },
+            ),
+            object_store_retry_interval_seconds: std::env::var(
+                "OBJECT_STORE_RETRY_INTERVAL_SECONDS",
+            )
+            .ok()
+            .map_or(2, |v| {
+                v.parse()
+                    .expect("OBJECT_STORE_RETRY_INTERVAL_SECONDS requires an integer.")
+            }),
+            snowflake_skew_seconds: Some(std::env::var("SNOWFLAKE_SKEW_SECONDS").ok().map_or(
+                3600,
+                |v| {
+                    v.parse()
+                        .expect("SNOWFLAKE_SKEW_SECONDS requires an integer.")
+                },
+            )),
+            worker_id: std::env::var("WORKER_ID").ok().map_or(0, |v| {
+                v.parse()
+                    .ok()
+                    .filter(|id| *id <= MAXIMUM_WORKER_ID)
+                    .expect("WORKER_ID requires an integer between 0 and 1023.")
+            }),
+            snowflake_generate_attempts: std::env::var("SNOWFLAKE_GENERATE_ATTEMPTS").ok().map_or(
+                3,
+                |v| {
+                    v.parse()
+                        .ok()
+                        .filter(|attempts| *attempts > 0)
+                        .expect("SNOWFLAKE_GENERATE_ATTEMPTS requires an integer above 0.")
+                },
+            ),
+            admin_token: std::env::var("ADMIN_TOKEN")
+                .ok()
+                .filter(|v| !v.is_empty())
+                .map(SecretString::from),
+            timestamp_precision: std::env::var("TIMESTAMP_PRECISION").ok().map_or(
+                TimestampPrecision::Second,
+                |v| match v.to_lowercase().as_str() {
+                    "second" => TimestampPrecision::Second,
+                    "millisecond" => TimestampPrecision::Millisecond,
+                    _ => panic!("TIMESTAMP_PRECISION requires either `second` or `millisecond`."),
+                },
+            ),
+            mime_policy: std::env::var("MIME_POLICY").ok().map_or(
+                MimePolicy::Denylist,
+                |v| match v.to_lowercase().as_str() {
+                    "denylist" => MimePolicy::Denylist,
+                    "allowlist" => MimePolicy::Allowlist,
+                    _ => panic!("MIME_POLICY requires either `denylist` or `allowlist`."),
+                },
+            ),
+            allowed_mimes: std::env::var("ALLOWED_MIMES")
+                .ok()
+                .map(|v| {
+                    v.split(',')
+                        .map(str::trim)
+                        .filter(|mime| !mime.is_empty())
+                        .map(ToString::to_string)
+                        .collect()
+                })
+                .unwrap_or_default(),
+            response_compression: std::env::var("RESPONSE_COMPRESSION")
+                .ok()
+                .is_some_and(|v| v.parse().expect("RESPONSE_COMPRESSION requires a boolean.")),
+            response_compression_minimum_size: std::env::var("RESPONSE_COMPRESSION_MINIMUM_SIZE")
+                .ok()
+                .map_or(1024, |v| {
+                    v.parse()
+                        .expect("RESPONSE_COMPRESSION_MINIMUM_SIZE requires an integer (0-65535).")
+                }),
+            trim_trailing_slash: std::env::var("TRIM_TRAILING_SLASH")
+                .ok()
+                .is_none_or(|v| v.parse().expect("TRIM_TRAILING_SLASH requires a boolean.")),
+            document_name_case: std::env::var("DOCUMENT_NAME_CASE").ok().map_or(
+                DocumentNameCase::Sensitive,
+                |v| match v.to_lowercase().as_str() {
+                    "sensitive" => DocumentNameCase::Sensitive,
+                    "insensitive" => DocumentNameCase::Insensitive,
+                    _ => panic!("DOCUMENT_NAME_CASE requires either `sensitive` or `insensitive`."),
+                },
+            ),
+            normalize_text: std::env::var("NORMALIZE_TEXT").ok().map_or(
+                TextNormalization::Off,
+                |v| match v.to_lowercase().as_str() {
+                    "off" => TextNormalization::Off,
+                    "trailing_ws" => TextNormalization::TrailingWhitespace,
+                    "crlf_to_lf" => TextNormalization::CrlfToLf,
+                    "both" => TextNormalization::Both,
+                    _ => panic!(
+                        "NORMALIZE_TEXT requires either `off`, `trailing_ws`, `crlf_to_lf` or `both`."
+                    ),
+                },
+            ),
+            require_file_extension: std::env::var("REQUIRE_FILE_EXTENSION")
+                .ok()
+                .is_some_and(|v| {
+                    v.parse()
+                        .expect("REQUIRE_FILE_EXTENSION requires a boolean.")
+                }),
+            extensionless_names: std::env::var("EXTENSIONLESS_NAMES").ok().map_or_else(
+                || {
+                    DEFAULT_EXTENSIONLESS_NAMES
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect()
+                },
+                |v| {
+                    v.split(',')
+                        .map(str::trim)
+                        .filter(|name| !name.is_empty())
+                        .map(ToString::to_string)
+                        .collect()
+                },
+            ),
+            unique_paste_names: std::env::var("UNIQUE_PASTE_NAMES")
+                .ok()
+                .is_some_and(|v| v.parse().expect("UNIQUE_PASTE_NAMES requires a boolean.")),
+            shutdown_drain_seconds: std::env::var("SHUTDOWN_DRAIN_SECONDS")
+                .ok()
+                .map_or(30, |v| {
+                    v.parse()
+                        .expect("SHUTDOWN_DRAIN_SECONDS requires an integer.")
+                }),
+            maintenance_mode: std::env::var("MAINTENANCE_MODE")
+                .ok()
+                .is_some_and(|v| v.parse().expect("MAINTENANCE_MODE requires a boolean.")),
+            maintenance_retry_after_seconds: std::env::var("MAINTENANCE_RETRY_AFTER_SECONDS")
+                .ok()
+                .map_or(60, |v| {
+                    v.parse()
+                        .expect("MAINTENANCE_RETRY_AFTER_SECONDS requires an integer.")
+                }),
+            read_only: std::env::var("READ_ONLY")
+                .ok()
+                .is_some_and(|v| v.parse().expect("READ_ONLY requires a boolean.")),
+            read_only_status: std::env::var("READ_ONLY_STATUS").ok().map_or(
+                ReadOnlyStatus::MethodNotAllowed,
+                |v| match v.trim() {
+                    "405" => ReadOnlyStatus::MethodNotAllowed,
+                    "403" => ReadOnlyStatus::Forbidden,
+                    _ => panic!("READ_ONLY_STATUS requires either `405` or `403`."),
+                },
+            ),
+            source_url_allowed_hosts: std::env::var("SOURCE_URL_ALLOWED_HOSTS")
+                .ok()
+                .map(|v| {
+                    v.split(',')
+                        .map(str::trim)
+                        .filter(|host| !host.is_empty())
+                        .map(str::to_lowercase)
+                        .collect()
+                })
+                .unwrap_or_default(),
+            source_url_allowed_networks: std::env::var("SOURCE_URL_ALLOWED_NETWORKS")
+                .ok()
+                .map(|v| {
+                    v.split(',')
+                        .map(str::trim)
+                        .filter(|network| !network.is_empty())
+                        .map(|network| {
+                            network
+                                .parse()
+                                .expect("SOURCE_URL_ALLOWED_NETWORKS requires a list of CIDRs.")
+                        })
+                        .collect()
+                })
+                .unwrap_or_default(),
+            source_url_timeout_seconds: std::env::var("SOURCE_URL_TIMEOUT_SECONDS").ok().map_or(
+                10,
+                |v| {
+                    v.parse()
+                        .expect("SOURCE_URL_TIMEOUT_SECONDS requires an integer.")
+                },
+            ),
+            webhook_allowed_hosts: std::env::var("WEBHOOK_ALLOWED_HOSTS")
+                .ok()
+                .map(|v| {
+                    v.split(',')
+                        .map(str::trim)
+                        .filter(|host| !host.is_empty())
+                        .map(str::to_lowercase)
+                        .collect()
+                })
+                .unwrap_or_default(),
+            webhook_view_milestone: std::env::var("WEBHOOK_VIEW_MILESTONE")
+                .ok()
+                .map_or(100, |v| {
+                    v.parse()
+                        .expect("WEBHOOK_VIEW_MILESTONE requires an integer.")
+                }),
         }
     }
 
@@ -72,6 +451,11 @@ impl Config {
         &self.database_url
     }
 
+    /// The maximum amount of milliseconds a single database statement can take (none disables the limit).
+    pub const fn db_statement_timeout_ms(&self) -> Option<u64> {
+        self.db_statement_timeout_ms
+    }
+
     /// The domain to use for cors.
     pub fn domain(&self) -> &str {
         &self.domain
@@ -86,6 +470,297 @@ impl Config {
     pub const fn size_limits(&self) -> &SizeLimitConfig {
         &self.size_limits
     }
+
+    /// Cors information.
+    pub const fn cors(&self) -> &CorsConfig {
+        &self.cors
+    }
+
+    /// The amount of hours a deleted paste can be restored for (0 disables soft deletion).
+    pub const fn deletion_grace_hours(&self) -> usize {
+        self.deletion_grace_hours
+    }
+
+    /// The maximum amount of hours any paste can exist for, regardless of its expiry (none disables the limit).
+    pub const fn absolute_max_age_hours(&self) -> Option<usize> {
+        self.absolute_max_age_hours
+    }
+
+    /// Whether deleting the last document of a paste deletes the paste, instead of being rejected.
+    pub const fn delete_paste_on_last_document(&self) -> bool {
+        self.delete_paste_on_last_document
+    }
+
+    /// Whether views from the pastes owner (using its token) are counted.
+    pub const fn count_owner_views(&self) -> bool {
+        self.count_owner_views
+    }
+
+    /// Whether this instance sweeps (deletes) expired and purged pastes.
+    pub const fn enable_expiry_sweep(&self) -> bool {
+        self.enable_expiry_sweep
+    }
+
+    /// The amount of seconds repeated views of a paste from the same address are counted once (0 disables this).
+    pub const fn view_dedup_seconds(&self) -> u64 {
+        self.view_dedup_seconds
+    }
+
+    /// Whether the time of each counted view is recorded, for the analytics of each paste.
+    pub const fn record_view_analytics(&self) -> bool {
+        self.record_view_analytics
+    }
+
+    /// The maximum amount of pastes a single address can create each day (none disables the limit).
+    pub const fn max_pastes_per_ip_per_day(&self) -> Option<usize> {
+        self.max_pastes_per_ip_per_day
+    }
+
+    /// The maximum amount of concurrent writes to the documents of a single paste (none disables the limit).
+    pub const fn max_concurrent_paste_writes(&self) -> Option<usize> {
+        self.max_concurrent_paste_writes
+    }
+
+    /// The amount of milliseconds a write waits for another write to the same paste to finish, before being rejected.
+    pub const fn paste_write_wait_ms(&self) -> u64 {
+        self.paste_write_wait_ms
+    }
+
+    /// The maximum amount of documents a single owner can have across all of its pastes (none disables the limit).
+    pub const fn max_documents_per_owner(&self) -> Option<usize> {
+        self.max_documents_per_owner
+    }
+
+    /// The maximum amount of tokens a single paste can have (none disables the limit).
+    pub const fn max_tokens_per_paste(&self) -> Option<usize> {
+        self.max_tokens_per_paste
+    }
+
+    /// The proxies trusted to provide the clients address via forwarded headers.
+    pub fn trusted_proxies(&self) -> &[IpNet] {
+        &self.trusted_proxies
+    }
+
+    /// Whether new pastes require at least one text document.
+    pub const fn require_text_document(&self) -> bool {
+        self.require_text_document
+    }
+
+    /// The maximum amount of attempts made at reaching the object store on startup.
+    pub const fn object_store_retry_attempts(&self) -> usize {
+        self.object_store_retry_attempts
+    }
+
+    /// The seconds waited after the first failed attempt at reaching the object store, doubling after each attempt.
+    pub const fn object_store_retry_interval_seconds(&self) -> u64 {
+        self.object_store_retry_interval_seconds
+    }
+
+    /// The amount of seconds a snowflake can be created ahead of the current time (none disables the check).
+    pub const fn snowflake_skew_seconds(&self) -> Option<u64> {
+        self.snowflake_skew_seconds
+    }
+
+    /// The ID of this worker, mixed into generated snowflakes (0-1023).
+    pub const fn worker_id(&self) -> u16 {
+        self.worker_id
+    }
+
+    /// The maximum amount of attempts made at generating a snowflake that is not already in use.
+    pub const fn snowflake_generate_attempts(&self) -> usize {
+        self.snowflake_generate_attempts
+    }
+
+    /// The token required for admin endpoints (none disables them).
+    pub const fn admin_token(&self) -> Option<&SecretString> {
+        self.admin_token.as_ref()
+    }
+
+    /// The precision timestamps provided and generated for pastes are stored with.
+    pub const fn timestamp_precision(&self) -> TimestampPrecision {
+        self.timestamp_precision
+    }
+
+    /// How the mimes of documents are checked.
+    pub const fn mime_policy(&self) -> MimePolicy {
+        self.mime_policy
+    }
+
+    /// The mimes documents are allowed to have, when using the allowlist policy.
+    pub fn allowed_mimes(&self) -> &[String] {
+        &self.allowed_mimes
+    }
+
+    /// Whether responses are compressed for clients that accept it.
+    pub const fn response_compression(&self) -> bool {
+        self.response_compression
+    }
+
+    /// The minimum size of a response before it is compressed (bytes).
+    pub const fn response_compression_minimum_size(&self) -> u16 {
+        self.response_compression_minimum_size
+    }
+
+    /// Whether trailing slashes are trimmed from request paths before routing.
+    pub const fn trim_trailing_slash(&self) -> bool {
+        self.trim_trailing_slash
+    }
+
+    /// Whether document names that only differ by case are treated as the same name.
+    pub const fn document_name_case(&self) -> DocumentNameCase {
+        self.document_name_case
+    }
+
+    /// How the contents of text documents are normalized before being stored.
+    pub const fn normalize_text(&self) -> TextNormalization {
+        self.normalize_text
+    }
+
+    /// Whether document names require a file extension.
+    pub const fn require_file_extension(&self) -> bool {
+        self.require_file_extension
+    }
+
+    /// The document names allowed without a file extension, when one is required.
+    pub fn extensionless_names(&self) -> &[String] {
+        &self.extensionless_names
+    }
+
+    /// Whether each (non deleted) paste must have a unique name.
+    pub const fn unique_paste_names(&self) -> bool {
+        self.unique_paste_names
+    }
+
+    /// The maximum amount of seconds in-flight requests are waited on when shutting down.
+    pub const fn shutdown_drain_seconds(&self) -> u64 {
+        self.shutdown_drain_seconds
+    }
+
+    /// Whether the server starts in maintenance mode, where write endpoints are unavailable.
+    pub const fn maintenance_mode(&self) -> bool {
+        self.maintenance_mode
+    }
+
+    /// The amount of seconds clients are told to wait before retrying, while in maintenance mode.
+    pub const fn maintenance_retry_after_seconds(&self) -> u64 {
+        self.maintenance_retry_after_seconds
+    }
+
+    /// Whether the server is read only, where write endpoints are always rejected.
+    pub const fn read_only(&self) -> bool {
+        self.read_only
+    }
+
+    /// The status write requests are rejected with, while read only.
+    pub const fn read_only_status(&self) -> ReadOnlyStatus {
+        self.read_only_status
+    }
+
+    /// The hosts documents can be fetched from by URL (`*` allows any host, empty disables fetching).
+    pub fn source_url_allowed_hosts(&self) -> &[String] {
+        &self.source_url_allowed_hosts
+    }
+
+    /// The private networks documents can be fetched from by URL, which are otherwise rejected.
+    pub fn source_url_allowed_networks(&self) -> &[IpNet] {
+        &self.source_url_allowed_networks
+    }
+
+    /// The maximum amount of seconds fetching a document by URL can take.
+    pub const fn source_url_timeout_seconds(&self) -> u64 {
+        self.source_url_timeout_seconds
+    }
+
+    /// The hosts paste webhooks can be sent to (`*` allows any host, empty disables paste webhooks).
+    pub fn webhook_allowed_hosts(&self) -> &[String] {
+        &self.webhook_allowed_hosts
+    }
+
+    /// The amount of views between each view milestone a paste webhook is sent for (0 only sends it at the maximum views).
+    pub const fn webhook_view_milestone(&self) -> usize {
+        self.webhook_view_milestone
+    }
+}
+
+/// ## Mime Policy
+///
+/// How the mimes of documents are checked.
+#[cfg_attr(test, derive(Default))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MimePolicy {
+    /// Documents are accepted, unless their mime is unsupported.
+    #[cfg_attr(test, default)]
+    Denylist,
+    /// Documents are only accepted, if their mime is allowed.
+    Allowlist,
+}
+
+/// ## Document Name Case
+///
+/// Whether the case of document names is significant.
+#[cfg_attr(test, derive(Default))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DocumentNameCase {
+    /// Names that only differ by case are different names.
+    #[cfg_attr(test, default)]
+    Sensitive,
+    /// Names that only differ by case are the same name, and paths use the lowercase name.
+    Insensitive,
+}
+
+/// ## Text Normalization
+///
+/// How the contents of text documents are normalized before being stored.
+#[cfg_attr(test, derive(Default))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextNormalization {
+    /// Contents are stored as received.
+    #[cfg_attr(test, default)]
+    Off,
+    /// Whitespace at the end of each line is removed.
+    TrailingWhitespace,
+    /// Windows line endings (`\r\n`) are replaced with `\n`.
+    CrlfToLf,
+    /// Both trailing whitespace is removed, and line endings are replaced.
+    Both,
+}
+
+impl TextNormalization {
+    /// Whether whitespace at the end of each line is removed.
+    pub const fn trailing_whitespace(self) -> bool {
+        matches!(self, Self::TrailingWhitespace | Self::Both)
+    }
+
+    /// Whether Windows line endings (`\r\n`) are replaced with `\n`.
+    pub const fn crlf_to_lf(self) -> bool {
+        matches!(self, Self::CrlfToLf | Self::Both)
+    }
+}
+
+/// ## Read Only Status
+///
+/// The status write requests are rejected with, while the server is read only.
+#[cfg_attr(test, derive(Default))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReadOnlyStatus {
+    /// Rejected with `405 Method Not Allowed`.
+    #[cfg_attr(test, default)]
+    MethodNotAllowed,
+    /// Rejected with `403 Forbidden`.
+    Forbidden,
+}
+
+/// ## Timestamp Precision
+///
+/// The precision timestamps are truncated to.
+#[cfg_attr(test, derive(Default))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimestampPrecision {
+    /// Timestamps are truncated to the second.
+    #[cfg_attr(test, default)]
+    Second,
+    /// Timestamps are truncated to the millisecond.
+    Millisecond,
 }
 
 /// ## Object Store Config
@@ -98,11 +773,11 @@ pub enum ObjectStoreConfig {
     ///
     /// The S3 Object Storage information.
     S3(S3ObjectStoreConfig),
-    // Testing item, docs not needed.
-    #[expect(missing_docs)]
-    #[cfg(test)]
+    /// ## Memory
+    ///
+    /// Documents are kept in memory, and lost once the server stops (for local development).
     #[cfg_attr(test, default)]
-    Test,
+    Memory,
 }
 
 impl ObjectStoreConfig {
@@ -121,14 +796,25 @@ impl ObjectStoreConfig {
 
         match obs_type.as_str() {
             "MINIO" => Self::S3(S3ObjectStoreConfig::from_env()),
+            "MEMORY" => Self::Memory,
             unknown => panic!("The OBS_TYPE `{unknown}` is unknown."),
         }
     }
+
+    /// The namespace every object key is placed under (if set).
+    pub fn key_prefix(&self) -> Option<&str> {
+        match self {
+            Self::S3(config) => config.key_prefix(),
+            Self::Memory => None,
+        }
+    }
 }
 
 /// ## S3 Object Store Config
 ///
 /// The S3 Object Storage information.
+#[cfg_attr(test, derive(Builder, Default))]
+#[cfg_attr(test, builder(default))]
 #[derive(Debug, Clone)]
 pub struct S3ObjectStoreConfig {
     /// The S3 Service URL.
@@ -137,9 +823,24 @@ pub struct S3ObjectStoreConfig {
     access_key: SecretString,
     /// The S3 Service Secret Key.
     secret_key: SecretString,
+    /// The S3 Service Region.
+    region: String,
+    /// Whether to use path style addressing, instead of virtual hosted addressing.
+    force_path_style: bool,
+    /// The `Cache-Control` stored alongside each documents contents (if set).
+    cache_control: Option<String>,
+    /// The namespace every object key is placed under (if set).
+    key_prefix: Option<String>,
 }
 
 impl S3ObjectStoreConfig {
+    // Testing item, docs not needed.
+    #[expect(missing_docs)]
+    #[cfg(test)]
+    pub fn test_builder() -> S3ObjectStoreConfigBuilder {
+        S3ObjectStoreConfigBuilder::default()
+    }
+
     /// ## From Env
     ///
     /// Create the configuration from environment values
@@ -158,6 +859,17 @@ impl S3ObjectStoreConfig {
             secret_key: std::env::var("OBS_SECRET_KEY")
                 .expect("OBS_SECRET_KEY environment variable must be set.")
                 .into(),
+            region: std::env::var("OBS_REGION").unwrap_or_else(|_| String::from("direct")),
+            force_path_style: std::env::var("OBS_FORCE_PATH_STYLE")
+                .ok()
+                .is_none_or(|v| v.parse().expect("OBS_FORCE_PATH_STYLE requires a boolean.")),
+            cache_control: std::env::var("OBS_CACHE_CONTROL")
+                .ok()
+                .filter(|v| !v.is_empty()),
+            key_prefix: std::env::var("OBS_KEY_PREFIX")
+                .ok()
+                .map(|v| v.trim_matches('/').to_string())
+                .filter(|v| !v.is_empty()),
         }
     }
 
@@ -175,8 +887,276 @@ impl S3ObjectStoreConfig {
     pub const fn secret_key(&self) -> &SecretString {
         &self.secret_key
     }
+
+    /// The S3 Service Region.
+    pub fn region(&self) -> &str {
+        &self.region
+    }
+
+    /// Whether to use path style addressing, instead of virtual hosted addressing.
+    pub const fn force_path_style(&self) -> bool {
+        self.force_path_style
+    }
+
+    /// The `Cache-Control` stored alongside each documents contents (if set).
+    pub fn cache_control(&self) -> Option<&str> {
+        self.cache_control.as_deref()
+    }
+
+    /// The namespace every object key is placed under (if set).
+    pub fn key_prefix(&self) -> Option<&str> {
+        self.key_prefix.as_deref()
+    }
 }
 
+/// ## Cors Config
+///
+/// The configuration information about CORS.
+#[cfg_attr(test, derive(Builder))]
+#[cfg_attr(test, builder(default))]
+#[derive(Debug, Clone)]
+pub struct CorsConfig {
+    /// Whether credentials are allowed on cross origin requests.
+    allow_credentials: bool,
+    /// The response headers exposed to cross origin requests.
+    expose_headers: Vec<String>,
+    /// The amount of seconds a preflight request can be cached for.
+    max_age: Option<u64>,
+}
+
+impl CorsConfig {
+    // Testing item, docs not needed.
+    #[expect(missing_docs)]
+    #[cfg(test)]
+    pub fn test_builder() -> CorsConfigBuilder {
+        CorsConfigBuilder::default()
+    }
+
+    /// ## From Env
+    ///
+    /// Create the configuration from environment values
+    ///
+    /// ## Panics
+    /// Panics if an environment value cannot be parsed to the expected type.
+    ///
+    /// ## Returns
+    /// Returns the [`CorsConfig`] object.
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+
+        Self {
+            allow_credentials: std::env::var("CORS_ALLOW_CREDENTIALS").ok().map_or(
+                defaults.allow_credentials,
+                |v| {
+                    v.parse()
+                        .expect("CORS_ALLOW_CREDENTIALS requires a boolean.")
+                },
+            ),
+            expose_headers: std::env::var("CORS_EXPOSE_HEADERS").ok().map_or(
+                defaults.expose_headers,
+                |v| {
+                    v.split(',')
+                        .map(str::trim)
+                        .filter(|header| !header.is_empty())
+                        .map(ToString::to_string)
+                        .collect()
+                },
+            ),
+            max_age: std::env::var("CORS_MAX_AGE")
+                .ok()
+                .map_or(defaults.max_age, |v| {
+                    Some(v.parse().expect("CORS_MAX_AGE requires an integer."))
+                }),
+        }
+    }
+
+    /// Whether credentials are allowed on cross origin requests.
+    pub const fn allow_credentials(&self) -> bool {
+        self.allow_credentials
+    }
+
+    /// The response headers exposed to cross origin requests.
+    pub fn expose_headers(&self) -> &[String] {
+        &self.expose_headers
+    }
+
+    /// The amount of seconds a preflight request can be cached for.
+    pub const fn max_age(&self) -> Option<u64> {
+        self.max_age
+    }
+}
+
+impl Default for CorsConfig {
+    fn default() -> Self {
+        Self {
+            allow_credentials: false,
+            expose_headers: vec![
+                String::from("ETag"),
+                String::from("Content-Range"),
+                String::from("Content-Disposition"),
+                String::from("X-Content-SHA256"),
+                String::from("X-Paste-Token"),
+                String::from("X-Applied-Defaults"),
+                String::from("X-Request-Id"),
+            ],
+            max_age: None,
+        }
+    }
+}
+
+/// ## Log Config
+///
+/// The configuration for logging.
+///
+/// This is loaded separately from [`Config`], as logging is set up before the application.
+#[cfg_attr(test, derive(Builder))]
+#[cfg_attr(test, builder(default))]
+#[derive(Debug, Clone)]
+pub struct LogConfig {
+    /// The directory log files are written to.
+    directory: String,
+    /// The maximum amount of log files kept.
+    max_files: usize,
+    /// How often a new log file is started.
+    rotation: LogRotation,
+    /// The level filter directives, in the same format as `RUST_LOG`.
+    level: String,
+    /// The OTLP endpoint traces are exported to (none disables exporting).
+    otlp_endpoint: Option<String>,
+}
+
+impl LogConfig {
+    // Testing item, docs not needed.
+    #[expect(missing_docs)]
+    #[cfg(test)]
+    pub fn test_builder() -> LogConfigBuilder {
+        LogConfigBuilder::default()
+    }
+
+    /// ## From Env
+    ///
+    /// Create the configuration from environment values
+    ///
+    /// ## Panics
+    /// Panics if an environment value cannot be parsed to the expected type.
+    ///
+    /// ## Returns
+    /// Returns the [`LogConfig`] object.
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+
+        Self {
+            directory: std::env::var("LOG_DIR").unwrap_or(defaults.directory),
+            max_files: std::env::var("LOG_MAX_FILES")
+                .ok()
+                .map_or(defaults.max_files, |v| {
+                    v.parse().expect("LOG_MAX_FILES requires an integer.")
+                }),
+            rotation: std::env::var("LOG_ROTATION")
+                .ok()
+                .map_or(defaults.rotation, |v| match v.to_lowercase().as_str() {
+                    "hourly" => LogRotation::Hourly,
+                    "daily" => LogRotation::Daily,
+                    _ => panic!("LOG_ROTATION requires either `hourly` or `daily`."),
+                }),
+            level: std::env::var("RUST_LOG").unwrap_or(defaults.level),
+            otlp_endpoint: std::env::var("OTEL_EXPORTER_OTLP_ENDPOINT")
+                .ok()
+                .filter(|v| !v.is_empty()),
+        }
+    }
+
+    /// The directory log files are written to.
+    pub fn directory(&self) -> &str {
+        &self.directory
+    }
+
+    /// The maximum amount of log files kept.
+    pub const fn max_files(&self) -> usize {
+        self.max_files
+    }
+
+    /// How often a new log file is started.
+    pub const fn rotation(&self) -> LogRotation {
+        self.rotation
+    }
+
+    /// The level filter directives, in the same format as `RUST_LOG`.
+    pub fn level(&self) -> &str {
+        &self.level
+    }
+
+    /// The OTLP endpoint traces are exported to (none disables exporting).
+    pub fn otlp_endpoint(&self) -> Option<&str> {
+        self.otlp_endpoint.as_deref()
+    }
+
+    /// ## Env Filter
+    ///
+    /// Build the level filter for the subscriber.
+    ///
+    /// ## Errors
+    ///
+    /// - [`ParseError`] - The level directives could not be parsed.
+    pub fn env_filter(&self) -> Result<EnvFilter, ParseError> {
+        EnvFilter::try_new(&self.level)
+    }
+
+    /// ## File Appender
+    ///
+    /// Build the rolling file appender that log files are written with.
+    ///
+    /// ## Errors
+    ///
+    /// - [`InitError`] - The log directory could not be created or written to.
+    pub fn file_appender(&self) -> Result<RollingFileAppender, InitError> {
+        RollingFileAppender::builder()
+            .rotation(self.rotation.into())
+            .max_log_files(self.max_files)
+            .filename_prefix("platy-paste")
+            .filename_suffix("log")
+            .build(&self.directory)
+    }
+}
+
+impl Default for LogConfig {
+    fn default() -> Self {
+        Self {
+            directory: String::from("./logs/"),
+            max_files: 25,
+            rotation: LogRotation::Daily,
+            level: String::from("info"),
+            otlp_endpoint: None,
+        }
+    }
+}
+
+/// ## Log Rotation
+///
+/// How often a new log file is started.
+#[cfg_attr(test, derive(Default))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogRotation {
+    /// A new log file is started every hour.
+    Hourly,
+    /// A new log file is started every day.
+    #[cfg_attr(test, default)]
+    Daily,
+}
+
+impl From<LogRotation> for Rotation {
+    fn from(value: LogRotation) -> Self {
+        match value {
+            LogRotation::Hourly => Self::HOURLY,
+            LogRotation::Daily => Self::DAILY,
+        }
+    }
+}
+
+/// The room left for the multipart overhead (boundaries and headers),
+/// when the maximum request body size is derived from the maximum total document size.
+const REQUEST_BODY_HEADROOM: usize = 1_048_576;
+
 /// ## Size Limit Config
 ///
 /// The configuration information about size limits.
@@ -186,10 +1166,12 @@ impl S3ObjectStoreConfig {
 pub struct SizeLimitConfig {
     /// The default expiry for pastes.
     default_expiry_hours: Option<usize>,
-    /// The default value for maximum views.
+    /// The default value for maximum views, applied only when a new paste omits them (regardless of its expiry).
     default_maximum_views: Option<usize>,
-    /// The default value for the pastes name.
+    /// The default value for the pastes name, as a template (supporting `{snowflake}` and `{date}`).
     default_paste_name: Option<String>,
+    /// The default mime for documents uploaded without a content type.
+    default_document_mime: String,
     /// The minimum expiry hours for pastes.
     minimum_expiry_hours: Option<usize>,
     /// The minimum allowed documents in a paste.
@@ -212,8 +1194,22 @@ pub struct SizeLimitConfig {
     maximum_total_document_size: usize,
     /// The maximum size of a document name (bytes).
     maximum_document_name_size: usize,
+    /// The maximum size of the object store key a documents contents are stored under (bytes).
+    maximum_object_key_size: usize,
     /// The maximum size of the paste name (bytes).
     maximum_paste_name_size: usize,
+    /// The maximum size of a document that can be diffed (bytes).
+    maximum_diff_document_size: usize,
+    /// The maximum amount of pastes that can be fetched in a single batch.
+    maximum_batch_paste_count: usize,
+    /// The maximum size of a request body (bytes).
+    ///
+    /// Defaults to the maximum total document size, with room for the multipart overhead.
+    maximum_request_body_size: Option<usize>,
+    /// The minimum size of each chunk of a resumable upload, other than the last (bytes).
+    minimum_upload_chunk_size: usize,
+    /// The maximum document size for each mime pattern, in place of the maximum document size (bytes).
+    mime_size_limits: Vec<(String, usize)>,
 }
 
 impl SizeLimitConfig {
@@ -260,6 +1256,8 @@ impl SizeLimitConfig {
                 default_paste_name: std::env::var("DEFAULT_PASTE_NAME")
                     .ok()
                     .map_or(defaults.default_paste_name, Some),
+                default_document_mime: std::env::var("DEFAULT_DOCUMENT_MIME")
+                    .unwrap_or(defaults.default_document_mime),
                 minimum_expiry_hours: std::env::var("MINIMUM_EXPIRY_HOURS").ok().map_or(
                     defaults.minimum_expiry_hours,
                     |v| {
@@ -335,6 +1333,13 @@ impl SizeLimitConfig {
                         v.parse()
                             .expect("MAXIMUM_DOCUMENT_NAME_SIZE requires an integer.")
                     }),
+                maximum_object_key_size: std::env::var("MAXIMUM_OBJECT_KEY_SIZE").ok().map_or(
+                    defaults.maximum_object_key_size,
+                    |v| {
+                        v.parse()
+                            .expect("MAXIMUM_OBJECT_KEY_SIZE requires an integer.")
+                    },
+                ),
                 maximum_paste_name_size: std::env::var("MAXIMUM_PASTE_NAME_SIZE").ok().map_or(
                     defaults.maximum_paste_name_size,
                     |v| {
@@ -342,6 +1347,47 @@ impl SizeLimitConfig {
                             .expect("MAXIMUM_PASTE_NAME_SIZE requires an integer.")
                     },
                 ),
+                maximum_diff_document_size: std::env::var("MAXIMUM_DIFF_DOCUMENT_SIZE")
+                    .ok()
+                    .map_or(defaults.maximum_diff_document_size, |v| {
+                        v.parse()
+                            .expect("MAXIMUM_DIFF_DOCUMENT_SIZE requires an integer.")
+                    }),
+                maximum_batch_paste_count: std::env::var("MAXIMUM_BATCH_PASTE_COUNT").ok().map_or(
+                    defaults.maximum_batch_paste_count,
+                    |v| {
+                        v.parse()
+                            .expect("MAXIMUM_BATCH_PASTE_COUNT requires an integer.")
+                    },
+                ),
+                maximum_request_body_size: std::env::var("MAXIMUM_REQUEST_BODY_SIZE").ok().map_or(
+                    defaults.maximum_request_body_size,
+                    |v| {
+                        Some(
+                            v.parse()
+                                .expect("MAXIMUM_REQUEST_BODY_SIZE requires an integer."),
+                        )
+                    },
+                ),
+                minimum_upload_chunk_size: std::env::var("MINIMUM_UPLOAD_CHUNK_SIZE").ok().map_or(
+                    defaults.minimum_upload_chunk_size,
+                    |v| {
+                        v.parse()
+                            .expect("MINIMUM_UPLOAD_CHUNK_SIZE requires an integer.")
+                    },
+                ),
+                mime_size_limits: std::env::vars()
+                    .filter_map(|(key, value)| {
+                        let pattern = key.strip_prefix("MIME_SIZE_LIMIT_")?.to_lowercase();
+
+                        Some((
+                            pattern,
+                            value
+                                .parse()
+                                .expect("MIME_SIZE_LIMIT_{mime} requires an integer."),
+                        ))
+                    })
+                    .collect(),
             };
 
         if let Some(default_expiry_hours) = value.default_expiry_hours {
@@ -380,6 +1426,13 @@ impl SizeLimitConfig {
         );
 
         if let Some(default_paste_name) = &value.default_paste_name {
+            // Checked with the longest ID a paste can have, as the placeholders are expanded on creation.
+            let default_paste_name = expand_paste_name(
+                default_paste_name,
+                &Snowflake::new(i64::MAX as u64),
+                &Utc::now(),
+            );
+
             assert!(
                 default_paste_name.len() > value.minimum_paste_name_size,
                 "The DEFAULT_PASTE_NAME must be equal to or greater than the MINIMUM_PASTE_NAME_SIZE"
@@ -391,6 +1444,16 @@ impl SizeLimitConfig {
             );
         }
 
+        assert!(
+            value.default_document_mime.parse::<mime::Mime>().is_ok(),
+            "The DEFAULT_DOCUMENT_MIME must be a valid mime."
+        );
+
+        assert!(
+            !contains_mime(UNSUPPORTED_MIMES, &value.default_document_mime),
+            "The DEFAULT_DOCUMENT_MIME must not be an unsupported mime."
+        );
+
         assert!(
             value.minimum_total_document_count > 0,
             "The MINIMUM_TOTAL_DOCUMENT_COUNT must be greater than 0."
@@ -439,16 +1502,21 @@ impl SizeLimitConfig {
         self.default_expiry_hours
     }
 
-    /// The default value for maximum views.
+    /// The default value for maximum views, applied only when a new paste omits them (regardless of its expiry).
     pub const fn default_maximum_views(&self) -> Option<usize> {
         self.default_maximum_views
     }
 
-    /// The default value for the pastes name.
+    /// The default value for the pastes name, as a template (supporting `{snowflake}` and `{date}`).
     pub fn default_paste_name(&self) -> Option<&str> {
         self.default_paste_name.as_deref()
     }
 
+    /// The default mime for documents uploaded without a content type.
+    pub fn default_document_mime(&self) -> &str {
+        &self.default_document_mime
+    }
+
     /// The minimum expiry hours for pastes.
     pub const fn minimum_expiry_hours(&self) -> Option<usize> {
         self.minimum_expiry_hours
@@ -504,10 +1572,71 @@ impl SizeLimitConfig {
         self.maximum_document_name_size
     }
 
+    /// The maximum size of the object store key a documents contents are stored under (bytes).
+    pub const fn maximum_object_key_size(&self) -> usize {
+        self.maximum_object_key_size
+    }
+
     /// The maximum size of the paste name (bytes).
     pub const fn maximum_paste_name_size(&self) -> usize {
         self.maximum_paste_name_size
     }
+
+    /// The maximum size of a document that can be diffed (bytes).
+    pub const fn maximum_diff_document_size(&self) -> usize {
+        self.maximum_diff_document_size
+    }
+
+    /// The maximum amount of pastes that can be fetched in a single batch.
+    pub const fn maximum_batch_paste_count(&self) -> usize {
+        self.maximum_batch_paste_count
+    }
+
+    /// The maximum size of a request body (bytes).
+    ///
+    /// Defaults to the maximum total document size, with room for the multipart overhead.
+    pub const fn maximum_request_body_size(&self) -> usize {
+        match self.maximum_request_body_size {
+            Some(size) => size,
+            None => self
+                .maximum_total_document_size
+                .saturating_add(REQUEST_BODY_HEADROOM),
+        }
+    }
+
+    /// The minimum size of each chunk of a resumable upload, other than the last (bytes).
+    pub const fn minimum_upload_chunk_size(&self) -> usize {
+        self.minimum_upload_chunk_size
+    }
+
+    /// The maximum document size for each mime pattern, in place of the maximum document size (bytes).
+    pub fn mime_size_limits(&self) -> &[(String, usize)] {
+        &self.mime_size_limits
+    }
+
+    /// Maximum Document Size For.
+    ///
+    /// The maximum size of a document with the mime provided (bytes).
+    ///
+    /// An exact pattern takes priority over a wildcard (such as `text/*`),
+    /// falling back to the maximum document size when neither matches.
+    ///
+    /// ## Arguments
+    ///
+    /// - `mime` - The mime of the document.
+    pub fn maximum_document_size_for(&self, mime: &str) -> usize {
+        let essence = mime.split(';').next().unwrap_or(mime).trim();
+
+        self.mime_size_limits
+            .iter()
+            .find(|(pattern, _)| pattern.eq_ignore_ascii_case(essence))
+            .or_else(|| {
+                self.mime_size_limits
+                    .iter()
+                    .find(|(pattern, _)| contains_mime(&[pattern.as_str()], essence))
+            })
+            .map_or(self.maximum_document_size, |(_, size)| *size)
+    }
 }
 
 impl Default for SizeLimitConfig {
@@ -516,6 +1645,7 @@ impl Default for SizeLimitConfig {
             default_expiry_hours: None,
             default_maximum_views: None,
             default_paste_name: None,
+            default_document_mime: String::from("text/plain"),
             minimum_expiry_hours: None,
             minimum_total_document_count: 1,
             minimum_document_size: 1,
@@ -527,7 +1657,55 @@ impl Default for SizeLimitConfig {
             maximum_document_size: 5_000_000,
             maximum_total_document_size: 10_000_000,
             maximum_document_name_size: 50,
+            maximum_object_key_size: 1024,
             maximum_paste_name_size: 50,
+            maximum_diff_document_size: 1_000_000,
+            maximum_batch_paste_count: 50,
+            maximum_request_body_size: None,
+            minimum_upload_chunk_size: 5_242_880,
+            mime_size_limits: Vec::new(),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use tracing_subscriber::filter::LevelFilter;
+
+    use super::*;
+
+    #[test]
+    fn test_log_config() {
+        let config = LogConfig::test_builder()
+            .directory(String::from("./test-logs/"))
+            .max_files(5)
+            .rotation(LogRotation::Hourly)
+            .level(String::from("warn,platy_paste=debug"))
+            .build()
+            .expect("Failed to build log config.");
+
+        assert_eq!(
+            Rotation::from(config.rotation()),
+            Rotation::HOURLY,
+            "Rotation does not match."
+        );
+
+        let filter = config.env_filter().expect("Failed to build env filter.");
+
+        assert_eq!(
+            filter.max_level_hint(),
+            Some(LevelFilter::DEBUG),
+            "Filter level does not match."
+        );
+    }
+
+    #[test]
+    fn test_log_config_invalid_level() {
+        let config = LogConfig::test_builder()
+            .level(String::from("platy_paste=loud"))
+            .build()
+            .expect("Failed to build log config.");
+
+        assert!(config.env_filter().is_err(), "Invalid level was accepted.");
+    }
+}
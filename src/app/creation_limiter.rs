@@ -0,0 +1,85 @@
+//! The creation limiter, used to cap the amount of pastes created by each client.
+
+use std::{
+    collections::HashMap,
+    net::IpAddr,
+    time::{Duration, Instant},
+};
+
+use tokio::sync::Mutex;
+
+/// ## Creation Window
+///
+/// The window the maximum amount of creations applies to.
+const CREATION_WINDOW: Duration = Duration::from_hours(24);
+
+/// ## Creation Limiter
+///
+/// Tracks the amount of pastes each client has created within the last day.
+pub struct CreationLimiter {
+    /// The maximum amount of pastes a client can create within the window (none disables the limit).
+    maximum: Option<usize>,
+    /// When each clients window started, and the amount of pastes created within it.
+    creations: Mutex<HashMap<IpAddr, (Instant, usize)>>,
+}
+
+impl CreationLimiter {
+    /// New.
+    ///
+    /// Create a new [`CreationLimiter`] object.
+    ///
+    /// ## Arguments
+    ///
+    /// - `maximum` - The maximum amount of pastes a client can create each day (none disables the limit).
+    pub fn new(maximum: Option<usize>) -> Self {
+        Self {
+            maximum,
+            creations: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Record Creation.
+    ///
+    /// Record a client creating a paste.
+    ///
+    /// ## Arguments
+    ///
+    /// - `address` - The address of the client creating the paste.
+    ///
+    /// ## Returns
+    ///
+    /// Returns [`true`] if the creation is allowed, otherwise returns [`false`].
+    pub async fn record_creation(&self, address: IpAddr) -> bool {
+        let Some(maximum) = self.maximum else {
+            return true;
+        };
+
+        let now = Instant::now();
+        let mut creations = self.creations.lock().await;
+
+        creations.retain(|_, (started_at, _)| now.duration_since(*started_at) < CREATION_WINDOW);
+
+        let (_, count) = creations.entry(address).or_insert((now, 0));
+
+        if *count >= maximum {
+            return false;
+        }
+
+        *count += 1;
+
+        true
+    }
+
+    /// Release Creation.
+    ///
+    /// Release a creation recorded for a client, as the paste was not created.
+    ///
+    /// ## Arguments
+    ///
+    /// - `address` - The address of the client that failed to create the paste.
+    pub async fn release_creation(&self, address: IpAddr) {
+        if let Some((_, count)) = self.creations.lock().await.get_mut(&address) {
+            *count = count.saturating_sub(1);
+        }
+    }
+}
@@ -6,12 +6,8 @@ use chrono::{TimeDelta, Utc};
 use tokio::sync::{mpsc, oneshot};
 
 use crate::{
-    app::{
-        config::Config,
-        database::Database,
-        object_store::{ObjectStore, ObjectStoreExt as _},
-    },
-    models::{DtUtc, document::Document, errors::HandlerError, paste::Paste, snowflake::Snowflake},
+    app::{config::Config, database::Database, object_store::ObjectStore},
+    models::{DtUtc, errors::HandlerError, paste::Paste, snowflake::Snowflake},
 };
 
 /// ## Default Timeout
@@ -25,6 +21,11 @@ const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
 /// The more pastes collected, the more memory that can be consumed.
 const COLLECTION_OFFSET: TimeDelta = TimeDelta::hours(1);
 
+/// ## Sweep Lock Key
+///
+/// The advisory lock key held while sweeping expired and deleted pastes.
+const SWEEP_LOCK_KEY: i64 = 0x0070_6173_7465;
+
 #[derive(Debug)]
 enum HandlerMessage {
     /// ## Get
@@ -64,6 +65,7 @@ enum HandlerMessage {
 #[derive(Debug, Clone)]
 pub struct Handler {
     sender: Option<mpsc::Sender<HandlerMessage>>,
+    disabled: bool,
 }
 
 impl Handler {
@@ -72,13 +74,19 @@ impl Handler {
     /// Create a new [`handler`] object.
     #[expect(clippy::new_without_default)]
     pub const fn new() -> Self {
-        Self { sender: None }
+        Self {
+            sender: None,
+            disabled: false,
+        }
     }
 
     /// ## Start
     ///
     /// Start up the handlers actor.
     ///
+    /// If the expiry sweep is disabled, the actor is not spawned,
+    /// and all further messages are ignored.
+    ///
     /// ## Errors
     ///
     /// Errors if the handler has already been started.
@@ -88,10 +96,16 @@ impl Handler {
         object_store: ObjectStore,
         config: Config,
     ) -> Result<(), HandlerError> {
-        if self.sender.is_some() {
+        if self.sender.is_some() || self.disabled {
             return Err(HandlerError::AlreadyStarted);
         }
 
+        if !config.enable_expiry_sweep() {
+            tracing::info!("The expiry sweep is disabled, the handler will not be started.");
+            self.disabled = true;
+            return Ok(());
+        }
+
         let (sender, receiver) = mpsc::channel(10);
 
         let actor = HandlerActor::new(database, object_store, config, receiver);
@@ -130,6 +144,10 @@ impl Handler {
     /// ## Errors
     /// Errors if it times out on a response, or the handler was never started.
     pub async fn add(&self, id: &Snowflake, expiry: DtUtc) -> Result<(), HandlerError> {
+        if self.disabled {
+            return Ok(());
+        }
+
         let (sender, receiver) = oneshot::channel();
 
         let message = HandlerMessage::Add {
@@ -155,6 +173,10 @@ impl Handler {
     /// ## Errors
     /// Errors if it times out on a response, or the handler was never started.
     pub async fn remove(&self, id: &Snowflake) -> Result<(), HandlerError> {
+        if self.disabled {
+            return Ok(());
+        }
+
         let (sender, receiver) = oneshot::channel();
 
         let message = HandlerMessage::Remove { id: *id, sender };
@@ -171,6 +193,10 @@ impl Handler {
     /// ## Errors
     /// Errors if it times out on a response, or the handler was never started.
     pub async fn close(&self) -> Result<(), HandlerError> {
+        if self.disabled {
+            return Ok(());
+        }
+
         let (sender, receiver) = oneshot::channel();
 
         let message = HandlerMessage::Close(sender);
@@ -186,7 +212,6 @@ struct HandlerActor {
     receiver: mpsc::Receiver<HandlerMessage>,
     database: Database,
     object_store: ObjectStore,
-    #[expect(unused)]
     config: Config,
     last_checked: DtUtc,
     nearby: HashMap<Snowflake, DtUtc>,
@@ -227,28 +252,61 @@ impl HandlerActor {
             }
         }
 
+        // Pastes are also expired once they reach the absolute maximum age, even without an expiry.
+        if let Some(absolute_max_age_hours) = self.config.absolute_max_age_hours() {
+            let maximum_age = TimeDelta::hours(absolute_max_age_hours as i64);
+
+            let pastes =
+                Paste::fetch_created_before(self.database.pool(), &(end - maximum_age)).await?;
+
+            for paste in pastes {
+                let maximum = *paste.creation() + maximum_age;
+                let expiry = paste
+                    .expiry()
+                    .map_or(maximum, |expiry| (*expiry).min(maximum));
+
+                self.nearby.insert(*paste.id(), expiry);
+            }
+        }
+
         self.last_checked = end;
 
         Ok(())
     }
 
-    /// ## Delete Paste
+    /// ## Purge Deleted Pastes
     ///
-    /// Completely delete a paste from its ID.
-    async fn delete_paste(&self, id: &Snowflake) -> Result<(), HandlerError> {
-        let documents = match Document::fetch_all(self.database.pool(), id).await {
-            Ok(documents) => documents,
-            Err(err) => {
-                tracing::warn!("Failed to fetch documents for the paste of: {id}. Error: {err}");
-                Paste::delete(self.database.pool(), id).await?;
-                return Ok(());
+    /// Completely delete soft deleted pastes, whose grace period has passed.
+    async fn purge_deleted_pastes(&self) -> Result<(), HandlerError> {
+        let grace_period = TimeDelta::hours(self.config.deletion_grace_hours() as i64);
+
+        let pastes =
+            Paste::fetch_deleted_before(self.database.pool(), &(Utc::now() - grace_period)).await?;
+
+        for paste in pastes {
+            let paste_id = paste.id();
+            match self.delete_paste(paste_id).await {
+                Ok(()) => {
+                    tracing::debug!("The deleted paste ({paste_id}) has successfully been purged.");
+                }
+                Err(err) => {
+                    tracing::warn!(
+                        "The deleted paste ({paste_id}) could not be purged. Error: {err}"
+                    );
+                }
             }
-        };
+        }
 
-        Paste::delete(self.database.pool(), id).await?;
+        Ok(())
+    }
 
-        for document in documents {
-            self.object_store.delete_document(&document).await?;
+    /// ## Delete Paste
+    ///
+    /// Completely delete a paste from its ID.
+    async fn delete_paste(&self, id: &Snowflake) -> Result<(), HandlerError> {
+        // Another instance may have already deleted the paste (and its documents).
+        if !Paste::delete_cascade(&self.database, &self.object_store, id).await? {
+            tracing::debug!("The paste ({id}) was already deleted.");
         }
 
         Ok(())
@@ -257,7 +315,13 @@ impl HandlerActor {
     /// ## Load Pastes
     ///
     /// Loads new pastes via [`HandlerActor::update_expired_tasks`] or backs off.
+    ///
+    /// Soft deleted pastes past their grace period are also purged.
     async fn load_pastes(&mut self) {
+        if let Err(err) = self.purge_deleted_pastes().await {
+            tracing::warn!("Failed to purge deleted pastes. Error: {err}");
+        }
+
         if let Err(err) = self.update_expired_pastes().await {
             self.attempts += 1;
 
@@ -272,35 +336,51 @@ impl HandlerActor {
         }
     }
 
-    pub async fn run(mut self) {
-        self.load_pastes().await;
+    /// ## Sweep
+    ///
+    /// Loads new pastes (if required), and deletes any pastes that have expired.
+    async fn sweep(&mut self, current: DtUtc) {
+        if current >= self.last_checked {
+            self.load_pastes().await;
+        }
+
+        let mut deleted_paste_ids = Vec::new();
+        for (paste_id, expiry) in &self.nearby {
+            if current >= *expiry {
+                match self.delete_paste(paste_id).await {
+                    Ok(()) => {
+                        deleted_paste_ids.push(*paste_id);
+                        tracing::debug!("The paste ({paste_id}) has successfully been deleted.");
+                    }
+                    Err(err) => {
+                        tracing::warn!("The paste ({paste_id}) could not be deleted. Error: {err}");
+                    }
+                }
+            }
+        }
 
+        for paste_id in deleted_paste_ids {
+            self.nearby.remove(&paste_id);
+        }
+    }
+
+    pub async fn run(mut self) {
         loop {
             let current = Utc::now();
 
-            if current >= self.last_checked {
-                self.load_pastes().await;
-            }
+            // Only a single instance sharing the database should sweep at a time.
+            match self.database.try_advisory_lock(SWEEP_LOCK_KEY).await {
+                Ok(Some(lock)) => {
+                    self.sweep(current).await;
 
-            let mut deleted_paste_ids = Vec::new();
-            for (paste_id, expiry) in &self.nearby {
-                if current >= *expiry {
-                    match self.delete_paste(paste_id).await {
-                        Ok(()) => {
-                            deleted_paste_ids.push(*paste_id);
-                            tracing::debug!(
-                                "The paste ({paste_id}) has successfully been deleted."
-                            );
-                        }
-                        Err(err) => tracing::warn!(
-                            "The paste ({paste_id}) could not be deleted. Error: {err}"
-                        ),
+                    if let Err(err) = lock.release().await {
+                        tracing::warn!("Failed to release the sweep lock. Error: {err}");
                     }
                 }
-            }
-
-            for paste_id in deleted_paste_ids {
-                self.nearby.remove(&paste_id);
+                Ok(None) => {
+                    tracing::trace!("The sweep lock is held by another instance, skipping sweep.");
+                }
+                Err(err) => tracing::warn!("Failed to acquire the sweep lock. Error: {err}"),
             }
 
             if let Some(message) = self.receiver.recv().await {
@@ -351,7 +431,8 @@ mod tests {
     use bytes::Bytes;
     use sqlx::PgPool;
 
-    use crate::app::object_store::TestObjectStore;
+    use crate::app::object_store::{MemoryObjectStore, ObjectStoreExt as _};
+    use crate::models::document::Document;
 
     use super::*;
 
@@ -361,8 +442,8 @@ mod tests {
             .build()
             .expect("Failed to build config.");
         let database = Database::from_pool(pool);
-        let test_object_store = TestObjectStore::new();
-        let object_store = ObjectStore::Test(test_object_store.clone());
+        let test_object_store = MemoryObjectStore::new();
+        let object_store = ObjectStore::Memory(test_object_store.clone());
 
         let now = Utc::now();
         let no_expiry_paste_id = Snowflake::new(1);
@@ -370,7 +451,17 @@ mod tests {
         let nearby_paste_id = Snowflake::new(3);
         let expired_paste_id = Snowflake::new(4);
 
-        let no_expiry_paste = Paste::new(no_expiry_paste_id, None, now, None, None, 0, None);
+        let no_expiry_paste = Paste::new(
+            no_expiry_paste_id,
+            None,
+            now,
+            None,
+            None,
+            0,
+            None,
+            None,
+            None,
+        );
 
         let future_paste = Paste::new(
             future_paste_id,
@@ -380,6 +471,8 @@ mod tests {
             Some(now + TimeDelta::hours(2)),
             0,
             None,
+            None,
+            None,
         );
 
         let nearby_paste = Paste::new(
@@ -390,6 +483,8 @@ mod tests {
             Some(now + TimeDelta::minutes(30)),
             0,
             None,
+            None,
+            None,
         );
 
         let expired_paste = Paste::new(
@@ -400,6 +495,8 @@ mod tests {
             Some(now - TimeDelta::minutes(15)),
             0,
             None,
+            None,
+            None,
         );
 
         no_expiry_paste
@@ -425,6 +522,8 @@ mod tests {
             "text/plain",
             "test.txt",
             2874,
+            None,
+            &[],
         );
 
         let document_2 = Document::new(
@@ -433,6 +532,8 @@ mod tests {
             "application/json",
             "cool.json",
             345,
+            None,
+            &[],
         );
 
         let document_3 = Document::new(
@@ -441,6 +542,8 @@ mod tests {
             "text/rust",
             "paste.rs",
             74211,
+            None,
+            &[],
         );
 
         let document_4 = Document::new(
@@ -449,6 +552,8 @@ mod tests {
             "text/css",
             "example.css",
             94,
+            None,
+            &[],
         );
 
         document_1
@@ -550,13 +655,122 @@ mod tests {
         handler.close().await.expect("Failed to close handler.");
     }
 
+    #[sqlx::test]
+    async fn test_purge_deleted(pool: PgPool) {
+        let config = Config::test_builder()
+            .deletion_grace_hours(1)
+            .build()
+            .expect("Failed to build config.");
+        let database = Database::from_pool(pool);
+        let test_object_store = MemoryObjectStore::new();
+        let object_store = ObjectStore::Memory(test_object_store.clone());
+
+        let now = Utc::now();
+        let recent_paste_id = Snowflake::new(13);
+        let purged_paste_id = Snowflake::new(14);
+
+        let recent_paste = Paste::new(recent_paste_id, None, now, None, None, 0, None, None, None);
+        let purged_paste = Paste::new(purged_paste_id, None, now, None, None, 0, None, None, None);
+
+        recent_paste
+            .insert(database.pool())
+            .await
+            .expect("Failed to insert paste.");
+        purged_paste
+            .insert(database.pool())
+            .await
+            .expect("Failed to insert paste.");
+
+        let document_1 = Document::new(
+            Snowflake::new(15),
+            recent_paste_id,
+            "text/plain",
+            "test.txt",
+            15,
+            None,
+            &[],
+        );
+
+        let document_2 = Document::new(
+            Snowflake::new(16),
+            purged_paste_id,
+            "text/plain",
+            "test.txt",
+            15,
+            None,
+            &[],
+        );
+
+        document_1
+            .insert(database.pool())
+            .await
+            .expect("Failed to insert document 1.");
+        object_store
+            .create_document(&document_1, Bytes::from("Test Document 1"))
+            .await
+            .expect("Failed to create document 1");
+        document_2
+            .insert(database.pool())
+            .await
+            .expect("Failed to insert document 2.");
+        object_store
+            .create_document(&document_2, Bytes::from("Test Document 2"))
+            .await
+            .expect("Failed to create document 2");
+
+        Paste::soft_delete(database.pool(), &recent_paste_id)
+            .await
+            .expect("Failed to soft delete paste.");
+        Paste::soft_delete(database.pool(), &purged_paste_id)
+            .await
+            .expect("Failed to soft delete paste.");
+
+        sqlx::query("UPDATE pastes SET deleted_at = $1 WHERE id = $2")
+            .bind(now - TimeDelta::hours(2))
+            .bind(i64::from(purged_paste_id))
+            .execute(database.pool())
+            .await
+            .expect("Failed to update deletion time.");
+
+        let mut handler = Handler::new();
+        handler
+            .start(database.clone(), object_store.clone(), config.clone())
+            .expect("Failed to start handler.");
+
+        tokio::time::sleep(Duration::from_millis(500)).await;
+
+        let recent_paste_db = Paste::fetch_deleted(database.pool(), &recent_paste_id)
+            .await
+            .expect("Failed to fetch paste.");
+        assert!(recent_paste_db.is_some(), "Recent paste should exist.");
+
+        let purged_paste_db = Paste::fetch_deleted(database.pool(), &purged_paste_id)
+            .await
+            .expect("Failed to fetch paste.");
+        assert!(purged_paste_db.is_none(), "Purged paste should not exist.");
+
+        let document_1_content = test_object_store
+            .fetch_document(&document_1)
+            .await
+            .expect("Failed to retrieve document");
+        let document_2_content = test_object_store
+            .fetch_document(&document_2)
+            .await
+            .expect("Failed to retrieve document");
+
+        assert!(document_1_content.is_some(), "Document 1 should exist.");
+        assert!(document_2_content.is_none(), "Document 2 should not exist.");
+
+        handler.close().await.expect("Failed to close handler.");
+    }
+
     #[sqlx::test]
     async fn test_add(pool: PgPool) {
         let config = Config::test_builder()
             .build()
             .expect("Failed to build config.");
         let database = Database::from_pool(pool);
-        let object_store = ObjectStore::Test(TestObjectStore::new());
+        let object_store = ObjectStore::Memory(MemoryObjectStore::new());
 
         let now = Utc::now();
         let paste_id_1 = Snowflake::new(9);
@@ -570,6 +784,8 @@ mod tests {
             Some(now + TimeDelta::minutes(30)),
             0,
             None,
+            None,
+            None,
         );
 
         paste_1
@@ -610,6 +826,8 @@ mod tests {
             Some(now - TimeDelta::minutes(30)),
             0,
             None,
+            None,
+            None,
         );
 
         paste_2
@@ -646,7 +864,7 @@ mod tests {
             .build()
             .expect("Failed to build config.");
         let database = Database::from_pool(pool);
-        let object_store = ObjectStore::Test(TestObjectStore::new());
+        let object_store = ObjectStore::Memory(MemoryObjectStore::new());
 
         let now = Utc::now();
         let paste_id_1 = Snowflake::new(11);
@@ -659,6 +877,8 @@ mod tests {
             Some(now + TimeDelta::minutes(30)),
             0,
             None,
+            None,
+            None,
         );
 
         paste_1
@@ -720,7 +940,7 @@ mod tests {
             .build()
             .expect("Failed to build config.");
         let database = Database::from_pool(pool);
-        let object_store = ObjectStore::Test(TestObjectStore::new());
+        let object_store = ObjectStore::Memory(MemoryObjectStore::new());
 
         let mut handler = Handler::new();
         assert!(
@@ -740,6 +960,8 @@ mod tests {
             Some(now + TimeDelta::minutes(30)),
             0,
             None,
+            None,
+            None,
         );
 
         paste_1
@@ -779,4 +1001,122 @@ mod tests {
 
         handler.close().await.expect_err("Failed to close handler.");
     }
+
+    #[sqlx::test]
+    async fn test_absolute_max_age(pool: PgPool) {
+        let config = Config::test_builder()
+            .absolute_max_age_hours(Some(1))
+            .build()
+            .expect("Failed to build config.");
+        let database = Database::from_pool(pool);
+        let object_store = ObjectStore::Memory(MemoryObjectStore::new());
+
+        let now = Utc::now();
+        let old_paste_id = Snowflake::new(18);
+        let new_paste_id = Snowflake::new(19);
+
+        let old_paste = Paste::new(
+            old_paste_id,
+            None,
+            now - TimeDelta::hours(2),
+            None,
+            None,
+            0,
+            None,
+            None,
+            None,
+        );
+
+        let new_paste = Paste::new(new_paste_id, None, now, None, None, 0, None, None, None);
+
+        old_paste
+            .insert(database.pool())
+            .await
+            .expect("Failed to insert old paste.");
+        new_paste
+            .insert(database.pool())
+            .await
+            .expect("Failed to insert new paste.");
+
+        let mut handler = Handler::new();
+        handler
+            .start(database.clone(), object_store.clone(), config.clone())
+            .expect("Failed to start handler.");
+
+        let (sender, receiver) = oneshot::channel();
+        handler
+            .send(HandlerMessage::Get(sender))
+            .await
+            .expect("Failed to send get message.");
+        let result = tokio::time::timeout(DEFAULT_TIMEOUT, receiver)
+            .await
+            .expect("Timed out on request.")
+            .expect("Failed to receive nearby tasks")
+            .expect("Failed to get nearby tasks");
+        assert!(!result.contains_key(&old_paste_id));
+        assert!(!result.contains_key(&new_paste_id));
+
+        let old_paste_db = Paste::fetch(database.pool(), &old_paste_id)
+            .await
+            .expect("Failed to fetch paste.");
+        assert!(old_paste_db.is_none(), "Old paste should be swept.");
+
+        let new_paste_db = Paste::fetch(database.pool(), &new_paste_id)
+            .await
+            .expect("Failed to fetch paste.");
+        assert!(new_paste_db.is_some(), "New paste should exist.");
+
+        handler.close().await.expect("Failed to close handler.");
+    }
+
+    #[sqlx::test]
+    async fn test_expiry_sweep_disabled(pool: PgPool) {
+        let config = Config::test_builder()
+            .enable_expiry_sweep(false)
+            .build()
+            .expect("Failed to build config.");
+        let database = Database::from_pool(pool);
+        let object_store = ObjectStore::Memory(MemoryObjectStore::new());
+
+        let now = Utc::now();
+        let expired_paste_id = Snowflake::new(17);
+
+        let expired_paste = Paste::new(
+            expired_paste_id,
+            None,
+            now,
+            None,
+            Some(now - TimeDelta::minutes(15)),
+            0,
+            None,
+            None,
+            None,
+        );
+
+        expired_paste
+            .insert(database.pool())
+            .await
+            .expect("Failed to insert paste.");
+
+        let mut handler = Handler::new();
+        handler
+            .start(database.clone(), object_store.clone(), config.clone())
+            .expect("Failed to start handler.");
+
+        assert!(handler.sender.is_none(), "The handler actor was spawned.");
+
+        handler
+            .add(&expired_paste_id, now - TimeDelta::minutes(15))
+            .await
+            .expect("Failed to add paste.");
+
+        tokio::time::sleep(Duration::from_millis(500)).await;
+
+        let expired_paste_db = Paste::fetch(database.pool(), &expired_paste_id)
+            .await
+            .expect("Failed to fetch paste.");
+        assert!(expired_paste_db.is_some(), "Expired paste should exist.");
+
+        handler.close().await.expect("Failed to close handler.");
+    }
 }
@@ -2,6 +2,13 @@
 
 pub mod application;
 pub mod config;
+pub mod creation_limiter;
 pub mod database;
 pub mod handler;
 pub mod object_store;
+pub mod shutdown;
+pub mod source;
+pub mod telemetry;
+pub mod view_tracker;
+pub mod webhook;
+pub mod write_limiter;
@@ -2,24 +2,40 @@
 use aws_config::{BehaviorVersion, Region};
 
 use aws_sdk_s3::{
-    Client as S3Client, Config as S3Config, config::Credentials, error::SdkError,
-    operation::head_bucket::HeadBucketError, primitives::ByteStream,
+    Client as S3Client, Config as S3Config,
+    config::Credentials,
+    error::SdkError,
+    operation::{get_object::GetObjectOutput, head_bucket::HeadBucketError},
+    primitives::ByteStream,
+    types::{CompletedMultipartUpload, CompletedPart},
 };
+use axum::body::Body;
+use axum_extra::headers::Header as _;
 use bytes::{Bytes, BytesMut};
+use percent_encoding::{AsciiSet, NON_ALPHANUMERIC, utf8_percent_encode};
 use secrecy::ExposeSecret as _;
-#[cfg(test)]
 use tokio::sync::Mutex;
 
 use crate::{
-    app::config::{ObjectStoreConfig, S3ObjectStoreConfig},
+    app::config::{DocumentNameCase, ObjectStoreConfig, S3ObjectStoreConfig},
     models::{document::Document, errors::ObjectStoreError},
+    rest::document::ContentDisposition,
 };
 
 use super::application::ApplicationState;
 
-#[cfg(test)]
-use std::collections::HashMap;
-use std::sync::{Arc, Weak};
+use http::{
+    HeaderMap, HeaderValue,
+    header::{CACHE_CONTROL, CONTENT_DISPOSITION, CONTENT_TYPE},
+};
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+use std::{
+    sync::{Arc, Weak},
+    time::Duration,
+};
 
 /// The document buckets name.
 const DOCUMENT_BUCKET: &str = "documents";
@@ -27,6 +43,44 @@ const DOCUMENT_BUCKET: &str = "documents";
 /// All the buckets that this application uses.
 const BUCKETS: [&str; 1] = [DOCUMENT_BUCKET];
 
+/// The characters that do not need to be percent encoded within a copy source.
+const COPY_SOURCE: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'/')
+    .remove(b'-')
+    .remove(b'_')
+    .remove(b'.')
+    .remove(b'~');
+
+/// ## Document Content Disposition
+///
+/// The inline `Content-Disposition` stored alongside a documents contents,
+/// so contents served directly from the object store keep the documents name.
+fn document_content_disposition(document: &Document) -> Option<String> {
+    let mut values = Vec::new();
+
+    ContentDisposition::new("inline", Some(document.name().to_string())).encode(&mut values);
+
+    values
+        .pop()
+        .and_then(|value| value.to_str().ok().map(ToString::to_string))
+}
+
+/// ## Document Key
+///
+/// The key of a documents contents, placed under the key prefix (if set).
+fn document_key(
+    key_prefix: Option<&str>,
+    document: &Document,
+    name_case: DocumentNameCase,
+) -> String {
+    let path = document.generate_path(name_case);
+
+    match key_prefix {
+        Some(key_prefix) => format!("{key_prefix}/{path}"),
+        None => path,
+    }
+}
+
 /// ## Object Store Extension
 ///
 /// The extension used, to easily implement all required functions used by object storage implementations.
@@ -62,6 +116,48 @@ pub trait ObjectStoreExt: Sized {
     /// [`None`] if the document does not exist, or [`Bytes`] of the documents content.
     async fn fetch_document(&self, document: &Document) -> Result<Option<Bytes>, ObjectStoreError>;
 
+    /// Fetch a document stream
+    ///
+    /// Fetch an existing document, streaming its content rather than buffering it.
+    ///
+    /// ## Arguments
+    ///
+    /// - `document` - The document object to fetch.
+    ///
+    /// ## Errors
+    ///
+    /// - [`ObjectStoreError`] - When the document cannot be found, or a read failure happens.
+    ///
+    /// ## Returns
+    /// [`None`] if the document does not exist, or a [`Body`] streaming the documents content.
+    async fn fetch_document_stream(
+        &self,
+        document: &Document,
+    ) -> Result<Option<Body>, ObjectStoreError>;
+
+    /// Fetch a document range
+    ///
+    /// Fetch a range of bytes from an existing document.
+    ///
+    /// ## Arguments
+    ///
+    /// - `document` - The document object to fetch.
+    /// - `start` - The first byte to fetch.
+    /// - `end` - The last byte to fetch (inclusive).
+    ///
+    /// ## Errors
+    ///
+    /// - [`ObjectStoreError`] - When the document cannot be found, or a read failure happens.
+    ///
+    /// ## Returns
+    /// [`None`] if the document does not exist, or [`Bytes`] of the requested range.
+    async fn fetch_document_range(
+        &self,
+        document: &Document,
+        start: u64,
+        end: u64,
+    ) -> Result<Option<Bytes>, ObjectStoreError>;
+
     /// Create a document
     ///
     /// Create a new document.
@@ -92,6 +188,141 @@ pub trait ObjectStoreExt: Sized {
     ///
     /// - [`ObjectStoreError`] - When the document could not be deleted.
     async fn delete_document(&self, document: &Document) -> Result<(), ObjectStoreError>;
+
+    /// Copy a document
+    ///
+    /// Copy an existing documents contents to the path of another document.
+    ///
+    /// ## Arguments
+    ///
+    /// - `from` - The document object to copy the contents of.
+    /// - `to` - The document object to copy the contents to.
+    ///
+    /// ## Errors
+    ///
+    /// - [`ObjectStoreError`] - When the document could not be copied.
+    async fn copy_document(&self, from: &Document, to: &Document) -> Result<(), ObjectStoreError>;
+
+    /// Create an upload
+    ///
+    /// Start a multipart upload of a documents contents, which are appended in parts.
+    ///
+    /// ## Arguments
+    ///
+    /// - `document` - The document the contents belong to.
+    ///
+    /// ## Errors
+    ///
+    /// - [`ObjectStoreError`] - When the upload could not be created.
+    ///
+    /// ## Returns
+    ///
+    /// The ID of the multipart upload.
+    async fn create_upload(&self, document: &Document) -> Result<String, ObjectStoreError>;
+
+    /// Upload a part
+    ///
+    /// Upload a single part of a multipart upload.
+    ///
+    /// ## Arguments
+    ///
+    /// - `document` - The document the contents belong to.
+    /// - `upload_id` - The ID of the multipart upload.
+    /// - `part_number` - The number of the part, starting from 1.
+    /// - `content` - The content of the part.
+    ///
+    /// ## Errors
+    ///
+    /// - [`ObjectStoreError`] - When the part could not be uploaded.
+    async fn upload_part(
+        &self,
+        document: &Document,
+        upload_id: &str,
+        part_number: usize,
+        content: impl Into<Bytes>,
+    ) -> Result<(), ObjectStoreError>;
+
+    /// Complete an upload
+    ///
+    /// Complete a multipart upload, joining every part (in order) into the documents contents.
+    ///
+    /// ## Arguments
+    ///
+    /// - `document` - The document the contents belong to.
+    /// - `upload_id` - The ID of the multipart upload.
+    ///
+    /// ## Errors
+    ///
+    /// - [`ObjectStoreError`] - When the upload could not be completed.
+    async fn complete_upload(
+        &self,
+        document: &Document,
+        upload_id: &str,
+    ) -> Result<(), ObjectStoreError>;
+
+    /// Abort an upload
+    ///
+    /// Abort a multipart upload, discarding every part uploaded.
+    ///
+    /// ## Arguments
+    ///
+    /// - `document` - The document the contents belong to.
+    /// - `upload_id` - The ID of the multipart upload.
+    ///
+    /// ## Errors
+    ///
+    /// - [`ObjectStoreError`] - When the upload could not be aborted.
+    async fn abort_upload(
+        &self,
+        document: &Document,
+        upload_id: &str,
+    ) -> Result<(), ObjectStoreError>;
+}
+
+/// Create Buckets With Retry.
+///
+/// Create the initial set of bucket(s), retrying with an exponential backoff if it fails.
+///
+/// This allows the object store to become reachable shortly after the server has started.
+///
+/// ## Arguments
+///
+/// - `object_store` - The object store to create the buckets in.
+/// - `attempts` - The maximum amount of attempts made.
+/// - `interval` - The time waited after the first failed attempt, doubling after each attempt.
+///
+/// ## Errors
+///
+/// - [`ObjectStoreError`] - If the buckets fail to be created within the attempts.
+pub async fn create_buckets_with_retry(
+    object_store: &impl ObjectStoreExt,
+    attempts: usize,
+    interval: Duration,
+) -> Result<(), ObjectStoreError> {
+    let mut delay = interval;
+    let mut attempt = 1;
+
+    loop {
+        match object_store.create_buckets().await {
+            Ok(()) => return Ok(()),
+            Err(err) if attempt < attempts => {
+                tracing::warn!(
+                    "Failed to create buckets (attempt {attempt} of {attempts}), retrying in {delay:?}. Error: {err}"
+                );
+            }
+            Err(err) => {
+                tracing::error!(
+                    "Failed to create buckets (attempt {attempt} of {attempts}), giving up. Error: {err}"
+                );
+                return Err(err);
+            }
+        }
+
+        tokio::time::sleep(delay).await;
+
+        delay = delay.saturating_mul(2);
+        attempt += 1;
+    }
 }
 
 /// ## Object Store
@@ -103,11 +334,10 @@ pub enum ObjectStore {
     ///
     /// The S3 Storage option.
     S3(S3ObjectStore),
-    /// The testing storage option.
+    /// ## Memory
     ///
-    /// This should not be used unless testing, as it is in memory only.
-    #[cfg(test)]
-    Test(TestObjectStore),
+    /// The in memory storage option, for local development and testing.
+    Memory(MemoryObjectStore),
 }
 
 impl ObjectStore {
@@ -124,8 +354,7 @@ impl ObjectStore {
     pub fn from_config(config: &ObjectStoreConfig) -> Result<Self, ObjectStoreError> {
         match config {
             ObjectStoreConfig::S3(config) => Ok(Self::S3(S3ObjectStore::from_config(config))),
-            #[cfg(test)]
-            ObjectStoreConfig::Test => Ok(Self::Test(TestObjectStore::new())),
+            ObjectStoreConfig::Memory => Ok(Self::Memory(MemoryObjectStore::new())),
         }
     }
 }
@@ -134,35 +363,55 @@ impl ObjectStoreExt for ObjectStore {
     fn bind_app(&mut self, app: Weak<ApplicationState>) {
         match self {
             Self::S3(os) => os.bind_app(app),
-            #[cfg(test)]
-            Self::Test(os) => os.bind_app(app),
+            Self::Memory(os) => os.bind_app(app),
         }
     }
 
     fn app(&self) -> Arc<ApplicationState> {
         match self {
             Self::S3(os) => os.app(),
-            #[cfg(test)]
-            Self::Test(os) => os.app(),
+            Self::Memory(os) => os.app(),
         }
     }
 
     async fn create_buckets(&self) -> Result<(), ObjectStoreError> {
         match self {
             Self::S3(os) => os.create_buckets().await,
-            #[cfg(test)]
-            Self::Test(os) => os.create_buckets().await,
+            Self::Memory(os) => os.create_buckets().await,
         }
     }
 
+    #[tracing::instrument(name = "ObjectStore::fetch_document", skip_all, fields(document.id = %document.id()))]
     async fn fetch_document(&self, document: &Document) -> Result<Option<Bytes>, ObjectStoreError> {
         match self {
             Self::S3(os) => os.fetch_document(document).await,
-            #[cfg(test)]
-            Self::Test(os) => os.fetch_document(document).await,
+            Self::Memory(os) => os.fetch_document(document).await,
+        }
+    }
+
+    async fn fetch_document_stream(
+        &self,
+        document: &Document,
+    ) -> Result<Option<Body>, ObjectStoreError> {
+        match self {
+            Self::S3(os) => os.fetch_document_stream(document).await,
+            Self::Memory(os) => os.fetch_document_stream(document).await,
         }
     }
 
+    async fn fetch_document_range(
+        &self,
+        document: &Document,
+        start: u64,
+        end: u64,
+    ) -> Result<Option<Bytes>, ObjectStoreError> {
+        match self {
+            Self::S3(os) => os.fetch_document_range(document, start, end).await,
+            Self::Memory(os) => os.fetch_document_range(document, start, end).await,
+        }
+    }
+
+    #[tracing::instrument(name = "ObjectStore::create_document", skip_all, fields(document.id = %document.id()))]
     async fn create_document(
         &self,
         document: &Document,
@@ -170,16 +419,69 @@ impl ObjectStoreExt for ObjectStore {
     ) -> Result<(), ObjectStoreError> {
         match self {
             Self::S3(os) => os.create_document(document, content).await,
-            #[cfg(test)]
-            Self::Test(os) => os.create_document(document, content).await,
+            Self::Memory(os) => os.create_document(document, content).await,
         }
     }
 
     async fn delete_document(&self, document: &Document) -> Result<(), ObjectStoreError> {
         match self {
             Self::S3(os) => os.delete_document(document).await,
-            #[cfg(test)]
-            Self::Test(os) => os.delete_document(document).await,
+            Self::Memory(os) => os.delete_document(document).await,
+        }
+    }
+
+    async fn copy_document(&self, from: &Document, to: &Document) -> Result<(), ObjectStoreError> {
+        match self {
+            Self::S3(os) => os.copy_document(from, to).await,
+            Self::Memory(os) => os.copy_document(from, to).await,
+        }
+    }
+
+    async fn create_upload(&self, document: &Document) -> Result<String, ObjectStoreError> {
+        match self {
+            Self::S3(os) => os.create_upload(document).await,
+            Self::Memory(os) => os.create_upload(document).await,
+        }
+    }
+
+    async fn upload_part(
+        &self,
+        document: &Document,
+        upload_id: &str,
+        part_number: usize,
+        content: impl Into<Bytes>,
+    ) -> Result<(), ObjectStoreError> {
+        match self {
+            Self::S3(os) => {
+                os.upload_part(document, upload_id, part_number, content)
+                    .await
+            }
+            Self::Memory(os) => {
+                os.upload_part(document, upload_id, part_number, content)
+                    .await
+            }
+        }
+    }
+
+    async fn complete_upload(
+        &self,
+        document: &Document,
+        upload_id: &str,
+    ) -> Result<(), ObjectStoreError> {
+        match self {
+            Self::S3(os) => os.complete_upload(document, upload_id).await,
+            Self::Memory(os) => os.complete_upload(document, upload_id).await,
+        }
+    }
+
+    async fn abort_upload(
+        &self,
+        document: &Document,
+        upload_id: &str,
+    ) -> Result<(), ObjectStoreError> {
+        match self {
+            Self::S3(os) => os.abort_upload(document, upload_id).await,
+            Self::Memory(os) => os.abort_upload(document, upload_id).await,
         }
     }
 }
@@ -191,6 +493,8 @@ impl ObjectStoreExt for ObjectStore {
 pub struct S3ObjectStore {
     app: Weak<ApplicationState>,
     client: S3Client,
+    cache_control: Option<String>,
+    key_prefix: Option<String>,
 }
 
 impl S3ObjectStore {
@@ -225,17 +529,18 @@ impl S3ObjectStore {
         );
 
         let s3conf = S3Config::builder()
-            //.region(Region::new("vault"))
             .endpoint_url(config.url())
             .credentials_provider(s3creds)
-            .region(Region::new("direct"))
-            .force_path_style(true) // MinIO does not support virtual hosts
+            .region(Region::new(config.region().to_string()))
+            .force_path_style(config.force_path_style())
             .behavior_version(BehaviorVersion::v2026_01_12())
             .build();
 
         Self {
             app: Weak::new(),
             client: S3Client::from_conf(s3conf),
+            cache_control: config.cache_control().map(ToString::to_string),
+            key_prefix: config.key_prefix().map(ToString::to_string),
         }
     }
 
@@ -243,6 +548,67 @@ impl S3ObjectStore {
     pub const fn client(&self) -> &S3Client {
         &self.client
     }
+
+    /// Document Path.
+    ///
+    /// The key of a documents contents, using the configured document name case and key prefix.
+    fn document_path(&self, document: &Document) -> String {
+        document_key(
+            self.key_prefix.as_deref(),
+            document,
+            self.app().config().document_name_case(),
+        )
+    }
+
+    /// Get an object
+    ///
+    /// Request a documents object, optionally limited to a HTTP byte range.
+    async fn get_object(
+        &self,
+        document: &Document,
+        range: Option<String>,
+    ) -> Result<Option<GetObjectOutput>, ObjectStoreError> {
+        match self
+            .client
+            .get_object()
+            .bucket(DOCUMENT_BUCKET)
+            .key(self.document_path(document))
+            .set_range(range)
+            .send()
+            .await
+        {
+            Ok(data) => Ok(Some(data)),
+            Err(SdkError::ServiceError(err))
+                if matches!(
+                    err.err(),
+                    aws_sdk_s3::operation::get_object::GetObjectError::NoSuchKey(_)
+                ) =>
+            {
+                Ok(None)
+            }
+            Err(err) => Err(ObjectStoreError::from(err)),
+        }
+    }
+
+    /// Get a document
+    ///
+    /// Read a documents contents, optionally limited to a HTTP byte range.
+    async fn get_document(
+        &self,
+        document: &Document,
+        range: Option<String>,
+    ) -> Result<Option<Bytes>, ObjectStoreError> {
+        let Some(mut data) = self.get_object(document, range).await? else {
+            return Ok(None);
+        };
+
+        let mut bytes = BytesMut::new();
+        while let Some(chunk) = data.body.next().await {
+            bytes.extend_from_slice(&chunk.expect("Failed to read S3 object chunk"));
+        }
+
+        Ok(Some(bytes.freeze()))
+    }
 }
 
 impl ObjectStoreExt for S3ObjectStore {
@@ -292,32 +658,27 @@ impl ObjectStoreExt for S3ObjectStore {
     }
 
     async fn fetch_document(&self, document: &Document) -> Result<Option<Bytes>, ObjectStoreError> {
-        let mut data = match self
-            .client
-            .get_object()
-            .bucket(DOCUMENT_BUCKET)
-            .key(document.generate_path())
-            .send()
-            .await
-        {
-            Ok(data) => data,
-            Err(SdkError::ServiceError(err))
-                if matches!(
-                    err.err(),
-                    aws_sdk_s3::operation::get_object::GetObjectError::NoSuchKey(_)
-                ) =>
-            {
-                return Ok(None);
-            }
-            Err(err) => return Err(ObjectStoreError::from(err)),
-        };
+        self.get_document(document, None).await
+    }
 
-        let mut bytes = BytesMut::new();
-        while let Some(chunk) = data.body.next().await {
-            bytes.extend_from_slice(&chunk.expect("Failed to read S3 object chunk"));
-        }
+    async fn fetch_document_stream(
+        &self,
+        document: &Document,
+    ) -> Result<Option<Body>, ObjectStoreError> {
+        Ok(self
+            .get_object(document, None)
+            .await?
+            .map(|data| Body::new(data.body.into_inner())))
+    }
 
-        Ok(Some(bytes.freeze()))
+    async fn fetch_document_range(
+        &self,
+        document: &Document,
+        start: u64,
+        end: u64,
+    ) -> Result<Option<Bytes>, ObjectStoreError> {
+        self.get_document(document, Some(format!("bytes={start}-{end}")))
+            .await
     }
 
     async fn create_document(
@@ -329,7 +690,9 @@ impl ObjectStoreExt for S3ObjectStore {
             .put_object()
             .bucket(DOCUMENT_BUCKET)
             .content_type(document.doc_type())
-            .key(document.generate_path())
+            .set_content_disposition(document_content_disposition(document))
+            .set_cache_control(self.cache_control.clone())
+            .key(self.document_path(document))
             .body(ByteStream::from(content.into()))
             .send()
             .await?;
@@ -341,7 +704,122 @@ impl ObjectStoreExt for S3ObjectStore {
         self.client
             .delete_object()
             .bucket(DOCUMENT_BUCKET)
-            .key(document.generate_path())
+            .key(self.document_path(document))
+            .send()
+            .await?;
+
+        Ok(())
+    }
+
+    async fn copy_document(&self, from: &Document, to: &Document) -> Result<(), ObjectStoreError> {
+        self.client
+            .copy_object()
+            .bucket(DOCUMENT_BUCKET)
+            .copy_source(format!(
+                "{DOCUMENT_BUCKET}/{}",
+                utf8_percent_encode(&self.document_path(from), COPY_SOURCE)
+            ))
+            .key(self.document_path(to))
+            .send()
+            .await?;
+
+        Ok(())
+    }
+
+    async fn create_upload(&self, document: &Document) -> Result<String, ObjectStoreError> {
+        let output = self
+            .client
+            .create_multipart_upload()
+            .bucket(DOCUMENT_BUCKET)
+            .content_type(document.doc_type())
+            .set_content_disposition(document_content_disposition(document))
+            .set_cache_control(self.cache_control.clone())
+            .key(self.document_path(document))
+            .send()
+            .await?;
+
+        output.upload_id().map(ToString::to_string).ok_or_else(|| {
+            ObjectStoreError::S3(String::from("The multipart upload ID was not returned."))
+        })
+    }
+
+    async fn upload_part(
+        &self,
+        document: &Document,
+        upload_id: &str,
+        part_number: usize,
+        content: impl Into<Bytes>,
+    ) -> Result<(), ObjectStoreError> {
+        let part_number =
+            i32::try_from(part_number).map_err(|e| ObjectStoreError::S3(e.to_string()))?;
+
+        self.client
+            .upload_part()
+            .bucket(DOCUMENT_BUCKET)
+            .key(self.document_path(document))
+            .upload_id(upload_id)
+            .part_number(part_number)
+            .body(ByteStream::from(content.into()))
+            .send()
+            .await?;
+
+        Ok(())
+    }
+
+    async fn complete_upload(
+        &self,
+        document: &Document,
+        upload_id: &str,
+    ) -> Result<(), ObjectStoreError> {
+        let mut parts = Vec::new();
+
+        let mut stream = self
+            .client
+            .list_parts()
+            .bucket(DOCUMENT_BUCKET)
+            .key(self.document_path(document))
+            .upload_id(upload_id)
+            .into_paginator()
+            .items()
+            .send();
+
+        while let Some(part) = stream.next().await {
+            let part = part?;
+
+            parts.push(
+                CompletedPart::builder()
+                    .set_e_tag(part.e_tag().map(ToString::to_string))
+                    .set_part_number(part.part_number())
+                    .build(),
+            );
+        }
+
+        self.client
+            .complete_multipart_upload()
+            .bucket(DOCUMENT_BUCKET)
+            .key(self.document_path(document))
+            .upload_id(upload_id)
+            .multipart_upload(
+                CompletedMultipartUpload::builder()
+                    .set_parts(Some(parts))
+                    .build(),
+            )
+            .send()
+            .await?;
+
+        Ok(())
+    }
+
+    async fn abort_upload(
+        &self,
+        document: &Document,
+        upload_id: &str,
+    ) -> Result<(), ObjectStoreError> {
+        self.client
+            .abort_multipart_upload()
+            .bucket(DOCUMENT_BUCKET)
+            .key(self.document_path(document))
+            .upload_id(upload_id)
             .send()
             .await?;
 
@@ -349,36 +827,99 @@ impl ObjectStoreExt for S3ObjectStore {
     }
 }
 
-/// ## Test Object Store
+/// ## Memory Object Store
 ///
-/// The testing object storage.
+/// The in memory object storage, used for local development and testing.
 ///
-/// This object store is completely in memory.
-#[cfg(test)]
+/// Nothing is persisted, so every document is lost once the server stops.
 #[derive(Debug, Clone)]
-pub struct TestObjectStore {
+pub struct MemoryObjectStore {
     app: Weak<ApplicationState>,
     buckets: Arc<Mutex<Vec<String>>>,
     data: Arc<Mutex<HashMap<(String, String), Bytes>>>,
+    metadata: Arc<Mutex<HashMap<(String, String), HeaderMap>>>,
+    uploads: Arc<Mutex<HashMap<String, BTreeMap<usize, Bytes>>>>,
+    upload_count: Arc<AtomicUsize>,
+    bucket_failures: Arc<AtomicUsize>,
+    cache_control: Option<String>,
+    key_prefix: Option<String>,
 }
 
-#[cfg(test)]
-impl TestObjectStore {
+impl MemoryObjectStore {
     /// ## New
     ///
-    /// Create a new [`TestObjectStore`] object.
+    /// Create a new [`MemoryObjectStore`] object.
     #[expect(clippy::new_without_default)]
     pub fn new() -> Self {
         Self {
             app: Weak::new(),
             buckets: Arc::new(Mutex::new(Vec::new())),
             data: Arc::new(Mutex::new(HashMap::new())),
+            metadata: Arc::new(Mutex::new(HashMap::new())),
+            uploads: Arc::new(Mutex::new(HashMap::new())),
+            upload_count: Arc::new(AtomicUsize::new(0)),
+            bucket_failures: Arc::new(AtomicUsize::new(0)),
+            cache_control: None,
+            key_prefix: None,
         }
     }
+
+    /// Document Path.
+    ///
+    /// The key of a documents contents, using the configured document name case and key prefix.
+    ///
+    /// Stores that are not bound to an application keep the case of names.
+    fn document_path(&self, document: &Document) -> String {
+        let name_case = self
+            .app
+            .upgrade()
+            .map_or(DocumentNameCase::Sensitive, |app| {
+                app.config().document_name_case()
+            });
+
+        document_key(self.key_prefix.as_deref(), document, name_case)
+    }
 }
 
 #[cfg(test)]
-impl ObjectStoreExt for TestObjectStore {
+impl MemoryObjectStore {
+    /// ## With Bucket Failures
+    ///
+    /// Fail the next `failures` attempts at creating the buckets, as if the object store was unreachable.
+    pub fn with_bucket_failures(self, failures: usize) -> Self {
+        self.bucket_failures.store(failures, Ordering::SeqCst);
+        self
+    }
+
+    /// ## With Cache Control
+    ///
+    /// Store the `Cache-Control` alongside each documents contents.
+    pub fn with_cache_control(mut self, cache_control: impl Into<String>) -> Self {
+        self.cache_control = Some(cache_control.into());
+        self
+    }
+
+    /// ## With Key Prefix
+    ///
+    /// Store each documents contents under the key prefix.
+    pub fn with_key_prefix(mut self, key_prefix: impl Into<String>) -> Self {
+        self.key_prefix = Some(key_prefix.into());
+        self
+    }
+
+    /// ## Fetch Metadata
+    ///
+    /// Fetch the headers stored alongside a documents contents.
+    pub async fn fetch_metadata(&self, document: &Document) -> Option<HeaderMap> {
+        self.metadata
+            .lock()
+            .await
+            .get(&(DOCUMENT_BUCKET.to_string(), self.document_path(document)))
+            .cloned()
+    }
+}
+
+impl ObjectStoreExt for MemoryObjectStore {
     fn bind_app(&mut self, app: Weak<ApplicationState>) {
         self.app = app;
     }
@@ -390,6 +931,16 @@ impl ObjectStoreExt for TestObjectStore {
     }
 
     async fn create_buckets(&self) -> Result<(), ObjectStoreError> {
+        if self
+            .bucket_failures
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |v| v.checked_sub(1))
+            .is_ok()
+        {
+            return Err(ObjectStoreError::S3(String::from(
+                "The object store is unreachable.",
+            )));
+        }
+
         for bucket in BUCKETS {
             let mut bucket_lock = self.buckets.lock().await;
 
@@ -407,11 +958,33 @@ impl ObjectStoreExt for TestObjectStore {
         let data_lock = self.data.lock().await;
 
         let document_contents =
-            data_lock.get(&(DOCUMENT_BUCKET.to_string(), document.generate_path()));
+            data_lock.get(&(DOCUMENT_BUCKET.to_string(), self.document_path(document)));
 
         document_contents.map_or_else(|| Ok(None), |contents| Ok(Some(contents.clone())))
     }
 
+    async fn fetch_document_stream(
+        &self,
+        document: &Document,
+    ) -> Result<Option<Body>, ObjectStoreError> {
+        Ok(self.fetch_document(document).await?.map(Body::from))
+    }
+
+    async fn fetch_document_range(
+        &self,
+        document: &Document,
+        start: u64,
+        end: u64,
+    ) -> Result<Option<Bytes>, ObjectStoreError> {
+        let Some(contents) = self.fetch_document(document).await? else {
+            return Ok(None);
+        };
+
+        let end = (end as usize).min(contents.len().saturating_sub(1));
+
+        Ok(Some(contents.slice(start as usize..=end)))
+    }
+
     async fn create_document(
         &self,
         document: &Document,
@@ -420,24 +993,384 @@ impl ObjectStoreExt for TestObjectStore {
         // FIXME: Check bucket exists.
         let mut data_lock = self.data.lock().await;
 
-        assert!(
-            !data_lock.contains_key(&(DOCUMENT_BUCKET.to_string(), document.generate_path())),
-            "Key already exists!"
-        );
-
+        // Existing contents are overwritten, matching S3s `PutObject`.
         data_lock.insert(
-            (DOCUMENT_BUCKET.to_string(), document.generate_path()),
+            (DOCUMENT_BUCKET.to_string(), self.document_path(document)),
             content.into(),
         );
 
+        let mut metadata = HeaderMap::new();
+
+        if let Ok(content_type) = HeaderValue::from_str(document.doc_type()) {
+            metadata.insert(CONTENT_TYPE, content_type);
+        }
+
+        if let Some(content_disposition) = document_content_disposition(document)
+            .and_then(|value| HeaderValue::from_str(&value).ok())
+        {
+            metadata.insert(CONTENT_DISPOSITION, content_disposition);
+        }
+
+        if let Some(cache_control) = self
+            .cache_control
+            .as_deref()
+            .and_then(|value| HeaderValue::from_str(value).ok())
+        {
+            metadata.insert(CACHE_CONTROL, cache_control);
+        }
+
+        self.metadata.lock().await.insert(
+            (DOCUMENT_BUCKET.to_string(), self.document_path(document)),
+            metadata,
+        );
+
         Ok(())
     }
 
     async fn delete_document(&self, document: &Document) -> Result<(), ObjectStoreError> {
         let mut data_lock = self.data.lock().await;
 
-        data_lock.remove(&(DOCUMENT_BUCKET.to_string(), document.generate_path()));
+        data_lock.remove(&(DOCUMENT_BUCKET.to_string(), self.document_path(document)));
+
+        self.metadata
+            .lock()
+            .await
+            .remove(&(DOCUMENT_BUCKET.to_string(), self.document_path(document)));
 
         Ok(())
     }
+
+    async fn copy_document(&self, from: &Document, to: &Document) -> Result<(), ObjectStoreError> {
+        let mut data_lock = self.data.lock().await;
+
+        if let Some(contents) = data_lock
+            .get(&(DOCUMENT_BUCKET.to_string(), self.document_path(from)))
+            .cloned()
+        {
+            data_lock.insert(
+                (DOCUMENT_BUCKET.to_string(), self.document_path(to)),
+                contents,
+            );
+        }
+
+        let mut metadata_lock = self.metadata.lock().await;
+
+        if let Some(metadata) = metadata_lock
+            .get(&(DOCUMENT_BUCKET.to_string(), self.document_path(from)))
+            .cloned()
+        {
+            metadata_lock.insert(
+                (DOCUMENT_BUCKET.to_string(), self.document_path(to)),
+                metadata,
+            );
+        }
+
+        Ok(())
+    }
+
+    async fn create_upload(&self, _document: &Document) -> Result<String, ObjectStoreError> {
+        let upload_id = self.upload_count.fetch_add(1, Ordering::SeqCst).to_string();
+
+        self.uploads
+            .lock()
+            .await
+            .insert(upload_id.clone(), BTreeMap::new());
+
+        Ok(upload_id)
+    }
+
+    async fn upload_part(
+        &self,
+        _document: &Document,
+        upload_id: &str,
+        part_number: usize,
+        content: impl Into<Bytes>,
+    ) -> Result<(), ObjectStoreError> {
+        let mut uploads_lock = self.uploads.lock().await;
+
+        let Some(parts) = uploads_lock.get_mut(upload_id) else {
+            return Err(ObjectStoreError::S3(String::from(
+                "The multipart upload does not exist.",
+            )));
+        };
+
+        parts.insert(part_number, content.into());
+
+        Ok(())
+    }
+
+    async fn complete_upload(
+        &self,
+        document: &Document,
+        upload_id: &str,
+    ) -> Result<(), ObjectStoreError> {
+        let Some(parts) = self.uploads.lock().await.remove(upload_id) else {
+            return Err(ObjectStoreError::S3(String::from(
+                "The multipart upload does not exist.",
+            )));
+        };
+
+        let mut contents = BytesMut::new();
+        for part in parts.into_values() {
+            contents.extend_from_slice(&part);
+        }
+
+        self.create_document(document, contents.freeze()).await
+    }
+
+    async fn abort_upload(
+        &self,
+        _document: &Document,
+        upload_id: &str,
+    ) -> Result<(), ObjectStoreError> {
+        self.uploads.lock().await.remove(upload_id);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aws_sdk_s3::presigning::PresigningConfig;
+    use rstest::rstest;
+
+    use crate::models::snowflake::Snowflake;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_create_buckets_with_retry() {
+        let object_store = MemoryObjectStore::new().with_bucket_failures(2);
+
+        create_buckets_with_retry(&object_store, 3, Duration::from_millis(1))
+            .await
+            .expect("Failed to create buckets.");
+
+        assert_eq!(
+            *object_store.buckets.lock().await,
+            vec![DOCUMENT_BUCKET.to_string()],
+            "Buckets do not match."
+        );
+    }
+
+    #[tokio::test]
+    async fn test_create_buckets_with_retry_exhausted() {
+        let object_store = MemoryObjectStore::new().with_bucket_failures(3);
+
+        let result = create_buckets_with_retry(&object_store, 3, Duration::from_millis(1)).await;
+
+        assert!(result.is_err(), "Buckets were created.");
+
+        assert!(
+            object_store.buckets.lock().await.is_empty(),
+            "Buckets were created."
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fetch_document_stream() {
+        let object_store = MemoryObjectStore::new();
+        let document = Document::new(
+            Snowflake::new(517_815_304_354_284_708),
+            Snowflake::new(517_815_304_354_284_605),
+            "text/plain",
+            "test.txt",
+            12,
+            None,
+            &[],
+        );
+
+        object_store
+            .create_document(&document, Bytes::from("hello, world"))
+            .await
+            .expect("Failed to create document contents.");
+
+        let body = object_store
+            .fetch_document_stream(&document)
+            .await
+            .expect("Failed to fetch document stream.")
+            .expect("Document contents were not found.");
+
+        let content = axum::body::to_bytes(body, usize::MAX)
+            .await
+            .expect("Failed to read document stream.");
+
+        assert_eq!(
+            content,
+            object_store
+                .fetch_document(&document)
+                .await
+                .expect("Failed to fetch document contents.")
+                .expect("Document contents were not found."),
+            "Streamed contents do not match."
+        );
+    }
+
+    #[tokio::test]
+    async fn test_create_document_replaces_contents() {
+        let object_store = MemoryObjectStore::new();
+        let document = Document::new(
+            Snowflake::new(517_815_304_354_284_708),
+            Snowflake::new(517_815_304_354_284_605),
+            "text/plain",
+            "test.txt",
+            12,
+            None,
+            &[],
+        );
+
+        object_store
+            .create_document(&document, Bytes::from("hello, world"))
+            .await
+            .expect("Failed to create document contents.");
+
+        object_store
+            .create_document(&document, Bytes::from("goodbye, world"))
+            .await
+            .expect("Failed to replace document contents.");
+
+        assert_eq!(
+            object_store
+                .fetch_document(&document)
+                .await
+                .expect("Failed to fetch document contents."),
+            Some(Bytes::from("goodbye, world")),
+            "Contents were not replaced."
+        );
+    }
+
+    #[tokio::test]
+    async fn test_create_document_metadata() {
+        let object_store = MemoryObjectStore::new().with_cache_control("public, max-age=3600");
+        let document = Document::new(
+            Snowflake::new(517_815_304_354_284_708),
+            Snowflake::new(517_815_304_354_284_605),
+            "text/plain",
+            "résumé.txt",
+            12,
+            None,
+            &[],
+        );
+
+        object_store
+            .create_document(&document, Bytes::from("hello, world"))
+            .await
+            .expect("Failed to create document contents.");
+
+        let metadata = object_store
+            .fetch_metadata(&document)
+            .await
+            .expect("Document metadata was not found.");
+
+        assert_eq!(
+            metadata.get(CONTENT_TYPE),
+            Some(&HeaderValue::from_static("text/plain")),
+            "Content type does not match."
+        );
+        assert_eq!(
+            metadata.get(CONTENT_DISPOSITION),
+            Some(&HeaderValue::from_static(
+                "inline; filename=\"r_sum_.txt\"; filename*=UTF-8''r%C3%A9sum%C3%A9.txt"
+            )),
+            "Content disposition does not match."
+        );
+        assert_eq!(
+            metadata.get(CACHE_CONTROL),
+            Some(&HeaderValue::from_static("public, max-age=3600")),
+            "Cache control does not match."
+        );
+    }
+
+    #[rstest]
+    #[case(None, "517815304354284605/517815304354284708/test.txt")]
+    #[case(
+        Some("tenant"),
+        "tenant/517815304354284605/517815304354284708/test.txt"
+    )]
+    #[tokio::test]
+    async fn test_key_prefix(#[case] key_prefix: Option<&str>, #[case] expected_key: &str) {
+        let object_store = key_prefix.map_or_else(MemoryObjectStore::new, |key_prefix| {
+            MemoryObjectStore::new().with_key_prefix(key_prefix)
+        });
+        let document = Document::new(
+            Snowflake::new(517_815_304_354_284_708),
+            Snowflake::new(517_815_304_354_284_605),
+            "text/plain",
+            "test.txt",
+            12,
+            None,
+            &[],
+        );
+
+        object_store
+            .create_document(&document, Bytes::from("hello, world"))
+            .await
+            .expect("Failed to create document contents.");
+
+        let key = (DOCUMENT_BUCKET.to_string(), expected_key.to_string());
+
+        assert!(
+            object_store.data.lock().await.contains_key(&key),
+            "Document contents were not stored under the expected key."
+        );
+
+        assert_eq!(
+            object_store
+                .fetch_document(&document)
+                .await
+                .expect("Failed to fetch document contents."),
+            Some(Bytes::from("hello, world")),
+            "Document contents do not match."
+        );
+
+        object_store
+            .delete_document(&document)
+            .await
+            .expect("Failed to delete document contents.");
+
+        assert!(
+            !object_store.data.lock().await.contains_key(&key),
+            "Document contents were not deleted."
+        );
+    }
+
+    #[tokio::test]
+    async fn test_from_config() {
+        let config = S3ObjectStoreConfig::test_builder()
+            .url("http://localhost:9000".to_string())
+            .access_key("access".to_string().into())
+            .secret_key("secret".to_string().into())
+            .region("eu-west-2".to_string())
+            .force_path_style(false)
+            .build()
+            .expect("Failed to build S3 object store config.");
+
+        let object_store = S3ObjectStore::from_config(&config);
+
+        assert_eq!(
+            object_store.client().config().region(),
+            Some(&Region::new("eu-west-2")),
+            "Region does not match."
+        );
+
+        let request = object_store
+            .client()
+            .get_object()
+            .bucket(DOCUMENT_BUCKET)
+            .key("document.txt")
+            .presigned(
+                PresigningConfig::expires_in(Duration::from_mins(1))
+                    .expect("Failed to build presigning config."),
+            )
+            .await
+            .expect("Failed to presign request.");
+
+        assert!(
+            request
+                .uri()
+                .starts_with("http://documents.localhost:9000/document.txt"),
+            "Request is not using virtual hosted addressing: {}",
+            request.uri()
+        );
+    }
 }
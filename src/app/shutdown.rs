@@ -0,0 +1,166 @@
+//! The shutdown sequence, used to drain in-flight requests before the server stops.
+
+use std::{net::SocketAddr, time::Duration};
+
+use axum::Router;
+use tokio::{net::TcpListener, sync::oneshot};
+
+/// ## Serve With Drain
+///
+/// Serve the router until the shutdown signal completes.
+///
+/// Once the signal completes, new connections are no longer accepted,
+/// and in-flight requests are waited on for at most the drain timeout.
+///
+/// ## Errors
+/// - [`std::io::Error`] - The server failed.
+///
+/// ## Returns
+/// - [`true`] - All in-flight requests completed within the drain timeout.
+/// - [`false`] - The drain timeout elapsed before all in-flight requests completed.
+pub async fn serve_with_drain(
+    listener: TcpListener,
+    router: Router,
+    shutdown_signal: impl Future<Output = ()> + Send + 'static,
+    drain_timeout: Duration,
+) -> std::io::Result<bool> {
+    let (draining_sender, draining_receiver) = oneshot::channel();
+
+    let shutdown_signal = async move {
+        shutdown_signal.await;
+        draining_sender.send(()).ok();
+    };
+
+    let server = axum::serve(
+        listener,
+        router.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .with_graceful_shutdown(shutdown_signal)
+    .into_future();
+
+    tokio::pin!(server);
+
+    tokio::select! {
+        result = &mut server => return result.map(|()| true),
+        _ = draining_receiver => {},
+    }
+
+    tracing::info!(
+        "Draining in-flight requests for up to {} seconds.",
+        drain_timeout.as_secs()
+    );
+
+    tokio::time::timeout(drain_timeout, server)
+        .await
+        .map_or(Ok(false), |result| result.map(|()| true))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::Arc, time::Instant};
+
+    use axum::routing::get;
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpStream,
+        sync::Notify,
+    };
+
+    use super::*;
+
+    /// Start the server with a single slow route, and a shutdown signal triggered once a request has started.
+    async fn start_slow_server(
+        request_duration: Duration,
+        drain_timeout: Duration,
+    ) -> (SocketAddr, tokio::task::JoinHandle<std::io::Result<bool>>) {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("Failed to bind listener.");
+        let address = listener.local_addr().expect("Failed to fetch address.");
+
+        let started = Arc::new(Notify::new());
+        let route_started = started.clone();
+
+        let router = Router::new().route(
+            "/slow",
+            get(move || {
+                let route_started = route_started.clone();
+                async move {
+                    route_started.notify_one();
+                    tokio::time::sleep(request_duration).await;
+                    "done"
+                }
+            }),
+        );
+
+        let shutdown_signal = async move { started.notified().await };
+
+        let server = tokio::spawn(serve_with_drain(
+            listener,
+            router,
+            shutdown_signal,
+            drain_timeout,
+        ));
+
+        (address, server)
+    }
+
+    async fn request_slow(address: SocketAddr) -> String {
+        let mut stream = TcpStream::connect(address)
+            .await
+            .expect("Failed to connect.");
+
+        stream
+            .write_all(b"GET /slow HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+            .await
+            .expect("Failed to write request.");
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.ok();
+
+        response
+    }
+
+    #[tokio::test]
+    async fn test_serve_with_drain_waits_for_request() {
+        let (address, server) =
+            start_slow_server(Duration::from_millis(200), Duration::from_secs(5)).await;
+
+        let response = request_slow(address).await;
+
+        let drained = server
+            .await
+            .expect("Failed to join server.")
+            .expect("Server failed.");
+
+        assert!(drained, "In-flight request was not drained.");
+        assert!(
+            response.starts_with("HTTP/1.1 200"),
+            "In-flight request did not complete: {response}"
+        );
+        assert!(response.ends_with("done"), "Response body does not match.");
+    }
+
+    #[tokio::test]
+    async fn test_serve_with_drain_timeout() {
+        let (address, server) =
+            start_slow_server(Duration::from_secs(30), Duration::from_millis(200)).await;
+
+        let request = tokio::spawn(request_slow(address));
+
+        let start = Instant::now();
+
+        let drained = server
+            .await
+            .expect("Failed to join server.")
+            .expect("Server failed.");
+
+        assert!(!drained, "In-flight request was drained.");
+        assert!(
+            start.elapsed() < Duration::from_secs(5),
+            "Shutdown did not stop at the drain timeout."
+        );
+
+        request.abort();
+    }
+}
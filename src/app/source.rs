@@ -0,0 +1,297 @@
+//! Fetching the contents of documents from a URL, instead of them being uploaded.
+//!
+//! The same checks protect any other request the server makes to a client provided URL.
+
+use std::{
+    net::{IpAddr, SocketAddr},
+    time::Duration,
+};
+
+use ipnet::IpNet;
+use mime::Mime;
+use reqwest::{
+    Client, Url,
+    header::{CONTENT_LENGTH, CONTENT_TYPE},
+    redirect::Policy,
+};
+use url::Host;
+
+use crate::{
+    app::config::Config,
+    models::{document::is_mime_allowed, errors::RESTError},
+};
+
+/// The networks that are rejected, unless explicitly allowed.
+///
+/// This covers private, loopback, link-local, shared and unspecified addresses.
+const BLOCKED_NETWORKS: &[&str] = &[
+    "0.0.0.0/8",
+    "10.0.0.0/8",
+    "100.64.0.0/10",
+    "127.0.0.0/8",
+    "169.254.0.0/16",
+    "172.16.0.0/12",
+    "192.168.0.0/16",
+    "::/128",
+    "::1/128",
+    "fc00::/7",
+    "fe80::/10",
+];
+
+/// Is Blocked Address.
+///
+/// Checks if an address is within a blocked network, and not within an allowed one.
+///
+/// ## Arguments
+///
+/// - `config` - The config containing the allowed networks.
+/// - `address` - The address to check.
+///
+/// ## Returns
+///
+/// Returns [`true`] if the address cannot be fetched from, otherwise [`false`].
+fn is_blocked_address(config: &Config, address: IpAddr) -> bool {
+    let address = address.to_canonical();
+
+    if config
+        .source_url_allowed_networks()
+        .iter()
+        .any(|network| network.contains(&address))
+    {
+        return false;
+    }
+
+    BLOCKED_NETWORKS
+        .iter()
+        .filter_map(|network| network.parse::<IpNet>().ok())
+        .any(|network| network.contains(&address))
+}
+
+/// Resolve URL.
+///
+/// Check that a URL can be requested by the server, and resolve the address to connect to.
+///
+/// ## Arguments
+///
+/// - `config` - The config values to use.
+/// - `allowed_hosts` - The hosts that can be requested (`*` allows any host).
+/// - `subject` - The label identifying the URL to the client (such as ``Document `files[0]`'s source URL``).
+/// - `url` - The URL to check.
+///
+/// ## Errors
+///
+/// - [`RESTError`] - The URL is invalid, its host is not allowed, or it resolves to a blocked address.
+///
+/// ## Returns
+///
+/// The parsed [`Url`], alongside the address its domain is pinned to (if the host is not an address).
+async fn resolve_url(
+    config: &Config,
+    allowed_hosts: &[String],
+    subject: &str,
+    url: &str,
+) -> Result<(Url, Option<(String, SocketAddr)>), RESTError> {
+    let invalid_url = || RESTError::bad_request(format!("{subject} is invalid."));
+
+    let url = Url::parse(url).map_err(|_| invalid_url())?;
+
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err(invalid_url());
+    }
+
+    let (Some(host), Some(port)) = (url.host(), url.port_or_known_default()) else {
+        return Err(invalid_url());
+    };
+
+    let host_name = host.to_string().to_lowercase();
+
+    if !allowed_hosts
+        .iter()
+        .any(|allowed| allowed == "*" || *allowed == host_name)
+    {
+        return Err(RESTError::bad_request(format!(
+            "{subject} host is not allowed."
+        )));
+    }
+
+    let addresses: Vec<IpAddr> = match host {
+        Host::Ipv4(address) => vec![address.into()],
+        Host::Ipv6(address) => vec![address.into()],
+        Host::Domain(domain) => tokio::net::lookup_host((domain, port))
+            .await
+            .map_err(|_| RESTError::bad_request(format!("{subject} host could not be resolved.")))?
+            .map(|address| address.ip())
+            .collect(),
+    };
+
+    let Some(first_address) = addresses.first() else {
+        return Err(RESTError::bad_request(format!(
+            "{subject} host could not be resolved."
+        )));
+    };
+
+    if addresses
+        .iter()
+        .any(|address| is_blocked_address(config, *address))
+    {
+        return Err(RESTError::bad_request(format!(
+            "{subject} resolves to a private address."
+        )));
+    }
+
+    let pinned = match host {
+        Host::Domain(domain) => Some((domain.to_string(), (*first_address, port).into())),
+        Host::Ipv4(_) | Host::Ipv6(_) => None,
+    };
+
+    Ok((url, pinned))
+}
+
+/// Pinned Client.
+///
+/// Check that a URL can be requested by the server,
+/// and build a client that can only connect to the address that was checked.
+///
+/// Redirects are not followed, as the address they lead to would not be checked.
+///
+/// ## Arguments
+///
+/// - `config` - The config values to use.
+/// - `allowed_hosts` - The hosts that can be requested (`*` allows any host).
+/// - `subject` - The label identifying the URL to the client (such as ``Document `files[0]`'s source URL``).
+/// - `url` - The URL to check.
+///
+/// ## Errors
+///
+/// - [`RESTError`] - The URL is not allowed, or the client could not be built.
+///
+/// ## Returns
+///
+/// The [`Client`] to make the request with, alongside the parsed [`Url`].
+pub async fn pinned_client(
+    config: &Config,
+    allowed_hosts: &[String],
+    subject: &str,
+    url: &str,
+) -> Result<(Client, Url), RESTError> {
+    let (url, pinned) = resolve_url(config, allowed_hosts, subject, url).await?;
+
+    let mut client = Client::builder()
+        .redirect(Policy::none())
+        .timeout(Duration::from_secs(config.source_url_timeout_seconds()));
+
+    // Pin the checked address, so the host cannot resolve somewhere else when connecting.
+    if let Some((domain, address)) = pinned {
+        client = client.resolve(&domain, address);
+    }
+
+    let client = client.build().map_err(RESTError::internal_server)?;
+
+    Ok((client, url))
+}
+
+/// Fetch Source.
+///
+/// Fetch the contents of a document from its source URL.
+///
+/// The host must be allowed, and every address it resolves to must be public (or within an allowed network).
+/// Redirects are not followed, and the body is not read past the maximum document size.
+///
+/// ## Arguments
+///
+/// - `config` - The config values to use.
+/// - `document` - The label identifying the document to the client (such as `files[0]`).
+/// - `name` - The name of the document.
+/// - `source_url` - The URL to fetch the contents from.
+///
+/// ## Errors
+///
+/// - [`RESTError`] - The URL is not allowed, could not be fetched, or its contents are invalid.
+///
+/// ## Returns
+///
+/// The contents of the document, and its mime.
+pub async fn fetch_source(
+    config: &Config,
+    document: &str,
+    name: &str,
+    source_url: &str,
+) -> Result<(String, Mime), RESTError> {
+    let allowed_hosts = config.source_url_allowed_hosts();
+
+    if allowed_hosts.is_empty() {
+        return Err(RESTError::bad_request(
+            "Fetching documents by URL is disabled.",
+        ));
+    }
+
+    let (client, url) = pinned_client(
+        config,
+        allowed_hosts,
+        &format!("Document `{document}`'s source URL"),
+        source_url,
+    )
+    .await?;
+
+    let fetch_failed = || {
+        RESTError::bad_request(format!(
+            "Document `{document}`'s source URL could not be fetched."
+        ))
+    };
+
+    let mut response = client.get(url).send().await.map_err(|_| fetch_failed())?;
+
+    if !response.status().is_success() {
+        return Err(RESTError::bad_request(format!(
+            "Document `{document}`'s source URL responded with {}.",
+            response.status()
+        )));
+    }
+
+    let content_type = response
+        .headers()
+        .get(CONTENT_TYPE)
+        .map(|value| value.to_str())
+        .transpose()?
+        .unwrap_or_else(|| config.size_limits().default_document_mime())
+        .to_string();
+
+    if !is_mime_allowed(config, &content_type) {
+        return Err(RESTError::bad_request(format!(
+            "Invalid mime type: {content_type} received for the document: {document}"
+        )));
+    }
+
+    let mime: Mime = content_type.parse()?;
+
+    let maximum_document_size = config
+        .size_limits()
+        .maximum_document_size_for(&content_type);
+    let too_large = || {
+        RESTError::unprocessable_with_code(
+            "DOCUMENT_TOO_LARGE",
+            format!("Document `{document}` ({name}) is too large."),
+        )
+    };
+
+    // Reject before reading the body, when its size is already known.
+    if response
+        .headers()
+        .get(CONTENT_LENGTH)
+        .and_then(|value| value.to_str().ok()?.parse::<usize>().ok())
+        .is_some_and(|length| length > maximum_document_size)
+    {
+        return Err(too_large());
+    }
+
+    let mut data = Vec::new();
+    while let Some(chunk) = response.chunk().await.map_err(|_| fetch_failed())? {
+        data.extend_from_slice(&chunk);
+
+        if data.len() > maximum_document_size {
+            return Err(too_large());
+        }
+    }
+
+    Ok((String::from_utf8(data)?, mime))
+}
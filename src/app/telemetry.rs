@@ -0,0 +1,59 @@
+//! OpenTelemetry trace export, used for distributed tracing across services.
+
+use opentelemetry::trace::TracerProvider as _;
+use opentelemetry_otlp::{ExporterBuildError, SpanExporter, WithExportConfig as _};
+use opentelemetry_sdk::{
+    Resource,
+    trace::{SdkTracerProvider, Tracer},
+};
+use tracing::Subscriber;
+use tracing_opentelemetry::OpenTelemetryLayer;
+use tracing_subscriber::registry::LookupSpan;
+
+/// The service name attached to all exported spans.
+const SERVICE_NAME: &str = "platy-paste";
+
+/// ## Tracer Provider
+///
+/// Build the tracer provider, exporting spans to the OTLP (gRPC) endpoint in batches.
+///
+/// ## Arguments
+///
+/// - `endpoint` - The OTLP collectors endpoint.
+///
+/// ## Errors
+///
+/// - [`ExporterBuildError`] - The exporter could not be built.
+///
+/// ## Returns
+///
+/// The [`SdkTracerProvider`], which must be shutdown to flush any remaining spans.
+pub fn tracer_provider(endpoint: &str) -> Result<SdkTracerProvider, ExporterBuildError> {
+    let exporter = SpanExporter::builder()
+        .with_tonic()
+        .with_endpoint(endpoint)
+        .build()?;
+
+    Ok(SdkTracerProvider::builder()
+        .with_resource(Resource::builder().with_service_name(SERVICE_NAME).build())
+        .with_batch_exporter(exporter)
+        .build())
+}
+
+/// ## Layer
+///
+/// Build the subscriber layer, that records spans to the tracer provider.
+///
+/// ## Arguments
+///
+/// - `provider` - The tracer provider spans are recorded to.
+///
+/// ## Returns
+///
+/// The [`OpenTelemetryLayer`].
+pub fn layer<S>(provider: &SdkTracerProvider) -> OpenTelemetryLayer<S, Tracer>
+where
+    S: Subscriber + for<'span> LookupSpan<'span>,
+{
+    tracing_opentelemetry::layer().with_tracer(provider.tracer(SERVICE_NAME))
+}
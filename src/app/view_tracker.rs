@@ -0,0 +1,69 @@
+//! The view tracker, used to debounce repeated views of a paste.
+
+use std::{
+    collections::{HashMap, hash_map::Entry},
+    net::IpAddr,
+    time::{Duration, Instant},
+};
+
+use tokio::sync::Mutex;
+
+use crate::models::snowflake::Snowflake;
+
+/// ## View Tracker
+///
+/// Tracks the pastes each client has recently viewed,
+/// so repeated views within the window are only counted once.
+pub struct ViewTracker {
+    /// The window a repeated view is not counted within.
+    window: Duration,
+    /// The time each paste was last counted as viewed by a client.
+    views: Mutex<HashMap<(Snowflake, IpAddr), Instant>>,
+}
+
+impl ViewTracker {
+    /// New.
+    ///
+    /// Create a new [`ViewTracker`] object.
+    ///
+    /// ## Arguments
+    ///
+    /// - `window` - The window a repeated view is not counted within (zero disables tracking).
+    pub fn new(window: Duration) -> Self {
+        Self {
+            window,
+            views: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Record View.
+    ///
+    /// Record a client viewing a paste.
+    ///
+    /// ## Arguments
+    ///
+    /// - `paste_id` - The ID of the paste viewed.
+    /// - `address` - The address of the client viewing the paste.
+    ///
+    /// ## Returns
+    ///
+    /// Returns [`true`] if the view should be counted, otherwise returns [`false`].
+    pub async fn record_view(&self, paste_id: &Snowflake, address: IpAddr) -> bool {
+        if self.window.is_zero() {
+            return true;
+        }
+
+        let now = Instant::now();
+        let mut views = self.views.lock().await;
+
+        views.retain(|_, viewed_at| now.duration_since(*viewed_at) < self.window);
+
+        match views.entry((*paste_id, address)) {
+            Entry::Occupied(_) => false,
+            Entry::Vacant(entry) => {
+                entry.insert(now);
+                true
+            }
+        }
+    }
+}
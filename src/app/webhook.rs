@@ -0,0 +1,165 @@
+//! Recording the views of pastes, and sending their view milestones to their webhooks.
+
+use std::net::IpAddr;
+
+use chrono::Utc;
+use reqwest::header::CONTENT_TYPE;
+use serde::Serialize;
+
+use crate::{
+    app::{application::App, config::Config, source::pinned_client},
+    models::{
+        errors::RESTError,
+        paste::{Paste, ViewEvent, ViewMilestone, hash_address},
+        snowflake::Snowflake,
+    },
+};
+
+/// The label identifying a webhook URL to the client.
+const WEBHOOK_SUBJECT: &str = "The pastes webhook URL";
+
+/// ## View Milestone Payload
+///
+/// The body sent to a pastes webhook when it reaches a view milestone.
+#[derive(Serialize)]
+struct ViewMilestonePayload {
+    /// The reason the milestone was reached.
+    event: ViewEvent,
+    /// The ID of the paste.
+    paste_id: Snowflake,
+    /// The views of the paste, once the milestone was reached.
+    views: usize,
+    /// The maximum allowed views of the paste.
+    max_views: Option<usize>,
+}
+
+/// Validate Webhook URL.
+///
+/// Check that a webhook URL can be sent to, before it is stored with a paste.
+///
+/// ## Arguments
+///
+/// - `config` - The config values to use.
+/// - `webhook_url` - The URL to check.
+///
+/// ## Errors
+///
+/// - [`RESTError`] - Webhooks are disabled, or the URL is not allowed.
+pub async fn validate_webhook_url(config: &Config, webhook_url: &str) -> Result<(), RESTError> {
+    let allowed_hosts = config.webhook_allowed_hosts();
+
+    if allowed_hosts.is_empty() {
+        return Err(RESTError::bad_request("Paste webhooks are disabled."));
+    }
+
+    pinned_client(config, allowed_hosts, WEBHOOK_SUBJECT, webhook_url).await?;
+
+    Ok(())
+}
+
+/// Record View.
+///
+/// Add a view to a paste, sending the view milestone to its webhook if one was reached.
+///
+/// The webhook is sent in the background, so a slow or failing webhook does not delay the view.
+/// When view analytics are enabled, the time of the view is also recorded.
+///
+/// ## Arguments
+///
+/// - `app` - The application state.
+/// - `paste` - The paste being viewed.
+/// - `address` - The address of the client viewing the paste (if known).
+///
+/// ## Errors
+///
+/// - [`RESTError`] - The view could not be added.
+pub async fn record_view(
+    app: &App,
+    paste: &mut Paste,
+    address: Option<IpAddr>,
+) -> Result<(), RESTError> {
+    let milestone = paste
+        .add_view(app.database().pool(), app.config().webhook_view_milestone())
+        .await?;
+
+    if app.config().record_view_analytics() {
+        let ip_hash = address.map(|address| hash_address(paste.id(), address));
+
+        Paste::insert_view(
+            app.database().pool(),
+            paste.id(),
+            Utc::now(),
+            ip_hash.as_deref(),
+        )
+        .await?;
+    }
+
+    if let Some(milestone) = milestone {
+        let payload = ViewMilestonePayload {
+            event: milestone.event(),
+            paste_id: *paste.id(),
+            views: milestone.views(),
+            max_views: paste.max_views(),
+        };
+
+        let app = app.clone();
+        tokio::spawn(async move {
+            if let Err(err) = send_view_milestone(app.config(), &milestone, &payload).await {
+                tracing::warn!(
+                    "Failed to send a view milestone of paste {}. Error: {err}",
+                    payload.paste_id
+                );
+            }
+        });
+    }
+
+    Ok(())
+}
+
+/// Send View Milestone.
+///
+/// Send a view milestone to the webhook of a paste.
+///
+/// The URL is checked again, as the addresses its host resolves to may have changed since it was stored.
+///
+/// ## Arguments
+///
+/// - `config` - The config values to use.
+/// - `milestone` - The milestone reached.
+/// - `payload` - The body to send.
+///
+/// ## Errors
+///
+/// - [`RESTError`] - The URL is no longer allowed, or the webhook could not be sent.
+async fn send_view_milestone(
+    config: &Config,
+    milestone: &ViewMilestone,
+    payload: &ViewMilestonePayload,
+) -> Result<(), RESTError> {
+    let (client, url) = pinned_client(
+        config,
+        config.webhook_allowed_hosts(),
+        WEBHOOK_SUBJECT,
+        milestone.webhook_url(),
+    )
+    .await?;
+
+    let body = serde_json::to_vec(payload).map_err(RESTError::internal_server)?;
+
+    let response = client
+        .post(url)
+        .header(CONTENT_TYPE, "application/json")
+        .body(body)
+        .send()
+        .await
+        .map_err(RESTError::internal_server)?;
+
+    if !response.status().is_success() {
+        return Err(RESTError::internal_server(format!(
+            "The webhook responded with {}.",
+            response.status()
+        )));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,88 @@
+//! The write limiter, used to cap the amount of concurrent writes to a single paste.
+
+use std::{collections::HashMap, sync::Arc, time::Duration};
+
+use tokio::sync::{Mutex, OwnedSemaphorePermit, Semaphore};
+
+use crate::models::snowflake::Snowflake;
+
+/// ## Write Limiter
+///
+/// Limits the amount of writes to the documents of each paste that can happen at once,
+/// so concurrent writes do not all race for the pastes size limits.
+pub struct WriteLimiter {
+    /// The maximum amount of concurrent writes to a single paste (none disables the limit).
+    maximum: Option<usize>,
+    /// How long a write waits for another to finish, before being rejected.
+    wait: Duration,
+    /// The semaphore of each paste currently being written to.
+    semaphores: Mutex<HashMap<Snowflake, Arc<Semaphore>>>,
+}
+
+impl WriteLimiter {
+    /// New.
+    ///
+    /// Create a new [`WriteLimiter`] object.
+    ///
+    /// ## Arguments
+    ///
+    /// - `maximum` - The maximum amount of concurrent writes to a single paste (none disables the limit).
+    /// - `wait` - How long a write waits for another to finish, before being rejected.
+    pub fn new(maximum: Option<usize>, wait: Duration) -> Self {
+        Self {
+            maximum,
+            wait,
+            semaphores: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Acquire.
+    ///
+    /// Acquire a permit to write to a paste, waiting briefly if too many writes are in progress.
+    ///
+    /// The permit must be held until the write is complete.
+    ///
+    /// ## Arguments
+    ///
+    /// - `paste_id` - The ID of the paste being written to.
+    ///
+    /// ## Returns
+    ///
+    /// - [`Option::Some`] - The [`WritePermit`].
+    /// - [`Option::None`] - Too many writes to the paste are in progress.
+    pub async fn acquire(&self, paste_id: &Snowflake) -> Option<WritePermit> {
+        let Some(maximum) = self.maximum else {
+            return Some(WritePermit { _permit: None });
+        };
+
+        let semaphore = {
+            let mut semaphores = self.semaphores.lock().await;
+
+            // A semaphore only referenced by the map has no writes holding or waiting on it.
+            semaphores.retain(|_, semaphore| Arc::strong_count(semaphore) > 1);
+
+            semaphores
+                .entry(*paste_id)
+                .or_insert_with(|| Arc::new(Semaphore::new(maximum)))
+                .clone()
+        };
+
+        tokio::time::timeout(self.wait, semaphore.acquire_owned())
+            .await
+            .ok()?
+            .ok()
+            .map(|permit| WritePermit {
+                _permit: Some(permit),
+            })
+    }
+}
+
+/// ## Write Permit
+///
+/// Allows a write to a paste, until it is dropped.
+///
+/// Empty when the limit is disabled.
+pub struct WritePermit {
+    /// The permit held, released once dropped.
+    _permit: Option<OwnedSemaphorePermit>,
+}
@@ -5,12 +5,14 @@ pub mod models;
 pub mod rest;
 
 use chrono::Local;
-use tracing_appender::rolling::{RollingFileAppender, Rotation};
 use tracing_subscriber::{fmt::time::FormatTime, layer::SubscriberExt};
 
-use std::net::SocketAddr;
+use std::time::Duration;
 
-use crate::rest::generate_router;
+use crate::{
+    app::{config::LogConfig, shutdown::serve_with_drain, telemetry},
+    rest::generate_router,
+};
 
 #[tokio::main]
 async fn main() {
@@ -28,29 +30,39 @@ async fn main() {
 
     let timer = LocalTimer {};
 
-    let file_appender = RollingFileAppender::builder()
-        .rotation(Rotation::DAILY)
-        .max_log_files(25)
-        .filename_prefix("platy-paste")
-        .filename_suffix("log")
-        .build("./logs/")
+    dotenvy::from_filename(".env").ok();
+
+    let log_config = LogConfig::from_env();
+
+    let file_appender = log_config
+        .file_appender()
         .expect("Rolling File Appender Failed to build.");
 
-    let (file_non_blocking, _file_guard) = tracing_appender::non_blocking(file_appender);
+    let (file_non_blocking, file_guard) = tracing_appender::non_blocking(file_appender);
 
     let file_subscriber = tracing_subscriber::fmt::layer()
         .with_ansi(false)
         .with_writer(file_non_blocking)
         .with_timer(timer.clone());
 
-    let (console_non_blocking, _console_guard) = tracing_appender::non_blocking(std::io::stdout());
+    let (console_non_blocking, console_guard) = tracing_appender::non_blocking(std::io::stdout());
     let console_subscriber = tracing_subscriber::fmt::layer()
         .with_writer(console_non_blocking)
         .with_timer(timer.clone());
 
+    let env_filter = log_config
+        .env_filter()
+        .expect("RUST_LOG requires valid level directives.");
+
+    let tracer_provider = log_config.otlp_endpoint().map(|endpoint| {
+        telemetry::tracer_provider(endpoint).expect("OTLP exporter failed to build.")
+    });
+
     let subscriber = tracing_subscriber::registry()
+        .with(env_filter)
         .with(file_subscriber)
-        .with(console_subscriber);
+        .with(console_subscriber)
+        .with(tracer_provider.as_ref().map(telemetry::layer));
 
     tracing::subscriber::set_global_default(subscriber).expect("Failed to set subscriber");
 
@@ -71,10 +83,11 @@ async fn main() {
     let version = env!("CARGO_PKG_VERSION");
 
     tracing::info!(
-        "Running Platy Paste Backend ({}) on {}:{}",
+        "Running Platy Paste Backend ({}) on {}:{} as worker {}",
         version,
         host,
-        port
+        port,
+        config.worker_id()
     );
 
     let listener = tokio::net::TcpListener::bind(format!("{host}:{port}"))
@@ -87,18 +100,32 @@ async fn main() {
             .expect("Failed to listen for shutdown signal");
     };
 
-    let server = axum::serve(
+    match serve_with_drain(
         listener,
-        app.into_make_service_with_connect_info::<SocketAddr>(),
-    );
+        app,
+        shutdown_signal,
+        Duration::from_secs(config.shutdown_drain_seconds()),
+    )
+    .await
+    {
+        Ok(true) => tracing::info!("Drained all in-flight requests."),
+        Ok(false) => tracing::warn!("Timed out draining in-flight requests."),
+        Err(err) => tracing::error!("Server failed. Error: {err}"),
+    }
+
+    if let Err(err) = handler.close().await {
+        tracing::error!("Failed to cleanly shutdown handler. Error: {err}");
+    } else {
+        tracing::info!("Successfully shutdown server.");
+    }
 
-    tokio::select! {
-        _ = server.with_graceful_shutdown(shutdown_signal) => {
-            if let Err(err) = handler.close().await {
-                tracing::error!("Failed to cleanly shutdown handler. Error: {err}");
-            } else {
-                tracing::info!("Successfully shutdown server.");
-            }
-        },
+    if let Some(tracer_provider) = tracer_provider
+        && let Err(err) = tracer_provider.shutdown()
+    {
+        tracing::error!("Failed to flush traces. Error: {err}");
     }
+
+    // Dropping the guards flushes any buffered logs, before the process exits.
+    drop(console_guard);
+    drop(file_guard);
 }
@@ -3,17 +3,27 @@
 use std::time::{SystemTime, UNIX_EPOCH};
 
 use crate::{
-    app::application::App,
+    app::{application::App, config::Config},
     models::errors::{AuthenticationError, DatabaseError, RESTError},
 };
-use axum::{RequestPartsExt, extract::FromRequestParts, http::request::Parts};
+use argon2::{
+    Argon2, PasswordHash, PasswordHasher as _, PasswordVerifier as _,
+    password_hash::{Error as PasswordHashError, SaltString},
+};
+use axum::{
+    RequestPartsExt,
+    extract::{FromRequestParts, OptionalFromRequestParts},
+    http::{header::AUTHORIZATION, request::Parts},
+};
 use axum_extra::{
     TypedHeader,
     headers::{Authorization, authorization::Bearer},
 };
 use base64::{Engine, prelude::BASE64_URL_SAFE};
 use secrecy::{ExposeSecret, SecretString};
-use sqlx::PgExecutor;
+use sha2::{Digest as _, Sha256};
+use sqlx::{PgExecutor, PgTransaction};
+use subtle::ConstantTimeEq as _;
 
 use super::snowflake::Snowflake;
 
@@ -52,6 +62,8 @@ impl Token {
     ///
     /// Fetch a paste ID from its token.
     ///
+    /// The token is looked up by its hash, as the plaintext token is never stored.
+    ///
     /// ## Arguments
     ///
     /// - `executor` - The database pool or transaction to use.
@@ -72,38 +84,77 @@ impl Token {
     where
         E: 'e + PgExecutor<'c>,
     {
-        Ok(sqlx::query_as!(
-            Self,
-            "SELECT paste_id, token FROM paste_tokens WHERE token = $1",
-            token,
+        let query = sqlx::query!(
+            "SELECT paste_id FROM paste_tokens WHERE token_hash = $1",
+            hash_token(token),
         )
         .fetch_optional(executor)
-        .await?)
+        .await?;
+
+        Ok(query.map(|q| Self::new(q.paste_id.into(), SecretString::from(token))))
+    }
+
+    /// Count For Paste.
+    ///
+    /// Count the tokens attached to a paste.
+    ///
+    /// ## Arguments
+    ///
+    /// - `executor` - The database pool or transaction to use.
+    /// - `paste_id` - The ID of the paste.
+    ///
+    /// ## Errors
+    ///
+    /// - [`DatabaseError`] - The database had an error.
+    ///
+    /// ## Returns
+    ///
+    /// The total count of tokens.
+    pub async fn count_for_paste<'e, 'c: 'e, E>(
+        executor: E,
+        paste_id: &Snowflake,
+    ) -> Result<usize, DatabaseError>
+    where
+        E: 'e + PgExecutor<'c>,
+    {
+        let paste_id: i64 = (*paste_id).into();
+        let count = sqlx::query_scalar!(
+            "SELECT COUNT(*) FROM paste_tokens WHERE paste_id = $1",
+            paste_id
+        )
+        .fetch_one(executor)
+        .await?
+        .unwrap_or(0);
+
+        Ok(count as usize)
     }
 
     /// Insert.
     ///
     /// Insert (create) a paste token.
     ///
+    /// Only the hash of the token is stored.
+    ///
     /// ## Arguments
     ///
     /// - `executor` - The database pool or transaction to use.
     ///
     /// ## Errors
     ///
-    /// - [`DatabaseError`] - The database had an error, or the snowflake exists already.
+    /// - [`DatabaseError`] - The database had an error, or the paste already has a token ([`DatabaseError::Conflict`]).
     pub async fn insert<'e, 'c: 'e, E>(&self, executor: E) -> Result<(), DatabaseError>
     where
         E: 'e + PgExecutor<'c>,
     {
         let paste_id: i64 = self.paste_id.into();
         sqlx::query!(
-            "INSERT INTO paste_tokens(paste_id, token) VALUES ($1, $2)",
+            "INSERT INTO paste_tokens(paste_id, token_hash) VALUES ($1, $2)",
             paste_id,
-            self.token.expose_secret()
+            hash_token(self.token.expose_secret())
         )
         .execute(executor)
-        .await?;
+        .await
+        .map_err(|e| DatabaseError::from_insert(e, "The paste already has a token."))?;
 
         Ok(())
     }
@@ -124,12 +175,40 @@ impl Token {
     where
         E: 'e + PgExecutor<'c>,
     {
-        sqlx::query!("DELETE FROM paste_tokens WHERE token = $1", token,)
-            .execute(executor)
-            .await?;
+        sqlx::query!(
+            "DELETE FROM paste_tokens WHERE token_hash = $1",
+            hash_token(token),
+        )
+        .execute(executor)
+        .await?;
 
         Ok(())
     }
+
+    /// Rotate.
+    ///
+    /// Replace this token with a freshly generated one.
+    ///
+    /// ## Arguments
+    ///
+    /// - `transaction` - The transaction to use.
+    ///
+    /// ## Errors
+    ///
+    /// - [`RESTError`] - The token could not be generated, or the database had an error.
+    ///
+    /// ## Returns
+    ///
+    /// The newly generated [`Token`].
+    pub async fn rotate(&self, transaction: &mut PgTransaction<'_>) -> Result<Self, RESTError> {
+        let token = Self::new(self.paste_id, generate_token(self.paste_id)?);
+
+        Self::delete(transaction.as_mut(), self.token.expose_secret()).await?;
+
+        token.insert(transaction.as_mut()).await?;
+
+        Ok(token)
+    }
 }
 
 impl FromRequestParts<App> for Token {
@@ -149,6 +228,166 @@ impl FromRequestParts<App> for Token {
     }
 }
 
+impl OptionalFromRequestParts<App> for Token {
+    type Rejection = RESTError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &App,
+    ) -> Result<Option<Self>, Self::Rejection> {
+        if !parts.headers.contains_key(AUTHORIZATION) {
+            return Ok(None);
+        }
+
+        Ok(Some(
+            <Self as FromRequestParts<App>>::from_request_parts(parts, state).await?,
+        ))
+    }
+}
+
+/// ## Admin Token
+///
+/// A request authenticated with the admin token from the config.
+#[derive(Clone, Copy, Debug)]
+pub struct AdminToken;
+
+impl FromRequestParts<App> for AdminToken {
+    type Rejection = RESTError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &App) -> Result<Self, Self::Rejection> {
+        let TypedHeader(Authorization(bearer)) = parts
+            .extract::<TypedHeader<Authorization<Bearer>>>()
+            .await
+            .map_err(|_| AuthenticationError::MissingCredentials)?;
+
+        let Some(admin_token) = state.config().admin_token() else {
+            return Err(AuthenticationError::InvalidCredentials.into());
+        };
+
+        if !bool::from(
+            bearer
+                .token()
+                .as_bytes()
+                .ct_eq(admin_token.expose_secret().as_bytes()),
+        ) {
+            return Err(AuthenticationError::InvalidCredentials.into());
+        }
+
+        Ok(Self)
+    }
+}
+
+/// ## Paste Password Header
+///
+/// The header used to provide the password for a password protected paste.
+pub const PASTE_PASSWORD_HEADER: &str = "x-paste-password";
+
+/// ## Other Paste Password Header
+///
+/// The header used to provide the password for the other paste of a request spanning two pastes.
+pub const OTHER_PASTE_PASSWORD_HEADER: &str = "x-other-paste-password";
+
+/// ## Paste Token Header
+///
+/// The header used to return the token of a newly created paste, when requested.
+pub const PASTE_TOKEN_HEADER: &str = "x-paste-token";
+
+/// ## Paste Password
+///
+/// The password provided for reading a password protected paste.
+#[derive(Clone, Debug, Default)]
+pub struct PastePassword(Option<SecretString>);
+
+impl PastePassword {
+    /// The password provided (if any).
+    #[inline]
+    pub const fn password(&self) -> Option<&SecretString> {
+        self.0.as_ref()
+    }
+}
+
+impl PastePassword {
+    /// Extract the password from the header provided.
+    fn from_header(parts: &Parts, header: &str) -> Result<Self, RESTError> {
+        let Some(value) = parts.headers.get(header) else {
+            return Ok(Self(None));
+        };
+
+        Ok(Self(Some(SecretString::from(value.to_str()?.to_string()))))
+    }
+}
+
+impl FromRequestParts<App> for PastePassword {
+    type Rejection = RESTError;
+
+    async fn from_request_parts(parts: &mut Parts, _: &App) -> Result<Self, Self::Rejection> {
+        Self::from_header(parts, PASTE_PASSWORD_HEADER)
+    }
+}
+
+/// ## Other Paste Password
+///
+/// The password provided for reading the other paste of a request spanning two pastes.
+#[derive(Clone, Debug, Default)]
+pub struct OtherPastePassword(PastePassword);
+
+impl OtherPastePassword {
+    /// The password provided, falling back to the password of the first paste if missing.
+    #[inline]
+    pub const fn or<'a>(&'a self, password: &'a PastePassword) -> &'a PastePassword {
+        if self.0.password().is_some() {
+            &self.0
+        } else {
+            password
+        }
+    }
+}
+
+impl FromRequestParts<App> for OtherPastePassword {
+    type Rejection = RESTError;
+
+    async fn from_request_parts(parts: &mut Parts, _: &App) -> Result<Self, Self::Rejection> {
+        Ok(Self(PastePassword::from_header(
+            parts,
+            OTHER_PASTE_PASSWORD_HEADER,
+        )?))
+    }
+}
+
+/// Enforce Token Maximum.
+///
+/// Enforce the maximum amount of tokens a single paste can have, before a new token is minted for it.
+///
+/// ## Arguments
+///
+/// - `transaction` - The transaction to use.
+/// - `config` - The config to check against.
+/// - `paste_id` - The ID of the paste the token is minted for.
+///
+/// ## Errors
+///
+/// - [`RESTError`] - Returned when the paste already has the maximum amount of tokens.
+pub async fn enforce_token_maximum(
+    transaction: &mut PgTransaction<'_>,
+    config: &Config,
+    paste_id: &Snowflake,
+) -> Result<(), RESTError> {
+    let Some(maximum) = config.max_tokens_per_paste() else {
+        return Ok(());
+    };
+
+    let token_count = Token::count_for_paste(transaction.as_mut(), paste_id).await?;
+
+    if token_count >= maximum {
+        return Err(RESTError::conflict(format!(
+            "The paste has too many tokens. Expected: {maximum}, Received: {}",
+            token_count + 1
+        )));
+    }
+
+    Ok(())
+}
+
 /// Generate Token.
 ///
 /// ## Parameters
@@ -191,3 +430,165 @@ pub fn generate_token(paste_id: Snowflake) -> Result<SecretString, RESTError> {
         format!("{paste_id_encrypted}.{timestamp_encrypted}.{unique_token}").into(),
     ))
 }
+
+/// Hash Token.
+///
+/// Tokens are stored (and looked up) by their hash, so they are not exposed by the database.
+///
+/// ## Parameters
+///
+/// - `token` - The token to hash.
+///
+/// ## Returns
+///
+/// The hex encoded SHA-256 digest of the token.
+pub fn hash_token(token: &str) -> String {
+    format!("{:x}", Sha256::digest(token.as_bytes()))
+}
+
+/// Hash Password.
+///
+/// ## Parameters
+///
+/// - `password` - The password to hash.
+///
+/// ## Errors
+///
+/// - [`RESTError`] - Raised when the salt could not be generated, or the password could not be hashed.
+///
+/// ## Returns
+///
+/// The argon2 hash of the password (in the PHC string format).
+pub fn hash_password(password: &SecretString) -> Result<String, RESTError> {
+    let mut buffer = [0; 16];
+
+    getrandom::fill(&mut buffer).map_err(|e| {
+        RESTError::internal_server(format!("Failed to obtain a random integers: {e}"))
+    })?;
+
+    let salt = SaltString::encode_b64(&buffer)
+        .map_err(|e| RESTError::internal_server(format!("Failed to encode the salt: {e}")))?;
+
+    let hash = Argon2::default()
+        .hash_password(password.expose_secret().as_bytes(), &salt)
+        .map_err(|e| RESTError::internal_server(format!("Failed to hash the password: {e}")))?;
+
+    Ok(hash.to_string())
+}
+
+/// Verify Password.
+///
+/// ## Parameters
+///
+/// - `password` - The password to verify.
+/// - `hash` - The argon2 hash to verify against.
+///
+/// ## Errors
+///
+/// - [`RESTError`] - Raised when the hash is invalid, or could not be verified.
+///
+/// ## Returns
+///
+/// True if the password matches the hash, otherwise False.
+pub fn verify_password(password: &SecretString, hash: &str) -> Result<bool, RESTError> {
+    let hash = PasswordHash::new(hash).map_err(|e| {
+        RESTError::internal_server(format!("Failed to parse the password hash: {e}"))
+    })?;
+
+    match Argon2::default().verify_password(password.expose_secret().as_bytes(), &hash) {
+        Ok(()) => Ok(true),
+        Err(PasswordHashError::Password) => Ok(false),
+        Err(e) => Err(RESTError::internal_server(format!(
+            "Failed to verify the password: {e}"
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::PgPool;
+
+    use super::*;
+
+    #[sqlx::test(fixtures(path = "../../tests/fixtures/", scripts("pastes")))]
+    async fn test_token_stored_hashed(pool: PgPool) {
+        let paste_id = Snowflake::new(517_815_304_354_284_605);
+        let token = generate_token(paste_id).expect("Failed to generate token.");
+
+        Token::new(paste_id, token.clone())
+            .insert(&pool)
+            .await
+            .expect("Failed to insert token.");
+
+        let stored: String =
+            sqlx::query_scalar("SELECT token_hash FROM paste_tokens WHERE paste_id = $1")
+                .bind(i64::from(paste_id))
+                .fetch_one(&pool)
+                .await
+                .expect("Failed to make DB request");
+
+        assert_ne!(
+            stored,
+            token.expose_secret(),
+            "The plaintext token was stored."
+        );
+        assert_eq!(
+            stored,
+            hash_token(token.expose_secret()),
+            "The stored token is not the hash of the token."
+        );
+
+        let fetched = Token::fetch(&pool, token.expose_secret())
+            .await
+            .expect("Failed to make DB request")
+            .expect("No token was found.");
+
+        assert_eq!(fetched.paste_id(), &paste_id, "Mismatched paste ID.");
+        assert_eq!(
+            fetched.token().expose_secret(),
+            token.expose_secret(),
+            "Mismatched token."
+        );
+
+        assert!(
+            Token::fetch(&pool, &hash_token(token.expose_secret()))
+                .await
+                .expect("Failed to make DB request")
+                .is_none(),
+            "The stored hash authenticated as a token."
+        );
+    }
+
+    #[sqlx::test(fixtures(path = "../../tests/fixtures/", scripts("pastes", "tokens")))]
+    async fn test_insert_duplicate(pool: PgPool) {
+        let paste_id = Snowflake::new(517_815_304_354_284_605);
+        let token = generate_token(paste_id).expect("Failed to generate token.");
+
+        let error = Token::new(paste_id, token)
+            .insert(&pool)
+            .await
+            .expect_err("A duplicate token was inserted.");
+
+        assert!(
+            matches!(error, DatabaseError::Conflict(_)),
+            "Error is not a conflict: {error}"
+        );
+    }
+
+    #[sqlx::test(fixtures(path = "../../tests/fixtures/", scripts("pastes", "tokens")))]
+    async fn test_fixture_token_authenticates(pool: PgPool) {
+        let token = Token::fetch(
+            &pool,
+            "NTE3ODE1MzA0MzU0Mjg0NjA1.MTc3MDQzODc5Mw==.ozlKKwEEZpoGVuNzPDCyOMRGv",
+        )
+        .await
+        .expect("Failed to make DB request")
+        .expect("No token was found.");
+
+        assert_eq!(
+            token.paste_id(),
+            &Snowflake::new(517_815_304_354_284_605),
+            "Mismatched paste ID."
+        );
+    }
+}
@@ -1,15 +1,19 @@
 //! Paste object and related items.
 
+use std::{borrow::Cow, collections::HashSet};
+
+use mime::Mime;
 use regex::Regex;
 use serde::Serialize;
+use sha2::{Digest as _, Sha256};
 use sqlx::{PgExecutor, PgTransaction, Postgres, QueryBuilder, Row};
 
 #[cfg(test)]
 use serde::Deserialize;
 
 use crate::{
-    app::config::Config,
-    models::{errors::RESTError, snowflake::PartialSnowflake, undefined::Undefined},
+    app::config::{Config, DocumentNameCase, MimePolicy, TextNormalization},
+    models::{errors::RESTError, undefined::Undefined},
 };
 
 use super::{errors::DatabaseError, snowflake::Snowflake};
@@ -38,6 +42,28 @@ const SUPPORTED_MIMES: &[&str] = &[
 pub const UNSUPPORTED_MIMES: &[&str] =
     &["image/*", "video/*", "audio/*", "font/*", "application/pdf"];
 
+/// The document names allowed without a file extension by default, when one is required.
+pub const DEFAULT_EXTENSIONLESS_NAMES: &[&str] = &[
+    "Makefile",
+    "Dockerfile",
+    "Containerfile",
+    "Jenkinsfile",
+    "Vagrantfile",
+    "Gemfile",
+    "Rakefile",
+    "Procfile",
+    "LICENSE",
+];
+
+/// The maximum amount of tags a document can have.
+pub const MAXIMUM_DOCUMENT_TAGS: usize = 10;
+
+/// The maximum size of a documents tag (bytes).
+pub const MAXIMUM_DOCUMENT_TAG_SIZE: usize = 32;
+
+/// The header containing the SHA-256 checksum of a documents contents.
+pub const CONTENT_SHA256_HEADER: &str = "x-content-sha256";
+
 /// ## Document
 ///
 /// The document object stored in the database.
@@ -55,6 +81,12 @@ pub struct Document {
     name: String,
     /// The size of the document.
     size: usize,
+    /// The SHA-256 hex digest of the document contents.
+    checksum: Option<String>,
+    /// The version of the document, incremented on every edit.
+    version: usize,
+    /// The tags of the document.
+    tags: Vec<String>,
 }
 
 impl Document {
@@ -67,6 +99,8 @@ impl Document {
         doc_type: &str,
         name: &str,
         size: usize,
+        checksum: Option<&str>,
+        tags: &[String],
     ) -> Self {
         Self {
             id,
@@ -74,6 +108,9 @@ impl Document {
             doc_type: doc_type.to_string(),
             name: name.to_string(),
             size,
+            checksum: checksum.map(ToString::to_string),
+            version: 0,
+            tags: tags.to_vec(),
         }
     }
 
@@ -107,6 +144,26 @@ impl Document {
         self.size
     }
 
+    /// The documents checksum.
+    ///
+    /// This is [`None`] for documents created before checksums were recorded.
+    #[inline]
+    pub fn checksum(&self) -> Option<&str> {
+        self.checksum.as_deref()
+    }
+
+    /// The documents version.
+    #[inline]
+    pub const fn version(&self) -> usize {
+        self.version
+    }
+
+    /// The documents tags.
+    #[inline]
+    pub fn tags(&self) -> &[String] {
+        &self.tags
+    }
+
     /// Generate URL.
     ///
     /// Generate a URL to fetch the location of the document.
@@ -120,19 +177,57 @@ impl Document {
     /// The URL generated.
     #[inline]
     pub fn generate_url(&self, base_url: &str) -> String {
-        format!("{}/documents/{}", base_url, self.generate_path())
+        format!(
+            "{}/documents/{}/{}/{}",
+            base_url, self.paste_id, self.id, self.name
+        )
     }
 
     /// Generate Path.
     ///
     /// Generate the path to the resource.
     ///
+    /// ## Arguments
+    ///
+    /// - `name_case` - Whether the case of the name is kept, or the canonical name is used.
+    ///
     /// ## Returns
     ///
     /// The path generated.
     #[inline]
-    pub fn generate_path(&self) -> String {
-        format!("{}/{}/{}", self.paste_id, self.id, self.name)
+    pub fn generate_path(&self, name_case: DocumentNameCase) -> String {
+        format!(
+            "{}/{}/{}",
+            self.paste_id,
+            self.id,
+            canonical_document_name(name_case, &self.name)
+        )
+    }
+
+    /// Generate `ETag`.
+    ///
+    /// Generate the entity tag for the documents contents.
+    ///
+    /// This is built from the stored metadata only, so it can be generated without reading the contents.
+    ///
+    /// ## Returns
+    ///
+    /// The quoted entity tag generated.
+    #[inline]
+    pub fn generate_etag(&self) -> String {
+        format!("\"{}-{}\"", self.id, self.size)
+    }
+
+    /// Generate Version `ETag`.
+    ///
+    /// Generate the entity tag for the documents current version.
+    ///
+    /// ## Returns
+    ///
+    /// The quoted entity tag generated.
+    #[inline]
+    pub fn generate_version_etag(&self) -> String {
+        format!("\"{}\"", self.version)
     }
 
     /// Fetch.
@@ -161,20 +256,25 @@ impl Document {
     {
         let paste_id: i64 = (*id).into();
         let query = sqlx::query!(
-            "SELECT id, paste_id, type, name, size FROM documents WHERE id = $1",
+            "SELECT id, paste_id, type, name, size, checksum, version, tags FROM documents WHERE id = $1",
             paste_id
         )
         .fetch_optional(executor)
         .await?;
 
         if let Some(q) = query {
-            return Ok(Some(Self::new(
-                q.id.into(),
-                q.paste_id.into(),
-                &q.r#type,
-                &q.name,
-                q.size as usize,
-            )));
+            return Ok(Some(Self {
+                version: q.version as usize,
+                ..Self::new(
+                    q.id.into(),
+                    q.paste_id.into(),
+                    &q.r#type,
+                    &q.name,
+                    q.size as usize,
+                    q.checksum.as_deref(),
+                    &q.tags,
+                )
+            }));
         }
 
         Ok(None)
@@ -209,7 +309,7 @@ impl Document {
         let paste_id: i64 = (*paste_id).into();
         let id: i64 = (*id).into();
         let query = sqlx::query!(
-            "SELECT id, paste_id, type, name, size FROM documents WHERE paste_id = $1 AND id = $2",
+            "SELECT id, paste_id, type, name, size, checksum, version, tags FROM documents WHERE paste_id = $1 AND id = $2",
             paste_id,
             id
         )
@@ -217,13 +317,75 @@ impl Document {
         .await?;
 
         if let Some(q) = query {
-            return Ok(Some(Self::new(
-                q.id.into(),
-                q.paste_id.into(),
-                &q.r#type,
-                &q.name,
-                q.size as usize,
-            )));
+            return Ok(Some(Self {
+                version: q.version as usize,
+                ..Self::new(
+                    q.id.into(),
+                    q.paste_id.into(),
+                    &q.r#type,
+                    &q.name,
+                    q.size as usize,
+                    q.checksum.as_deref(),
+                    &q.tags,
+                )
+            }));
+        }
+
+        Ok(None)
+    }
+
+    /// Fetch By Name.
+    ///
+    /// Fetch a document via its name, within a paste.
+    ///
+    /// ## Arguments
+    ///
+    /// - `executor` - The database pool or transaction to use.
+    /// - `paste_id` - The ID of the paste the document belongs to.
+    /// - `name` - The name of the document.
+    /// - `name_case` - Whether names that only differ by case are the same name.
+    ///
+    /// ## Errors
+    ///
+    /// - [`DatabaseError`] - The database had an error.
+    ///
+    /// ## Returns
+    ///
+    /// - [`Option::Some`] - The [`Document`] object.
+    /// - [`Option::None`] - No document was found.
+    pub async fn fetch_by_name<'e, 'c: 'e, E>(
+        executor: E,
+        paste_id: &Snowflake,
+        name: &str,
+        name_case: DocumentNameCase,
+    ) -> Result<Option<Self>, DatabaseError>
+    where
+        E: 'e + PgExecutor<'c>,
+    {
+        let paste_id: i64 = (*paste_id).into();
+        let case_insensitive = name_case == DocumentNameCase::Insensitive;
+        let query = sqlx::query!(
+            "SELECT id, paste_id, type, name, size, checksum, version, tags FROM documents WHERE paste_id = $1 AND (name = $2 OR ($3 AND lower(name) = lower($2))) ORDER BY id LIMIT 1",
+            paste_id,
+            name,
+            case_insensitive
+        )
+        .fetch_optional(executor)
+        .await?;
+
+        if let Some(q) = query {
+            return Ok(Some(Self {
+                version: q.version as usize,
+                ..Self::new(
+                    q.id.into(),
+                    q.paste_id.into(),
+                    &q.r#type,
+                    &q.name,
+                    q.size as usize,
+                    q.checksum.as_deref(),
+                    &q.tags,
+                )
+            }));
         }
 
         Ok(None)
@@ -231,7 +393,7 @@ impl Document {
 
     /// Fetch All.
     ///
-    /// Fetch all documents attached to a paste.
+    /// Fetch all documents attached to a paste, ordered by their ID (oldest first).
     ///
     /// ## Arguments
     ///
@@ -245,6 +407,7 @@ impl Document {
     /// ## Returns
     ///
     /// A [`Vec`] of [`Document`]'s.
+    #[tracing::instrument(name = "Document::fetch_all", skip_all, fields(paste.id = %id))]
     pub async fn fetch_all<'e, 'c: 'e, E>(
         executor: E,
         id: &Snowflake,
@@ -254,7 +417,7 @@ impl Document {
     {
         let paste_id: i64 = (*id).into();
         let query = sqlx::query!(
-            "SELECT id, paste_id, type, name, size FROM documents WHERE paste_id = $1",
+            "SELECT id, paste_id, type, name, size, checksum, version, tags FROM documents WHERE paste_id = $1 ORDER BY id",
             paste_id
         )
         .fetch_all(executor)
@@ -262,13 +425,70 @@ impl Document {
 
         let mut documents: Vec<Self> = Vec::new();
         for record in query {
-            documents.push(Self::new(
-                record.id.into(),
-                record.paste_id.into(),
-                &record.r#type,
-                &record.name,
-                record.size as usize,
-            ));
+            documents.push(Self {
+                version: record.version as usize,
+                ..Self::new(
+                    record.id.into(),
+                    record.paste_id.into(),
+                    &record.r#type,
+                    &record.name,
+                    record.size as usize,
+                    record.checksum.as_deref(),
+                    &record.tags,
+                )
+            });
+        }
+        Ok(documents)
+    }
+
+    /// Fetch All By Tag.
+    ///
+    /// Fetch all documents attached to a paste, that have the tag, ordered by their ID (oldest first).
+    ///
+    /// ## Arguments
+    ///
+    /// - `executor` - The database pool or transaction to use.
+    /// - `id` - The ID of the paste.
+    /// - `tag` - The tag the documents must have.
+    ///
+    /// ## Errors
+    ///
+    /// - [`DatabaseError`] - The database had an error.
+    ///
+    /// ## Returns
+    ///
+    /// A [`Vec`] of [`Document`]'s.
+    pub async fn fetch_all_by_tag<'e, 'c: 'e, E>(
+        executor: E,
+        id: &Snowflake,
+        tag: &str,
+    ) -> Result<Vec<Self>, DatabaseError>
+    where
+        E: 'e + PgExecutor<'c>,
+    {
+        let paste_id: i64 = (*id).into();
+        let query = sqlx::query!(
+            "SELECT id, paste_id, type, name, size, checksum, version, tags FROM documents WHERE paste_id = $1 AND $2 = ANY(tags) ORDER BY id",
+            paste_id,
+            tag
+        )
+        .fetch_all(executor)
+        .await?;
+
+        let mut documents: Vec<Self> = Vec::new();
+        for record in query {
+            documents.push(Self {
+                version: record.version as usize,
+                ..Self::new(
+                    record.id.into(),
+                    record.paste_id.into(),
+                    &record.r#type,
+                    &record.name,
+                    record.size as usize,
+                    record.checksum.as_deref(),
+                    &record.tags,
+                )
+            });
         }
         Ok(documents)
     }
@@ -340,6 +560,71 @@ impl Document {
         Ok(size as usize)
     }
 
+    /// Fetch Owned Document Count.
+    ///
+    /// Fetch the total amount of documents attached to every paste owned by an owner.
+    ///
+    /// Pastes that have been soft deleted are not included.
+    ///
+    /// ## Arguments
+    ///
+    /// - `executor` - The database pool or transaction to use.
+    /// - `owner_id` - The paste ID of the owner.
+    ///
+    /// ## Errors
+    ///
+    /// - [`DatabaseError`] - The database had an error.
+    ///
+    /// ## Returns
+    ///
+    /// The total count of documents.
+    pub async fn fetch_owned_document_count<'e, 'c: 'e, E>(
+        executor: E,
+        owner_id: &Snowflake,
+    ) -> Result<usize, DatabaseError>
+    where
+        E: 'e + PgExecutor<'c>,
+    {
+        let owner_id: i64 = (*owner_id).into();
+        let count = sqlx::query_scalar!(
+            "SELECT COUNT(*) FROM documents INNER JOIN pastes ON pastes.id = documents.paste_id WHERE COALESCE(pastes.owner_id, pastes.id) = $1 AND pastes.deleted_at IS NULL",
+            owner_id
+        )
+        .fetch_one(executor)
+        .await?
+        .unwrap_or(0);
+
+        Ok(count as usize)
+    }
+
+    /// Fetch Totals.
+    ///
+    /// Fetch the total amount of documents, and their total size, across every paste.
+    ///
+    /// ## Arguments
+    ///
+    /// - `executor` - The database pool or transaction to use.
+    ///
+    /// ## Errors
+    ///
+    /// - [`DatabaseError`] - The database had an error.
+    ///
+    /// ## Returns
+    ///
+    /// The total count of documents, and their total size (bytes).
+    pub async fn fetch_totals<'e, 'c: 'e, E>(executor: E) -> Result<(usize, usize), DatabaseError>
+    where
+        E: 'e + PgExecutor<'c>,
+    {
+        let record = sqlx::query!(
+            r#"SELECT COUNT(*) AS "document_count!", COALESCE(SUM(size), 0)::BIGINT AS "total_size!" FROM documents"#
+        )
+        .fetch_one(executor)
+        .await?;
+
+        Ok((record.document_count as usize, record.total_size as usize))
+    }
+
     /// Insert.
     ///
     /// Insert (create) a document.
@@ -350,7 +635,8 @@ impl Document {
     ///
     /// ## Errors
     ///
-    /// - [`DatabaseError`] - The database had an error, or the snowflake exists already.
+    /// - [`DatabaseError`] - The database had an error, or the snowflake exists already ([`DatabaseError::Conflict`]).
+    #[tracing::instrument(name = "Document::insert", skip_all, fields(document.id = %self.id))]
     pub async fn insert<'e, 'c: 'e, E>(&self, executor: E) -> Result<(), DatabaseError>
     where
         E: 'e + PgExecutor<'c>,
@@ -359,15 +645,18 @@ impl Document {
         let paste_id: i64 = self.paste_id.into();
 
         sqlx::query!(
-            "INSERT INTO documents(id, paste_id, type, name, size) VALUES ($1, $2, $3, $4, $5)",
+            "INSERT INTO documents(id, paste_id, type, name, size, checksum, tags) VALUES ($1, $2, $3, $4, $5, $6, $7)",
             document_id,
             paste_id,
             self.doc_type,
             self.name,
-            self.size as i64
+            self.size as i64,
+            self.checksum,
+            &self.tags
         )
         .execute(executor)
-        .await?;
+        .await
+        .map_err(|e| DatabaseError::from_insert(e, "A document with this ID already exists."))?;
 
         Ok(())
     }
@@ -399,42 +688,33 @@ impl Document {
         let paste_id_val: i64 = self.paste_id.into();
 
         let mut builder: QueryBuilder<'_, Postgres> =
-            sqlx::QueryBuilder::new("UPDATE documents SET");
-        let mut requires_comma = false;
+            sqlx::QueryBuilder::new("UPDATE documents SET version = version + 1");
 
         if let Undefined::Some(doc_type) = parameters.doc_type() {
-            if requires_comma {
-                builder.push(",");
-            } else {
-                requires_comma = true;
-            }
-
-            builder.push(" type = ");
+            builder.push(", type = ");
             builder.push_bind(doc_type);
         }
 
         if let Undefined::Some(name) = parameters.name() {
-            if requires_comma {
-                builder.push(",");
-            } else {
-                requires_comma = true;
-            }
-
-            builder.push(" name = ");
+            builder.push(", name = ");
             builder.push_bind(name);
         }
 
         if let Undefined::Some(size) = parameters.size() {
-            if requires_comma {
-                builder.push(",");
-            } else {
-                //requires_comma = true; // Left for future implementations
-            }
-
-            builder.push(" size = ");
+            builder.push(", size = ");
             builder.push_bind(size as i64);
         }
 
+        if let Undefined::Some(checksum) = parameters.checksum() {
+            builder.push(", checksum = ");
+            builder.push_bind(checksum);
+        }
+
+        if let Undefined::Some(tags) = parameters.tags() {
+            builder.push(", tags = ");
+            builder.push_bind(tags);
+        }
+
         builder.push(" WHERE paste_id = ");
         builder.push_bind(paste_id_val);
         builder.push(" AND id = ");
@@ -447,6 +727,56 @@ impl Document {
         self.name = record.get("name");
         let size: i64 = record.get("size");
         self.size = size as usize;
+        self.checksum = record.get("checksum");
+        let version: i64 = record.get("version");
+        self.version = version as usize;
+        self.tags = record.get("tags");
+
+        Ok(true)
+    }
+
+    /// Set Paste ID.
+    ///
+    /// Move the document to another paste.
+    ///
+    /// ## Arguments
+    ///
+    /// - `executor` - The database pool or transaction to use.
+    /// - `paste_id` - The ID of the paste to move the document to.
+    ///
+    /// ## Errors
+    ///
+    /// - [`DatabaseError`] - The database had an error.
+    ///
+    /// ## Returns
+    ///
+    /// Returns [`true`] if the document was moved, otherwise returns [`false`].
+    pub async fn set_paste_id<'e, 'c: 'e, E>(
+        &mut self,
+        executor: E,
+        paste_id: &Snowflake,
+    ) -> Result<bool, DatabaseError>
+    where
+        E: 'e + PgExecutor<'c>,
+    {
+        let id: i64 = self.id.into();
+        let current_paste_id: i64 = self.paste_id.into();
+        let new_paste_id: i64 = (*paste_id).into();
+
+        let result = sqlx::query!(
+            "UPDATE documents SET paste_id = $1 WHERE paste_id = $2 AND id = $3",
+            new_paste_id,
+            current_paste_id,
+            id
+        )
+        .execute(executor)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Ok(false);
+        }
+
+        self.paste_id = *paste_id;
 
         Ok(true)
     }
@@ -484,6 +814,8 @@ pub struct DocumentUpdateParameters {
     doc_type: Undefined<String>,
     name: Undefined<String>,
     size: Undefined<usize>,
+    checksum: Undefined<String>,
+    tags: Undefined<Vec<String>>,
 }
 
 impl DocumentUpdateParameters {
@@ -494,11 +826,15 @@ impl DocumentUpdateParameters {
         doc_type: Undefined<String>,
         name: Undefined<String>,
         size: Undefined<usize>,
+        checksum: Undefined<String>,
+        tags: Undefined<Vec<String>>,
     ) -> Self {
         Self {
             doc_type,
             name,
             size,
+            checksum,
+            tags,
         }
     }
 
@@ -517,6 +853,16 @@ impl DocumentUpdateParameters {
         self.size
     }
 
+    /// The checksum to update the document with.
+    pub fn checksum(&self) -> Undefined<&str> {
+        self.checksum.as_deref()
+    }
+
+    /// The tags to update the document with.
+    pub fn tags(&self) -> Undefined<&[String]> {
+        self.tags.as_deref()
+    }
+
     /// ## Is Empty
     ///
     /// Used to check if the update parameters updates nothing.
@@ -524,7 +870,11 @@ impl DocumentUpdateParameters {
     /// ## Returns
     /// Returns [`true`] if all parameters are undefined, otherwise returns [`false`].
     pub const fn is_empty(&self) -> bool {
-        self.doc_type.is_undefined() && self.name.is_undefined() && self.size.is_undefined()
+        self.doc_type.is_undefined()
+            && self.name.is_undefined()
+            && self.size.is_undefined()
+            && self.checksum.is_undefined()
+            && self.tags.is_undefined()
     }
 }
 
@@ -570,120 +920,518 @@ pub fn contains_mime(mimes: &[&str], value: &str) -> bool {
     false
 }
 
-/// Document Limits.
+/// Validate Tags.
 ///
-/// Validate that a document is within the requirements.
+/// Validate the tags of a document.
 ///
 /// ## Arguments
 ///
-/// - `id` - The documents relavant ID.
-/// - `config` - The config to check again.
-/// - `document` - The document to check.
+/// - `document` - The label of the document in the request, used in errors.
+/// - `tags` - The tags to validate.
 ///
 /// ## Errors
 ///
-/// - [`RESTError`] - Returned when the documents are outside of the limits.
-pub fn document_limits(
-    config: &Config,
-    id: &PartialSnowflake,
-    name: Undefined<&str>,
-    content: Undefined<&str>,
-) -> Result<(), RESTError> {
-    let size_limits = config.size_limits();
-
-    if let Undefined::Some(content) = content {
-        let content_length = content.len();
+/// - [`RESTError`] - Returned when there are too many tags, or a tag is blank or too large.
+pub fn validate_tags(document: &str, tags: &[String]) -> Result<(), RESTError> {
+    if tags.len() > MAXIMUM_DOCUMENT_TAGS {
+        return Err(RESTError::bad_request(format!(
+            "Document `{document}` has too many tags. Expected: {MAXIMUM_DOCUMENT_TAGS}, Received: {}",
+            tags.len()
+        )));
+    }
 
-        if size_limits.minimum_document_size() > content_length {
+    for tag in tags {
+        if tag.trim().is_empty() {
             return Err(RESTError::bad_request(format!(
-                "Document `{id}` is too small."
+                "Document `{document}`'s tags cannot be blank."
             )));
         }
 
-        if size_limits.maximum_document_size() < content_length {
+        if tag.len() > MAXIMUM_DOCUMENT_TAG_SIZE {
             return Err(RESTError::bad_request(format!(
-                "Document `{id}` is too large."
+                "Document `{document}` has a tag that is too large."
             )));
         }
     }
 
-    if let Undefined::Some(name) = name {
-        let name_length = name.len();
-
-        if size_limits.minimum_document_name_size() > name_length {
-            return Err(RESTError::bad_request(format!(
-                "Document `{id}`'s name: `{name}` is too small."
-            )));
-        }
+    Ok(())
+}
 
-        if size_limits.maximum_document_name_size() < name_length {
-            if name_length > 50 {
-                return Err(RESTError::bad_request(format!(
-                    "Document `{}`'s name: `{}`... is too large.",
-                    id,
-                    &name[..name.char_indices().nth(47).map_or(name.len(), |(i, _)| i)]
-                )));
-            }
-            return Err(RESTError::bad_request(format!(
-                "Document `{id}`'s name: `{name}` is too large."
-            )));
+/// Is Mime Allowed.
+///
+/// Checks if a document can have the mime, under the configured mime policy.
+///
+/// ## Arguments
+///
+/// - `config` - The config values to use.
+/// - `value` - The mime to check.
+///
+/// ## Returns
+///
+/// True if the mime is allowed, otherwise False.
+pub fn is_mime_allowed(config: &Config, value: &str) -> bool {
+    match config.mime_policy() {
+        MimePolicy::Denylist => !contains_mime(UNSUPPORTED_MIMES, value),
+        MimePolicy::Allowlist => {
+            let allowed_mimes: Vec<&str> =
+                config.allowed_mimes().iter().map(String::as_str).collect();
+
+            contains_mime(&allowed_mimes, value)
         }
     }
-
-    Ok(())
 }
 
-/// Total Document Size Limit.
+/// Generate Checksum.
 ///
-/// Validate that all documents attached to a paste are within the limits.
+/// Generate the checksum of a documents contents.
 ///
 /// ## Arguments
 ///
-/// - `transaction` - The transaction to use.
-/// - `config` - The config to check again.
-/// - `paste_id` - The Paste ID the document(s) are attached to.
+/// - `content` - The contents of the document.
 ///
-/// ## Errors
+/// ## Returns
 ///
-/// - [`RESTError`] - Returned when the documents are outside of the limits.
-pub async fn total_document_limits(
-    transaction: &mut PgTransaction<'_>,
-    config: &Config,
-    paste_id: &Snowflake,
-) -> Result<(), RESTError> {
-    let size_limits = config.size_limits();
+/// The SHA-256 digest of the contents, as a lowercase hex string.
+pub fn generate_checksum(content: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(content))
+}
 
+/// Is Text Mime.
+///
+/// Checks if the mime is a text mime (`text/*` or `application/json`).
+pub fn is_text_mime(mime: &Mime) -> bool {
+    mime.type_() == mime::TEXT
+        || (mime.type_() == mime::APPLICATION && mime.subtype() == mime::JSON)
+}
+
+/// Normalize Content.
+///
+/// Normalize the contents of a text document, as configured.
+///
+/// The contents of non text documents are returned unchanged.
+///
+/// ## Arguments
+///
+/// - `config` - The config values to use.
+/// - `mime` - The mime of the document.
+/// - `content` - The contents of the document.
+///
+/// ## Returns
+///
+/// The normalized contents.
+pub fn normalize_content(config: &Config, mime: &Mime, content: String) -> String {
+    let normalization = config.normalize_text();
+
+    if normalization == TextNormalization::Off || !is_text_mime(mime) {
+        return content;
+    }
+
+    let content = if normalization.crlf_to_lf() {
+        content.replace("\r\n", "\n")
+    } else {
+        content
+    };
+
+    if !normalization.trailing_whitespace() {
+        return content;
+    }
+
+    content
+        .split('\n')
+        .map(|line| {
+            // A kept carriage return still ends the line, so the whitespace before it is removed.
+            line.strip_suffix('\r').map_or_else(
+                || Cow::Borrowed(line.trim_end_matches([' ', '\t'])),
+                |line| Cow::Owned(format!("{}\r", line.trim_end_matches([' ', '\t']))),
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Object Key Size.
+///
+/// The largest size the object store key of a document with the name can have,
+/// as its paste and document IDs are not known before it is stored.
+///
+/// ## Arguments
+///
+/// - `key_prefix` - The namespace the key is placed under (if set).
+/// - `name_case` - Whether the case of the name is kept, or the canonical name is used.
+/// - `name` - The name of the document.
+///
+/// ## Returns
+///
+/// The size of the key (bytes).
+fn object_key_size(key_prefix: Option<&str>, name_case: DocumentNameCase, name: &str) -> usize {
+    // The key is `{prefix}/{paste_id}/{id}/{name}`, see [`Document::generate_path`].
+    let maximum_snowflake_size = u64::MAX.ilog10() as usize + 1;
+    let key_prefix_size = key_prefix.map_or(0, |key_prefix| key_prefix.len() + 1);
+
+    key_prefix_size
+        + maximum_snowflake_size * 2
+        + 2
+        + canonical_document_name(name_case, name).len()
+}
+
+/// Has File Extension.
+///
+/// Checks if a document name ends with a file extension, that follows a non empty stem.
+///
+/// ## Arguments
+///
+/// - `name` - The name of the document.
+///
+/// ## Returns
+///
+/// Returns [`true`] if the name has an alphanumeric extension (such as `.txt`), otherwise [`false`].
+fn has_file_extension(name: &str) -> bool {
+    name.rsplit_once('.').is_some_and(|(stem, extension)| {
+        !stem.is_empty()
+            && !extension.is_empty()
+            && extension.chars().all(|c| c.is_ascii_alphanumeric())
+    })
+}
+
+/// Document Limits.
+///
+/// Validate that a document is within the requirements.
+///
+/// ## Arguments
+///
+/// - `config` - The config to check again.
+/// - `document` - The label identifying the document to the client (such as `files[0]`).
+/// - `name` - The name of the document.
+/// - `content` - The content of the document.
+/// - `mime` - The mime of the document, which the maximum size of its content depends on.
+///
+/// ## Errors
+///
+/// - [`RESTError`] - Returned when the documents are outside of the limits.
+pub fn document_limits(
+    config: &Config,
+    document: &str,
+    name: Undefined<&str>,
+    content: Undefined<&str>,
+    mime: &str,
+) -> Result<(), RESTError> {
+    let size_limits = config.size_limits();
+
+    if let Undefined::Some(name) = name {
+        if name.chars().all(|c| c.is_whitespace() || c.is_control()) {
+            return Err(RESTError::unprocessable(format!(
+                "Document `{document}`'s name cannot be blank."
+            )));
+        }
+
+        let name_length = name.len();
+
+        if size_limits.minimum_document_name_size() > name_length {
+            return Err(RESTError::unprocessable(format!(
+                "Document `{document}`'s name: `{name}` is too small."
+            )));
+        }
+
+        if size_limits.maximum_document_name_size() < name_length {
+            if name_length > 50 {
+                return Err(RESTError::unprocessable(format!(
+                    "Document `{}`'s name: `{}`... is too large.",
+                    document,
+                    &name[..name.char_indices().nth(47).map_or(name.len(), |(i, _)| i)]
+                )));
+            }
+            return Err(RESTError::unprocessable(format!(
+                "Document `{document}`'s name: `{name}` is too large."
+            )));
+        }
+
+        let maximum_object_key_size = size_limits.maximum_object_key_size();
+
+        if object_key_size(
+            config.object_store().key_prefix(),
+            config.document_name_case(),
+            name,
+        ) > maximum_object_key_size
+        {
+            return Err(RESTError::unprocessable(format!(
+                "Document `{document}`'s name is too large to be stored (its key would exceed {maximum_object_key_size} bytes)."
+            )));
+        }
+
+        if config.require_file_extension()
+            && !has_file_extension(name)
+            && !config
+                .extensionless_names()
+                .iter()
+                .any(|allowed| allowed.eq_ignore_ascii_case(name))
+        {
+            return Err(RESTError::unprocessable(format!(
+                "Document `{document}`'s name: `{name}` requires a file extension (such as `{name}.txt`)."
+            )));
+        }
+    }
+
+    if let Undefined::Some(content) = content {
+        let content_length = content.len();
+
+        let described = match name {
+            Undefined::Some(name) => format!("Document `{document}` ({name})"),
+            Undefined::Undefined => format!("Document `{document}`"),
+        };
+
+        if size_limits.minimum_document_size() > content_length {
+            return Err(RESTError::unprocessable_with_code(
+                "DOCUMENT_TOO_SMALL",
+                format!("{described} is too small."),
+            ));
+        }
+
+        if size_limits.maximum_document_size_for(mime) < content_length {
+            return Err(RESTError::unprocessable_with_code(
+                "DOCUMENT_TOO_LARGE",
+                format!("{described} is too large."),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Enforce Maximums.
+///
+/// Validate that all documents attached to a paste do not exceed the maximum limits.
+///
+/// This should always be applied whenever documents are added to a paste.
+///
+/// ## Arguments
+///
+/// - `transaction` - The transaction to use.
+/// - `config` - The config to check again.
+/// - `paste_id` - The Paste ID the document(s) are attached to.
+///
+/// ## Errors
+///
+/// - [`RESTError`] - Returned when the documents are above the maximum limits.
+pub async fn enforce_maximums(
+    transaction: &mut PgTransaction<'_>,
+    config: &Config,
+    paste_id: &Snowflake,
+) -> Result<(), RESTError> {
     let total_document_count =
         Document::fetch_total_document_count(transaction.as_mut(), paste_id).await?;
 
-    if size_limits.minimum_total_document_count() > total_document_count {
+    let total_document_size =
+        Document::fetch_total_document_size(transaction.as_mut(), paste_id).await?;
+
+    total_document_maximums(config, total_document_count, total_document_size)
+}
+
+/// Enforce Owner Maximum.
+///
+/// Enforce the maximum amount of documents a single owner can have across all of its pastes.
+///
+/// ## Arguments
+///
+/// - `transaction` - The transaction to use.
+/// - `config` - The config to check against.
+/// - `owner_id` - The paste ID of the owner.
+///
+/// ## Errors
+///
+/// - [`RESTError`] - Returned when the owner has more documents than allowed.
+pub async fn enforce_owner_maximum(
+    transaction: &mut PgTransaction<'_>,
+    config: &Config,
+    owner_id: &Snowflake,
+) -> Result<(), RESTError> {
+    let Some(maximum) = config.max_documents_per_owner() else {
+        return Ok(());
+    };
+
+    let owned_document_count =
+        Document::fetch_owned_document_count(transaction.as_mut(), owner_id).await?;
+
+    if owned_document_count > maximum {
         return Err(RESTError::bad_request(format!(
-            "Not enough documents were provided. Expected: {}, Received: {}",
-            size_limits.minimum_total_document_count(),
-            total_document_count,
+            "The owner has too many documents. Expected: {maximum}, Received: {owned_document_count}"
         )));
     }
 
+    Ok(())
+}
+
+/// Enforce Unique Names.
+///
+/// Enforce that no two documents attached to a paste share the same name.
+///
+/// ## Arguments
+///
+/// - `transaction` - The transaction to use.
+/// - `config` - The config to check against.
+/// - `paste_id` - The Paste ID the document(s) are attached to.
+///
+/// ## Errors
+///
+/// - [`RESTError`] - Returned when two documents share the same name.
+pub async fn enforce_unique_names(
+    transaction: &mut PgTransaction<'_>,
+    config: &Config,
+    paste_id: &Snowflake,
+) -> Result<(), RESTError> {
+    let documents = Document::fetch_all(transaction.as_mut(), paste_id).await?;
+
+    validate_unique_names(
+        config.document_name_case(),
+        documents.iter().map(Document::name),
+    )
+}
+
+/// Validate Unique Names.
+///
+/// Validate that none of the names are the same, once made canonical.
+///
+/// ## Arguments
+///
+/// - `name_case` - Whether names that only differ by case are the same name.
+/// - `names` - The names to check.
+///
+/// ## Errors
+///
+/// - [`RESTError`] - Returned when two of the names are the same.
+pub fn validate_unique_names<'a>(
+    name_case: DocumentNameCase,
+    names: impl IntoIterator<Item = &'a str>,
+) -> Result<(), RESTError> {
+    let mut seen = HashSet::new();
+
+    for name in names {
+        if !seen.insert(canonical_document_name(name_case, name)) {
+            return Err(RESTError::bad_request(format!(
+                "More than one document has the name: `{name}`."
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// Canonical Document Name.
+///
+/// The name used to compare documents, and to generate their paths.
+///
+/// ## Arguments
+///
+/// - `name_case` - Whether names that only differ by case are the same name.
+/// - `name` - The name of the document.
+///
+/// ## Returns
+///
+/// The name unchanged when case sensitive, otherwise the lowercase name.
+pub fn canonical_document_name(name_case: DocumentNameCase, name: &str) -> Cow<'_, str> {
+    match name_case {
+        DocumentNameCase::Sensitive => Cow::Borrowed(name),
+        DocumentNameCase::Insensitive => Cow::Owned(name.to_lowercase()),
+    }
+}
+
+/// Total Document Maximums.
+///
+/// Validate that the totals of a pastes documents do not exceed the maximum limits.
+///
+/// ## Arguments
+///
+/// - `config` - The config to check again.
+/// - `total_document_count` - The amount of documents attached to the paste.
+/// - `total_document_size` - The total size of the documents attached to the paste.
+///
+/// ## Errors
+///
+/// - [`RESTError`] - Returned when the documents are above the maximum limits.
+pub fn total_document_maximums(
+    config: &Config,
+    total_document_count: usize,
+    total_document_size: usize,
+) -> Result<(), RESTError> {
+    let size_limits = config.size_limits();
+
     if size_limits.maximum_total_document_count() < total_document_count {
-        return Err(RESTError::bad_request(format!(
+        return Err(RESTError::unprocessable(format!(
             "Too many documents were provided. Expected: {}, Received: {}",
             size_limits.maximum_total_document_count(),
             total_document_count,
         )));
     }
 
+    if size_limits.maximum_total_document_size() < total_document_size {
+        return Err(RESTError::unprocessable(
+            "One or more documents exceed the maximum individual document size.",
+        ));
+    }
+
+    Ok(())
+}
+
+/// Enforce Minimums.
+///
+/// Validate that all documents attached to a paste meet the minimum limits.
+///
+/// This should only be applied once the paste is finalized (for example on creation),
+/// so that a paste can grow one document at a time.
+///
+/// ## Arguments
+///
+/// - `transaction` - The transaction to use.
+/// - `config` - The config to check again.
+/// - `paste_id` - The Paste ID the document(s) are attached to.
+///
+/// ## Errors
+///
+/// - [`RESTError`] - Returned when the documents are below the minimum limits.
+pub async fn enforce_minimums(
+    transaction: &mut PgTransaction<'_>,
+    config: &Config,
+    paste_id: &Snowflake,
+) -> Result<(), RESTError> {
+    let total_document_count =
+        Document::fetch_total_document_count(transaction.as_mut(), paste_id).await?;
+
     let total_document_size =
         Document::fetch_total_document_size(transaction.as_mut(), paste_id).await?;
 
-    if size_limits.minimum_total_document_size() > total_document_size {
-        return Err(RESTError::bad_request(
-            "One or more documents is below the minimum individual document size.",
-        ));
+    total_document_minimums(config, total_document_count, total_document_size)
+}
+
+/// Total Document Minimums.
+///
+/// Validate that the totals of a pastes documents meet the minimum limits.
+///
+/// ## Arguments
+///
+/// - `config` - The config to check again.
+/// - `total_document_count` - The amount of documents attached to the paste.
+/// - `total_document_size` - The total size of the documents attached to the paste.
+///
+/// ## Errors
+///
+/// - [`RESTError`] - Returned when the documents are below the minimum limits.
+pub fn total_document_minimums(
+    config: &Config,
+    total_document_count: usize,
+    total_document_size: usize,
+) -> Result<(), RESTError> {
+    let size_limits = config.size_limits();
+
+    if size_limits.minimum_total_document_count() > total_document_count {
+        return Err(RESTError::unprocessable(format!(
+            "Not enough documents were provided. Expected: {}, Received: {}",
+            size_limits.minimum_total_document_count(),
+            total_document_count,
+        )));
     }
 
-    if size_limits.maximum_total_document_size() < total_document_size {
-        return Err(RESTError::bad_request(
-            "One or more documents exceed the maximum individual document size.",
+    if size_limits.minimum_total_document_size() > total_document_size {
+        return Err(RESTError::unprocessable(
+            "One or more documents is below the minimum individual document size.",
         ));
     }
 
@@ -697,7 +1445,7 @@ mod tests {
     use sqlx::PgPool;
 
     use crate::app::{
-        config::{Config, SizeLimitConfig},
+        config::{Config, DocumentNameCase, MimePolicy, SizeLimitConfig},
         database::Database,
     };
 
@@ -721,15 +1469,284 @@ mod tests {
             .expect("Failed to build config.")
     }
 
+    #[rstest]
+    #[case(DocumentNameCase::Sensitive, &["A.txt", "a.txt"], true)]
+    #[case(DocumentNameCase::Insensitive, &["A.txt", "a.txt"], false)]
+    #[case(DocumentNameCase::Sensitive, &["a.txt", "a.txt"], false)]
+    #[case(DocumentNameCase::Insensitive, &["a.txt", "b.txt"], true)]
+    fn test_validate_unique_names(
+        #[case] name_case: DocumentNameCase,
+        #[case] names: &[&str],
+        #[case] expected: bool,
+    ) {
+        assert_eq!(
+            validate_unique_names(name_case, names.iter().copied()).is_ok(),
+            expected,
+            "Unexpected validation result for names: {names:?}"
+        );
+    }
+
+    #[rstest]
+    #[case(
+        DocumentNameCase::Sensitive,
+        "517815304354284605/517815304354284708/A.txt"
+    )]
+    #[case(
+        DocumentNameCase::Insensitive,
+        "517815304354284605/517815304354284708/a.txt"
+    )]
+    fn test_generate_path(#[case] name_case: DocumentNameCase, #[case] expected: &str) {
+        let document = Document::new(
+            Snowflake::new(517_815_304_354_284_708),
+            Snowflake::new(517_815_304_354_284_605),
+            "text/plain",
+            "A.txt",
+            4,
+            None,
+            &[],
+        );
+
+        assert_eq!(
+            document.generate_path(name_case),
+            expected,
+            "Path does not match."
+        );
+    }
+
+    #[rstest]
+    #[case(&[], true)]
+    #[case(&["rust", "examples"], true)]
+    #[case(&["a"; MAXIMUM_DOCUMENT_TAGS], true)]
+    #[case(&["a"; MAXIMUM_DOCUMENT_TAGS + 1], false)]
+    #[case(&[""], false)]
+    #[case(&["   "], false)]
+    #[case(&["abcdefghijklmnopqrstuvwxyz0123456"], false)]
+    fn test_validate_tags(#[case] tags: &[&str], #[case] expected: bool) {
+        let tags: Vec<String> = tags.iter().map(ToString::to_string).collect();
+
+        assert_eq!(
+            validate_tags("files[0]", &tags).is_ok(),
+            expected,
+            "Unexpected validation result for tags: {tags:?}"
+        );
+    }
+
+    #[rstest]
+    #[case(MimePolicy::Allowlist, "text/plain", true)]
+    #[case(MimePolicy::Allowlist, "text/markdown", true)]
+    #[case(MimePolicy::Allowlist, "application/json", true)]
+    #[case(MimePolicy::Allowlist, "application/zip", false)]
+    #[case(MimePolicy::Allowlist, "image/png", false)]
+    #[case(MimePolicy::Denylist, "text/plain", true)]
+    #[case(MimePolicy::Denylist, "application/zip", true)]
+    #[case(MimePolicy::Denylist, "image/png", false)]
+    #[case(MimePolicy::Denylist, "application/pdf", false)]
+    fn test_is_mime_allowed(
+        #[case] mime_policy: MimePolicy,
+        #[case] mime: &str,
+        #[case] expected: bool,
+    ) {
+        let config = Config::test_builder()
+            .mime_policy(mime_policy)
+            .allowed_mimes(vec![
+                String::from("text/*"),
+                String::from("application/json"),
+            ])
+            .build()
+            .expect("Failed to build config.");
+
+        assert_eq!(
+            is_mime_allowed(&config, mime),
+            expected,
+            "Mime allowance does not match."
+        );
+    }
+
+    #[rstest]
+    #[case(TextNormalization::Off, "text/plain", "a \r\nb\t\r\n", "a \r\nb\t\r\n")]
+    #[case(
+        TextNormalization::TrailingWhitespace,
+        "text/plain",
+        "a \r\nb\t\r\n",
+        "a\r\nb\r\n"
+    )]
+    #[case(
+        TextNormalization::CrlfToLf,
+        "text/plain",
+        "a \r\nb\t\r\n",
+        "a \nb\t\n"
+    )]
+    #[case(TextNormalization::Both, "text/plain", "a \r\nb\t\r\n", "a\nb\n")]
+    #[case(TextNormalization::Both, "application/json", "{} \r\n", "{}\n")]
+    #[case(TextNormalization::Both, "text/plain", "a\rb \r", "a\rb\r")]
+    #[case(TextNormalization::Both, "application/x-yaml", "a \r\n", "a \r\n")]
+    fn test_normalize_content(
+        #[case] normalize_text: TextNormalization,
+        #[case] mime: &str,
+        #[case] content: &str,
+        #[case] expected: &str,
+    ) {
+        let config = Config::test_builder()
+            .normalize_text(normalize_text)
+            .build()
+            .expect("Failed to build config.");
+
+        let mime: Mime = mime.parse().expect("Failed to parse mime.");
+
+        assert_eq!(
+            normalize_content(&config, &mime, content.to_string()),
+            expected,
+            "Contents do not match."
+        );
+    }
+
     #[test]
     fn test_document_limits() {
         document_limits(
             &make_document_limits_config(1, 3, 1_000_000, 50),
-            &PartialSnowflake::new(123),
+            "123",
             Undefined::Some("text/plain"),
             Undefined::Some("some random content."),
+            "text/plain",
         )
         .expect("An error occurred.");
+
+        document_limits(
+            &make_document_limits_config(1, 3, 1_000_000, 50),
+            "123",
+            Undefined::Some("a.txt"),
+            Undefined::Some("some random content."),
+            "text/plain",
+        )
+        .expect("An error occurred.");
+    }
+
+    #[rstest]
+    #[case("text/plain", true)]
+    #[case("text/plain; charset=utf-8", true)]
+    #[case("text/markdown", false)]
+    #[case("application/json", false)]
+    fn test_document_limits_mime(#[case] mime: &str, #[case] expected: bool) {
+        let config = Config::test_builder()
+            .size_limits(
+                SizeLimitConfig::test_builder()
+                    .minimum_document_size(1)
+                    .minimum_document_name_size(3)
+                    .maximum_document_size(10)
+                    .maximum_document_name_size(50)
+                    .mime_size_limits(vec![
+                        (String::from("text/*"), 50),
+                        (String::from("text/plain"), 100),
+                        (String::from("text/markdown"), 20),
+                    ])
+                    .build()
+                    .expect("Failed to build size limits."),
+            )
+            .build()
+            .expect("Failed to build config.");
+
+        let content: String = (0..80).map(|_| 'a').collect();
+
+        let result = document_limits(
+            &config,
+            "123",
+            Undefined::Some("test_doc"),
+            Undefined::Some(&content),
+            mime,
+        );
+
+        assert_eq!(result.is_ok(), expected, "Document limits do not match.");
+    }
+
+    #[rstest]
+    #[case(900, true)]
+    #[case(982, true)]
+    #[case(983, false)]
+    #[case(1000, false)]
+    fn test_document_limits_object_key_size(#[case] name_size: usize, #[case] expected: bool) {
+        let config = Config::test_builder()
+            .size_limits(
+                SizeLimitConfig::test_builder()
+                    .minimum_document_size(1)
+                    .minimum_document_name_size(3)
+                    .maximum_document_size(1_000_000)
+                    .maximum_document_name_size(2000)
+                    .maximum_object_key_size(1024)
+                    .build()
+                    .expect("Failed to build size limits."),
+            )
+            .build()
+            .expect("Failed to build config.");
+
+        let name: String = (0..name_size).map(|_| 'a').collect();
+
+        let result = document_limits(
+            &config,
+            "123",
+            Undefined::Some(&name),
+            Undefined::Some("some random content."),
+            "text/plain",
+        );
+
+        assert_eq!(result.is_ok(), expected, "Document limits do not match.");
+
+        if let Err(RESTError::Unprocessable(message)) = result {
+            assert_eq!(
+                message,
+                "Document `123`'s name is too large to be stored (its key would exceed 1024 bytes).",
+                "Message does not match."
+            );
+        }
+    }
+
+    #[rstest]
+    #[case(true, "notes", false)]
+    #[case(true, "notes.txt", true)]
+    #[case(true, "notes.", false)]
+    #[case(true, ".notes", false)]
+    #[case(true, "Makefile", true)]
+    #[case(true, "makefile", true)]
+    #[case(false, "notes", true)]
+    #[case(false, "notes.txt", true)]
+    #[case(false, ".notes", true)]
+    fn test_document_limits_file_extension(
+        #[case] require_file_extension: bool,
+        #[case] name: &str,
+        #[case] expected: bool,
+    ) {
+        let config = Config::test_builder()
+            .size_limits(
+                SizeLimitConfig::test_builder()
+                    .minimum_document_size(1)
+                    .minimum_document_name_size(3)
+                    .maximum_document_size(1_000_000)
+                    .maximum_document_name_size(50)
+                    .build()
+                    .expect("Failed to build size limits."),
+            )
+            .require_file_extension(require_file_extension)
+            .build()
+            .expect("Failed to build config.");
+
+        let result = document_limits(
+            &config,
+            "123",
+            Undefined::Some(name),
+            Undefined::Some("some random content."),
+            "text/plain",
+        );
+
+        assert_eq!(result.is_ok(), expected, "Document limits do not match.");
+
+        if let Err(RESTError::Unprocessable(message)) = result {
+            assert_eq!(
+                message,
+                format!(
+                    "Document `123`'s name: `{name}` requires a file extension (such as `{name}.txt`)."
+                ),
+                "Message does not match."
+            );
+        }
     }
 
     #[rstest]
@@ -748,15 +1765,30 @@ mod tests {
         "this_is_a_really_long_document_name_that_is_far_too_long_for_use.txt",
         "Document `123`'s name: `this_is_a_really_long_document_name_that_is_far`... is too large."
     )]
+    #[case(
+        make_document_limits_config(1, 3, 1_000_000, 50),
+        "   ",
+        "Document `123`'s name cannot be blank."
+    )]
+    #[case(
+        make_document_limits_config(1, 0, 1_000_000, 50),
+        "",
+        "Document `123`'s name cannot be blank."
+    )]
+    #[case(
+        make_document_limits_config(1, 3, 1_000_000, 50),
+        "\t\t\t",
+        "Document `123`'s name cannot be blank."
+    )]
     #[case(
         make_document_limits_config(500, 3, 1_000_000, 50),
         "test_doc.txt",
-        "Document `123` is too small."
+        "Document `123` (test_doc.txt) is too small."
     )]
     #[case(
         make_document_limits_config(1, 3, 250, 50),
         "test_doc.txt",
-        "Document `123` is too large."
+        "Document `123` (test_doc.txt) is too large."
     )]
     fn test_document_limits_errors(
         #[case] config: Config,
@@ -767,13 +1799,19 @@ mod tests {
 
         let error = document_limits(
             &config,
-            &PartialSnowflake::new(123),
+            "123",
             Undefined::Some(title),
             Undefined::Some(&content),
+            "text/plain",
         )
         .expect_err("No error received.");
 
-        if let RESTError::BadRequest(bad_request) = error {
+        if let RESTError::Unprocessable(bad_request)
+        | RESTError::UnprocessableWithCode {
+            message: bad_request,
+            ..
+        } = error
+        {
             assert_eq!(
                 bad_request, expected,
                 "The bad request message received was unexpected."
@@ -803,8 +1841,118 @@ mod tests {
             .expect("Failed to build config.")
     }
 
+    #[sqlx::test(fixtures(path = "../../tests/fixtures/", scripts("pastes")))]
+    async fn test_fetch_all_order(pool: PgPool) {
+        let paste_id = Snowflake::new(517_815_304_354_284_605);
+
+        // Inserted out of order, so the insertion order cannot be relied upon.
+        for (id, name) in [(903, "c.txt"), (901, "a.txt"), (902, "b.txt")] {
+            Document::new(
+                Snowflake::new(id),
+                paste_id,
+                "text/plain",
+                name,
+                1,
+                None,
+                &[],
+            )
+            .insert(&pool)
+            .await
+            .expect("Failed to insert document.");
+        }
+
+        let documents = Document::fetch_all(&pool, &paste_id)
+            .await
+            .expect("Failed to make DB request");
+
+        let ids: Vec<u64> = documents
+            .iter()
+            .map(|document| document.id().id())
+            .collect();
+
+        assert_eq!(ids, vec![901, 902, 903], "Documents are not ordered by ID.");
+    }
+
+    #[sqlx::test(fixtures(path = "../../tests/fixtures/", scripts("pastes", "documents")))]
+    async fn test_insert_duplicate(pool: PgPool) {
+        let error = Document::new(
+            Snowflake::new(517_815_304_354_284_708),
+            Snowflake::new(517_815_304_354_284_605),
+            "text/plain",
+            "other.txt",
+            1,
+            None,
+            &[],
+        )
+        .insert(&pool)
+        .await
+        .expect_err("A duplicate document was inserted.");
+
+        assert!(
+            matches!(error, DatabaseError::Conflict(_)),
+            "Error is not a conflict: {error}"
+        );
+    }
+
+    #[sqlx::test(fixtures(path = "../../tests/fixtures/", scripts("pastes", "documents")))]
+    async fn test_enforce_limits(pool: PgPool) {
+        let db = Database::from_pool(pool);
+
+        let mut transaction = db
+            .pool()
+            .begin()
+            .await
+            .expect("Failed to generate a transaction.");
+
+        let config = make_total_document_limits_config(1, 1, 10, 10_000_000);
+        let paste_id = Snowflake::new(517_815_304_354_284_601);
+
+        enforce_maximums(&mut transaction, &config, &paste_id)
+            .await
+            .expect("An error occurred.");
+
+        enforce_minimums(&mut transaction, &config, &paste_id)
+            .await
+            .expect("An error occurred.");
+    }
+
+    #[sqlx::test(fixtures(path = "../../tests/fixtures/", scripts("pastes", "documents")))]
+    async fn test_enforce_maximums_below_minimums(pool: PgPool) {
+        let db = Database::from_pool(pool);
+
+        let mut transaction = db
+            .pool()
+            .begin()
+            .await
+            .expect("Failed to generate a transaction.");
+
+        let config = make_total_document_limits_config(5, 2500, 10, 10_000_000);
+        let paste_id = Snowflake::new(517_815_304_354_284_602);
+
+        enforce_maximums(&mut transaction, &config, &paste_id)
+            .await
+            .expect("An append below the minimums should be allowed.");
+
+        enforce_minimums(&mut transaction, &config, &paste_id)
+            .await
+            .expect_err("A finalized paste below the minimums should not be allowed.");
+    }
+
+    #[rstest]
+    #[case(
+        make_total_document_limits_config(1, 1, 1, 5000),
+        "Too many documents were provided. Expected: 1, Received: 2"
+    )]
+    #[case(
+        make_total_document_limits_config(1, 1, 5, 2000),
+        "One or more documents exceed the maximum individual document size."
+    )]
     #[sqlx::test(fixtures(path = "../../tests/fixtures/", scripts("pastes", "documents")))]
-    async fn test_total_document_limits(pool: PgPool) {
+    async fn test_enforce_maximums_errors(
+        #[ignore] pool: PgPool,
+        #[case] config: Config,
+        #[case] expected: &str,
+    ) {
         let db = Database::from_pool(pool);
 
         let mut transaction = db
@@ -813,13 +1961,22 @@ mod tests {
             .await
             .expect("Failed to generate a transaction.");
 
-        total_document_limits(
+        let error = enforce_maximums(
             &mut transaction,
-            &make_total_document_limits_config(1, 1, 10, 10_000_000),
-            &Snowflake::new(517_815_304_354_284_601),
+            &config,
+            &Snowflake::new(517_815_304_354_284_602),
         )
         .await
-        .expect("An error occurred.");
+        .expect_err("No error received.");
+
+        if let RESTError::Unprocessable(bad_request) = error {
+            assert_eq!(
+                bad_request, expected,
+                "The bad request message received was unexpected."
+            );
+        } else {
+            panic!("The error received, was not expected.");
+        }
     }
 
     #[rstest]
@@ -827,26 +1984,16 @@ mod tests {
         make_total_document_limits_config(5, 1, 5, 5000),
         "Not enough documents were provided. Expected: 5, Received: 2"
     )]
-    #[case(
-        make_total_document_limits_config(1, 1, 1, 5000),
-        "Too many documents were provided. Expected: 1, Received: 2"
-    )]
     #[case(
         make_total_document_limits_config(1, 2500, 5, 5000),
         "One or more documents is below the minimum individual document size."
     )]
-    #[case(
-        make_total_document_limits_config(1, 1, 5, 2000),
-        "One or more documents exceed the maximum individual document size."
-    )]
     #[sqlx::test(fixtures(path = "../../tests/fixtures/", scripts("pastes", "documents")))]
-    async fn test_total_document_limits_errors(
+    async fn test_enforce_minimums_errors(
         #[ignore] pool: PgPool,
         #[case] config: Config,
         #[case] expected: &str,
     ) {
-        use crate::app::database::Database;
-
         let db = Database::from_pool(pool);
 
         let mut transaction = db
@@ -855,7 +2002,7 @@ mod tests {
             .await
             .expect("Failed to generate a transaction.");
 
-        let error = total_document_limits(
+        let error = enforce_minimums(
             &mut transaction,
             &config,
             &Snowflake::new(517_815_304_354_284_602),
@@ -863,7 +2010,7 @@ mod tests {
         .await
         .expect_err("No error received.");
 
-        if let RESTError::BadRequest(bad_request) = error {
+        if let RESTError::Unprocessable(bad_request) = error {
             assert_eq!(
                 bad_request, expected,
                 "The bad request message received was unexpected."
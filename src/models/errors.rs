@@ -11,6 +11,8 @@ use chrono::Utc;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+use crate::rest::current_request_id;
+
 /// ## Application Error
 ///
 /// Errors related to the applictions creation and lifetime.
@@ -158,6 +160,34 @@ pub enum DatabaseError {
     /// Custom database errors.
     #[error("Database Custom: {0}")]
     Custom(String),
+    /// ## Conflict
+    ///
+    /// An item could not be inserted, as an item with the same unique value already exists.
+    #[error("Database Conflict: {0}")]
+    Conflict(String),
+}
+
+impl DatabaseError {
+    /// From Insert.
+    ///
+    /// Convert the error of an insert, so a unique violation becomes a [`Self::Conflict`].
+    ///
+    /// ## Arguments
+    ///
+    /// - `error` - The error returned by the insert.
+    /// - `message` - The message returned to the client, if a unique value already exists.
+    ///
+    /// ## Returns
+    ///
+    /// The [`DatabaseError`].
+    pub fn from_insert(error: sqlx::Error, message: &str) -> Self {
+        match error {
+            sqlx::Error::Database(ref database_error) if database_error.is_unique_violation() => {
+                Self::Conflict(message.to_string())
+            }
+            error => Self::Sqlx(error),
+        }
+    }
 }
 
 impl IntoResponse for DatabaseError {
@@ -176,6 +206,9 @@ impl IntoResponse for DatabaseError {
             Self::Custom(error) => {
                 RESTErrorResponse::new_response(StatusCode::BAD_REQUEST, "Custom Error", error)
             }
+            Self::Conflict(error) => {
+                RESTErrorResponse::new_response(StatusCode::CONFLICT, "Conflict", error)
+            }
         }
     }
 }
@@ -231,6 +264,14 @@ pub enum GenerateError {
     /// Errors from [`getrandom::Error`].
     #[error("Get Random Error: {0}")]
     GetRandom(#[from] getrandom::Error),
+    /// ## Database
+    ///
+    /// Errors from [`DatabaseError`], while checking if a generated value is in use.
+    #[error(transparent)]
+    Database(#[from] DatabaseError),
+    /// Every generated value was already in use.
+    #[error("Failed to generate an unused value after {0} attempts.")]
+    Exhausted(usize),
 }
 
 impl IntoResponse for GenerateError {
@@ -241,6 +282,12 @@ impl IntoResponse for GenerateError {
                 "Get Random Value Error",
                 error,
             ),
+            Self::Database(error) => error.into_response(),
+            Self::Exhausted(attempts) => RESTErrorResponse::new_response(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Generate Error",
+                format!("Failed to generate an unused ID after {attempts} attempts."),
+            ),
         }
     }
 }
@@ -294,6 +341,34 @@ impl From<serde_json::Error> for RESTError {
     }
 }
 
+/// Converts a payload that failed to deserialize into a bad request,
+/// identifying where within the payload the error occurred.
+impl From<serde_path_to_error::Error<serde_json::Error>> for RESTError {
+    fn from(value: serde_path_to_error::Error<serde_json::Error>) -> Self {
+        // The path is only known when the error occurred within a field.
+        let field = if value
+            .path()
+            .iter()
+            .any(|segment| !matches!(segment, serde_path_to_error::Segment::Unknown))
+        {
+            format!(" (`{}`)", value.path())
+        } else {
+            String::new()
+        };
+        let error = value.into_inner();
+
+        let (line, column) = (error.line(), error.column());
+        let message = error.to_string();
+        let message = message
+            .strip_suffix(&format!(" at line {line} column {column}"))
+            .unwrap_or(&message);
+
+        Self::bad_request(format!(
+            "Invalid payload at line {line} column {column}{field}: {message}"
+        ))
+    }
+}
+
 /// Implemented for easy conversion without mapping error type.
 impl From<regex::Error> for RESTError {
     fn from(value: regex::Error) -> Self {
@@ -423,6 +498,16 @@ pub enum AuthenticationError {
     /// The credentials that have been provided are invalid.
     #[error("Invalid Token and/or mismatched paste ID")]
     InvalidCredentials,
+    /// ## Missing Password
+    ///
+    /// The paste requires a password, and none was provided.
+    #[error("The paste requires a password")]
+    MissingPassword,
+    /// ## Invalid Password
+    ///
+    /// The password that has been provided for the paste is incorrect.
+    #[error("The password provided is incorrect")]
+    InvalidPassword,
 }
 
 impl IntoResponse for AuthenticationError {
@@ -438,6 +523,16 @@ impl IntoResponse for AuthenticationError {
                 "Invalid Credentials",
                 "Invalid Token and/or mismatched paste ID",
             ),
+            Self::MissingPassword => RESTErrorResponse::new_response(
+                StatusCode::UNAUTHORIZED,
+                "Missing Password",
+                "The paste requires a password",
+            ),
+            Self::InvalidPassword => RESTErrorResponse::new_response(
+                StatusCode::UNAUTHORIZED,
+                "Invalid Password",
+                "The password provided is incorrect",
+            ),
         }
     }
 }
@@ -500,11 +595,81 @@ pub enum RESTError {
     /// Custom errors related to bad requests (400).
     #[error("Bad Request Error: {0}")]
     BadRequest(String),
+    /// ## Bad Request With Code
+    ///
+    /// Custom errors related to bad requests, with a machine readable code (400).
+    #[error("Bad Request Error ({code}): {message}")]
+    BadRequestWithCode {
+        /// The machine readable code for the error.
+        code: &'static str,
+        /// The message about the error.
+        message: String,
+    },
+    /// ## Forbidden
+    ///
+    /// Custom errors related to requests the server refuses to fulfil (403).
+    #[error("Forbidden: {0}")]
+    Forbidden(String),
     /// ## Not Found
     ///
     /// Custom errors related to unfound items or endpoints (404).
     #[error("Not Found: {0}")]
     NotFound(String),
+    /// ## Method Not Allowed
+    ///
+    /// Custom errors related to methods that are not accepted by the endpoint (405).
+    #[error("Method Not Allowed: {0}")]
+    MethodNotAllowed(String),
+    /// ## Gone
+    ///
+    /// Custom errors related to items that existed, but are no longer available (410).
+    #[error("Gone: {0}")]
+    Gone(String),
+    /// ## Conflict
+    ///
+    /// Custom errors related to requests that conflict with an existing item (409).
+    #[error("Conflict: {0}")]
+    Conflict(String),
+    /// ## Precondition Failed
+    ///
+    /// Custom errors related to request preconditions that were not met (412).
+    #[error("Precondition Failed: {0}")]
+    PreconditionFailed(String),
+    /// ## Too Many Requests
+    ///
+    /// Custom errors related to clients exceeding a limit (429).
+    #[error("Too Many Requests: {0}")]
+    TooManyRequests(String),
+    /// ## Service Unavailable
+    ///
+    /// Custom errors related to the server being temporarily unavailable (503).
+    #[error("Service Unavailable: {0}")]
+    ServiceUnavailable(String),
+    /// ## Unprocessable
+    ///
+    /// Custom errors related to well formed requests, that are semantically invalid (422).
+    #[error("Unprocessable Entity: {0}")]
+    Unprocessable(String),
+    /// ## Unprocessable With Code
+    ///
+    /// Custom errors related to well formed requests that are semantically invalid, with a machine readable code (422).
+    #[error("Unprocessable Entity ({code}): {message}")]
+    UnprocessableWithCode {
+        /// The machine readable code for the error.
+        code: &'static str,
+        /// The message about the error.
+        message: String,
+    },
+    /// ## Unprocessable Entity
+    ///
+    /// Every validation failure of a request, when all of them were collected (422).
+    #[error("Unprocessable Entity: {} validation errors", .0.len())]
+    UnprocessableEntity(Vec<ValidationError>),
+    /// ## Maximum Views Below Current
+    ///
+    /// The maximum views requested is equal to or lower than the current view count (400).
+    #[error("Maximum views must be greater than the current view count: {0}")]
+    MaxViewsBelowCurrent(usize),
 }
 
 impl RESTError {
@@ -524,6 +689,44 @@ impl RESTError {
         Self::BadRequest(e.to_string())
     }
 
+    /// The easier method of using [`Self::BadRequestWithCode`] that takes any message that can be displayed.
+    pub fn bad_request_with_code<T>(code: &'static str, e: T) -> Self
+    where
+        T: std::fmt::Display,
+    {
+        Self::BadRequestWithCode {
+            code,
+            message: e.to_string(),
+        }
+    }
+
+    /// The easier method of using [`Self::Unprocessable`] that takes any value that can be displayed.
+    pub fn unprocessable<T>(e: T) -> Self
+    where
+        T: std::fmt::Display,
+    {
+        Self::Unprocessable(e.to_string())
+    }
+
+    /// The easier method of using [`Self::UnprocessableWithCode`] that takes any message that can be displayed.
+    pub fn unprocessable_with_code<T>(code: &'static str, e: T) -> Self
+    where
+        T: std::fmt::Display,
+    {
+        Self::UnprocessableWithCode {
+            code,
+            message: e.to_string(),
+        }
+    }
+
+    /// The easier method of using [`Self::Forbidden`] that takes any value that can be displayed.
+    pub fn forbidden<T>(e: T) -> Self
+    where
+        T: std::fmt::Display,
+    {
+        Self::Forbidden(e.to_string())
+    }
+
     /// The easier method of using [`Self::NotFound`] that takes any value that can be displayed.
     pub fn not_found<T>(e: T) -> Self
     where
@@ -531,6 +734,54 @@ impl RESTError {
     {
         Self::NotFound(e.to_string())
     }
+
+    /// The easier method of using [`Self::MethodNotAllowed`] that takes any value that can be displayed.
+    pub fn method_not_allowed<T>(e: T) -> Self
+    where
+        T: std::fmt::Display,
+    {
+        Self::MethodNotAllowed(e.to_string())
+    }
+
+    /// The easier method of using [`Self::Gone`] that takes any value that can be displayed.
+    pub fn gone<T>(e: T) -> Self
+    where
+        T: std::fmt::Display,
+    {
+        Self::Gone(e.to_string())
+    }
+
+    /// The easier method of using [`Self::Conflict`] that takes any value that can be displayed.
+    pub fn conflict<T>(e: T) -> Self
+    where
+        T: std::fmt::Display,
+    {
+        Self::Conflict(e.to_string())
+    }
+
+    /// The easier method of using [`Self::PreconditionFailed`] that takes any value that can be displayed.
+    pub fn precondition_failed<T>(e: T) -> Self
+    where
+        T: std::fmt::Display,
+    {
+        Self::PreconditionFailed(e.to_string())
+    }
+
+    /// The easier method of using [`Self::TooManyRequests`] that takes any value that can be displayed.
+    pub fn too_many_requests<T>(e: T) -> Self
+    where
+        T: std::fmt::Display,
+    {
+        Self::TooManyRequests(e.to_string())
+    }
+
+    /// The easier method of using [`Self::ServiceUnavailable`] that takes any value that can be displayed.
+    pub fn service_unavailable<T>(e: T) -> Self
+    where
+        T: std::fmt::Display,
+    {
+        Self::ServiceUnavailable(e.to_string())
+    }
 }
 
 impl IntoResponse for RESTError {
@@ -552,9 +803,76 @@ impl IntoResponse for RESTError {
             Self::BadRequest(ref e) => {
                 RESTErrorResponse::new_response(StatusCode::BAD_REQUEST, "Bad Request", e)
             }
+            Self::BadRequestWithCode { code, ref message } => {
+                RESTErrorResponse::new_response_with_code(
+                    StatusCode::BAD_REQUEST,
+                    "Bad Request",
+                    code,
+                    message,
+                )
+            }
+            Self::Forbidden(ref e) => {
+                RESTErrorResponse::new_response(StatusCode::FORBIDDEN, "Forbidden", e)
+            }
             Self::NotFound(ref e) => {
                 RESTErrorResponse::new_response(StatusCode::NOT_FOUND, "Not Found", e)
             }
+            Self::MethodNotAllowed(ref e) => RESTErrorResponse::new_response(
+                StatusCode::METHOD_NOT_ALLOWED,
+                "Method Not Allowed",
+                e,
+            ),
+            Self::Gone(ref e) => RESTErrorResponse::new_response(StatusCode::GONE, "Gone", e),
+            Self::Conflict(ref e) => {
+                RESTErrorResponse::new_response(StatusCode::CONFLICT, "Conflict", e)
+            }
+            Self::PreconditionFailed(ref e) => RESTErrorResponse::new_response(
+                StatusCode::PRECONDITION_FAILED,
+                "Precondition Failed",
+                e,
+            ),
+            Self::TooManyRequests(ref e) => RESTErrorResponse::new_response(
+                StatusCode::TOO_MANY_REQUESTS,
+                "Too Many Requests",
+                e,
+            ),
+            Self::ServiceUnavailable(ref e) => RESTErrorResponse::new_response(
+                StatusCode::SERVICE_UNAVAILABLE,
+                "Service Unavailable",
+                e,
+            ),
+            Self::Unprocessable(ref e) => RESTErrorResponse::new_response(
+                StatusCode::UNPROCESSABLE_ENTITY,
+                "Unprocessable Entity",
+                e,
+            ),
+            Self::UnprocessableWithCode { code, ref message } => {
+                RESTErrorResponse::new_response_with_code(
+                    StatusCode::UNPROCESSABLE_ENTITY,
+                    "Unprocessable Entity",
+                    code,
+                    message,
+                )
+            }
+            Self::UnprocessableEntity(errors) => (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                Json(RESTErrorResponse {
+                    errors,
+                    ..RESTErrorResponse::new(
+                        "Unprocessable Entity",
+                        "The request failed validation.",
+                    )
+                }),
+            )
+                .into_response(),
+            Self::MaxViewsBelowCurrent(views) => RESTErrorResponse::new_response_with_code(
+                StatusCode::BAD_REQUEST,
+                "Bad Request",
+                "MAX_VIEWS_BELOW_CURRENT",
+                format!(
+                    "The maximum views must be greater than the current view count of {views}."
+                ),
+            ),
         }
     }
 }
@@ -568,6 +886,15 @@ pub struct RESTErrorResponse {
     reason: String,
     /// The message about the error.
     message: String,
+    /// The machine readable code for the error (if any).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    code: Option<String>,
+    /// Every validation failure, when all of them were collected.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    errors: Vec<ValidationError>,
+    /// The ID of the request that caused the error.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    request_id: Option<String>,
     /// Time since epoch of when the error occurred.
     timestamp: u64,
 }
@@ -580,6 +907,9 @@ impl RESTErrorResponse {
         Self {
             reason: reason.to_string(),
             message: message.to_string(),
+            code: None,
+            errors: Vec::new(),
+            request_id: current_request_id(),
             timestamp: Utc::now().timestamp() as u64,
         }
     }
@@ -602,6 +932,38 @@ impl RESTErrorResponse {
             Json(Self {
                 reason: reason.to_string(),
                 message: message.to_string(),
+                code: None,
+                errors: Vec::new(),
+                request_id: current_request_id(),
+                timestamp: Utc::now().timestamp() as u64,
+            }),
+        )
+            .into_response()
+    }
+
+    /// ## New Response With Code
+    ///
+    /// Creates a new [`Response`] object where the body is a [`RESTErrorResponse`] as JSON, including an error code.
+    ///
+    /// ## Parameters
+    /// - `status_code` - The status code to set the response to.
+    /// - `reason` - The reason this error occurred.
+    /// - `code` - The machine readable code for the error.
+    /// - `message` - The full error message.
+    pub fn new_response_with_code<R: std::fmt::Display, M: std::fmt::Display>(
+        status_code: StatusCode,
+        reason: R,
+        code: &str,
+        message: M,
+    ) -> Response {
+        (
+            status_code,
+            Json(Self {
+                reason: reason.to_string(),
+                message: message.to_string(),
+                code: Some(code.to_string()),
+                errors: Vec::new(),
+                request_id: current_request_id(),
                 timestamp: Utc::now().timestamp() as u64,
             }),
         )
@@ -623,9 +985,168 @@ impl RESTErrorResponse {
         &self.message
     }
 
+    // Testing item, docs not needed.
+    #[expect(missing_docs)]
+    pub fn code(&self) -> Option<&str> {
+        self.code.as_deref()
+    }
+
+    // Testing item, docs not needed.
+    #[expect(missing_docs)]
+    pub fn errors(&self) -> &[ValidationError] {
+        &self.errors
+    }
+
+    // Testing item, docs not needed.
+    #[expect(missing_docs)]
+    pub fn request_id(&self) -> Option<&str> {
+        self.request_id.as_deref()
+    }
+
     // Testing item, docs not needed.
     #[expect(missing_docs)]
     pub fn timestamp(&self) -> u64 {
         self.timestamp
     }
 }
+
+/// ## Validation Error
+///
+/// A single validation failure, within an unprocessable entity response.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ValidationError {
+    /// The field that failed validation.
+    field: String,
+    /// The machine readable code for the failure.
+    code: String,
+    /// The message about the failure.
+    message: String,
+}
+
+impl ValidationError {
+    /// ## New
+    ///
+    /// Create a new [`ValidationError`] from the error the validation returned.
+    pub fn new(field: impl Into<String>, error: RESTError) -> Self {
+        let (code, message) = match error {
+            RESTError::BadRequestWithCode { code, message }
+            | RESTError::UnprocessableWithCode { code, message } => (code.to_string(), message),
+            RESTError::BadRequest(message) | RESTError::Unprocessable(message) => {
+                ("INVALID".to_string(), message)
+            }
+            error => ("INVALID".to_string(), error.to_string()),
+        };
+
+        Self {
+            field: field.into(),
+            code,
+            message,
+        }
+    }
+}
+
+#[cfg(test)]
+impl ValidationError {
+    // Testing item, docs not needed.
+    #[expect(missing_docs)]
+    pub fn field(&self) -> &str {
+        &self.field
+    }
+
+    // Testing item, docs not needed.
+    #[expect(missing_docs)]
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    // Testing item, docs not needed.
+    #[expect(missing_docs)]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+/// ## Validation Errors
+///
+/// Collects the failures of validating a request.
+///
+/// Unless every failure is being collected, the first failure is returned as is.
+pub struct ValidationErrors {
+    /// Whether every failure is collected, instead of returning the first.
+    collect_all: bool,
+    /// The failures collected so far.
+    errors: Vec<ValidationError>,
+}
+
+impl ValidationErrors {
+    /// ## New
+    ///
+    /// Create a new [`ValidationErrors`] object.
+    pub const fn new(collect_all: bool) -> Self {
+        Self {
+            collect_all,
+            errors: Vec::new(),
+        }
+    }
+
+    /// Whether every failure is collected, instead of returning the first.
+    #[inline]
+    pub const fn collect_all(&self) -> bool {
+        self.collect_all
+    }
+
+    /// ## Push
+    ///
+    /// Record a failed validation of a field.
+    ///
+    /// ## Errors
+    ///
+    /// - [`RESTError`] - The failure, when not every failure is being collected.
+    pub fn push(&mut self, field: impl Into<String>, error: RESTError) -> Result<(), RESTError> {
+        if !self.collect_all {
+            return Err(error);
+        }
+
+        self.errors.push(ValidationError::new(field, error));
+
+        Ok(())
+    }
+
+    /// ## Check
+    ///
+    /// Record the result of validating a field.
+    ///
+    /// ## Errors
+    ///
+    /// - [`RESTError`] - The failure, when not every failure is being collected.
+    ///
+    /// ## Returns
+    ///
+    /// - [`Some`] - The validated value.
+    /// - [`None`] - The validation failed, and was collected.
+    pub fn check<T>(
+        &mut self,
+        field: impl Into<String>,
+        result: Result<T, RESTError>,
+    ) -> Result<Option<T>, RESTError> {
+        match result {
+            Ok(value) => Ok(Some(value)),
+            Err(error) => self.push(field, error).map(|()| None),
+        }
+    }
+
+    /// ## Finish
+    ///
+    /// Finish validating.
+    ///
+    /// ## Errors
+    ///
+    /// - [`RESTError`] - Every failure collected, as an unprocessable entity.
+    pub fn finish(self) -> Result<(), RESTError> {
+        if self.errors.is_empty() {
+            return Ok(());
+        }
+
+        Err(RESTError::UnprocessableEntity(self.errors))
+    }
+}
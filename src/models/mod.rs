@@ -7,6 +7,7 @@ pub mod paste;
 pub mod payload;
 pub mod snowflake;
 pub mod undefined;
+pub mod upload;
 
 /// A type implementation of the a chrono datetime that uses UTC as its timezone.
 pub type DtUtc = chrono::DateTime<chrono::Utc>;
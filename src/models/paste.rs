@@ -1,18 +1,32 @@
 //! Paste object and related items.
 
+use std::net::IpAddr;
+
 use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest as _, Sha256};
 use sqlx::{PgExecutor, Postgres, QueryBuilder, Row as _};
 
 use crate::{
-    app::database::Database,
+    app::{config::Config, database::Database, object_store::ObjectStoreExt},
     models::{
         DtUtc,
-        errors::{AuthenticationError, RESTError},
+        document::Document,
+        errors::{AuthenticationError, HandlerError, RESTError},
         undefined::{Undefined, UndefinedOption},
     },
 };
 
-use super::{authentication::Token, errors::DatabaseError, snowflake::Snowflake};
+use super::{
+    authentication::{PastePassword, Token, hash_token, verify_password},
+    errors::DatabaseError,
+    snowflake::Snowflake,
+};
+
+/// ## Applied Defaults Header
+///
+/// The header listing the fields of a new paste that were omitted, and had a default applied.
+pub const APPLIED_DEFAULTS_HEADER: &str = "x-applied-defaults";
 
 /// ## Paste
 ///
@@ -33,12 +47,21 @@ pub struct Paste {
     views: usize,
     /// The maximum allowed views for a paste.
     max_views: Option<usize>,
+    /// The hash of the password required to read the paste.
+    password_hash: Option<String>,
+    /// When the paste was deleted (if it has been soft deleted).
+    deleted_at: Option<DtUtc>,
+    /// The paste ID whose token owns this paste (if not the paste itself).
+    owner_id: Option<Snowflake>,
+    /// The version of the paste, incremented on every edit.
+    version: usize,
 }
 
 impl Paste {
     /// New.
     ///
     /// Create a new [`Paste`] object.
+    #[expect(clippy::too_many_arguments)]
     pub const fn new(
         id: Snowflake,
         name: Option<String>,
@@ -47,6 +70,8 @@ impl Paste {
         expiry: Option<DtUtc>,
         views: usize,
         max_views: Option<usize>,
+        password_hash: Option<String>,
+        owner_id: Option<Snowflake>,
     ) -> Self {
         Self {
             id,
@@ -56,6 +81,10 @@ impl Paste {
             expiry,
             views,
             max_views,
+            password_hash,
+            deleted_at: None,
+            owner_id,
+            version: 0,
         }
     }
 
@@ -83,6 +112,15 @@ impl Paste {
         self.edited.as_ref()
     }
 
+    /// The pastes last modified time, being the last edited time, or the creation time if never edited.
+    #[inline]
+    pub const fn last_modified(&self) -> &DtUtc {
+        match &self.edited {
+            Some(edited) => edited,
+            None => &self.creation,
+        }
+    }
+
     /// The pastes expiry time.
     #[inline]
     pub const fn expiry(&self) -> Option<&DtUtc> {
@@ -101,10 +139,50 @@ impl Paste {
         self.max_views
     }
 
+    /// The hash of the password required to read the paste.
+    #[inline]
+    pub fn password_hash(&self) -> Option<&str> {
+        self.password_hash.as_deref()
+    }
+
+    /// When the paste was soft deleted.
+    #[inline]
+    pub const fn deleted_at(&self) -> Option<&DtUtc> {
+        self.deleted_at.as_ref()
+    }
+
+    /// The paste ID whose token owns this paste.
+    ///
+    /// This is the pastes own ID, unless it was created with another pastes token.
+    #[inline]
+    pub fn owner_id(&self) -> &Snowflake {
+        self.owner_id.as_ref().unwrap_or(&self.id)
+    }
+
+    /// The pastes version.
+    #[inline]
+    pub const fn version(&self) -> usize {
+        self.version
+    }
+
+    /// Generate `ETag`.
+    ///
+    /// Generate the entity tag for the pastes current version.
+    ///
+    /// ## Returns
+    ///
+    /// The quoted entity tag generated.
+    #[inline]
+    pub fn generate_etag(&self) -> String {
+        format!("\"{}\"", self.version)
+    }
+
     /// Fetch.
     ///
     /// Fetch a paste via its ID.
     ///
+    /// Pastes that have been soft deleted are not returned.
+    ///
     /// ## Arguments
     ///
     /// - `executor` - The database pool or transaction to use.
@@ -118,6 +196,7 @@ impl Paste {
     ///
     /// - [`Option::Some`] - The [`Paste`] object.
     /// - [`Option::None`] - No paste was found.
+    #[tracing::instrument(name = "Paste::fetch", skip_all, fields(paste.id = %id))]
     pub async fn fetch<'e, 'c: 'e, E>(
         executor: E,
         id: &Snowflake,
@@ -127,22 +206,81 @@ impl Paste {
     {
         let paste_id: i64 = (*id).into();
         let query = sqlx::query!(
-            "SELECT id, name, creation, edited, expiry, views, max_views FROM pastes WHERE id = $1",
+            "SELECT id, name, creation, edited, expiry, views, max_views, password_hash, owner_id, version FROM pastes WHERE id = $1 AND deleted_at IS NULL",
             paste_id
         )
         .fetch_optional(executor)
         .await?;
 
         if let Some(q) = query {
-            return Ok(Some(Self::new(
-                q.id.into(),
-                q.name,
-                q.creation,
-                q.edited,
-                q.expiry,
-                q.views as usize,
-                q.max_views.map(|v| v as usize),
-            )));
+            return Ok(Some(Self {
+                version: q.version as usize,
+                ..Self::new(
+                    q.id.into(),
+                    q.name,
+                    q.creation,
+                    q.edited,
+                    q.expiry,
+                    q.views as usize,
+                    q.max_views.map(|v| v as usize),
+                    q.password_hash,
+                    q.owner_id.map(Into::into),
+                )
+            }));
+        }
+
+        Ok(None)
+    }
+
+    /// Fetch By Name.
+    ///
+    /// Fetch a paste via its name.
+    ///
+    /// Pastes that have been soft deleted are not returned.
+    /// If multiple pastes share the name, the oldest is returned.
+    ///
+    /// ## Arguments
+    ///
+    /// - `executor` - The database pool or transaction to use.
+    /// - `name` - The name of the paste.
+    ///
+    /// ## Errors
+    ///
+    /// - [`DatabaseError`] - The database had an error.
+    ///
+    /// ## Returns
+    ///
+    /// - [`Option::Some`] - The [`Paste`] object.
+    /// - [`Option::None`] - No paste was found.
+    pub async fn fetch_by_name<'e, 'c: 'e, E>(
+        executor: E,
+        name: &str,
+    ) -> Result<Option<Self>, DatabaseError>
+    where
+        E: 'e + PgExecutor<'c>,
+    {
+        let query = sqlx::query!(
+            "SELECT id, name, creation, edited, expiry, views, max_views, password_hash, owner_id, version FROM pastes WHERE name = $1 AND deleted_at IS NULL ORDER BY id LIMIT 1",
+            name
+        )
+        .fetch_optional(executor)
+        .await?;
+
+        if let Some(q) = query {
+            return Ok(Some(Self {
+                version: q.version as usize,
+                ..Self::new(
+                    q.id.into(),
+                    q.name,
+                    q.creation,
+                    q.edited,
+                    q.expiry,
+                    q.views as usize,
+                    q.max_views.map(|v| v as usize),
+                    q.password_hash,
+                    q.owner_id.map(Into::into),
+                )
+            }));
         }
 
         Ok(None)
@@ -174,7 +312,7 @@ impl Paste {
         E: 'e + PgExecutor<'c>,
     {
         let records = sqlx::query!(
-            "SELECT id, name, creation, edited, expiry, views, max_views FROM pastes WHERE expiry >= $1 AND expiry <= $2",
+            "SELECT id, name, creation, edited, expiry, views, max_views, password_hash, owner_id, version FROM pastes WHERE expiry >= $1 AND expiry <= $2 AND deleted_at IS NULL",
             start,
             end
         )
@@ -183,15 +321,297 @@ impl Paste {
 
         let mut pastes = Vec::new();
         for record in records {
-            let paste = Self::new(
-                record.id.into(),
-                record.name,
-                record.creation,
-                record.edited,
-                record.expiry,
-                record.views as usize,
-                record.max_views.map(|v| v as usize),
-            );
+            let paste = Self {
+                version: record.version as usize,
+                ..Self::new(
+                    record.id.into(),
+                    record.name,
+                    record.creation,
+                    record.edited,
+                    record.expiry,
+                    record.views as usize,
+                    record.max_views.map(|v| v as usize),
+                    record.password_hash,
+                    record.owner_id.map(Into::into),
+                )
+            };
+
+            pastes.push(paste);
+        }
+
+        Ok(pastes)
+    }
+
+    /// Fetch Many.
+    ///
+    /// Fetch multiple pastes via their IDs.
+    ///
+    /// Pastes that have been soft deleted are not returned,
+    /// and IDs that do not exist are skipped.
+    ///
+    /// ## Arguments
+    ///
+    /// - `executor` - The database pool or transaction to use.
+    /// - `ids` - The IDs of the pastes.
+    ///
+    /// ## Errors
+    ///
+    /// - [`DatabaseError`] - The database had an error.
+    ///
+    /// ## Returns
+    ///
+    /// A [`Vec`] of [`Paste`]'s.
+    pub async fn fetch_many<'e, 'c: 'e, E>(
+        executor: E,
+        ids: &[Snowflake],
+    ) -> Result<Vec<Self>, DatabaseError>
+    where
+        E: 'e + PgExecutor<'c>,
+    {
+        let paste_ids: Vec<i64> = ids.iter().map(|id| (*id).into()).collect();
+
+        let records = sqlx::query!(
+            "SELECT id, name, creation, edited, expiry, views, max_views, password_hash, owner_id, version FROM pastes WHERE id = ANY($1) AND deleted_at IS NULL",
+            &paste_ids
+        )
+        .fetch_all(executor)
+        .await?;
+
+        let mut pastes = Vec::new();
+        for record in records {
+            let paste = Self {
+                version: record.version as usize,
+                ..Self::new(
+                    record.id.into(),
+                    record.name,
+                    record.creation,
+                    record.edited,
+                    record.expiry,
+                    record.views as usize,
+                    record.max_views.map(|v| v as usize),
+                    record.password_hash,
+                    record.owner_id.map(Into::into),
+                )
+            };
+
+            pastes.push(paste);
+        }
+
+        Ok(pastes)
+    }
+
+    /// Fetch Owned.
+    ///
+    /// Fetch all pastes owned by the owner of a token.
+    ///
+    /// The owner is the owner of the tokens paste,
+    /// so the token of any paste owned by an owner lists all of its pastes.
+    /// Pastes that have been soft deleted are not returned.
+    ///
+    /// ## Arguments
+    ///
+    /// - `executor` - The database pool or transaction to use.
+    /// - `token` - The token of a paste owned by the owner.
+    ///
+    /// ## Errors
+    ///
+    /// - [`DatabaseError`] - The database had an error.
+    ///
+    /// ## Returns
+    ///
+    /// A [`Vec`] of [`Paste`]'s, ordered by their ID.
+    pub async fn fetch_owned<'e, 'c: 'e, E>(
+        executor: E,
+        token: &str,
+    ) -> Result<Vec<Self>, DatabaseError>
+    where
+        E: 'e + PgExecutor<'c>,
+    {
+        let records = sqlx::query!(
+            "SELECT pastes.id, pastes.name, pastes.creation, pastes.edited, pastes.expiry, pastes.views, pastes.max_views, pastes.password_hash, pastes.owner_id, pastes.version FROM paste_tokens JOIN pastes AS token_pastes ON token_pastes.id = paste_tokens.paste_id JOIN pastes ON COALESCE(pastes.owner_id, pastes.id) = COALESCE(token_pastes.owner_id, token_pastes.id) WHERE paste_tokens.token_hash = $1 AND pastes.deleted_at IS NULL ORDER BY pastes.id",
+            hash_token(token)
+        )
+        .fetch_all(executor)
+        .await?;
+
+        let mut pastes = Vec::new();
+        for record in records {
+            let paste = Self {
+                version: record.version as usize,
+                ..Self::new(
+                    record.id.into(),
+                    record.name,
+                    record.creation,
+                    record.edited,
+                    record.expiry,
+                    record.views as usize,
+                    record.max_views.map(|v| v as usize),
+                    record.password_hash,
+                    record.owner_id.map(Into::into),
+                )
+            };
+
+            pastes.push(paste);
+        }
+
+        Ok(pastes)
+    }
+
+    /// Fetch Deleted.
+    ///
+    /// Fetch a soft deleted paste via its ID.
+    ///
+    /// ## Arguments
+    ///
+    /// - `executor` - The database pool or transaction to use.
+    /// - `id` - The ID of the paste.
+    ///
+    /// ## Errors
+    ///
+    /// - [`DatabaseError`] - The database had an error.
+    ///
+    /// ## Returns
+    ///
+    /// - [`Option::Some`] - The [`Paste`] object.
+    /// - [`Option::None`] - No soft deleted paste was found.
+    pub async fn fetch_deleted<'e, 'c: 'e, E>(
+        executor: E,
+        id: &Snowflake,
+    ) -> Result<Option<Self>, DatabaseError>
+    where
+        E: 'e + PgExecutor<'c>,
+    {
+        let paste_id: i64 = (*id).into();
+        let query = sqlx::query!(
+            "SELECT id, name, creation, edited, expiry, views, max_views, password_hash, owner_id, deleted_at, version FROM pastes WHERE id = $1 AND deleted_at IS NOT NULL",
+            paste_id
+        )
+        .fetch_optional(executor)
+        .await?;
+
+        if let Some(q) = query {
+            return Ok(Some(Self {
+                deleted_at: q.deleted_at,
+                version: q.version as usize,
+                ..Self::new(
+                    q.id.into(),
+                    q.name,
+                    q.creation,
+                    q.edited,
+                    q.expiry,
+                    q.views as usize,
+                    q.max_views.map(|v| v as usize),
+                    q.password_hash,
+                    q.owner_id.map(Into::into),
+                )
+            }));
+        }
+
+        Ok(None)
+    }
+
+    /// Fetch Created Before.
+    ///
+    /// Fetch all pastes that were created before the provided time.
+    ///
+    /// Pastes that have been soft deleted are not returned.
+    ///
+    /// ## Arguments
+    ///
+    /// - `executor` - The database pool or transaction to use.
+    /// - `before` - The [`DtUtc`] the pastes must have been created before (inclusive).
+    ///
+    /// ## Errors
+    ///
+    /// - [`DatabaseError`] - The database had an error.
+    ///
+    /// ## Returns
+    ///
+    /// A [`Vec`] of [`Paste`]'s.
+    pub async fn fetch_created_before<'e, 'c: 'e, E>(
+        executor: E,
+        before: &DtUtc,
+    ) -> Result<Vec<Self>, DatabaseError>
+    where
+        E: 'e + PgExecutor<'c>,
+    {
+        let records = sqlx::query!(
+            "SELECT id, name, creation, edited, expiry, views, max_views, password_hash, owner_id, version FROM pastes WHERE creation <= $1 AND deleted_at IS NULL",
+            before
+        )
+        .fetch_all(executor)
+        .await?;
+
+        let mut pastes = Vec::new();
+        for record in records {
+            let paste = Self {
+                version: record.version as usize,
+                ..Self::new(
+                    record.id.into(),
+                    record.name,
+                    record.creation,
+                    record.edited,
+                    record.expiry,
+                    record.views as usize,
+                    record.max_views.map(|v| v as usize),
+                    record.password_hash,
+                    record.owner_id.map(Into::into),
+                )
+            };
+
+            pastes.push(paste);
+        }
+
+        Ok(pastes)
+    }
+
+    /// Fetch Deleted Before.
+    ///
+    /// Fetch all pastes that were soft deleted before the provided time.
+    ///
+    /// ## Arguments
+    ///
+    /// - `executor` - The database pool or transaction to use.
+    /// - `before` - The [`DtUtc`] the pastes must have been deleted before (inclusive).
+    ///
+    /// ## Errors
+    ///
+    /// - [`DatabaseError`] - The database had an error.
+    ///
+    /// ## Returns
+    ///
+    /// A [`Vec`] of [`Paste`]'s.
+    pub async fn fetch_deleted_before<'e, 'c: 'e, E>(
+        executor: E,
+        before: &DtUtc,
+    ) -> Result<Vec<Self>, DatabaseError>
+    where
+        E: 'e + PgExecutor<'c>,
+    {
+        let records = sqlx::query!(
+            "SELECT id, name, creation, edited, expiry, views, max_views, password_hash, owner_id, deleted_at, version FROM pastes WHERE deleted_at <= $1",
+            before
+        )
+        .fetch_all(executor)
+        .await?;
+
+        let mut pastes = Vec::new();
+        for record in records {
+            let paste = Self {
+                deleted_at: record.deleted_at,
+                version: record.version as usize,
+                ..Self::new(
+                    record.id.into(),
+                    record.name,
+                    record.creation,
+                    record.edited,
+                    record.expiry,
+                    record.views as usize,
+                    record.max_views.map(|v| v as usize),
+                    record.password_hash,
+                    record.owner_id.map(Into::into),
+                )
+            };
 
             pastes.push(paste);
         }
@@ -209,7 +629,8 @@ impl Paste {
     ///
     /// ## Errors
     ///
-    /// - [`DatabaseError`] - The database had an error, or the snowflake exists already.
+    /// - [`DatabaseError`] - The database had an error, or the snowflake exists already ([`DatabaseError::Conflict`]).
+    #[tracing::instrument(name = "Paste::insert", skip_all, fields(paste.id = %self.id))]
     pub async fn insert<'e, 'c: 'e, E>(&self, executor: E) -> Result<(), DatabaseError>
     where
         E: 'e + PgExecutor<'c>,
@@ -217,17 +638,20 @@ impl Paste {
         let paste_id: i64 = self.id.into();
 
         sqlx::query!(
-            "INSERT INTO pastes(id, name, creation, edited, expiry, views, max_views) VALUES ($1, $2, $3, $4, $5, $6, $7)",
+            "INSERT INTO pastes(id, name, creation, edited, expiry, views, max_views, password_hash, owner_id) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)",
             paste_id,
             self.name,
             self.creation,
             self.edited,
             self.expiry,
             self.views as i64,
-            self.max_views.map(|v| v as i64)
+            self.max_views.map(|v| v as i64),
+            self.password_hash,
+            self.owner_id.map(i64::from)
         )
         .execute(executor)
-        .await?;
+        .await
+        .map_err(|e| DatabaseError::from_insert(e, "A paste with this ID already exists."))?;
 
         Ok(())
     }
@@ -258,7 +682,7 @@ impl Paste {
         let id_val: i64 = self.id.into();
 
         let mut builder: QueryBuilder<'_, Postgres> =
-            sqlx::QueryBuilder::new("UPDATE pastes SET edited = ");
+            sqlx::QueryBuilder::new("UPDATE pastes SET version = version + 1, edited = ");
         builder.push_bind(Utc::now());
 
         if !parameters.name().is_undefined() {
@@ -300,6 +724,8 @@ impl Paste {
         self.views = views as usize;
         let max_views: Option<i64> = record.get("max_views");
         self.max_views = max_views.map(|v| v as usize);
+        let version: i64 = record.get("version");
+        self.version = version as usize;
 
         Ok(true)
     }
@@ -308,58 +734,676 @@ impl Paste {
     ///
     /// Increment a pastes view count by 1.
     ///
+    /// The view count is checked against the milestones as returned by the increment,
+    /// so each milestone is only reached by a single view, even when viewed concurrently.
+    ///
     /// ## Arguments
     ///
     /// - `executor` - The database pool or transaction to use.
-    /// - `id` - The ID of the paste to add the view to.
+    /// - `milestone` - The amount of views between each view milestone (0 disables them).
     ///
     /// ## Errors
     ///
     /// - [`DatabaseError`] - The database had an error.
-    pub async fn add_view<'e, 'c: 'e, E>(&mut self, executor: E) -> Result<(), DatabaseError>
+    ///
+    /// ## Returns
+    ///
+    /// - [`Option::Some`] - The [`ViewMilestone`] reached, if the paste has a webhook.
+    /// - [`Option::None`] - No milestone was reached, or the paste has no webhook.
+    pub async fn add_view<'e, 'c: 'e, E>(
+        &mut self,
+        executor: E,
+        milestone: usize,
+    ) -> Result<Option<ViewMilestone>, DatabaseError>
     where
         E: 'e + PgExecutor<'c>,
     {
         let id_val: i64 = self.id.into();
 
-        let views = sqlx::query_scalar!(
-            "UPDATE pastes SET views = views + 1 WHERE id = $1 RETURNING views",
+        let record = sqlx::query!(
+            "UPDATE pastes SET views = views + 1 WHERE id = $1 RETURNING views, max_views, webhook_url",
             id_val,
         )
         .fetch_one(executor)
         .await?;
 
-        self.views = views as usize;
+        self.views = record.views as usize;
 
-        Ok(())
+        let Some(webhook_url) = record.webhook_url else {
+            return Ok(None);
+        };
+
+        let event = if record.max_views == Some(record.views) {
+            ViewEvent::MaxViews
+        } else if milestone != 0 && self.views.is_multiple_of(milestone) {
+            ViewEvent::Milestone
+        } else {
+            return Ok(None);
+        };
+
+        Ok(Some(ViewMilestone {
+            webhook_url,
+            event,
+            views: self.views,
+        }))
     }
 
-    /// Delete.
+    /// Insert View.
     ///
-    /// Delete a paste.
+    /// Record when a paste was viewed, for its analytics.
     ///
     /// ## Arguments
     ///
     /// - `executor` - The database pool or transaction to use.
-    /// - `id` - The id of the paste.
+    /// - `id` - The ID of the paste viewed.
+    /// - `viewed_at` - When the view was counted.
+    /// - `ip_hash` - The hash of the address the view came from (if known).
     ///
     /// ## Errors
     ///
     /// - [`DatabaseError`] - The database had an error.
-    pub async fn delete<'e, 'c: 'e, E>(executor: E, id: &Snowflake) -> Result<bool, DatabaseError>
+    pub async fn insert_view<'e, 'c: 'e, E>(
+        executor: E,
+        id: &Snowflake,
+        viewed_at: DtUtc,
+        ip_hash: Option<&str>,
+    ) -> Result<(), DatabaseError>
     where
         E: 'e + PgExecutor<'c>,
     {
-        let paste_id: i64 = (*id).into();
-        let result = sqlx::query!("DELETE FROM pastes WHERE id = $1", paste_id,)
-            .execute(executor)
-            .await?;
+        let id_val: i64 = (*id).into();
 
-        Ok(result.rows_affected() > 0)
+        sqlx::query!(
+            "INSERT INTO paste_views(paste_id, viewed_at, ip_hash) VALUES ($1, $2, $3)",
+            id_val,
+            viewed_at,
+            ip_hash
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(())
     }
-}
 
-/// ## Paste Update Parameters
+    /// Fetch View Buckets.
+    ///
+    /// Fetch the amount of recorded views of a paste, within each interval.
+    ///
+    /// ## Arguments
+    ///
+    /// - `executor` - The database pool or transaction to use.
+    /// - `id` - The ID of the paste.
+    /// - `interval` - The interval the views are bucketed by.
+    ///
+    /// ## Errors
+    ///
+    /// - [`DatabaseError`] - The database had an error.
+    ///
+    /// ## Returns
+    ///
+    /// A list of [`ViewBucket`]'s, ordered by when they start (oldest first).
+    /// Intervals without any views are omitted.
+    pub async fn fetch_view_buckets<'e, 'c: 'e, E>(
+        executor: E,
+        id: &Snowflake,
+        interval: AnalyticsInterval,
+    ) -> Result<Vec<ViewBucket>, DatabaseError>
+    where
+        E: 'e + PgExecutor<'c>,
+    {
+        let id_val: i64 = (*id).into();
+
+        let records = sqlx::query!(
+            r#"SELECT date_trunc($2, viewed_at) AS "start!", COUNT(*) AS "views!" FROM paste_views WHERE paste_id = $1 GROUP BY 1 ORDER BY 1"#,
+            id_val,
+            interval.as_str()
+        )
+        .fetch_all(executor)
+        .await?;
+
+        Ok(records
+            .into_iter()
+            .map(|record| ViewBucket {
+                start: record.start,
+                views: record.views as usize,
+            })
+            .collect())
+    }
+
+    /// Claim View.
+    ///
+    /// Increment a pastes view count by 1, only if the paste has not reached its maximum views.
+    ///
+    /// The check and the increment happen in a single statement,
+    /// so concurrent requests cannot claim more views than the paste allows.
+    ///
+    /// ## Arguments
+    ///
+    /// - `executor` - The database pool or transaction to use.
+    ///
+    /// ## Errors
+    ///
+    /// - [`DatabaseError`] - The database had an error.
+    ///
+    /// ## Returns
+    ///
+    /// Whether the view was claimed.
+    pub async fn claim_view<'e, 'c: 'e, E>(&mut self, executor: E) -> Result<bool, DatabaseError>
+    where
+        E: 'e + PgExecutor<'c>,
+    {
+        let id_val: i64 = self.id.into();
+
+        let views = sqlx::query_scalar!(
+            "UPDATE pastes SET views = views + 1 WHERE id = $1 AND (max_views IS NULL OR views < max_views) RETURNING views",
+            id_val,
+        )
+        .fetch_optional(executor)
+        .await?;
+
+        let Some(views) = views else {
+            return Ok(false);
+        };
+
+        self.views = views as usize;
+
+        Ok(true)
+    }
+
+    /// Set Webhook URL.
+    ///
+    /// Set the URL view milestones of a paste are sent to.
+    ///
+    /// ## Arguments
+    ///
+    /// - `executor` - The database pool or transaction to use.
+    /// - `id` - The id of the paste.
+    /// - `webhook_url` - The URL to send the view milestones to (none removes the webhook).
+    ///
+    /// ## Errors
+    ///
+    /// - [`DatabaseError`] - The database had an error.
+    pub async fn set_webhook_url<'e, 'c: 'e, E>(
+        executor: E,
+        id: &Snowflake,
+        webhook_url: Option<&str>,
+    ) -> Result<(), DatabaseError>
+    where
+        E: 'e + PgExecutor<'c>,
+    {
+        let paste_id: i64 = (*id).into();
+
+        sqlx::query!(
+            "UPDATE pastes SET webhook_url = $1 WHERE id = $2",
+            webhook_url,
+            paste_id,
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Delete.
+    ///
+    /// Delete a paste.
+    ///
+    /// ## Arguments
+    ///
+    /// - `executor` - The database pool or transaction to use.
+    /// - `id` - The id of the paste.
+    ///
+    /// ## Errors
+    ///
+    /// - [`DatabaseError`] - The database had an error.
+    pub async fn delete<'e, 'c: 'e, E>(executor: E, id: &Snowflake) -> Result<bool, DatabaseError>
+    where
+        E: 'e + PgExecutor<'c>,
+    {
+        let paste_id: i64 = (*id).into();
+        let result = sqlx::query!("DELETE FROM pastes WHERE id = $1", paste_id,)
+            .execute(executor)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Delete Cascade.
+    ///
+    /// Completely delete a paste, along with its tokens, documents and their contents.
+    ///
+    /// The rows are only removed once every document's contents have been deleted.
+    ///
+    /// ## Arguments
+    ///
+    /// - `database` - The database to use.
+    /// - `object_store` - The object store containing the documents contents.
+    /// - `id` - The id of the paste.
+    ///
+    /// ## Errors
+    ///
+    /// - [`HandlerError`] - The database or object store had an error.
+    ///
+    /// ## Returns
+    ///
+    /// Returns [`true`] if the paste was deleted, otherwise returns [`false`].
+    pub async fn delete_cascade(
+        database: &Database,
+        object_store: &impl ObjectStoreExt,
+        id: &Snowflake,
+    ) -> Result<bool, HandlerError> {
+        let paste_id: i64 = (*id).into();
+
+        let mut transaction = database.pool().begin().await.map_err(DatabaseError::from)?;
+
+        let documents = Document::fetch_all(transaction.as_mut(), id).await?;
+
+        sqlx::query!("DELETE FROM documents WHERE paste_id = $1", paste_id)
+            .execute(transaction.as_mut())
+            .await
+            .map_err(DatabaseError::from)?;
+
+        sqlx::query!("DELETE FROM paste_tokens WHERE paste_id = $1", paste_id)
+            .execute(transaction.as_mut())
+            .await
+            .map_err(DatabaseError::from)?;
+
+        // Nothing is removed (the transaction is rolled back) if the paste does not exist.
+        if !Self::delete(transaction.as_mut(), id).await? {
+            return Ok(false);
+        }
+
+        for document in documents {
+            object_store.delete_document(&document).await?;
+        }
+
+        transaction.commit().await.map_err(DatabaseError::from)?;
+
+        Ok(true)
+    }
+
+    /// Soft Delete.
+    ///
+    /// Mark a paste as deleted, without removing it.
+    ///
+    /// ## Arguments
+    ///
+    /// - `executor` - The database pool or transaction to use.
+    /// - `id` - The id of the paste.
+    ///
+    /// ## Errors
+    ///
+    /// - [`DatabaseError`] - The database had an error.
+    ///
+    /// ## Returns
+    ///
+    /// Returns [`true`] if the paste was soft deleted, otherwise returns [`false`].
+    pub async fn soft_delete<'e, 'c: 'e, E>(
+        executor: E,
+        id: &Snowflake,
+    ) -> Result<bool, DatabaseError>
+    where
+        E: 'e + PgExecutor<'c>,
+    {
+        let paste_id: i64 = (*id).into();
+        let result = sqlx::query!(
+            "UPDATE pastes SET deleted_at = $1 WHERE id = $2 AND deleted_at IS NULL",
+            Utc::now(),
+            paste_id,
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Restore.
+    ///
+    /// Restore a soft deleted paste.
+    ///
+    /// ## Arguments
+    ///
+    /// - `executor` - The database pool or transaction to use.
+    /// - `id` - The id of the paste.
+    ///
+    /// ## Errors
+    ///
+    /// - [`DatabaseError`] - The database had an error.
+    ///
+    /// ## Returns
+    ///
+    /// Returns [`true`] if the paste was restored, otherwise returns [`false`].
+    pub async fn restore<'e, 'c: 'e, E>(executor: E, id: &Snowflake) -> Result<bool, DatabaseError>
+    where
+        E: 'e + PgExecutor<'c>,
+    {
+        let paste_id: i64 = (*id).into();
+        let result = sqlx::query!(
+            "UPDATE pastes SET deleted_at = NULL WHERE id = $1 AND deleted_at IS NOT NULL",
+            paste_id,
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Lock.
+    ///
+    /// Lock a paste until the transaction ends, so concurrent changes to its documents are
+    /// applied one at a time.
+    ///
+    /// ## Arguments
+    ///
+    /// - `executor` - The transaction to use.
+    /// - `id` - The id of the paste.
+    ///
+    /// ## Errors
+    ///
+    /// - [`DatabaseError`] - The database had an error.
+    ///
+    /// ## Returns
+    ///
+    /// Returns [`true`] if the paste was locked, otherwise returns [`false`].
+    pub async fn lock<'e, 'c: 'e, E>(executor: E, id: &Snowflake) -> Result<bool, DatabaseError>
+    where
+        E: 'e + PgExecutor<'c>,
+    {
+        let paste_id: i64 = (*id).into();
+        let result = sqlx::query_scalar!(
+            "SELECT id FROM pastes WHERE id = $1 AND deleted_at IS NULL FOR UPDATE",
+            paste_id,
+        )
+        .fetch_optional(executor)
+        .await?;
+
+        Ok(result.is_some())
+    }
+
+    /// Fetch Owned Stats.
+    ///
+    /// Fetch the storage usage of all pastes owned by an owner.
+    ///
+    /// Pastes that have been soft deleted are not included.
+    ///
+    /// ## Arguments
+    ///
+    /// - `executor` - The database pool or transaction to use.
+    /// - `owner_id` - The paste ID of the owner.
+    ///
+    /// ## Errors
+    ///
+    /// - [`DatabaseError`] - The database had an error.
+    ///
+    /// ## Returns
+    ///
+    /// The [`PasteOwnedStats`] of the owner.
+    pub async fn fetch_owned_stats<'e, 'c: 'e, E>(
+        executor: E,
+        owner_id: &Snowflake,
+    ) -> Result<PasteOwnedStats, DatabaseError>
+    where
+        E: 'e + PgExecutor<'c>,
+    {
+        let owner_id: i64 = (*owner_id).into();
+        let record = sqlx::query!(
+            r#"SELECT COUNT(DISTINCT pastes.id) AS "paste_count!", COUNT(documents.id) AS "document_count!", COALESCE(SUM(documents.size), 0)::BIGINT AS "total_size!" FROM pastes LEFT JOIN documents ON documents.paste_id = pastes.id WHERE COALESCE(pastes.owner_id, pastes.id) = $1 AND pastes.deleted_at IS NULL"#,
+            owner_id
+        )
+        .fetch_one(executor)
+        .await?;
+
+        Ok(PasteOwnedStats::new(
+            record.paste_count as usize,
+            record.document_count as usize,
+            record.total_size as usize,
+        ))
+    }
+
+    /// Fetch Count.
+    ///
+    /// Fetch the total amount of pastes, including soft deleted pastes.
+    ///
+    /// ## Arguments
+    ///
+    /// - `executor` - The database pool or transaction to use.
+    ///
+    /// ## Errors
+    ///
+    /// - [`DatabaseError`] - The database had an error.
+    ///
+    /// ## Returns
+    ///
+    /// The total count of pastes.
+    pub async fn fetch_count<'e, 'c: 'e, E>(executor: E) -> Result<usize, DatabaseError>
+    where
+        E: 'e + PgExecutor<'c>,
+    {
+        let count = sqlx::query_scalar!(r#"SELECT COUNT(*) AS "count!" FROM pastes"#)
+            .fetch_one(executor)
+            .await?;
+
+        Ok(count as usize)
+    }
+
+    /// Fetch Expiring Count.
+    ///
+    /// Fetch the amount of pastes that will expire between now and the time provided.
+    ///
+    /// Pastes that have been soft deleted are not included.
+    ///
+    /// ## Arguments
+    ///
+    /// - `executor` - The database pool or transaction to use.
+    /// - `before` - The time the pastes will expire before.
+    ///
+    /// ## Errors
+    ///
+    /// - [`DatabaseError`] - The database had an error.
+    ///
+    /// ## Returns
+    ///
+    /// The count of expiring pastes.
+    pub async fn fetch_expiring_count<'e, 'c: 'e, E>(
+        executor: E,
+        before: &DtUtc,
+    ) -> Result<usize, DatabaseError>
+    where
+        E: 'e + PgExecutor<'c>,
+    {
+        let count = sqlx::query_scalar!(
+            r#"SELECT COUNT(*) AS "count!" FROM pastes WHERE expiry > $1 AND expiry <= $2 AND deleted_at IS NULL"#,
+            Utc::now(),
+            before
+        )
+        .fetch_one(executor)
+        .await?;
+
+        Ok(count as usize)
+    }
+}
+
+/// ## Paste Owned Stats
+///
+/// The storage usage of all pastes owned by an owner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PasteOwnedStats {
+    /// The amount of pastes owned.
+    paste_count: usize,
+    /// The amount of documents attached to the owned pastes.
+    document_count: usize,
+    /// The total size of the documents attached to the owned pastes (bytes).
+    total_size: usize,
+}
+
+impl PasteOwnedStats {
+    /// New.
+    ///
+    /// Create a new [`PasteOwnedStats`] object.
+    pub const fn new(paste_count: usize, document_count: usize, total_size: usize) -> Self {
+        Self {
+            paste_count,
+            document_count,
+            total_size,
+        }
+    }
+
+    /// The amount of pastes owned.
+    #[inline]
+    pub const fn paste_count(&self) -> usize {
+        self.paste_count
+    }
+
+    /// The amount of documents attached to the owned pastes.
+    #[inline]
+    pub const fn document_count(&self) -> usize {
+        self.document_count
+    }
+
+    /// The total size of the documents attached to the owned pastes (bytes).
+    #[inline]
+    pub const fn total_size(&self) -> usize {
+        self.total_size
+    }
+}
+
+/// ## View Event
+///
+/// The reason a view milestone was reached.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ViewEvent {
+    /// The views reached a multiple of the milestone.
+    Milestone,
+    /// The views reached the pastes maximum views.
+    MaxViews,
+}
+
+/// ## View Milestone
+///
+/// A view milestone reached by a paste, to be sent to its webhook.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ViewMilestone {
+    /// The URL the milestone is sent to.
+    webhook_url: String,
+    /// The reason the milestone was reached.
+    event: ViewEvent,
+    /// The views of the paste, once the milestone was reached.
+    views: usize,
+}
+
+impl ViewMilestone {
+    /// The URL the milestone is sent to.
+    #[inline]
+    pub fn webhook_url(&self) -> &str {
+        &self.webhook_url
+    }
+
+    /// The reason the milestone was reached.
+    #[inline]
+    pub const fn event(&self) -> ViewEvent {
+        self.event
+    }
+
+    /// The views of the paste, once the milestone was reached.
+    #[inline]
+    pub const fn views(&self) -> usize {
+        self.views
+    }
+}
+
+/// Expand Paste Name.
+///
+/// Expand the placeholders of a paste name template, once the paste is being created.
+///
+/// - `{snowflake}` - The ID of the paste.
+/// - `{date}` - The date the paste was created (`YYYY-MM-DD`).
+///
+/// ## Arguments
+///
+/// - `template` - The name template to expand.
+/// - `id` - The ID of the paste.
+/// - `creation` - When the paste was created.
+///
+/// ## Returns
+///
+/// The name, with every placeholder replaced.
+pub fn expand_paste_name(template: &str, id: &Snowflake, creation: &DtUtc) -> String {
+    template
+        .replace("{snowflake}", &id.to_string())
+        .replace("{date}", &creation.format("%Y-%m-%d").to_string())
+}
+
+/// Hash Address.
+///
+/// The address of a view is stored by its hash, combined with the paste,
+/// so views cannot be linked to a client (or across pastes) by the database.
+///
+/// ## Arguments
+///
+/// - `paste_id` - The ID of the paste viewed.
+/// - `address` - The address of the client.
+///
+/// ## Returns
+///
+/// The hex encoded SHA-256 digest of the paste ID and address.
+pub fn hash_address(paste_id: &Snowflake, address: IpAddr) -> String {
+    format!(
+        "{:x}",
+        Sha256::digest(format!("{paste_id}:{}", address.to_canonical()).as_bytes())
+    )
+}
+
+/// ## Analytics Interval
+///
+/// The interval the views of a paste are bucketed by.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AnalyticsInterval {
+    /// The views within each hour.
+    #[default]
+    Hour,
+    /// The views within each day.
+    Day,
+}
+
+impl AnalyticsInterval {
+    /// The field the view times are truncated to.
+    #[inline]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Hour => "hour",
+            Self::Day => "day",
+        }
+    }
+}
+
+/// ## View Bucket
+///
+/// The amount of views a paste received within an interval.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ViewBucket {
+    /// When the interval starts.
+    start: DtUtc,
+    /// The amount of views within the interval.
+    views: usize,
+}
+
+impl ViewBucket {
+    /// When the interval starts.
+    #[inline]
+    pub const fn start(&self) -> &DtUtc {
+        &self.start
+    }
+
+    /// The amount of views within the interval.
+    #[inline]
+    pub const fn views(&self) -> usize {
+        self.views
+    }
+}
+
+/// ## Paste Update Parameters
 ///
 /// The parameters that can be used to update a paste.
 pub struct PasteUpdateParameters {
@@ -426,6 +1470,9 @@ impl PasteUpdateParameters {
 /// Checks that a paste exists, and has not expired,
 /// as well as supporting validating the token.
 ///
+/// Pastes that have expired, or reached their maximum views,
+/// are deleted and reported as [`RESTError::Gone`].
+///
 /// ## Arguments
 ///
 /// - `db` - The database to use.
@@ -454,17 +1501,15 @@ pub async fn validate_paste(
         && expiry < Utc::now()
     {
         Paste::delete(db.pool(), paste_id).await?;
-        return Err(RESTError::not_found(
-            "The paste requested could not be found",
-        ));
+        return Err(RESTError::gone("The paste requested has expired"));
     }
 
     if let Some(max_views) = paste.max_views
         && paste.views >= max_views
     {
         Paste::delete(db.pool(), paste_id).await?;
-        return Err(RESTError::not_found(
-            "The paste requested could not be found",
+        return Err(RESTError::gone(
+            "The paste requested has reached its maximum views",
         ));
     }
 
@@ -478,3 +1523,111 @@ pub async fn validate_paste(
 
     Ok(paste)
 }
+
+/// Validate Paste Password.
+///
+/// Checks that the password provided matches the pastes password,
+/// if the paste is password protected.
+///
+/// ## Arguments
+///
+/// - `paste` - The paste to check.
+/// - `password` - The password provided.
+///
+/// ## Errors
+///
+/// - [`RESTError`] - The password is missing, incorrect, or could not be verified.
+pub fn validate_paste_password(paste: &Paste, password: &PastePassword) -> Result<(), RESTError> {
+    let Some(password_hash) = paste.password_hash() else {
+        return Ok(());
+    };
+
+    let Some(password) = password.password() else {
+        return Err(AuthenticationError::MissingPassword.into());
+    };
+
+    if !verify_password(password, password_hash)? {
+        return Err(AuthenticationError::InvalidPassword.into());
+    }
+
+    Ok(())
+}
+
+/// Validate Unique Name.
+///
+/// Checks that no other paste has the name, when unique paste names are enabled.
+///
+/// ## Arguments
+///
+/// - `executor` - The database pool or transaction to use.
+/// - `config` - The config to check against.
+/// - `name` - The name of the paste (if it has one).
+/// - `paste_id` - The ID of the paste being named, or none for a new paste.
+///
+/// ## Errors
+///
+/// - [`RESTError`] - Another paste already has the name, or the database had an error.
+pub async fn validate_unique_name<'e, 'c: 'e, E>(
+    executor: E,
+    config: &Config,
+    name: Option<&str>,
+    paste_id: Option<&Snowflake>,
+) -> Result<(), RESTError>
+where
+    E: 'e + PgExecutor<'c>,
+{
+    let Some(name) = name.filter(|_| config.unique_paste_names()) else {
+        return Ok(());
+    };
+
+    if let Some(existing) = Paste::fetch_by_name(executor, name).await?
+        && Some(existing.id()) != paste_id
+    {
+        return Err(RESTError::conflict(format!(
+            "A paste with the name `{name}` already exists."
+        )));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{http::StatusCode, response::IntoResponse as _};
+    use sqlx::PgPool;
+
+    use super::*;
+
+    #[sqlx::test(fixtures(path = "../../tests/fixtures/", scripts("pastes")))]
+    async fn test_insert_duplicate(pool: PgPool) {
+        let paste = Paste::new(
+            Snowflake::new(517_815_304_354_284_605),
+            None,
+            Utc::now(),
+            None,
+            None,
+            0,
+            None,
+            None,
+            None,
+        );
+
+        let error = paste
+            .insert(&pool)
+            .await
+            .expect_err("A duplicate paste was inserted.");
+
+        assert!(
+            matches!(error, DatabaseError::Conflict(_)),
+            "Error is not a conflict: {error}"
+        );
+
+        let response = RESTError::from(error).into_response();
+
+        assert_eq!(
+            response.status(),
+            StatusCode::CONFLICT,
+            "Status does not match."
+        );
+    }
+}
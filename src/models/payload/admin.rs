@@ -0,0 +1,125 @@
+//! Paths, Queries, Bodies and Responses related to the admin endpoints.
+
+use serde::{Deserialize, Serialize};
+
+//------//
+// Body //
+//------//
+
+/// ## Put Maintenance Body
+///
+/// The body of the maintenance endpoint.
+#[cfg_attr(test, derive(Serialize))]
+#[derive(Deserialize)]
+pub struct PutMaintenanceBody {
+    /// Whether maintenance mode is enabled.
+    enabled: bool,
+}
+
+impl PutMaintenanceBody {
+    // Testing item, docs not needed.
+    #[expect(missing_docs)]
+    #[cfg(test)]
+    pub const fn new(enabled: bool) -> Self {
+        Self { enabled }
+    }
+
+    /// Whether maintenance mode is enabled.
+    #[inline]
+    pub const fn enabled(&self) -> bool {
+        self.enabled
+    }
+}
+
+//----------//
+// Response //
+//----------//
+
+/// ## Response Maintenance
+///
+/// The maintenance mode of the server.
+#[cfg_attr(test, derive(Deserialize))]
+#[derive(Serialize, Debug)]
+pub struct ResponseMaintenance {
+    /// Whether maintenance mode is enabled.
+    enabled: bool,
+}
+
+impl ResponseMaintenance {
+    /// New.
+    ///
+    /// Create a new [`ResponseMaintenance`] object.
+    pub const fn new(enabled: bool) -> Self {
+        Self { enabled }
+    }
+
+    // Testing item, docs not needed.
+    #[expect(missing_docs)]
+    #[cfg(test)]
+    pub const fn enabled(&self) -> bool {
+        self.enabled
+    }
+}
+
+/// ## Response Stats
+///
+/// The statistics of every paste stored.
+#[cfg_attr(test, derive(Deserialize))]
+#[derive(Serialize, Debug)]
+pub struct ResponseStats {
+    /// The amount of pastes.
+    paste_count: usize,
+    /// The amount of documents.
+    document_count: usize,
+    /// The total size of every document (bytes).
+    total_size: usize,
+    /// The amount of pastes expiring within the next 24 hours.
+    expiring_count: usize,
+}
+
+impl ResponseStats {
+    /// New.
+    ///
+    /// Create a new [`ResponseStats`] object.
+    pub const fn new(
+        paste_count: usize,
+        document_count: usize,
+        total_size: usize,
+        expiring_count: usize,
+    ) -> Self {
+        Self {
+            paste_count,
+            document_count,
+            total_size,
+            expiring_count,
+        }
+    }
+
+    // Testing item, docs not needed.
+    #[expect(missing_docs)]
+    #[cfg(test)]
+    pub const fn paste_count(&self) -> usize {
+        self.paste_count
+    }
+
+    // Testing item, docs not needed.
+    #[expect(missing_docs)]
+    #[cfg(test)]
+    pub const fn document_count(&self) -> usize {
+        self.document_count
+    }
+
+    // Testing item, docs not needed.
+    #[expect(missing_docs)]
+    #[cfg(test)]
+    pub const fn total_size(&self) -> usize {
+        self.total_size
+    }
+
+    // Testing item, docs not needed.
+    #[expect(missing_docs)]
+    #[cfg(test)]
+    pub const fn expiring_count(&self) -> usize {
+        self.expiring_count
+    }
+}
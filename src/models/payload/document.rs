@@ -1,12 +1,21 @@
 //! Paths, Queries, Bodies and Responses related to the document endpoints.
 
-use serde::Deserialize;
+use std::ops::Deref;
 
-use crate::models::{
-    document::DocumentUpdateParameters,
-    errors::RESTError,
-    snowflake::{PartialSnowflake, Snowflake},
-    undefined::Undefined,
+use secrecy::SecretString;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{
+    app::config::Config,
+    models::{
+        document::{
+            Document, DocumentUpdateParameters, MAXIMUM_DOCUMENT_TAG_SIZE, MAXIMUM_DOCUMENT_TAGS,
+        },
+        errors::RESTError,
+        snowflake::{PartialSnowflake, Snowflake, validate_snowflakes},
+        undefined::Undefined,
+    },
 };
 
 //------//
@@ -36,11 +45,195 @@ impl DocumentPath {
     pub const fn document_id(&self) -> &Snowflake {
         &self.document_id
     }
+
+    /// ## Validate
+    ///
+    /// Validate that the IDs within the path are plausible snowflakes.
+    ///
+    /// ## Errors
+    ///
+    /// - [`RESTError`] - Returned when an ID was created too far in the future.
+    pub fn validate(&self, config: &Config) -> Result<(), RESTError> {
+        validate_snowflakes(config, &[&self.paste_id, &self.document_id])
+    }
 }
 
 /// Used for getting documents.
 pub type GetDocumentPath = DocumentPath;
 
+/// Used for moving documents.
+pub type MoveDocumentPath = DocumentPath;
+
+/// Used for creating or replacing documents.
+pub type PutDocumentPath = DocumentPath;
+
+/// Used for deleting documents.
+pub type DeleteDocumentPath = DocumentPath;
+
+/// ## Document Name Path
+///
+/// The values within the path of a document endpoint, where the document is found by its name.
+#[derive(Deserialize)]
+pub struct DocumentNamePath {
+    /// The paste ID.
+    paste_id: Snowflake,
+    /// The document name.
+    name: String,
+}
+
+impl DocumentNamePath {
+    /// The paste ID found within the path.
+    #[inline]
+    pub const fn paste_id(&self) -> &Snowflake {
+        &self.paste_id
+    }
+
+    /// The document name found within the path.
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// ## Validate
+    ///
+    /// Validate that the IDs within the path are plausible snowflakes.
+    ///
+    /// ## Errors
+    ///
+    /// - [`RESTError`] - Returned when an ID was created too far in the future.
+    pub fn validate(&self, config: &Config) -> Result<(), RESTError> {
+        validate_snowflakes(config, &[&self.paste_id])
+    }
+}
+
+/// Used for getting documents by their name.
+pub type GetDocumentByNamePath = DocumentNamePath;
+
+/// ## Document Diff Path
+///
+/// The values within the path of the document diff endpoint.
+#[expect(clippy::struct_field_names)]
+#[derive(Deserialize)]
+pub struct DocumentDiffPath {
+    /// The paste ID.
+    paste_id: Snowflake,
+    /// The document ID.
+    document_id: Snowflake,
+    /// The paste ID to compare against.
+    other_paste_id: Snowflake,
+    /// The document ID to compare against.
+    other_document_id: Snowflake,
+}
+
+impl DocumentDiffPath {
+    /// The paste ID found within the path.
+    #[inline]
+    pub const fn paste_id(&self) -> &Snowflake {
+        &self.paste_id
+    }
+
+    /// The document ID found within the path.
+    #[inline]
+    pub const fn document_id(&self) -> &Snowflake {
+        &self.document_id
+    }
+
+    /// The paste ID to compare against found within the path.
+    #[inline]
+    pub const fn other_paste_id(&self) -> &Snowflake {
+        &self.other_paste_id
+    }
+
+    /// The document ID to compare against found within the path.
+    #[inline]
+    pub const fn other_document_id(&self) -> &Snowflake {
+        &self.other_document_id
+    }
+
+    /// ## Validate
+    ///
+    /// Validate that the IDs within the path are plausible snowflakes.
+    ///
+    /// ## Errors
+    ///
+    /// - [`RESTError`] - Returned when an ID was created too far in the future.
+    pub fn validate(&self, config: &Config) -> Result<(), RESTError> {
+        validate_snowflakes(
+            config,
+            &[
+                &self.paste_id,
+                &self.document_id,
+                &self.other_paste_id,
+                &self.other_document_id,
+            ],
+        )
+    }
+}
+
+//-------//
+// Query //
+//-------//
+
+/// ## Get Document Raw Query
+///
+/// The query parameters of the raw document endpoint.
+#[derive(Deserialize, Default)]
+pub struct GetDocumentRawQuery {
+    /// Whether the document should be downloaded, rather than displayed inline.
+    #[serde(default)]
+    download: bool,
+    /// The expected checksum of the documents contents.
+    checksum: Option<String>,
+}
+
+impl GetDocumentRawQuery {
+    /// Whether the document should be downloaded, rather than displayed inline.
+    #[inline]
+    pub const fn download(&self) -> bool {
+        self.download
+    }
+
+    /// The expected checksum of the documents contents.
+    #[inline]
+    pub fn checksum(&self) -> Option<&str> {
+        self.checksum.as_deref()
+    }
+}
+
+/// ## Get Documents Query
+///
+/// The query parameters of the get documents endpoint.
+#[derive(Deserialize, Default)]
+pub struct GetDocumentsQuery {
+    /// The tag the documents must have.
+    tag: Option<String>,
+}
+
+impl GetDocumentsQuery {
+    /// The tag the documents must have.
+    #[inline]
+    pub fn tag(&self) -> Option<&str> {
+        self.tag.as_deref()
+    }
+}
+
+/// ## Put Document Query
+///
+/// The query parameters of the put document endpoint.
+#[derive(Deserialize, Default)]
+pub struct PutDocumentQuery {
+    /// The name of the document.
+    name: Option<String>,
+}
+
+impl PutDocumentQuery {
+    /// The name of the document.
+    #[inline]
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+}
+
 //------//
 // Body //
 //------//
@@ -57,9 +250,27 @@ pub struct PostPasteDocumentBody {
     id: PartialSnowflake,
     /// The name of the document.
     name: String,
+    /// The tags of the document.
+    #[serde(default)]
+    tags: Vec<String>,
+    /// The URL the contents of the document are fetched from, instead of a multipart field.
+    #[serde(default)]
+    source_url: Option<String>,
 }
 
 impl PostPasteDocumentBody {
+    /// New.
+    ///
+    /// Create a new [`PostPasteDocumentBody`] object.
+    pub const fn new(id: PartialSnowflake, name: String, tags: Vec<String>) -> Self {
+        Self {
+            id,
+            name,
+            tags,
+            source_url: None,
+        }
+    }
+
     /// The ID of the document.
     ///
     /// This is **not** a snowflake.
@@ -74,6 +285,68 @@ impl PostPasteDocumentBody {
     pub fn name(&self) -> &str {
         &self.name
     }
+
+    /// The tags of the document.
+    #[inline]
+    pub fn tags(&self) -> &[String] {
+        &self.tags
+    }
+
+    /// The URL the contents of the document are fetched from, instead of a multipart field.
+    #[inline]
+    pub fn source_url(&self) -> Option<&str> {
+        self.source_url.as_deref()
+    }
+
+    /// JSON Schema.
+    ///
+    /// Build the JSON Schema describing a document within the paste creation payload.
+    ///
+    /// ## Arguments
+    ///
+    /// - `config` - The config the constraints are taken from.
+    ///
+    /// ## Returns
+    ///
+    /// The JSON Schema of the document.
+    pub fn json_schema(config: &Config) -> serde_json::Value {
+        let size_limits = config.size_limits();
+
+        json!({
+            "type": "object",
+            "required": ["id", "name"],
+            "additionalProperties": false,
+            "properties": {
+                "id": {
+                    "description": "The ID of the multipart field (`files[{id}]`) containing the contents.",
+                    "type": ["integer", "string"],
+                    "pattern": "^[0-9]+$",
+                    "minimum": 0
+                },
+                "name": {
+                    "description": "The name of the document.",
+                    "type": "string",
+                    "minLength": size_limits.minimum_document_name_size(),
+                    "maxLength": size_limits.maximum_document_name_size()
+                },
+                "tags": {
+                    "description": "The tags of the document.",
+                    "type": "array",
+                    "maxItems": MAXIMUM_DOCUMENT_TAGS,
+                    "items": {
+                        "type": "string",
+                        "minLength": 1,
+                        "maxLength": MAXIMUM_DOCUMENT_TAG_SIZE
+                    }
+                },
+                "source_url": {
+                    "description": "The URL the contents are fetched from, instead of a multipart field.",
+                    "type": "string",
+                    "format": "uri"
+                }
+            }
+        })
+    }
 }
 
 /// ## Patch Paste Document Body
@@ -89,6 +362,9 @@ pub struct PatchPasteDocumentBody {
     /// The name of the document.
     #[serde(default)]
     name: Undefined<String>,
+    /// The tags of the document.
+    #[serde(default)]
+    tags: Undefined<Vec<String>>,
 }
 
 impl PatchPasteDocumentBody {
@@ -106,6 +382,37 @@ impl PatchPasteDocumentBody {
     pub fn name(&self) -> Undefined<&str> {
         self.name.as_deref()
     }
+
+    /// The tags of the document.
+    #[inline]
+    pub fn tags(&self) -> Undefined<&[String]> {
+        self.tags.as_deref()
+    }
+}
+
+/// ## Post Document Move Body
+///
+/// The body of the document move endpoint.
+#[derive(Deserialize)]
+pub struct PostDocumentMoveBody {
+    /// The ID of the paste to move the document to.
+    target_paste_id: Snowflake,
+    /// The token of the paste to move the document to.
+    target_token: SecretString,
+}
+
+impl PostDocumentMoveBody {
+    /// The ID of the paste to move the document to.
+    #[inline]
+    pub const fn target_paste_id(&self) -> &Snowflake {
+        &self.target_paste_id
+    }
+
+    /// The token of the paste to move the document to.
+    #[inline]
+    pub const fn target_token(&self) -> &SecretString {
+        &self.target_token
+    }
 }
 
 impl TryFrom<PatchPasteDocumentBody> for PostPasteDocumentBody {
@@ -119,13 +426,24 @@ impl TryFrom<PatchPasteDocumentBody> for PostPasteDocumentBody {
             )));
         };
 
-        Ok(Self { id: value.id, name })
+        Ok(Self {
+            id: value.id,
+            name,
+            tags: Option::from(value.tags).unwrap_or_default(),
+            source_url: None,
+        })
     }
 }
 
 impl From<PatchPasteDocumentBody> for DocumentUpdateParameters {
     fn from(value: PatchPasteDocumentBody) -> Self {
-        Self::new(Undefined::Undefined, value.name, Undefined::Undefined)
+        Self::new(
+            Undefined::Undefined,
+            value.name,
+            Undefined::Undefined,
+            Undefined::Undefined,
+            value.tags,
+        )
     }
 }
 
@@ -135,6 +453,59 @@ impl From<&PatchPasteDocumentBody> for DocumentUpdateParameters {
             Undefined::Undefined,
             value.name.clone(),
             Undefined::Undefined,
+            Undefined::Undefined,
+            value.tags.clone(),
         )
     }
 }
+
+//----------//
+// Response //
+//----------//
+
+/// ## Response Document
+///
+/// The document returned when requested, alongside the URL its contents can be fetched from.
+#[cfg_attr(test, derive(Deserialize))]
+#[derive(Serialize)]
+pub struct ResponseDocument {
+    /// The document.
+    #[serde(flatten)]
+    document: Document,
+    /// The URL the contents of the document can be fetched from.
+    url: String,
+}
+
+impl ResponseDocument {
+    /// From Document.
+    ///
+    /// Create a new [`ResponseDocument`] from a [`Document`].
+    ///
+    /// ## Arguments
+    ///
+    /// - `document` - The document to extract from.
+    /// - `base_url` - The base url the documents URL is generated from.
+    ///
+    /// ## Returns
+    ///
+    /// The [`ResponseDocument`].
+    pub fn from_document(document: Document, base_url: &str) -> Self {
+        let url = document.generate_url(base_url);
+
+        Self { document, url }
+    }
+
+    /// The URL the contents of the document can be fetched from.
+    #[inline]
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+}
+
+impl Deref for ResponseDocument {
+    type Target = Document;
+
+    fn deref(&self) -> &Self::Target {
+        &self.document
+    }
+}
@@ -0,0 +1,86 @@
+//! Paths, Queries, Bodies and Responses related to the owner endpoints.
+
+#[cfg(test)]
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::models::{paste::PasteOwnedStats, snowflake::Snowflake};
+
+//----------//
+// Response //
+//----------//
+
+/// ## Response Usage
+///
+/// The storage usage of all pastes owned by the owner of a token.
+#[cfg_attr(test, derive(Deserialize))]
+#[derive(Serialize, Debug)]
+pub struct ResponseUsage {
+    /// The paste ID of the owner.
+    owner_id: Snowflake,
+    /// The amount of pastes owned.
+    paste_count: usize,
+    /// The amount of documents attached to the owned pastes.
+    document_count: usize,
+    /// The total size of the documents attached to the owned pastes (bytes).
+    total_size: usize,
+}
+
+impl ResponseUsage {
+    /// New.
+    ///
+    /// Create a new [`ResponseUsage`] object.
+    pub const fn new(
+        owner_id: Snowflake,
+        paste_count: usize,
+        document_count: usize,
+        total_size: usize,
+    ) -> Self {
+        Self {
+            owner_id,
+            paste_count,
+            document_count,
+            total_size,
+        }
+    }
+
+    /// From Stats.
+    ///
+    /// Create a new [`ResponseUsage`] object from an owners [`PasteOwnedStats`].
+    pub const fn from_stats(owner_id: Snowflake, stats: &PasteOwnedStats) -> Self {
+        Self::new(
+            owner_id,
+            stats.paste_count(),
+            stats.document_count(),
+            stats.total_size(),
+        )
+    }
+
+    // Testing item, docs not needed.
+    #[expect(missing_docs)]
+    #[cfg(test)]
+    pub const fn owner_id(&self) -> &Snowflake {
+        &self.owner_id
+    }
+
+    // Testing item, docs not needed.
+    #[expect(missing_docs)]
+    #[cfg(test)]
+    pub const fn paste_count(&self) -> usize {
+        self.paste_count
+    }
+
+    // Testing item, docs not needed.
+    #[expect(missing_docs)]
+    #[cfg(test)]
+    pub const fn document_count(&self) -> usize {
+        self.document_count
+    }
+
+    // Testing item, docs not needed.
+    #[expect(missing_docs)]
+    #[cfg(test)]
+    pub const fn total_size(&self) -> usize {
+        self.total_size
+    }
+}
@@ -1,5 +1,8 @@
 //! All payload related objects for sending via serde.
 
+pub mod admin;
 pub mod document;
 pub mod information;
+pub mod me;
 pub mod paste;
+pub mod upload;
@@ -2,24 +2,27 @@
 
 use std::collections::{HashMap, HashSet};
 
-use axum::extract::{FromRequest, Multipart};
+use axum::extract::{FromRequest, Multipart, Query};
+use base64::{Engine as _, prelude::BASE64_STANDARD};
 use bytes::Bytes;
 use http::header::CONTENT_TYPE;
 use mime::Mime;
 use regex::Regex;
-use secrecy::ExposeSecret;
-use serde::{Deserialize, Serialize};
+use secrecy::{ExposeSecret, SecretString};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use serde_json::json;
+use serde_path_to_error::Track;
 
 use crate::{
-    app::application::App,
+    app::{application::App, config::Config},
     models::{
         DtUtc,
         authentication::Token,
-        document::{Document, UNSUPPORTED_MIMES, contains_mime, document_limits},
-        errors::RESTError,
-        paste::Paste,
-        payload::document::{PatchPasteDocumentBody, PostPasteDocumentBody},
-        snowflake::{PartialSnowflake, Snowflake},
+        document::{Document, document_limits, is_mime_allowed, validate_tags},
+        errors::{RESTError, ValidationErrors},
+        paste::{AnalyticsInterval, Paste, ViewBucket},
+        payload::document::{PatchPasteDocumentBody, PostPasteDocumentBody, ResponseDocument},
+        snowflake::{PartialSnowflake, Snowflake, validate_snowflakes},
         undefined::{Undefined, UndefinedOption},
     },
 };
@@ -43,6 +46,17 @@ impl PastePath {
     pub const fn paste_id(&self) -> &Snowflake {
         &self.paste_id
     }
+
+    /// ## Validate
+    ///
+    /// Validate that the IDs within the path are plausible snowflakes.
+    ///
+    /// ## Errors
+    ///
+    /// - [`RESTError`] - Returned when an ID was created too far in the future.
+    pub fn validate(&self, config: &Config) -> Result<(), RESTError> {
+        validate_snowflakes(config, &[&self.paste_id])
+    }
 }
 
 /// Used for getting pastes.
@@ -54,6 +68,114 @@ pub type PatchPastePath = PastePath;
 /// Used for deleting pastes.
 pub type DeletePastePath = PastePath;
 
+/// Used for restoring pastes.
+pub type RestorePastePath = PastePath;
+
+/// Used for rotating paste tokens.
+pub type RotatePasteTokenPath = PastePath;
+
+/// Used for exporting pastes.
+pub type ExportPastePath = PastePath;
+
+/// Used for listing the documents of a paste.
+pub type GetDocumentsPath = PastePath;
+
+/// Used for getting the analytics of a paste.
+pub type GetPasteAnalyticsPath = PastePath;
+
+//-------//
+// Query //
+//-------//
+
+/// ## Token Location
+///
+/// Where the token of a newly created paste is returned.
+#[derive(Deserialize, Default, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum TokenLocation {
+    /// The token is returned in the response body.
+    #[default]
+    Body,
+    /// The token is only returned in the `X-Paste-Token` response header.
+    Header,
+}
+
+/// ## Validation Mode
+///
+/// How the failures of validating a new paste are returned.
+#[derive(Deserialize, Default, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ValidationMode {
+    /// Only the first failure is returned.
+    #[default]
+    First,
+    /// Every failure is collected, and returned together.
+    All,
+}
+
+/// ## Post Paste Query
+///
+/// The query parameters of the post paste endpoint.
+#[derive(Deserialize, Default)]
+pub struct PostPasteQuery {
+    /// Where the token of the new paste is returned.
+    #[serde(default)]
+    token_in: TokenLocation,
+    /// How the failures of validating the paste are returned.
+    #[serde(default)]
+    validate: ValidationMode,
+}
+
+impl PostPasteQuery {
+    /// Where the token of the new paste is returned.
+    #[inline]
+    pub const fn token_in(&self) -> TokenLocation {
+        self.token_in
+    }
+
+    /// How the failures of validating the paste are returned.
+    #[inline]
+    pub const fn validate(&self) -> ValidationMode {
+        self.validate
+    }
+}
+
+/// ## Post Paste Validate Query
+///
+/// The query parameters of the paste validation endpoint.
+#[derive(Deserialize, Default)]
+pub struct PostPasteValidateQuery {
+    /// How the failures of validating the paste are returned.
+    #[serde(default)]
+    validate: ValidationMode,
+}
+
+impl PostPasteValidateQuery {
+    /// How the failures of validating the paste are returned.
+    #[inline]
+    pub const fn validate(&self) -> ValidationMode {
+        self.validate
+    }
+}
+
+/// ## Get Paste Analytics Query
+///
+/// The query parameters of the paste analytics endpoint.
+#[derive(Deserialize, Default)]
+pub struct GetPasteAnalyticsQuery {
+    /// The interval the views are bucketed by.
+    #[serde(default)]
+    interval: AnalyticsInterval,
+}
+
+impl GetPasteAnalyticsQuery {
+    /// The interval the views are bucketed by.
+    #[inline]
+    pub const fn interval(&self) -> AnalyticsInterval {
+        self.interval
+    }
+}
+
 //------//
 // Body //
 //------//
@@ -72,6 +194,12 @@ pub struct PostPasteBodyInner {
     /// The maximum allowed views for the paste.
     #[serde(default)]
     max_views: UndefinedOption<usize>,
+    /// The password required to read the paste.
+    #[serde(default)]
+    password: Option<SecretString>,
+    /// The URL view milestones of the paste are sent to.
+    #[serde(default)]
+    webhook_url: Option<String>,
     /// The documents attached to the paste.
     documents: Vec<PostPasteDocumentBody>,
 }
@@ -82,6 +210,84 @@ impl PostPasteBodyInner {
         &self.documents
     }
 
+    /// JSON Schema.
+    ///
+    /// Build the JSON Schema describing the paste creation payload.
+    ///
+    /// The constraints are taken from the configured size limits,
+    /// so they match the limits the payload is validated against.
+    ///
+    /// ## Arguments
+    ///
+    /// - `config` - The config the constraints are taken from.
+    ///
+    /// ## Returns
+    ///
+    /// The JSON Schema of the payload.
+    pub fn json_schema(config: &Config) -> serde_json::Value {
+        let size_limits = config.size_limits();
+
+        let mut expiry_description = vec![String::from("When the paste expires (RFC 3339).")];
+        if let Some(minimum_expiry_hours) = size_limits.minimum_expiry_hours() {
+            expiry_description.push(format!("At least {minimum_expiry_hours} hours from now."));
+        }
+        if let Some(maximum_expiry_hours) = size_limits.maximum_expiry_hours() {
+            expiry_description.push(format!("At most {maximum_expiry_hours} hours from now."));
+        }
+
+        // The expiry can only be null when no minimum or maximum is set.
+        let expiry_type = if size_limits.minimum_expiry_hours().is_some()
+            || size_limits.maximum_expiry_hours().is_some()
+        {
+            json!("string")
+        } else {
+            json!(["string", "null"])
+        };
+
+        json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "title": "Paste",
+            "type": "object",
+            "required": ["documents"],
+            "additionalProperties": false,
+            "properties": {
+                "name": {
+                    "description": "The name of the paste.",
+                    "type": ["string", "null"],
+                    "minLength": size_limits.minimum_paste_name_size(),
+                    "maxLength": size_limits.maximum_paste_name_size()
+                },
+                "expiry_timestamp": {
+                    "description": expiry_description.join(" "),
+                    "type": expiry_type,
+                    "format": "date-time"
+                },
+                "max_views": {
+                    "description": "The maximum amount of times the paste can be viewed.",
+                    "type": ["integer", "null"],
+                    "minimum": 0
+                },
+                "password": {
+                    "description": "The password required to read the paste.",
+                    "type": "string",
+                    "minLength": 1
+                },
+                "webhook_url": {
+                    "description": "The URL view milestones of the paste are sent to.",
+                    "type": "string",
+                    "format": "uri"
+                },
+                "documents": {
+                    "description": "The documents attached to the paste.",
+                    "type": "array",
+                    "minItems": size_limits.minimum_total_document_count(),
+                    "maxItems": size_limits.maximum_total_document_count(),
+                    "items": PostPasteDocumentBody::json_schema(config)
+                }
+            }
+        })
+    }
+
     /// ## Into Parts
     ///
     /// Convert the paste body into its individual parts
@@ -93,6 +299,8 @@ impl PostPasteBodyInner {
             name: self.name,
             expiry: self.expiry,
             max_views: self.max_views,
+            password: self.password,
+            webhook_url: self.webhook_url,
         };
 
         (body, self.documents)
@@ -109,6 +317,10 @@ pub struct PostPasteBody {
     expiry: UndefinedOption<DtUtc>,
     /// The maximum allowed views for the paste.
     max_views: UndefinedOption<usize>,
+    /// The password required to read the paste.
+    password: Option<SecretString>,
+    /// The URL view milestones of the paste are sent to.
+    webhook_url: Option<String>,
 }
 
 impl PostPasteBody {
@@ -129,6 +341,18 @@ impl PostPasteBody {
     pub const fn max_views(&self) -> UndefinedOption<usize> {
         self.max_views
     }
+
+    /// The password required to read the paste.
+    #[inline]
+    pub const fn password(&self) -> Option<&SecretString> {
+        self.password.as_ref()
+    }
+
+    /// The URL view milestones of the paste are sent to.
+    #[inline]
+    pub fn webhook_url(&self) -> Option<&str> {
+        self.webhook_url.as_deref()
+    }
 }
 
 /// ## Post Paste Body
@@ -176,6 +400,89 @@ impl PatchPasteBody {
     }
 }
 
+/// ## Post Pastes Batch Body
+///
+/// The body for fetching multiple pastes at once.
+#[derive(Deserialize)]
+pub struct PostPastesBatchBody {
+    /// The IDs of the pastes to fetch.
+    ids: Vec<Snowflake>,
+}
+
+impl PostPastesBatchBody {
+    /// The IDs of the pastes to fetch.
+    #[inline]
+    pub fn ids(&self) -> &[Snowflake] {
+        &self.ids
+    }
+}
+
+/// ## Paste Bundle
+///
+/// A self contained paste, including the contents of its documents.
+///
+/// Used as both the export response, and the import body.
+#[derive(Serialize, Deserialize)]
+pub struct PasteBundle {
+    /// The name for the paste.
+    name: Option<String>,
+    /// The expiry time for the paste.
+    #[serde(rename = "expiry_timestamp")]
+    expiry: Option<DtUtc>,
+    /// The maximum allowed views for the paste.
+    max_views: Option<usize>,
+    /// The documents attached to the paste.
+    documents: Vec<PasteBundleDocument>,
+}
+
+impl PasteBundle {
+    /// From Paste.
+    ///
+    /// Create a new [`PasteBundle`] from a [`Paste`] and its documents contents.
+    ///
+    /// ## Arguments
+    ///
+    /// - `paste` - The paste to extract from.
+    /// - `documents` - The documents to attach, alongside their contents.
+    ///
+    /// ## Returns
+    ///
+    /// The [`PasteBundle`].
+    pub fn from_paste(paste: &Paste, documents: Vec<(Document, Bytes)>) -> Self {
+        Self {
+            name: paste.name().map(ToString::to_string),
+            expiry: paste.expiry().copied(),
+            max_views: paste.max_views(),
+            documents: documents
+                .into_iter()
+                .map(|(document, content)| PasteBundleDocument {
+                    name: document.name().to_string(),
+                    doc_type: document.doc_type().to_string(),
+                    tags: document.tags().to_vec(),
+                    content: BASE64_STANDARD.encode(content),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// ## Paste Bundle Document
+///
+/// A document within a [`PasteBundle`].
+#[derive(Serialize, Deserialize)]
+pub struct PasteBundleDocument {
+    /// The name of the document.
+    name: String,
+    /// The type of the document.
+    #[serde(rename = "type")]
+    doc_type: String,
+    /// The tags of the document.
+    #[serde(default)]
+    tags: Vec<String>,
+    /// The base64 encoded contents of the document.
+    content: String,
+}
+
 //----------//
 // Response //
 //----------//
@@ -206,8 +513,16 @@ pub struct ResponsePaste {
     views: usize,
     /// The maximum amount of views the paste can have.
     max_views: Option<usize>,
+    /// The amount of views the paste has left, before reaching its maximum views.
+    remaining_views: Option<usize>,
+    /// The version of the paste, used as its `ETag`.
+    version: usize,
+    /// The amount of documents attached to the paste.
+    document_count: usize,
+    /// The total size of all documents attached to the paste.
+    total_size: usize,
     /// The documents attached to the paste.
-    documents: Vec<Document>,
+    documents: Vec<ResponseDocument>,
 }
 
 impl ResponsePaste {
@@ -224,7 +539,10 @@ impl ResponsePaste {
         expiry: Option<DtUtc>,
         views: usize,
         max_views: Option<usize>,
-        documents: Vec<Document>,
+        version: usize,
+        document_count: usize,
+        total_size: usize,
+        documents: Vec<ResponseDocument>,
     ) -> Self {
         Self {
             id,
@@ -235,6 +553,14 @@ impl ResponsePaste {
             expiry,
             views,
             max_views,
+            // Clamped, as the views can exceed the maximum views once edited.
+            remaining_views: match max_views {
+                Some(max_views) => Some(max_views.saturating_sub(views)),
+                None => None,
+            },
+            version,
+            document_count,
+            total_size,
             documents,
         }
     }
@@ -248,13 +574,21 @@ impl ResponsePaste {
     /// - `paste` - The paste to extract from.
     /// - `token` - The token to use (if provided).
     /// - `documents` - The documents to attach.
+    /// - `base_url` - The base url the documents URLs are generated from.
     ///
     /// ## Returns
     ///
     /// The [`ResponsePaste`].
-    pub fn from_paste(paste: &Paste, token: Option<Token>, documents: Vec<Document>) -> Self {
+    pub fn from_paste(
+        paste: &Paste,
+        token: Option<Token>,
+        documents: Vec<Document>,
+        base_url: &str,
+    ) -> Self {
         let token_value: Option<String> = { token.map(|t| t.token().expose_secret().to_string()) };
 
+        let total_size = documents.iter().map(Document::size).sum();
+
         Self::new(
             *paste.id(),
             paste.name().map(ToString::to_string),
@@ -264,9 +598,26 @@ impl ResponsePaste {
             paste.expiry().copied(),
             paste.views(),
             paste.max_views(),
-            documents,
+            paste.version(),
+            documents.len(),
+            total_size,
+            documents
+                .into_iter()
+                .map(|document| ResponseDocument::from_document(document, base_url))
+                .collect(),
         )
     }
+
+    /// Take Token.
+    ///
+    /// Remove the token from the paste, so it is not returned in the body.
+    ///
+    /// ## Returns
+    ///
+    /// The token that was attached (if any).
+    pub const fn take_token(&mut self) -> Option<String> {
+        self.token.take()
+    }
 }
 
 #[cfg(test)]
@@ -321,28 +672,413 @@ impl ResponsePaste {
 
     // Testing item, docs not needed.
     #[expect(missing_docs)]
-    pub fn documents(&self) -> &Vec<Document> {
+    pub fn remaining_views(&self) -> Option<usize> {
+        self.remaining_views
+    }
+
+    // Testing item, docs not needed.
+    #[expect(missing_docs)]
+    pub fn version(&self) -> usize {
+        self.version
+    }
+
+    // Testing item, docs not needed.
+    #[expect(missing_docs)]
+    pub fn document_count(&self) -> usize {
+        self.document_count
+    }
+
+    // Testing item, docs not needed.
+    #[expect(missing_docs)]
+    pub fn total_size(&self) -> usize {
+        self.total_size
+    }
+
+    // Testing item, docs not needed.
+    #[expect(missing_docs)]
+    pub fn documents(&self) -> &Vec<ResponseDocument> {
         &self.documents
     }
 }
 
+/// ## Response Paste Summary
+///
+/// The paste returned when requested in bulk, without its documents.
+#[cfg_attr(test, derive(Deserialize))]
+#[derive(Serialize)]
+pub struct ResponsePasteSummary {
+    /// The ID for the paste.
+    id: Snowflake,
+    /// The name for the paste.
+    name: Option<String>,
+    /// The time at which the paste was created.
+    #[serde(rename = "timestamp")]
+    creation: DtUtc,
+    /// Whether the paste has been edited.
+    #[serde(rename = "edited_timestamp")]
+    edited: Option<DtUtc>,
+    /// The expiry time of the paste.
+    #[serde(rename = "expiry_timestamp")]
+    expiry: Option<DtUtc>,
+    /// The view count for the paste.
+    views: usize,
+    /// The maximum amount of views the paste can have.
+    max_views: Option<usize>,
+}
+
+impl ResponsePasteSummary {
+    /// From Paste.
+    ///
+    /// Create a new [`ResponsePasteSummary`] from a [`Paste`].
+    ///
+    /// ## Arguments
+    ///
+    /// - `paste` - The paste to extract from.
+    ///
+    /// ## Returns
+    ///
+    /// The [`ResponsePasteSummary`].
+    pub fn from_paste(paste: &Paste) -> Self {
+        Self {
+            id: *paste.id(),
+            name: paste.name().map(ToString::to_string),
+            creation: *paste.creation(),
+            edited: paste.edited().copied(),
+            expiry: paste.expiry().copied(),
+            views: paste.views(),
+            max_views: paste.max_views(),
+        }
+    }
+}
+
+#[cfg(test)]
+impl ResponsePasteSummary {
+    // Testing item, docs not needed.
+    #[expect(missing_docs)]
+    pub fn id(&self) -> Snowflake {
+        self.id
+    }
+}
+
+/// ## Response Paste Analytics
+///
+/// The views of a paste over time.
+#[cfg_attr(test, derive(Deserialize))]
+#[derive(Serialize)]
+pub struct ResponsePasteAnalytics {
+    /// The interval the views are bucketed by.
+    interval: AnalyticsInterval,
+    /// The views within each interval, oldest first (intervals without any views are omitted).
+    buckets: Vec<ResponseViewBucket>,
+}
+
+impl ResponsePasteAnalytics {
+    /// New.
+    ///
+    /// Create a new [`ResponsePasteAnalytics`] from the bucketed views of a paste.
+    ///
+    /// ## Arguments
+    ///
+    /// - `interval` - The interval the views are bucketed by.
+    /// - `buckets` - The views within each interval.
+    ///
+    /// ## Returns
+    ///
+    /// The [`ResponsePasteAnalytics`].
+    pub fn new(interval: AnalyticsInterval, buckets: Vec<ViewBucket>) -> Self {
+        Self {
+            interval,
+            buckets: buckets
+                .into_iter()
+                .map(|bucket| ResponseViewBucket {
+                    start: *bucket.start(),
+                    views: bucket.views(),
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+impl ResponsePasteAnalytics {
+    // Testing item, docs not needed.
+    #[expect(missing_docs)]
+    pub fn buckets(&self) -> &[ResponseViewBucket] {
+        &self.buckets
+    }
+}
+
+/// ## Response View Bucket
+///
+/// The amount of views a paste received within an interval.
+#[cfg_attr(test, derive(Deserialize))]
+#[derive(Serialize)]
+pub struct ResponseViewBucket {
+    /// When the interval starts.
+    start: DtUtc,
+    /// The amount of views within the interval.
+    views: usize,
+}
+
+#[cfg(test)]
+impl ResponseViewBucket {
+    // Testing item, docs not needed.
+    #[expect(missing_docs)]
+    pub fn start(&self) -> DtUtc {
+        self.start
+    }
+
+    // Testing item, docs not needed.
+    #[expect(missing_docs)]
+    pub fn views(&self) -> usize {
+        self.views
+    }
+}
+
+/// ## Response Paste Validation
+///
+/// The normalized paste returned when a paste is validated, without being created.
+#[cfg_attr(test, derive(Deserialize))]
+#[derive(Serialize)]
+pub struct ResponsePasteValidation {
+    /// The name the paste would have.
+    name: Option<String>,
+    /// The expiry time the paste would have.
+    #[serde(rename = "expiry_timestamp")]
+    expiry: Option<DtUtc>,
+    /// The maximum amount of views the paste would have.
+    max_views: Option<usize>,
+    /// The amount of documents that would be attached to the paste.
+    document_count: usize,
+    /// The total size of all documents that would be attached to the paste.
+    total_size: usize,
+}
+
+impl ResponsePasteValidation {
+    /// New.
+    ///
+    /// Create a new [`ResponsePasteValidation`] object.
+    pub const fn new(
+        name: Option<String>,
+        expiry: Option<DtUtc>,
+        max_views: Option<usize>,
+        document_count: usize,
+        total_size: usize,
+    ) -> Self {
+        Self {
+            name,
+            expiry,
+            max_views,
+            document_count,
+            total_size,
+        }
+    }
+}
+
+#[cfg(test)]
+impl ResponsePasteValidation {
+    // Testing item, docs not needed.
+    #[expect(missing_docs)]
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    // Testing item, docs not needed.
+    #[expect(missing_docs)]
+    pub fn document_count(&self) -> usize {
+        self.document_count
+    }
+
+    // Testing item, docs not needed.
+    #[expect(missing_docs)]
+    pub fn total_size(&self) -> usize {
+        self.total_size
+    }
+}
+
 //------------//
 // Extractors //
 //------------//
 
+impl FromRequest<App> for PostPastesBatchBody {
+    type Rejection = RESTError;
+
+    async fn from_request(
+        req: axum::extract::Request,
+        state: &App,
+    ) -> Result<Self, Self::Rejection> {
+        let bytes = Bytes::from_request(req, state).await?;
+
+        Ok(serde_json::from_slice(&bytes)?)
+    }
+}
+
+/// ## Post Paste Import Body
+///
+/// The extractor for importing a [`PasteBundle`].
+///
+/// The documents are validated the same as a paste creation.
+pub struct PostPasteImportBody {
+    /// The payload of the bundle.
+    pub payload: PostPasteBody,
+    /// The documents attached to the bundle.
+    pub documents: Vec<(PostPasteDocumentBody, String, Mime)>,
+}
+
+impl FromRequest<App> for PostPasteImportBody {
+    type Rejection = RESTError;
+
+    async fn from_request(
+        req: axum::extract::Request,
+        state: &App,
+    ) -> Result<Self, Self::Rejection> {
+        let bytes = Bytes::from_request(req, state).await?;
+
+        let bundle: PasteBundle = serde_json::from_slice(&bytes)?;
+
+        let maximum_total_document_count =
+            state.config().size_limits().maximum_total_document_count();
+        if bundle.documents.len() > maximum_total_document_count {
+            return Err(RESTError::unprocessable(format!(
+                "Too many documents were provided. Expected: {maximum_total_document_count}, Received: {}",
+                bundle.documents.len()
+            )));
+        }
+
+        let payload = PostPasteBody {
+            name: bundle.name.into(),
+            expiry: bundle.expiry.into(),
+            max_views: bundle.max_views.into(),
+            password: None,
+            webhook_url: None,
+        };
+
+        let mut documents = Vec::new();
+        for (index, document) in bundle.documents.into_iter().enumerate() {
+            let id = PartialSnowflake::new(index as u64);
+
+            if !is_mime_allowed(state.config(), &document.doc_type) {
+                return Err(RESTError::bad_request(format!(
+                    "Invalid mime type: {} received for the document: {id}",
+                    document.doc_type
+                )));
+            }
+
+            let mime: Mime = document.doc_type.parse()?;
+
+            let data = BASE64_STANDARD.decode(&document.content).map_err(|_| {
+                RESTError::bad_request(format!("Document `{id}` is not valid base64."))
+            })?;
+            let content = String::from_utf8(data)?;
+
+            document_limits(
+                state.config(),
+                &format!("documents[{id}]"),
+                Undefined::Some(&document.name),
+                Undefined::Some(&content),
+                mime.as_ref(),
+            )?;
+
+            validate_tags(&format!("documents[{id}]"), &document.tags)?;
+
+            documents.push((
+                PostPasteDocumentBody::new(id, document.name, document.tags),
+                content,
+                mime,
+            ));
+        }
+
+        Ok(Self { payload, documents })
+    }
+}
+
+/// ## Parse Field Name
+///
+/// Checks the name of a multipart field, before any of its body is read.
+///
+/// Only the `payload` and `files[{id}]` names are accepted.
+///
+/// ## Arguments
+///
+/// - `name_regex` - The regex matching the `files[{id}]` names.
+/// - `name` - The name of the field.
+///
+/// ## Errors
+///
+/// - [`RESTError`] - The name is neither `payload` or `files[{id}]`.
+///
+/// ## Returns
+///
+/// - [`Some`] - The ID of the document, for a `files[{id}]` field.
+/// - [`None`] - The field is the `payload`.
+fn parse_field_name<'a>(name_regex: &Regex, name: &'a str) -> Result<Option<&'a str>, RESTError> {
+    if name == "payload" {
+        return Ok(None);
+    }
+
+    name_regex
+        .captures(name)
+        .and_then(|captures| captures.name("id"))
+        .map(|id| Some(id.as_str()))
+        .ok_or_else(|| {
+            RESTError::bad_request(format!("An unknown multipart item was received: {name}"))
+        })
+}
+
+/// Parse Payload.
+///
+/// Deserialize the JSON `payload` of a request.
+///
+/// ## Arguments
+///
+/// - `data` - The bytes of the payload.
+///
+/// ## Errors
+///
+/// - [`RESTError`] - The payload is not valid JSON, or does not parse into the expected data.
+///   The message includes the line, column and (where known) the field the error occurred at.
+///
+/// ## Returns
+///
+/// The deserialized payload.
+fn parse_payload<T: DeserializeOwned>(data: &[u8]) -> Result<T, RESTError> {
+    let mut deserializer = serde_json::Deserializer::from_slice(data);
+    let payload = serde_path_to_error::deserialize(&mut deserializer)?;
+
+    // Trailing characters are not within any field, so are reported against the root.
+    deserializer
+        .end()
+        .map_err(|error| serde_path_to_error::Error::new(Track::new().path(), error))?;
+
+    Ok(payload)
+}
+
 /// ## Post Paste Multipart Body
 ///
 /// The multipart extractor for a paste creation.
+///
+/// The `payload` and `files[{id}]` fields are accepted in any order,
+/// and any other field is rejected before its body is read.
+///
+/// Documents listing a `source_url` have no `files[{id}]` field, as their contents are fetched instead.
+///
+/// When every validation failure is requested (`?validate=all`),
+/// the failures of each document are collected instead of returned.
 pub struct PostPasteMultipartBody {
     /// The payload of the multipart body.
     pub payload: PostPasteBody,
     /// The documents attached to the multipart body.
     pub documents: Vec<(PostPasteDocumentBody, String, Mime)>,
+    /// The documents whose contents are fetched from their source URL.
+    pub sources: Vec<PostPasteDocumentBody>,
+    /// The validation failures collected from the documents.
+    pub errors: ValidationErrors,
 }
 
 impl FromRequest<App> for PostPasteMultipartBody {
     type Rejection = RESTError;
 
+    #[expect(clippy::too_many_lines)]
     async fn from_request(
         req: axum::extract::Request,
         state: &App,
@@ -364,6 +1100,13 @@ impl FromRequest<App> for PostPasteMultipartBody {
             )));
         }
 
+        // An invalid query is rejected by the handlers own query extractor.
+        let validate = Query::<PostPasteValidateQuery>::try_from_uri(req.uri())
+            .map(|Query(query)| query.validate())
+            .unwrap_or_default();
+
+        let mut errors = ValidationErrors::new(validate == ValidationMode::All);
+
         let mut multipart = Multipart::from_request(req, state).await?;
 
         let mut payload: Option<PostPasteBodyInner> = None;
@@ -376,23 +1119,34 @@ impl FromRequest<App> for PostPasteMultipartBody {
                 ));
             };
 
-            let Some(content_type) = field.content_type() else {
-                return Err(RESTError::bad_request(
-                    "All multipart fields require a content type.",
-                ));
+            // Unknown fields are rejected before any of their body is read.
+            let document_id = parse_field_name(&name_regex, name)?;
+
+            let content_type = match field.content_type() {
+                Some(content_type) => content_type.to_string(),
+                None if document_id.is_some() => state
+                    .config()
+                    .size_limits()
+                    .default_document_mime()
+                    .to_string(),
+                None => {
+                    return Err(RESTError::bad_request(
+                        "All multipart fields require a content type.",
+                    ));
+                }
             };
 
             let content_type_mime: mime::Mime = content_type.parse()?;
 
-            if name == "payload" {
-                if content_type != mime::APPLICATION_JSON {
+            let Some(document_id) = document_id else {
+                if content_type.as_str() != mime::APPLICATION_JSON {
                     return Err(RESTError::bad_request(
                         "Payload must have a content type of application/json",
                     ));
                 }
 
                 let data = field.bytes().await?;
-                let json: PostPasteBodyInner = serde_json::from_slice(&data)?;
+                let json: PostPasteBodyInner = parse_payload(&data)?;
 
                 let document_ids: Vec<PartialSnowflake> =
                     json.documents().iter().map(|v| *v.id()).collect();
@@ -405,48 +1159,78 @@ impl FromRequest<App> for PostPasteMultipartBody {
                     ));
                 }
 
-                payload = Some(json);
-                continue;
-            }
-
-            if let Some(captures) = name_regex.captures(name) {
-                if contains_mime(UNSUPPORTED_MIMES, content_type) {
-                    return Err(RESTError::bad_request(format!(
-                        "Invalid mime type: {content_type} received for the document: {}",
-                        &captures["id"]
+                // The document count is known once the payload is read, so reject before any file is.
+                // When collecting every failure, the count is validated alongside the totals instead.
+                let maximum_total_document_count =
+                    state.config().size_limits().maximum_total_document_count();
+                if !errors.collect_all() && document_ids.len() > maximum_total_document_count {
+                    return Err(RESTError::unprocessable(format!(
+                        "Too many documents were provided. Expected: {maximum_total_document_count}, Received: {}",
+                        document_ids.len()
                     )));
                 }
 
-                let id: PartialSnowflake = (&captures["id"]).try_into()?;
+                payload = Some(json);
+                continue;
+            };
 
-                if document_contents.contains_key(&id) {
-                    return Err(RESTError::bad_request(
-                        "A duplicate ID was found in the form data",
-                    ));
-                }
+            // Reject early, rather than reading every file field before the limits are checked.
+            let maximum_total_document_count =
+                state.config().size_limits().maximum_total_document_count();
+            if !errors.collect_all() && document_contents.len() >= maximum_total_document_count {
+                return Err(RESTError::unprocessable(format!(
+                    "Too many documents were provided. Expected: {maximum_total_document_count}, Received: {}",
+                    document_contents.len() + 1
+                )));
+            }
 
-                let data = field.bytes().await?;
-                let content = String::from_utf8(data.to_vec())?;
+            if !is_mime_allowed(state.config(), &content_type) {
+                errors.push(
+                    name,
+                    RESTError::bad_request(format!(
+                        "Invalid mime type: {content_type} received for the document: {document_id}"
+                    )),
+                )?;
+            }
 
-                document_contents.insert(id, (content, content_type_mime));
-                continue;
+            let id: PartialSnowflake = document_id.try_into()?;
+
+            if document_contents.contains_key(&id) {
+                return Err(RESTError::bad_request(
+                    "A duplicate ID was found in the form data",
+                ));
             }
 
-            return Err(RESTError::bad_request(format!(
-                "An unknown multipart item was received: {name}"
-            )));
+            let data = field.bytes().await?;
+            let content = String::from_utf8(data.to_vec())?;
+
+            document_contents.insert(id, (content, content_type_mime));
         }
 
         let Some(payload) = payload else {
             return Err(RESTError::bad_request(
-                "Payload was not found in the form data",
+                "The `payload` field was not found in the form data.",
             ));
         };
 
         let (payload, body_documents) = payload.into_parts();
 
         let mut documents = Vec::new();
+        let mut sources = Vec::new();
         for document in body_documents {
+            let field = format!("files[{}]", document.id());
+
+            if document.source_url().is_some() {
+                if document_contents.contains_key(document.id()) {
+                    return Err(RESTError::bad_request(format!(
+                        "Document `{field}` cannot have both a file and a source URL."
+                    )));
+                }
+
+                sources.push(document);
+                continue;
+            }
+
             let Some((content, mime)) = document_contents.remove(document.id()) else {
                 return Err(RESTError::bad_request(format!(
                     "A document with the ID of {} was not found",
@@ -454,13 +1238,19 @@ impl FromRequest<App> for PostPasteMultipartBody {
                 )));
             };
 
-            document_limits(
-                state.config(),
-                document.id(),
-                Undefined::Some(document.name()),
-                Undefined::Some(&content),
+            errors.check(
+                &field,
+                document_limits(
+                    state.config(),
+                    &field,
+                    Undefined::Some(document.name()),
+                    Undefined::Some(&content),
+                    mime.as_ref(),
+                ),
             )?;
 
+            errors.check(&field, validate_tags(&field, document.tags()))?;
+
             documents.push((document, content, mime));
         }
 
@@ -470,13 +1260,21 @@ impl FromRequest<App> for PostPasteMultipartBody {
             ));
         }
 
-        Ok(Self { payload, documents })
+        Ok(Self {
+            payload,
+            documents,
+            sources,
+            errors,
+        })
     }
 }
 
 /// ## Patch Paste Multipart Body
 ///
 /// The multipart extractor for paste modification.
+///
+/// The `payload` and `files[{id}]` fields are accepted in any order,
+/// and any other field is rejected before its body is read.
 pub struct PatchPasteMultipartBody {
     /// The payload of the multipart body.
     pub payload: PatchPasteBody,
@@ -497,7 +1295,7 @@ impl PatchPasteMultipartBody {
     pub async fn from_json(req: axum::extract::Request, state: &App) -> Result<Self, RESTError> {
         let bytes = Bytes::from_request(req, state).await?;
 
-        let json: PatchPasteBody = serde_json::from_slice(&bytes)?;
+        let json: PatchPasteBody = parse_payload(&bytes)?;
 
         if let Undefined::Some(documents) = json.documents() {
             let document_ids: Vec<PartialSnowflake> = documents.iter().map(|v| *v.id()).collect();
@@ -509,6 +1307,12 @@ impl PatchPasteMultipartBody {
                     "One or more documents provided has the same ID",
                 ));
             }
+
+            for document in documents {
+                if let Undefined::Some(tags) = document.tags() {
+                    validate_tags(&format!("documents[{}]", document.id()), tags)?;
+                }
+            }
         }
 
         Ok(Self {
@@ -545,23 +1349,34 @@ impl PatchPasteMultipartBody {
                 ));
             };
 
-            let Some(content_type) = field.content_type() else {
-                return Err(RESTError::bad_request(
-                    "All multipart fields require a content type.",
-                ));
+            // Unknown fields are rejected before any of their body is read.
+            let document_id = parse_field_name(&name_regex, name)?;
+
+            let content_type = match field.content_type() {
+                Some(content_type) => content_type.to_string(),
+                None if document_id.is_some() => state
+                    .config()
+                    .size_limits()
+                    .default_document_mime()
+                    .to_string(),
+                None => {
+                    return Err(RESTError::bad_request(
+                        "All multipart fields require a content type.",
+                    ));
+                }
             };
 
             let content_type_mime: mime::Mime = content_type.parse()?;
 
-            if name == "payload" {
-                if content_type != mime::APPLICATION_JSON {
+            let Some(document_id) = document_id else {
+                if content_type.as_str() != mime::APPLICATION_JSON {
                     return Err(RESTError::bad_request(
                         "Payload must have a content type of application/json",
                     ));
                 }
 
                 let data = field.bytes().await?;
-                let json: PatchPasteBody = serde_json::from_slice(&data)?;
+                let json: PatchPasteBody = parse_payload(&data)?;
 
                 if let Undefined::Some(documents) = json.documents() {
                     let document_ids: Vec<PartialSnowflake> =
@@ -574,46 +1389,56 @@ impl PatchPasteMultipartBody {
                             "One or more documents provided has the same ID",
                         ));
                     }
+
+                    for document in documents {
+                        if let Undefined::Some(tags) = document.tags() {
+                            validate_tags(&format!("documents[{}]", document.id()), tags)?;
+                        }
+                    }
                 }
 
                 payload = Some(json);
                 continue;
-            }
+            };
 
-            if let Some(captures) = name_regex.captures(name) {
-                if contains_mime(UNSUPPORTED_MIMES, content_type) {
-                    return Err(RESTError::bad_request(format!(
-                        "Invalid mime type received for a document: {content_type}"
-                    )));
-                }
+            // Reject early, rather than reading every file field before the limits are checked.
+            let maximum_total_document_count =
+                state.config().size_limits().maximum_total_document_count();
+            let received = document_contents.as_ref().map_or(0, HashMap::len);
+            if received >= maximum_total_document_count {
+                return Err(RESTError::unprocessable(format!(
+                    "Too many documents were provided. Expected: {maximum_total_document_count}, Received: {}",
+                    received + 1
+                )));
+            }
 
-                let id: PartialSnowflake = (&captures["id"]).try_into()?;
+            if !is_mime_allowed(state.config(), &content_type) {
+                return Err(RESTError::bad_request(format!(
+                    "Invalid mime type received for a document: {content_type}"
+                )));
+            }
 
-                if let Some(document_contents) = &document_contents
-                    && document_contents.contains_key(&id)
-                {
-                    return Err(RESTError::bad_request(
-                        "A duplicate ID was found in the form data",
-                    ));
-                }
+            let id: PartialSnowflake = document_id.try_into()?;
 
-                let data = field.bytes().await?;
-                let content = String::from_utf8(data.to_vec())?;
+            if let Some(document_contents) = &document_contents
+                && document_contents.contains_key(&id)
+            {
+                return Err(RESTError::bad_request(
+                    "A duplicate ID was found in the form data",
+                ));
+            }
 
-                let document_contents = document_contents.get_or_insert_default();
+            let data = field.bytes().await?;
+            let content = String::from_utf8(data.to_vec())?;
 
-                document_contents.insert(id, (content, content_type_mime));
-                continue;
-            }
+            let document_contents = document_contents.get_or_insert_default();
 
-            return Err(RESTError::bad_request(format!(
-                "An unknown multipart item was received: {name}"
-            )));
+            document_contents.insert(id, (content, content_type_mime));
         }
 
         let Some(payload) = payload else {
             return Err(RESTError::bad_request(
-                "Payload was not found in the form data",
+                "The `payload` field was not found in the form data.",
             ));
         };
 
@@ -637,9 +1462,10 @@ impl PatchPasteMultipartBody {
 
                         document_limits(
                             state.config(),
-                            &id,
+                            &format!("files[{id}]"),
                             body.name(),
                             Undefined::Some(&content),
+                            mime.as_ref(),
                         )?;
 
                         documents_inner.push((body, content, mime));
@@ -0,0 +1,186 @@
+//! Paths, Queries, Bodies and Responses related to the upload endpoints.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    app::config::Config,
+    models::{
+        errors::RESTError,
+        snowflake::{Snowflake, validate_snowflakes},
+        upload::Upload,
+    },
+};
+
+//------//
+// Path //
+//------//
+
+/// ## Upload Path
+///
+/// The values within the path of an upload endpoint.
+#[derive(Deserialize)]
+pub struct UploadPath {
+    /// The upload ID.
+    upload_id: Snowflake,
+}
+
+impl UploadPath {
+    /// The upload ID found within the path.
+    #[inline]
+    pub const fn upload_id(&self) -> &Snowflake {
+        &self.upload_id
+    }
+
+    /// ## Validate
+    ///
+    /// Validate that the IDs within the path are plausible snowflakes.
+    ///
+    /// ## Errors
+    ///
+    /// - [`RESTError`] - Returned when an ID was created too far in the future.
+    pub fn validate(&self, config: &Config) -> Result<(), RESTError> {
+        validate_snowflakes(config, &[&self.upload_id])
+    }
+}
+
+/// Used for checking the offset of uploads.
+pub type HeadUploadPath = UploadPath;
+
+/// Used for appending to uploads.
+pub type PatchUploadPath = UploadPath;
+
+/// Used for cancelling uploads.
+pub type DeleteUploadPath = UploadPath;
+
+//------//
+// Body //
+//------//
+
+/// ## Post Upload Body
+///
+/// The body of the upload creation endpoint.
+#[cfg_attr(test, derive(Serialize))]
+#[derive(Deserialize)]
+pub struct PostUploadBody {
+    /// The ID of the paste the document is uploaded to.
+    paste_id: Snowflake,
+    /// The name of the document.
+    name: String,
+    /// The type of the document.
+    #[serde(rename = "type")]
+    doc_type: Option<String>,
+    /// The total size of the document (bytes).
+    length: usize,
+}
+
+impl PostUploadBody {
+    // Testing item, docs not needed.
+    #[expect(missing_docs)]
+    #[cfg(test)]
+    pub fn new(paste_id: Snowflake, name: &str, doc_type: Option<&str>, length: usize) -> Self {
+        Self {
+            paste_id,
+            name: name.to_string(),
+            doc_type: doc_type.map(ToString::to_string),
+            length,
+        }
+    }
+
+    /// The ID of the paste the document is uploaded to.
+    #[inline]
+    pub const fn paste_id(&self) -> &Snowflake {
+        &self.paste_id
+    }
+
+    /// The name of the document.
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The type of the document.
+    #[inline]
+    pub fn doc_type(&self) -> Option<&str> {
+        self.doc_type.as_deref()
+    }
+
+    /// The total size of the document (bytes).
+    #[inline]
+    pub const fn length(&self) -> usize {
+        self.length
+    }
+}
+
+//----------//
+// Response //
+//----------//
+
+/// ## Response Upload
+///
+/// The upload returned when created, alongside the URL its chunks are appended to.
+#[cfg_attr(test, derive(Deserialize))]
+#[derive(Serialize, Debug)]
+pub struct ResponseUpload {
+    /// The ID of the upload, which becomes the ID of the document once finalized.
+    id: Snowflake,
+    /// The ID of the paste the document is uploaded to.
+    paste_id: Snowflake,
+    /// The name of the document.
+    name: String,
+    /// The type of the document.
+    #[serde(rename = "type")]
+    doc_type: String,
+    /// The total size of the document (bytes).
+    length: usize,
+    /// The amount of bytes received so far.
+    offset: usize,
+    /// The URL chunks of the document are appended to.
+    url: String,
+}
+
+impl ResponseUpload {
+    /// From Upload.
+    ///
+    /// Create a new [`ResponseUpload`] from an [`Upload`].
+    ///
+    /// ## Arguments
+    ///
+    /// - `upload` - The upload to extract from.
+    /// - `url` - The URL chunks of the document are appended to.
+    ///
+    /// ## Returns
+    ///
+    /// The [`ResponseUpload`].
+    pub fn from_upload(upload: &Upload, url: String) -> Self {
+        Self {
+            id: *upload.id(),
+            paste_id: *upload.paste_id(),
+            name: upload.name().to_string(),
+            doc_type: upload.doc_type().to_string(),
+            length: upload.length(),
+            offset: upload.offset(),
+            url,
+        }
+    }
+
+    // Testing item, docs not needed.
+    #[expect(missing_docs)]
+    #[cfg(test)]
+    pub const fn id(&self) -> &Snowflake {
+        &self.id
+    }
+
+    // Testing item, docs not needed.
+    #[expect(missing_docs)]
+    #[cfg(test)]
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    // Testing item, docs not needed.
+    #[expect(missing_docs)]
+    #[cfg(test)]
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+}
@@ -3,14 +3,25 @@
 //! An implemention of a `Snowflake` type,
 //! used for unique identification of objects and items.
 
-use std::{fmt, num::ParseIntError, str::FromStr};
+use std::{
+    fmt,
+    num::ParseIntError,
+    str::FromStr,
+    sync::{
+        OnceLock,
+        atomic::{AtomicU64, Ordering},
+    },
+};
 
 use chrono::Utc;
 use serde::{Deserializer, Serialize, Serializer, de::Error as DEError};
 use serde_json::Value;
-use sqlx::{Decode, Encode};
+use sqlx::{Decode, Encode, PgConnection, PgExecutor};
 
-use crate::models::errors::{GenerateError, ParseError};
+use crate::{
+    app::config::Config,
+    models::errors::{DatabaseError, GenerateError, ParseError, RESTError},
+};
 
 /// ## Partial Snowflake
 ///
@@ -120,9 +131,18 @@ impl PartialEq<Snowflake> for PartialSnowflake {
     }
 }
 
+/// The largest worker ID that can be stored within a snowflake.
+pub const MAXIMUM_WORKER_ID: u16 = 0x03FF;
+
+/// The sequence of the snowflakes generated by this process, seeded with a random value.
+static SEQUENCE: OnceLock<AtomicU64> = OnceLock::new();
+
 /// ## Snowflake
 ///
 /// A Simple snowflake implementation.
+///
+/// The upper 42 bits contain the creation timestamp, the next 10 bits contain the worker ID,
+/// and the lower 12 bits contain the sequence.
 #[derive(Encode, Decode, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Snowflake(u64);
 
@@ -136,11 +156,26 @@ impl Snowflake {
 
     /// Generate.
     ///
-    /// Generate a new snowflake.
+    /// Generate a new snowflake, using the worker ID of `0`.
     ///
-    /// ## Panics
+    /// ## Errors
     ///
-    /// If time went backwards
+    /// - [`GenerateError`] - Failed to get a random value.
+    ///
+    /// ## Returns
+    ///
+    /// A [`Snowflake`].
+    pub fn generate() -> Result<Self, GenerateError> {
+        Self::generate_with_worker(0)
+    }
+
+    /// Generate With Worker.
+    ///
+    /// Generate a new snowflake, unique to the worker that generated it.
+    ///
+    /// ## Arguments
+    ///
+    /// - `worker_id` - The ID of the worker (0-1023), values above this are truncated.
     ///
     /// ## Errors
     ///
@@ -149,16 +184,105 @@ impl Snowflake {
     /// ## Returns
     ///
     /// A [`Snowflake`].
-    pub fn generate() -> Result<Self, GenerateError> {
+    pub fn generate_with_worker(worker_id: u16) -> Result<Self, GenerateError> {
         let timestamp = Utc::now().timestamp() as u64;
 
-        let id = getrandom::u64()?;
+        let sequence = if let Some(sequence) = SEQUENCE.get() {
+            sequence
+        } else {
+            let seed = getrandom::u64()?;
+            SEQUENCE.get_or_init(|| AtomicU64::new(seed))
+        };
+
+        let sequence = sequence.fetch_add(1, Ordering::Relaxed);
 
-        let new_snowflake = Self::new((timestamp << 22) | (id as u64 & 0x003F_FFFF));
+        let new_snowflake = Self::new(
+            (timestamp << 22)
+                | (u64::from(worker_id & MAXIMUM_WORKER_ID) << 12)
+                | (sequence & 0x0FFF),
+        );
 
         Ok(new_snowflake)
     }
 
+    /// Generate Unique.
+    ///
+    /// Generate a new snowflake that is not already in use,
+    /// regenerating it up to the configured amount of attempts.
+    ///
+    /// ## Arguments
+    ///
+    /// - `connection` - The database connection to check for existing IDs with.
+    /// - `config` - The config containing the worker ID and amount of attempts.
+    ///
+    /// ## Errors
+    ///
+    /// - [`GenerateError`] - Failed to get a random value, the database had an error, or every attempt was in use.
+    ///
+    /// ## Returns
+    ///
+    /// A [`Snowflake`] that is not in use.
+    pub async fn generate_unique(
+        connection: &mut PgConnection,
+        config: &Config,
+    ) -> Result<Self, GenerateError> {
+        Self::generate_unique_with(connection, config.snowflake_generate_attempts(), || {
+            Self::generate_with_worker(config.worker_id())
+        })
+        .await
+    }
+
+    /// Generate Unique With.
+    ///
+    /// Generate snowflakes with the generator provided, until one is not already in use.
+    async fn generate_unique_with(
+        connection: &mut PgConnection,
+        attempts: usize,
+        mut generate: impl FnMut() -> Result<Self, GenerateError>,
+    ) -> Result<Self, GenerateError> {
+        for _ in 0..attempts {
+            let snowflake = generate()?;
+
+            if !Self::exists(&mut *connection, &snowflake).await? {
+                return Ok(snowflake);
+            }
+        }
+
+        Err(GenerateError::Exhausted(attempts))
+    }
+
+    /// Exists.
+    ///
+    /// Check if a paste, document or upload is using an ID.
+    ///
+    /// ## Arguments
+    ///
+    /// - `executor` - The database pool or transaction to use.
+    /// - `id` - The ID to check.
+    ///
+    /// ## Errors
+    ///
+    /// - [`DatabaseError`] - The database had an error.
+    ///
+    /// ## Returns
+    ///
+    /// - [`true`] - The ID is in use.
+    /// - [`false`] - The ID is free.
+    pub async fn exists<'e, 'c: 'e, E>(executor: E, id: &Self) -> Result<bool, DatabaseError>
+    where
+        E: 'e + PgExecutor<'c>,
+    {
+        let id: i64 = (*id).into();
+        let query = sqlx::query!(
+            "SELECT EXISTS(SELECT 1 FROM pastes WHERE id = $1 UNION ALL SELECT 1 FROM documents WHERE id = $1 UNION ALL SELECT 1 FROM uploads WHERE id = $1) AS \"exists!\"",
+            id
+        )
+        .fetch_one(executor)
+        .await?;
+
+        Ok(query.exists)
+    }
+
     /// Id.
     ///
     /// Get the raw ID for the snowflake.
@@ -172,6 +296,54 @@ impl Snowflake {
     pub const fn created_at(&self) -> u64 {
         self.id() >> 22
     }
+
+    /// Worker ID.
+    ///
+    /// The ID of the worker that generated this ID.
+    pub const fn worker_id(&self) -> u16 {
+        ((self.id() >> 12) & MAXIMUM_WORKER_ID as u64) as u16
+    }
+
+    /// Is Plausible.
+    ///
+    /// Checks that this ID was not created further in the future than the allowed skew.
+    ///
+    /// ## Arguments
+    ///
+    /// - `skew` - The amount of seconds the creation time can be ahead of the current time.
+    ///
+    /// ## Returns
+    ///
+    /// Returns [`true`] if the ID could have been generated, otherwise returns [`false`].
+    pub fn is_plausible(&self, skew: u64) -> bool {
+        self.created_at() <= (Utc::now().timestamp() as u64).saturating_add(skew)
+    }
+}
+
+/// Validate Snowflakes.
+///
+/// Validate that the snowflakes provided by a client are plausible.
+///
+/// ## Arguments
+///
+/// - `config` - The config containing the allowed skew.
+/// - `ids` - The snowflakes to validate.
+///
+/// ## Errors
+///
+/// - [`RESTError`] - Returned when a snowflake was created too far in the future.
+pub fn validate_snowflakes(config: &Config, ids: &[&Snowflake]) -> Result<(), RESTError> {
+    let Some(skew) = config.snowflake_skew_seconds() else {
+        return Ok(());
+    };
+
+    if let Some(id) = ids.iter().find(|id| !id.is_plausible(skew)) {
+        return Err(RESTError::bad_request(format!(
+            "The ID `{id}` is not a valid snowflake."
+        )));
+    }
+
+    Ok(())
 }
 
 impl Serialize for Snowflake {
@@ -262,3 +434,45 @@ impl PartialEq<PartialSnowflake> for Snowflake {
         self.0 == other.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::PgPool;
+
+    #[sqlx::test(fixtures(path = "../../tests/fixtures/", scripts("pastes")))]
+    async fn test_generate_unique_collision(pool: PgPool) {
+        let mut connection = pool.acquire().await.expect("Failed to acquire connection.");
+
+        let taken = Snowflake::new(517_815_304_354_284_605);
+        let free = Snowflake::new(517_815_304_354_284_999);
+
+        let mut generated = vec![free, taken];
+
+        let snowflake = Snowflake::generate_unique_with(&mut connection, 3, || {
+            Ok(generated.pop().expect("Generated too many snowflakes."))
+        })
+        .await
+        .expect("Failed to generate snowflake.");
+
+        assert_eq!(snowflake, free, "Snowflake does not match.");
+
+        assert!(generated.is_empty(), "Collision was not retried.");
+    }
+
+    #[sqlx::test(fixtures(path = "../../tests/fixtures/", scripts("pastes")))]
+    async fn test_generate_unique_exhausted(pool: PgPool) {
+        let mut connection = pool.acquire().await.expect("Failed to acquire connection.");
+
+        let taken = Snowflake::new(517_815_304_354_284_605);
+
+        let error = Snowflake::generate_unique_with(&mut connection, 3, || Ok(taken))
+            .await
+            .expect_err("Generated a snowflake in use.");
+
+        assert!(
+            matches!(error, GenerateError::Exhausted(3)),
+            "Error does not match."
+        );
+    }
+}
@@ -0,0 +1,313 @@
+//! Upload object and related items.
+
+use sqlx::PgExecutor;
+
+use crate::models::{DtUtc, document::Document};
+
+use super::{errors::DatabaseError, snowflake::Snowflake};
+
+/// The header containing the amount of bytes an upload has received.
+pub const UPLOAD_OFFSET_HEADER: &str = "upload-offset";
+
+/// The header containing the total size of an upload.
+pub const UPLOAD_LENGTH_HEADER: &str = "upload-length";
+
+/// ## Upload
+///
+/// The resumable upload session stored in the database.
+///
+/// The contents are appended in chunks, and finalized into a document once complete.
+#[derive(Debug, Clone)]
+pub struct Upload {
+    /// The ID of the upload, which becomes the ID of the document once finalized.
+    id: Snowflake,
+    /// The paste the document is being uploaded to.
+    paste_id: Snowflake,
+    /// The type of the documents contents.
+    doc_type: String,
+    /// The name of the document.
+    name: String,
+    /// The total size of the document being uploaded.
+    length: usize,
+    /// The amount of bytes received so far.
+    offset: usize,
+    /// The amount of parts uploaded to the object store so far.
+    part_count: usize,
+    /// The ID of the object stores multipart upload.
+    multipart_id: String,
+    /// When the upload was created.
+    creation: DtUtc,
+}
+
+impl Upload {
+    /// New.
+    ///
+    /// Create a new [`Upload`] object.
+    pub fn new(
+        id: Snowflake,
+        paste_id: Snowflake,
+        doc_type: &str,
+        name: &str,
+        length: usize,
+        multipart_id: &str,
+        creation: DtUtc,
+    ) -> Self {
+        Self {
+            id,
+            paste_id,
+            doc_type: doc_type.to_string(),
+            name: name.to_string(),
+            length,
+            offset: 0,
+            part_count: 0,
+            multipart_id: multipart_id.to_string(),
+            creation,
+        }
+    }
+
+    /// The uploads ID.
+    #[inline]
+    pub const fn id(&self) -> &Snowflake {
+        &self.id
+    }
+
+    /// The paste ID this upload belongs too.
+    #[inline]
+    pub const fn paste_id(&self) -> &Snowflake {
+        &self.paste_id
+    }
+
+    /// The type of the documents contents.
+    #[inline]
+    pub fn doc_type(&self) -> &str {
+        &self.doc_type
+    }
+
+    /// The name of the document.
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The total size of the document being uploaded.
+    #[inline]
+    pub const fn length(&self) -> usize {
+        self.length
+    }
+
+    /// The amount of bytes received so far.
+    #[inline]
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// The amount of parts uploaded to the object store so far.
+    #[inline]
+    pub const fn part_count(&self) -> usize {
+        self.part_count
+    }
+
+    /// The ID of the object stores multipart upload.
+    #[inline]
+    pub fn multipart_id(&self) -> &str {
+        &self.multipart_id
+    }
+
+    /// When the upload was created.
+    #[inline]
+    pub const fn creation(&self) -> &DtUtc {
+        &self.creation
+    }
+
+    /// Whether every byte of the document has been received.
+    #[inline]
+    pub const fn is_complete(&self) -> bool {
+        self.offset >= self.length
+    }
+
+    /// Document.
+    ///
+    /// The document this upload is finalized into.
+    ///
+    /// ## Returns
+    ///
+    /// The [`Document`], without a checksum as the contents have not been read.
+    pub fn document(&self) -> Document {
+        Document::new(
+            self.id,
+            self.paste_id,
+            &self.doc_type,
+            &self.name,
+            self.length,
+            None,
+            &[],
+        )
+    }
+
+    /// Fetch.
+    ///
+    /// Fetch an upload via its ID.
+    ///
+    /// ## Arguments
+    ///
+    /// - `executor` - The database pool or transaction to use.
+    /// - `id` - The ID of the upload.
+    ///
+    /// ## Errors
+    ///
+    /// - [`DatabaseError`] - The database had an error.
+    ///
+    /// ## Returns
+    ///
+    /// - [`Option::Some`] - The [`Upload`] object.
+    /// - [`Option::None`] - No upload was found.
+    pub async fn fetch<'e, 'c: 'e, E>(
+        executor: E,
+        id: &Snowflake,
+    ) -> Result<Option<Self>, DatabaseError>
+    where
+        E: 'e + PgExecutor<'c>,
+    {
+        let upload_id: i64 = (*id).into();
+        let query = sqlx::query!(
+            "SELECT id, paste_id, type, name, length, upload_offset, part_count, multipart_id, creation FROM uploads WHERE id = $1",
+            upload_id
+        )
+        .fetch_optional(executor)
+        .await?;
+
+        if let Some(q) = query {
+            return Ok(Some(Self {
+                offset: q.upload_offset as usize,
+                part_count: q.part_count as usize,
+                ..Self::new(
+                    q.id.into(),
+                    q.paste_id.into(),
+                    &q.r#type,
+                    &q.name,
+                    q.length as usize,
+                    &q.multipart_id,
+                    q.creation,
+                )
+            }));
+        }
+
+        Ok(None)
+    }
+
+    /// Insert.
+    ///
+    /// Insert (create) an upload.
+    ///
+    /// ## Arguments
+    ///
+    /// - `executor` - The database pool or transaction to use.
+    ///
+    /// ## Errors
+    ///
+    /// - [`DatabaseError`] - The database had an error, or the snowflake exists already.
+    pub async fn insert<'e, 'c: 'e, E>(&self, executor: E) -> Result<(), DatabaseError>
+    where
+        E: 'e + PgExecutor<'c>,
+    {
+        let upload_id: i64 = self.id.into();
+        let paste_id: i64 = self.paste_id.into();
+
+        sqlx::query!(
+            "INSERT INTO uploads(id, paste_id, type, name, length, upload_offset, part_count, multipart_id, creation) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)",
+            upload_id,
+            paste_id,
+            self.doc_type,
+            self.name,
+            self.length as i64,
+            self.offset as i64,
+            self.part_count as i64,
+            self.multipart_id,
+            self.creation
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Append.
+    ///
+    /// Record a chunk as appended to the upload, as a single part.
+    ///
+    /// The upload is only updated if no other chunk has been appended since it was fetched.
+    ///
+    /// ## Arguments
+    ///
+    /// - `executor` - The database pool or transaction to use.
+    /// - `size` - The size of the chunk appended.
+    ///
+    /// ## Errors
+    ///
+    /// - [`DatabaseError`] - The database had an error.
+    ///
+    /// ## Returns
+    ///
+    /// - [`true`] - The chunk was recorded.
+    /// - [`false`] - Another chunk was appended first.
+    pub async fn append<'e, 'c: 'e, E>(
+        &mut self,
+        executor: E,
+        size: usize,
+    ) -> Result<bool, DatabaseError>
+    where
+        E: 'e + PgExecutor<'c>,
+    {
+        let upload_id: i64 = self.id.into();
+        let offset = self.offset + size;
+        let part_count = self.part_count + 1;
+
+        let result = sqlx::query!(
+            "UPDATE uploads SET upload_offset = $1, part_count = $2 WHERE id = $3 AND upload_offset = $4",
+            offset as i64,
+            part_count as i64,
+            upload_id,
+            self.offset as i64
+        )
+        .execute(executor)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Ok(false);
+        }
+
+        self.offset = offset;
+        self.part_count = part_count;
+
+        Ok(true)
+    }
+
+    /// Delete.
+    ///
+    /// Delete an upload.
+    ///
+    /// ## Arguments
+    ///
+    /// - `executor` - The database pool or transaction to use.
+    /// - `id` - The ID of the upload.
+    ///
+    /// ## Errors
+    ///
+    /// - [`DatabaseError`] - The database had an error.
+    ///
+    /// ## Returns
+    ///
+    /// - [`true`] - The upload was deleted.
+    /// - [`false`] - The upload was not found.
+    pub async fn delete<'e, 'c: 'e, E>(executor: E, id: &Snowflake) -> Result<bool, DatabaseError>
+    where
+        E: 'e + PgExecutor<'c>,
+    {
+        let upload_id: i64 = (*id).into();
+        let result = sqlx::query!("DELETE FROM uploads WHERE id = $1", upload_id)
+            .execute(executor)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
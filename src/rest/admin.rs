@@ -0,0 +1,504 @@
+//! Admin related endpoints and router generator.
+
+use axum::{
+    Json, Router,
+    extract::{DefaultBodyLimit, Path, State},
+    routing::{delete, get, put},
+};
+use chrono::{TimeDelta, Utc};
+use http::StatusCode;
+
+use crate::{
+    app::{application::App, config::Config},
+    models::{
+        authentication::AdminToken,
+        document::Document,
+        errors::RESTError,
+        paste::Paste,
+        payload::{
+            admin::{PutMaintenanceBody, ResponseMaintenance, ResponseStats},
+            paste::DeletePastePath,
+        },
+    },
+};
+
+/// ## Generate Router
+///
+/// Generates the router for admin related endpoints.
+///
+/// ## Returns
+/// The router with all the admin related endpoints attached.
+pub fn generate_router(config: &Config) -> Router<App> {
+    Router::new()
+        .route("/admin/stats", get(get_stats))
+        .route("/admin/pastes/{paste_id}", delete(delete_paste))
+        .route("/admin/maintenance", put(put_maintenance))
+        .layer(DefaultBodyLimit::max(
+            config.size_limits().maximum_request_body_size(),
+        ))
+}
+
+/// Get Stats.
+///
+/// Get the statistics of every paste stored.
+///
+/// **Requires admin authentication.**
+///
+/// ## Headers
+///
+/// - `Authorization` - The admin token.
+///
+/// ## Errors
+/// Returns an error if the request failed.
+///
+/// ## Returns
+///
+/// - `401` - Invalid or missing admin token.
+/// - `200` - The [`ResponseStats`] object.
+pub async fn get_stats(
+    State(app): State<App>,
+    _: AdminToken,
+) -> Result<(StatusCode, Json<ResponseStats>), RESTError> {
+    let paste_count = Paste::fetch_count(app.database().pool()).await?;
+
+    let expiring_count =
+        Paste::fetch_expiring_count(app.database().pool(), &(Utc::now() + TimeDelta::hours(24)))
+            .await?;
+
+    let (document_count, total_size) = Document::fetch_totals(app.database().pool()).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ResponseStats::new(
+            paste_count,
+            document_count,
+            total_size,
+            expiring_count,
+        )),
+    ))
+}
+
+/// Delete Paste.
+///
+/// Completely delete any paste, along with its documents.
+///
+/// **Requires admin authentication.**
+///
+/// ## Path
+///
+/// - `paste_id` - The pastes ID.
+///
+/// ## Headers
+///
+/// - `Authorization` - The admin token.
+///
+/// ## Errors
+/// Returns an error if the request failed.
+///
+/// ## Returns
+///
+/// - `400` - The paste ID is not a valid snowflake.
+/// - `401` - Invalid or missing admin token.
+/// - `404` - The paste was not found.
+/// - `204` - Successful deletion of the paste.
+pub async fn delete_paste(
+    State(app): State<App>,
+    Path(path): Path<DeletePastePath>,
+    _: AdminToken,
+) -> Result<StatusCode, RESTError> {
+    path.validate(app.config())?;
+
+    if !Paste::delete_cascade(app.database(), app.object_store(), path.paste_id()).await? {
+        return Err(RESTError::not_found("The paste was not found."));
+    }
+
+    app.handler().remove(path.paste_id()).await?;
+
+    tracing::info!("The paste ({}) was deleted by an admin.", path.paste_id());
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Put Maintenance.
+///
+/// Enable or disable maintenance mode, where write endpoints are unavailable.
+///
+/// **Requires admin authentication.**
+///
+/// ## Headers
+///
+/// - `Authorization` - The admin token.
+///
+/// ## Body
+///
+/// References: [`PutMaintenanceBody`]
+///
+/// - `enabled` - Whether maintenance mode is enabled.
+///
+/// ## Errors
+/// Returns an error if the request failed.
+///
+/// ## Returns
+///
+/// - `400` - The body is invalid.
+/// - `401` - Invalid or missing admin token.
+/// - `200` - The [`ResponseMaintenance`] object.
+pub async fn put_maintenance(
+    State(app): State<App>,
+    _: AdminToken,
+    Json(body): Json<PutMaintenanceBody>,
+) -> Result<(StatusCode, Json<ResponseMaintenance>), RESTError> {
+    app.set_maintenance_mode(body.enabled());
+
+    tracing::info!(
+        "Maintenance mode was {} by an admin.",
+        if body.enabled() {
+            "enabled"
+        } else {
+            "disabled"
+        }
+    );
+
+    Ok((
+        StatusCode::OK,
+        Json(ResponseMaintenance::new(body.enabled())),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum_test::TestServer;
+    use chrono::{TimeDelta, Utc};
+    use http::StatusCode;
+    use secrecy::SecretString;
+    use sqlx::PgPool;
+
+    use crate::app::{
+        application::ApplicationState, config::Config, object_store::MemoryObjectStore,
+    };
+    use crate::models::{
+        document::Document,
+        errors::RESTErrorResponse,
+        paste::Paste,
+        payload::admin::{PutMaintenanceBody, ResponseMaintenance, ResponseStats},
+        snowflake::Snowflake,
+    };
+    use crate::rest::generate_router as main_generate_router;
+
+    mod v1 {
+        use super::*;
+
+        mod get_stats {
+            use super::*;
+
+            #[sqlx::test(fixtures(path = "../../tests/fixtures", scripts("pastes", "documents")))]
+            async fn test_successful(pool: PgPool) {
+                let config = Config::test_builder()
+                    .admin_token(Some(SecretString::from("admin")))
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let now = Utc::now();
+                let paste = Paste::new(
+                    Snowflake::new(1),
+                    None,
+                    now,
+                    None,
+                    Some(now + TimeDelta::hours(1)),
+                    0,
+                    None,
+                    None,
+                    None,
+                );
+
+                paste.insert(&pool).await.expect("Failed to insert paste.");
+
+                let response = server
+                    .get("/v1/admin/stats")
+                    .add_header("Authorization", "Bearer admin")
+                    .await;
+
+                response.assert_status(StatusCode::OK);
+
+                let body: ResponseStats = response.json();
+
+                assert_eq!(body.paste_count(), 6, "Paste count does not match.");
+
+                assert_eq!(body.document_count(), 9, "Document count does not match.");
+
+                assert_eq!(body.total_size(), 8948, "Total size does not match.");
+
+                assert_eq!(body.expiring_count(), 1, "Expiring count does not match.");
+            }
+
+            #[sqlx::test]
+            async fn test_invalid_token(pool: PgPool) {
+                let config = Config::test_builder()
+                    .admin_token(Some(SecretString::from("admin")))
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let response = server
+                    .get("/v1/admin/stats")
+                    .add_header("Authorization", "Bearer beans")
+                    .await;
+
+                response.assert_status(StatusCode::UNAUTHORIZED);
+
+                let body: RESTErrorResponse = response.json();
+
+                assert_eq!(
+                    body.reason(),
+                    "Invalid Credentials",
+                    "Reason does not match."
+                );
+            }
+
+            #[sqlx::test]
+            async fn test_disabled(pool: PgPool) {
+                let config = Config::test_builder()
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let response = server
+                    .get("/v1/admin/stats")
+                    .add_header("Authorization", "Bearer ")
+                    .await;
+
+                response.assert_status(StatusCode::UNAUTHORIZED);
+            }
+        }
+
+        mod delete_paste {
+            use super::*;
+
+            #[sqlx::test(fixtures(
+                path = "../../tests/fixtures",
+                scripts("pastes", "documents", "tokens")
+            ))]
+            async fn test_successful(pool: PgPool) {
+                let config = Config::test_builder()
+                    .admin_token(Some(SecretString::from("admin")))
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let paste_id = Snowflake::new(517_815_304_354_284_605);
+
+                let response = server
+                    .delete(&format!("/v1/admin/pastes/{paste_id}"))
+                    .add_header("Authorization", "Bearer admin")
+                    .await;
+
+                response.assert_status(StatusCode::NO_CONTENT);
+
+                let paste = Paste::fetch(&pool, &paste_id)
+                    .await
+                    .expect("Failed to make DB request");
+                let documents = Document::fetch_all(&pool, &paste_id)
+                    .await
+                    .expect("Failed to make DB request");
+
+                assert!(paste.is_none(), "Paste was found");
+
+                assert!(documents.is_empty(), "One or more documents were found");
+            }
+
+            #[sqlx::test(fixtures(
+                path = "../../tests/fixtures",
+                scripts("pastes", "documents", "tokens")
+            ))]
+            async fn test_owner_token(pool: PgPool) {
+                let config = Config::test_builder()
+                    .admin_token(Some(SecretString::from("admin")))
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let paste_id = Snowflake::new(517_815_304_354_284_605);
+
+                let response = server
+                    .delete(&format!("/v1/admin/pastes/{paste_id}"))
+                    .add_header(
+                        "Authorization",
+                        "Bearer NTE3ODE1MzA0MzU0Mjg0NjA1.MTc3MDQzODc5Mw==.ozlKKwEEZpoGVuNzPDCyOMRGv",
+                    )
+                    .await;
+
+                response.assert_status(StatusCode::UNAUTHORIZED);
+
+                let paste = Paste::fetch(&pool, &paste_id)
+                    .await
+                    .expect("Failed to make DB request");
+
+                assert!(paste.is_some(), "Paste was deleted");
+            }
+        }
+
+        mod put_maintenance {
+            use super::*;
+
+            #[sqlx::test(fixtures(
+                path = "../../tests/fixtures",
+                scripts("pastes", "documents", "tokens")
+            ))]
+            async fn test_successful(pool: PgPool) {
+                let config = Config::test_builder()
+                    .admin_token(Some(SecretString::from("admin")))
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let paste_id = Snowflake::new(517_815_304_354_284_605);
+                let token_string =
+                    "NTE3ODE1MzA0MzU0Mjg0NjA1.MTc3MDQzODc5Mw==.ozlKKwEEZpoGVuNzPDCyOMRGv";
+
+                let response = server
+                    .put("/v1/admin/maintenance")
+                    .add_header("Authorization", "Bearer admin")
+                    .json(&PutMaintenanceBody::new(true))
+                    .await;
+
+                response.assert_status_ok();
+
+                let body: ResponseMaintenance = response.json();
+
+                assert!(body.enabled(), "Maintenance mode was not enabled.");
+
+                let response = server
+                    .delete(&format!("/v1/pastes/{paste_id}"))
+                    .add_header("Authorization", format!("Bearer {token_string}"))
+                    .await;
+
+                response.assert_status(StatusCode::SERVICE_UNAVAILABLE);
+
+                response.assert_header("Retry-After", "60");
+
+                let body: RESTErrorResponse = response.json();
+
+                assert_eq!(
+                    body.message(),
+                    "The server is in maintenance mode, try again later.",
+                    "Message does not match."
+                );
+
+                let response = server.get(&format!("/v1/pastes/{paste_id}")).await;
+
+                response.assert_status_ok();
+
+                let response = server
+                    .put("/v1/admin/maintenance")
+                    .add_header("Authorization", "Bearer admin")
+                    .json(&PutMaintenanceBody::new(false))
+                    .await;
+
+                response.assert_status_ok();
+
+                let response = server
+                    .delete(&format!("/v1/pastes/{paste_id}"))
+                    .add_header("Authorization", format!("Bearer {token_string}"))
+                    .await;
+
+                response.assert_status(StatusCode::NO_CONTENT);
+            }
+
+            #[sqlx::test(fixtures(path = "../../tests/fixtures", scripts("pastes", "documents")))]
+            async fn test_config(pool: PgPool) {
+                let config = Config::test_builder()
+                    .maintenance_mode(true)
+                    .maintenance_retry_after_seconds(120)
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let response = server
+                    .post("/v1/pastes")
+                    .json(&serde_json::json!({"documents": []}))
+                    .await;
+
+                response.assert_status(StatusCode::SERVICE_UNAVAILABLE);
+
+                response.assert_header("Retry-After", "120");
+
+                let response = server.get("/v1/pastes/517815304354284605").await;
+
+                response.assert_status_ok();
+            }
+
+            #[sqlx::test]
+            async fn test_missing_authentication(pool: PgPool) {
+                let config = Config::test_builder()
+                    .admin_token(Some(SecretString::from("admin")))
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let response = server
+                    .put("/v1/admin/maintenance")
+                    .json(&PutMaintenanceBody::new(true))
+                    .await;
+
+                response.assert_status(StatusCode::UNAUTHORIZED);
+            }
+        }
+    }
+}
@@ -0,0 +1,151 @@
+//! The client IP extractor, used to find the address of clients behind trusted proxies.
+
+use std::net::{IpAddr, SocketAddr};
+
+use axum::extract::{ConnectInfo, FromRequestParts};
+use http::{HeaderMap, request::Parts};
+use ipnet::IpNet;
+
+use crate::{app::application::App, models::errors::RESTError};
+
+/// ## Forwarded For Header
+///
+/// The header containing the chain of addresses a request was forwarded for.
+pub const FORWARDED_FOR_HEADER: &str = "x-forwarded-for";
+
+/// ## Real IP Header
+///
+/// The header containing the address of the client, set by a proxy.
+pub const REAL_IP_HEADER: &str = "x-real-ip";
+
+/// ## Client IP
+///
+/// The address of the client making the request.
+///
+/// Forwarded headers are only used when the peer is a trusted proxy,
+/// and the address is none when the peer is unknown.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ClientIp(pub Option<IpAddr>);
+
+impl FromRequestParts<App> for ClientIp {
+    type Rejection = RESTError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &App) -> Result<Self, Self::Rejection> {
+        let Some(ConnectInfo(peer)) = parts.extensions.get::<ConnectInfo<SocketAddr>>() else {
+            return Ok(Self(None));
+        };
+
+        Ok(Self(Some(resolve_client_ip(
+            peer.ip(),
+            &parts.headers,
+            state.config().trusted_proxies(),
+        ))))
+    }
+}
+
+/// Resolve Client IP.
+///
+/// Find the address of the client, skipping any trusted proxies it was forwarded through.
+///
+/// ## Arguments
+///
+/// - `peer` - The address of the peer connected to the server.
+/// - `headers` - The headers of the request.
+/// - `trusted_proxies` - The proxies trusted to provide forwarded headers.
+///
+/// ## Returns
+///
+/// The address of the client.
+fn resolve_client_ip(peer: IpAddr, headers: &HeaderMap, trusted_proxies: &[IpNet]) -> IpAddr {
+    let is_trusted = |address: &IpAddr| trusted_proxies.iter().any(|net| net.contains(address));
+
+    if !is_trusted(&peer) {
+        return peer;
+    }
+
+    let forwarded: Vec<IpAddr> = headers
+        .get_all(FORWARDED_FOR_HEADER)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .filter_map(|address| address.trim().parse().ok())
+        .collect();
+
+    if let Some(address) = forwarded
+        .iter()
+        .rev()
+        .find(|address| !is_trusted(address))
+        .or_else(|| forwarded.first())
+    {
+        return *address;
+    }
+
+    headers
+        .get(REAL_IP_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse().ok())
+        .unwrap_or(peer)
+}
+
+#[cfg(test)]
+mod tests {
+    use http::HeaderValue;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn trusted_proxies() -> Vec<IpNet> {
+        vec![
+            "10.0.0.0/8".parse().expect("Failed to parse CIDR."),
+            "::1/128".parse().expect("Failed to parse CIDR."),
+        ]
+    }
+
+    #[rstest]
+    #[case("203.0.113.9", Some("198.51.100.1"), None, "203.0.113.9")]
+    #[case("203.0.113.9", None, Some("198.51.100.1"), "203.0.113.9")]
+    #[case("10.0.0.1", Some("198.51.100.1"), None, "198.51.100.1")]
+    #[case("10.0.0.1", Some("198.51.100.1, 10.0.0.2"), None, "198.51.100.1")]
+    #[case("10.0.0.1", Some("192.0.2.7, 198.51.100.1"), None, "198.51.100.1")]
+    #[case("10.0.0.1", Some("10.0.0.3, 10.0.0.2"), None, "10.0.0.3")]
+    #[case("10.0.0.1", None, Some("198.51.100.1"), "198.51.100.1")]
+    #[case("::1", Some("2001:db8::1"), None, "2001:db8::1")]
+    #[case("10.0.0.1", Some("not an address"), None, "10.0.0.1")]
+    #[case("10.0.0.1", None, None, "10.0.0.1")]
+    fn test_resolve_client_ip(
+        #[case] peer: &str,
+        #[case] forwarded_for: Option<&str>,
+        #[case] real_ip: Option<&str>,
+        #[case] expected: &str,
+    ) {
+        let mut headers = HeaderMap::new();
+
+        if let Some(forwarded_for) = forwarded_for {
+            headers.insert(
+                FORWARDED_FOR_HEADER,
+                HeaderValue::from_str(forwarded_for).expect("Failed to build header."),
+            );
+        }
+
+        if let Some(real_ip) = real_ip {
+            headers.insert(
+                REAL_IP_HEADER,
+                HeaderValue::from_str(real_ip).expect("Failed to build header."),
+            );
+        }
+
+        let address = resolve_client_ip(
+            peer.parse().expect("Failed to parse peer address."),
+            &headers,
+            &trusted_proxies(),
+        );
+
+        assert_eq!(
+            address,
+            expected
+                .parse::<IpAddr>()
+                .expect("Failed to parse expected address."),
+            "Client address does not match."
+        );
+    }
+}
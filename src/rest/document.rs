@@ -2,17 +2,53 @@
 
 use axum::{
     Json, Router,
-    extract::{DefaultBodyLimit, Path, State},
-    routing::get,
+    extract::{DefaultBodyLimit, Path, Query, State},
+    response::{IntoResponse as _, Response},
+    routing::{get, post},
 };
-use axum_extra::headers::{self, Header};
-use http::{HeaderName, HeaderValue, StatusCode};
+use axum_extra::{
+    TypedHeader,
+    headers::{self, Header, HeaderMapExt as _, Range},
+};
+use bytes::Bytes;
+use http::{
+    HeaderMap, HeaderName, HeaderValue, StatusCode,
+    header::{ACCEPT_RANGES, CACHE_CONTROL, CONTENT_LENGTH, CONTENT_RANGE, CONTENT_TYPE, ETAG},
+};
+use percent_encoding::{AsciiSet, NON_ALPHANUMERIC, percent_decode_str, utf8_percent_encode};
+use secrecy::ExposeSecret as _;
+use similar::TextDiff;
+use std::{net::IpAddr, ops::Bound};
 
 use crate::{
-    app::{application::App, config::Config},
+    app::{
+        application::App, config::Config, object_store::ObjectStoreExt as _, webhook::record_view,
+        write_limiter::WritePermit,
+    },
     models::{
-        document::Document, errors::RESTError, paste::validate_paste,
-        payload::document::GetDocumentPath,
+        authentication::{OtherPastePassword, PastePassword, Token},
+        document::{
+            CONTENT_SHA256_HEADER, Document, DocumentUpdateParameters, document_limits,
+            enforce_maximums, enforce_minimums, enforce_owner_maximum, enforce_unique_names,
+            generate_checksum, is_mime_allowed, is_text_mime, normalize_content,
+        },
+        errors::{AuthenticationError, RESTError, RESTErrorResponse},
+        paste::{Paste, validate_paste, validate_paste_password},
+        payload::{
+            document::{
+                DeleteDocumentPath, DocumentDiffPath, GetDocumentByNamePath, GetDocumentPath,
+                GetDocumentRawQuery, GetDocumentsQuery, MoveDocumentPath, PostDocumentMoveBody,
+                PutDocumentPath, PutDocumentQuery, ResponseDocument,
+            },
+            paste::GetDocumentsPath,
+        },
+        snowflake::Snowflake,
+        undefined::Undefined,
+    },
+    rest::{
+        client_ip::ClientIp,
+        negotiated::{Negotiated, ResponseFormat},
+        validate_if_match,
     },
 };
 
@@ -24,12 +60,33 @@ use crate::{
 /// The router with all the document related endpoints attached.
 pub fn generate_router(config: &Config) -> Router<App> {
     Router::new()
+        .route("/pastes/{paste_id}/documents", get(get_documents))
         .route(
             "/pastes/{paste_id}/documents/{document_id}",
-            get(get_document),
+            get(get_document).put(put_document).delete(delete_document),
+        )
+        .route(
+            "/pastes/{paste_id}/documents/{document_id}/raw",
+            get(get_document_raw).head(head_document_raw),
+        )
+        .route(
+            "/pastes/{paste_id}/documents/by-name/{name}",
+            get(get_document_by_name),
+        )
+        .route(
+            "/pastes/{paste_id}/documents/by-name/{name}/raw",
+            get(get_document_by_name_raw).head(head_document_by_name_raw),
+        )
+        .route(
+            "/pastes/{paste_id}/documents/{document_id}/diff/{other_paste_id}/{other_document_id}",
+            get(get_document_diff),
+        )
+        .route(
+            "/pastes/{paste_id}/documents/{document_id}/move",
+            post(post_document_move),
         )
         .layer(DefaultBodyLimit::max(
-            config.size_limits().maximum_total_document_size(),
+            config.size_limits().maximum_request_body_size(),
         ))
 }
 
@@ -42,134 +99,3092 @@ pub fn generate_router(config: &Config) -> Router<App> {
 /// - `paste_id` - The pastes ID.
 /// - `document_id` - The documents ID.
 ///
+/// ## Headers
+///
+/// - `X-Paste-Password` - The pastes password (required if the paste is password protected).
+///
 /// ## Errors
 /// Returns an error if the request failed.
 ///
 /// ## Returns
 ///
+/// - `400` - An ID within the path is not a valid snowflake.
+/// - `401` - The pastes password is missing or incorrect.
 /// - `404` - The paste or document was not found.
+/// - `410` - The paste has expired, or reached its maximum views.
 /// - `200` - The [`ResponseDocument`] object.
 pub async fn get_document(
     State(app): State<App>,
+    format: ResponseFormat,
     Path(path): Path<GetDocumentPath>,
-) -> Result<(StatusCode, Json<Document>), RESTError> {
+    password: PastePassword,
+    ClientIp(client_ip): ClientIp,
+) -> Result<(StatusCode, Negotiated<ResponseDocument>), RESTError> {
+    path.validate(app.config())?;
+
+    let (mut paste, document) =
+        validate_document(&app, path.paste_id(), path.document_id(), &password).await?;
+
+    record_view(&app, &mut paste, client_ip).await?;
+
+    Ok((
+        StatusCode::OK,
+        Negotiated(
+            format,
+            ResponseDocument::from_document(document, app.config().domain()),
+        ),
+    ))
+}
+
+/// Get Documents.
+///
+/// Get the documents attached to an existing paste.
+///
+/// ## Path
+///
+/// - `paste_id` - The pastes ID.
+///
+/// ## Query
+///
+/// - `tag` - Only return the documents with this tag (optional).
+///
+/// ## Headers
+///
+/// - `X-Paste-Password` - The pastes password (required if the paste is password protected).
+///
+/// ## Errors
+/// Returns an error if the request failed.
+///
+/// ## Returns
+///
+/// - `400` - The ID within the path is not a valid snowflake.
+/// - `401` - The pastes password is missing or incorrect.
+/// - `404` - The paste was not found.
+/// - `410` - The paste has expired, or reached its maximum views.
+/// - `200` - A list of [`ResponseDocument`] objects.
+pub async fn get_documents(
+    State(app): State<App>,
+    format: ResponseFormat,
+    Path(path): Path<GetDocumentsPath>,
+    Query(query): Query<GetDocumentsQuery>,
+    password: PastePassword,
+    ClientIp(client_ip): ClientIp,
+) -> Result<(StatusCode, Negotiated<Vec<ResponseDocument>>), RESTError> {
+    path.validate(app.config())?;
+
     let mut paste = validate_paste(app.database(), path.paste_id(), None).await?;
 
-    let document = Document::fetch(app.database().pool(), path.document_id())
-        .await?
-        .ok_or_else(|| RESTError::not_found("Document not found."))?;
+    validate_paste_password(&paste, &password)?;
+
+    let documents = match query.tag() {
+        Some(tag) => Document::fetch_all_by_tag(app.database().pool(), paste.id(), tag).await?,
+        None => Document::fetch_all(app.database().pool(), paste.id()).await?,
+    };
+
+    record_view(&app, &mut paste, client_ip).await?;
+
+    let documents = documents
+        .into_iter()
+        .map(|document| ResponseDocument::from_document(document, app.config().domain()))
+        .collect();
+
+    Ok((StatusCode::OK, Negotiated(format, documents)))
+}
+
+/// Put Document.
+///
+/// Create or replace a document, with an ID chosen by the client.
+///
+/// If the document exists it is replaced, otherwise it is created with the ID provided.
+///
+/// **Requires authentication.**
+///
+/// ## Path
+///
+/// - `paste_id` - The pastes ID.
+/// - `document_id` - The documents ID.
+///
+/// ## Query
+///
+/// - `name` - The name of the document (required if the document does not exist).
+///
+/// ## Headers
+///
+/// - `Content-Type` - The type of the document (defaults to the configured default document type).
+/// - `If-Match` - The `ETag` of the documents version being replaced (optional).
+///
+/// ## Body
+///
+/// The contents of the document.
+///
+/// ## Errors
+/// Returns an error if the request failed.
+///
+/// ## Returns
+///
+/// - `400` - An ID within the path is not a valid snowflake, the document belongs to another paste, or the name is missing.
+/// - `401` - Invalid token and/or paste ID.
+/// - `404` - The paste was not found.
+/// - `410` - The paste has expired, or reached its maximum views.
+/// - `412` - The document has been replaced since the version provided, or does not exist.
+/// - `422` - The limits are exceeded.
+/// - `429` - Too many documents are being written to the paste at once.
+/// - `201` - The created [`Document`] object.
+/// - `200` - The replaced [`Document`] object.
+#[expect(clippy::too_many_lines, clippy::too_many_arguments)]
+pub async fn put_document(
+    State(app): State<App>,
+    format: ResponseFormat,
+    Path(path): Path<PutDocumentPath>,
+    Query(query): Query<PutDocumentQuery>,
+    token: Token,
+    content_type: Option<TypedHeader<headers::ContentType>>,
+    if_match: Option<TypedHeader<headers::IfMatch>>,
+    body: Bytes,
+) -> Result<(StatusCode, Negotiated<ResponseDocument>), RESTError> {
+    path.validate(app.config())?;
+
+    let paste = validate_paste(app.database(), path.paste_id(), Some(token)).await?;
+
+    let _permit = acquire_write(&app, paste.id()).await?;
+
+    let mime = content_type.map_or_else(
+        || {
+            app.config()
+                .size_limits()
+                .default_document_mime()
+                .to_string()
+        },
+        |TypedHeader(content_type)| content_type.to_string(),
+    );
+
+    if !is_mime_allowed(app.config(), &mime) {
+        return Err(RESTError::bad_request(format!(
+            "Invalid mime type: {mime} received for the document: {}",
+            path.document_id()
+        )));
+    }
+
+    let content = normalize_content(
+        app.config(),
+        &mime.parse()?,
+        String::from_utf8(body.to_vec())?,
+    );
+
+    let existing = Document::fetch(app.database().pool(), path.document_id()).await?;
+
+    if let Some(ref document) = existing
+        && document.paste_id() != paste.id()
+    {
+        return Err(RESTError::bad_request(
+            "The document ID does not belong to that paste.",
+        ));
+    }
+
+    match (&existing, if_match.as_deref()) {
+        (Some(document), if_match) => {
+            validate_if_match(if_match, &document.generate_version_etag())?;
+        }
+        (None, Some(_)) => {
+            return Err(RESTError::precondition_failed(
+                "The document does not exist to match against.",
+            ));
+        }
+        (None, None) => {}
+    }
+
+    let name = match (query.name(), &existing) {
+        (Some(name), _) => name.to_string(),
+        (None, Some(document)) => document.name().to_string(),
+        (None, None) => {
+            return Err(RESTError::bad_request(
+                "A name is required when creating a document.",
+            ));
+        }
+    };
+
+    document_limits(
+        app.config(),
+        &path.document_id().to_string(),
+        Undefined::Some(&name),
+        Undefined::Some(&content),
+        &mime,
+    )?;
+
+    let checksum = generate_checksum(content.as_bytes());
+
+    // The previous version, whose contents are kept until the new contents are stored.
+    let previous = existing.clone();
+
+    let mut transaction = app.database().pool().begin().await?;
+
+    let (status, document) = if let Some(mut document) = existing {
+        document
+            .update(
+                transaction.as_mut(),
+                DocumentUpdateParameters::new(
+                    Undefined::Some(mime),
+                    Undefined::Some(name),
+                    Undefined::Some(content.len()),
+                    Undefined::Some(checksum),
+                    Undefined::Undefined,
+                ),
+            )
+            .await?;
+
+        (StatusCode::OK, document)
+    } else {
+        let document = Document::new(
+            *path.document_id(),
+            *paste.id(),
+            &mime,
+            &name,
+            content.len(),
+            Some(&checksum),
+            &[],
+        );
+
+        document.insert(transaction.as_mut()).await?;
+
+        (StatusCode::CREATED, document)
+    };
+
+    enforce_maximums(&mut transaction, app.config(), paste.id()).await?;
+
+    enforce_minimums(&mut transaction, app.config(), paste.id()).await?;
+
+    enforce_owner_maximum(&mut transaction, app.config(), paste.owner_id()).await?;
+
+    enforce_unique_names(&mut transaction, app.config(), paste.id()).await?;
+
+    // Every limit is checked before the object store is touched, and the new contents are stored
+    // before the previous contents are removed, so a failure never leaves the document without contents.
+    app.object_store()
+        .create_document(&document, content)
+        .await?;
+
+    transaction.commit().await?;
+
+    // When renamed, the previous contents are stored under a different path.
+    let name_case = app.config().document_name_case();
+    if let Some(previous) = previous
+        && previous.generate_path(name_case) != document.generate_path(name_case)
+    {
+        app.object_store().delete_document(&previous).await?;
+    }
+
+    Ok((
+        status,
+        Negotiated(
+            format,
+            ResponseDocument::from_document(document, app.config().domain()),
+        ),
+    ))
+}
+
+/// Get Document Raw.
+///
+/// Get the contents of an existing document.
+///
+/// The full contents are streamed from the object store, rather than buffered.
+/// A single byte range can be requested via the `Range` header.
+///
+/// ## Path
+///
+/// - `paste_id` - The pastes ID.
+/// - `document_id` - The documents ID.
+///
+/// ## Query
+///
+/// - `download` - Whether the document should be downloaded, rather than displayed inline (defaults to false).
+/// - `checksum` - The expected SHA-256 checksum of the documents contents. When provided, the response may be cached indefinitely.
+///
+/// ## Headers
+///
+/// - `X-Paste-Password` - The pastes password (required if the paste is password protected).
+///
+/// ## Errors
+/// Returns an error if the request failed.
+///
+/// ## Returns
+///
+/// - `400` - An ID within the path is not a valid snowflake.
+/// - `401` - The pastes password is missing or incorrect.
+/// - `404` - The paste, document or its contents were not found, or the checksum does not match.
+/// - `410` - The paste has expired, or reached its maximum views.
+/// - `416` - The requested range cannot be satisfied.
+/// - `206` - The requested range of the documents contents.
+/// - `200` - The documents contents.
+pub async fn get_document_raw(
+    State(app): State<App>,
+    Path(path): Path<GetDocumentPath>,
+    Query(query): Query<GetDocumentRawQuery>,
+    password: PastePassword,
+    ClientIp(client_ip): ClientIp,
+    range: Option<TypedHeader<Range>>,
+) -> Result<Response, RESTError> {
+    path.validate(app.config())?;
+
+    let (paste, document) =
+        validate_document(&app, path.paste_id(), path.document_id(), &password).await?;
+
+    document_raw(&app, paste, &document, &query, range, client_ip).await
+}
+
+/// Get Document By Name.
+///
+/// Get an existing document, via its name.
+///
+/// ## Path
+///
+/// - `paste_id` - The pastes ID.
+/// - `name` - The documents name.
+///
+/// ## Headers
+///
+/// - `X-Paste-Password` - The pastes password (required if the paste is password protected).
+///
+/// ## Errors
+/// Returns an error if the request failed.
+///
+/// ## Returns
+///
+/// - `400` - The ID within the path is not a valid snowflake.
+/// - `401` - The pastes password is missing or incorrect.
+/// - `404` - The paste or document was not found.
+/// - `410` - The paste has expired, or reached its maximum views.
+/// - `200` - The [`ResponseDocument`] object.
+pub async fn get_document_by_name(
+    State(app): State<App>,
+    format: ResponseFormat,
+    Path(path): Path<GetDocumentByNamePath>,
+    password: PastePassword,
+    ClientIp(client_ip): ClientIp,
+) -> Result<(StatusCode, Negotiated<ResponseDocument>), RESTError> {
+    path.validate(app.config())?;
+
+    let (mut paste, document) =
+        validate_document_by_name(&app, path.paste_id(), path.name(), &password).await?;
+
+    record_view(&app, &mut paste, client_ip).await?;
+
+    Ok((
+        StatusCode::OK,
+        Negotiated(
+            format,
+            ResponseDocument::from_document(document, app.config().domain()),
+        ),
+    ))
+}
+
+/// Get Document By Name Raw.
+///
+/// Get the contents of an existing document, via its name.
+///
+/// The contents are returned the same as [`get_document_raw`].
+///
+/// ## Path
+///
+/// - `paste_id` - The pastes ID.
+/// - `name` - The documents name.
+///
+/// ## Query
+///
+/// - `download` - Whether the document should be downloaded, rather than displayed inline (defaults to false).
+/// - `checksum` - The expected SHA-256 checksum of the documents contents. When provided, the response may be cached indefinitely.
+///
+/// ## Headers
+///
+/// - `X-Paste-Password` - The pastes password (required if the paste is password protected).
+///
+/// ## Errors
+/// Returns an error if the request failed.
+///
+/// ## Returns
+///
+/// - `400` - The ID within the path is not a valid snowflake.
+/// - `401` - The pastes password is missing or incorrect.
+/// - `404` - The paste, document or its contents were not found, or the checksum does not match.
+/// - `410` - The paste has expired, or reached its maximum views.
+/// - `416` - The requested range cannot be satisfied.
+/// - `206` - The requested range of the documents contents.
+/// - `200` - The documents contents.
+pub async fn get_document_by_name_raw(
+    State(app): State<App>,
+    Path(path): Path<GetDocumentByNamePath>,
+    Query(query): Query<GetDocumentRawQuery>,
+    password: PastePassword,
+    ClientIp(client_ip): ClientIp,
+    range: Option<TypedHeader<Range>>,
+) -> Result<Response, RESTError> {
+    path.validate(app.config())?;
+
+    let (paste, document) =
+        validate_document_by_name(&app, path.paste_id(), path.name(), &password).await?;
+
+    document_raw(&app, paste, &document, &query, range, client_ip).await
+}
+
+/// Document Raw.
+///
+/// Respond with the contents of a document (or the range requested), counting it as a view of the paste.
+async fn document_raw(
+    app: &App,
+    mut paste: Paste,
+    document: &Document,
+    query: &GetDocumentRawQuery,
+    range: Option<TypedHeader<Range>>,
+    client_ip: Option<IpAddr>,
+) -> Result<Response, RESTError> {
+    let mut headers = document_headers(document, query)?;
+    let size = document.size() as u64;
+
+    let Some(TypedHeader(range)) = range else {
+        let content = app
+            .object_store()
+            .fetch_document_stream(document)
+            .await?
+            .ok_or_else(|| RESTError::not_found("Document not found."))?;
+
+        record_view(app, &mut paste, client_ip).await?;
+
+        return Ok((StatusCode::OK, headers, content).into_response());
+    };
+
+    let Some((start, end)) = satisfiable_range(&range, size) else {
+        let mut response = RESTErrorResponse::new_response(
+            StatusCode::RANGE_NOT_SATISFIABLE,
+            "Range Not Satisfiable",
+            "The range requested cannot be satisfied.",
+        );
+
+        response.headers_mut().insert(
+            CONTENT_RANGE,
+            HeaderValue::from_str(&format!("bytes */{size}"))
+                .map_err(RESTError::internal_server)?,
+        );
+
+        return Ok(response);
+    };
+
+    let content = app
+        .object_store()
+        .fetch_document_range(document, start, end)
+        .await?
+        .ok_or_else(|| RESTError::not_found("Document not found."))?;
+
+    record_view(app, &mut paste, client_ip).await?;
+
+    headers.insert(CONTENT_LENGTH, HeaderValue::from(end - start + 1));
+    headers.insert(
+        CONTENT_RANGE,
+        HeaderValue::from_str(&format!("bytes {start}-{end}/{size}"))
+            .map_err(RESTError::internal_server)?,
+    );
+
+    Ok((StatusCode::PARTIAL_CONTENT, headers, content).into_response())
+}
+
+/// Head Document Raw.
+///
+/// Get the headers for the contents of an existing document.
+///
+/// This does not read the contents, and does not count as a view.
+///
+/// ## Path
+///
+/// - `paste_id` - The pastes ID.
+/// - `document_id` - The documents ID.
+///
+/// ## Query
+///
+/// - `download` - Whether the document should be downloaded, rather than displayed inline (defaults to false).
+/// - `checksum` - The expected SHA-256 checksum of the documents contents. When provided, the response may be cached indefinitely.
+///
+/// ## Headers
+///
+/// - `X-Paste-Password` - The pastes password (required if the paste is password protected).
+///
+/// ## Errors
+/// Returns an error if the request failed.
+///
+/// ## Returns
+///
+/// - `400` - An ID within the path is not a valid snowflake.
+/// - `401` - The pastes password is missing or incorrect.
+/// - `404` - The paste or document was not found, or the checksum does not match.
+/// - `410` - The paste has expired, or reached its maximum views.
+/// - `200` - The headers of the documents contents.
+pub async fn head_document_raw(
+    State(app): State<App>,
+    Path(path): Path<GetDocumentPath>,
+    Query(query): Query<GetDocumentRawQuery>,
+    password: PastePassword,
+) -> Result<Response, RESTError> {
+    path.validate(app.config())?;
+
+    let (_, document) =
+        validate_document(&app, path.paste_id(), path.document_id(), &password).await?;
+
+    Ok((StatusCode::OK, document_headers(&document, &query)?).into_response())
+}
+
+/// Head Document By Name Raw.
+///
+/// Get the headers for the contents of an existing document, via its name.
+///
+/// This does not read the contents, and does not count as a view.
+///
+/// ## Path
+///
+/// - `paste_id` - The pastes ID.
+/// - `name` - The documents name.
+///
+/// ## Query
+///
+/// - `download` - Whether the document should be downloaded, rather than displayed inline (defaults to false).
+/// - `checksum` - The expected SHA-256 checksum of the documents contents. When provided, the response may be cached indefinitely.
+///
+/// ## Headers
+///
+/// - `X-Paste-Password` - The pastes password (required if the paste is password protected).
+///
+/// ## Errors
+/// Returns an error if the request failed.
+///
+/// ## Returns
+///
+/// - `400` - The ID within the path is not a valid snowflake.
+/// - `401` - The pastes password is missing or incorrect.
+/// - `404` - The paste or document was not found, or the checksum does not match.
+/// - `410` - The paste has expired, or reached its maximum views.
+/// - `200` - The headers of the documents contents.
+pub async fn head_document_by_name_raw(
+    State(app): State<App>,
+    Path(path): Path<GetDocumentByNamePath>,
+    Query(query): Query<GetDocumentRawQuery>,
+    password: PastePassword,
+) -> Result<Response, RESTError> {
+    path.validate(app.config())?;
+
+    let (_, document) =
+        validate_document_by_name(&app, path.paste_id(), path.name(), &password).await?;
+
+    Ok((StatusCode::OK, document_headers(&document, &query)?).into_response())
+}
+
+/// Get Document Diff.
+///
+/// Get a unified diff between the contents of two text documents.
+///
+/// The documents can belong to the same, or different pastes.
+///
+/// ## Path
+///
+/// - `paste_id` - The pastes ID.
+/// - `document_id` - The documents ID.
+/// - `other_paste_id` - The pastes ID to compare against.
+/// - `other_document_id` - The documents ID to compare against.
+///
+/// ## Headers
+///
+/// - `X-Paste-Password` - The pastes password (required if the paste is password protected).
+/// - `X-Other-Paste-Password` - The other pastes password (defaults to `X-Paste-Password`, required if the other paste is password protected).
+///
+/// ## Errors
+/// Returns an error if the request failed.
+///
+/// ## Returns
+///
+/// - `400` - An ID within the path is not a valid snowflake, or either document is not text, or is too large to diff.
+/// - `401` - Either pastes password is missing or incorrect.
+/// - `404` - Either paste, document or its contents were not found.
+/// - `410` - The paste has expired, or reached its maximum views.
+/// - `200` - The unified diff of the documents contents.
+pub async fn get_document_diff(
+    State(app): State<App>,
+    Path(path): Path<DocumentDiffPath>,
+    password: PastePassword,
+    other_password: OtherPastePassword,
+    ClientIp(client_ip): ClientIp,
+) -> Result<Response, RESTError> {
+    path.validate(app.config())?;
+
+    let (mut paste, document) =
+        validate_document(&app, path.paste_id(), path.document_id(), &password).await?;
+    let (mut other_paste, other_document) = validate_document(
+        &app,
+        path.other_paste_id(),
+        path.other_document_id(),
+        other_password.or(&password),
+    )
+    .await?;
+
+    let content = diff_content(&app, &document).await?;
+    let other_content = diff_content(&app, &other_document).await?;
+
+    let diff = TextDiff::from_lines(&content, &other_content)
+        .unified_diff()
+        .header(
+            &format!("a/{}", document.name()),
+            &format!("b/{}", other_document.name()),
+        )
+        .to_string();
+
+    record_view(&app, &mut paste, client_ip).await?;
+
+    if other_paste.id() != paste.id() {
+        record_view(&app, &mut other_paste, client_ip).await?;
+    }
+
+    Ok((
+        StatusCode::OK,
+        [(
+            CONTENT_TYPE,
+            HeaderValue::from_static("text/plain; charset=utf-8"),
+        )],
+        diff,
+    )
+        .into_response())
+}
+
+/// Post Document Move.
+///
+/// Move a document to another paste.
+///
+/// **Requires authentication** of both pastes.
+///
+/// ## Path
+///
+/// - `paste_id` - The pastes ID.
+/// - `document_id` - The documents ID.
+///
+/// ## Body
+///
+/// - `target_paste_id` - The ID of the paste to move the document to.
+/// - `target_token` - The token of the paste to move the document to.
+///
+/// ## Errors
+/// Returns an error if the request failed.
+///
+/// ## Returns
+///
+/// - `400` - An ID within the path is not a valid snowflake, or the target is the same paste.
+/// - `401` - Invalid token(s) and/or paste ID(s).
+/// - `404` - Either paste, or the document was not found.
+/// - `410` - Either paste has expired, or reached its maximum views.
+/// - `422` - Either pastes limits are exceeded.
+/// - `429` - Too many documents are being written to either paste at once.
+/// - `200` - The moved [`Document`] object.
+pub async fn post_document_move(
+    State(app): State<App>,
+    format: ResponseFormat,
+    Path(path): Path<MoveDocumentPath>,
+    token: Token,
+    Json(body): Json<PostDocumentMoveBody>,
+) -> Result<(StatusCode, Negotiated<ResponseDocument>), RESTError> {
+    path.validate(app.config())?;
+
+    if body.target_paste_id() == path.paste_id() {
+        return Err(RESTError::bad_request(
+            "The document cannot be moved to the paste it belongs to.",
+        ));
+    }
+
+    let paste = validate_paste(app.database(), path.paste_id(), Some(token)).await?;
+
+    let target_token = Token::fetch(app.database().pool(), body.target_token().expose_secret())
+        .await?
+        .ok_or(AuthenticationError::InvalidCredentials)?;
+
+    let target_paste =
+        validate_paste(app.database(), body.target_paste_id(), Some(target_token)).await?;
+
+    // Both pastes are changed, so both permits are held, always taking the lowest ID first so two
+    // opposing moves can never each hold one permit while waiting on the other.
+    let (first_id, second_id) = if paste.id() < target_paste.id() {
+        (paste.id(), target_paste.id())
+    } else {
+        (target_paste.id(), paste.id())
+    };
+    let _first_permit = acquire_write(&app, first_id).await?;
+    let _second_permit = acquire_write(&app, second_id).await?;
+
+    let Some(document) =
+        Document::fetch_with_paste(app.database().pool(), paste.id(), path.document_id()).await?
+    else {
+        return Err(RESTError::not_found("Document not found."));
+    };
+
+    let mut transaction = app.database().pool().begin().await?;
+
+    let mut moved_document = document.clone();
+
+    if !moved_document
+        .set_paste_id(transaction.as_mut(), target_paste.id())
+        .await?
+    {
+        return Err(RESTError::not_found("Document not found."));
+    }
+
+    enforce_maximums(&mut transaction, app.config(), target_paste.id()).await?;
+
+    enforce_minimums(&mut transaction, app.config(), paste.id()).await?;
+
+    enforce_owner_maximum(&mut transaction, app.config(), target_paste.owner_id()).await?;
+
+    enforce_unique_names(&mut transaction, app.config(), target_paste.id()).await?;
+
+    // The contents are copied before the move is committed, and the source is only removed once it
+    // is, so a failed commit never leaves the document without contents.
+    app.object_store()
+        .copy_document(&document, &moved_document)
+        .await?;
+
+    transaction.commit().await?;
+
+    if let Err(err) = app.object_store().delete_document(&document).await {
+        tracing::warn!(
+            "Failed to delete the previous contents of moved document {}, leaving an orphaned object. Error: {err}",
+            document.id()
+        );
+    }
+
+    Ok((
+        StatusCode::OK,
+        Negotiated(
+            format,
+            ResponseDocument::from_document(moved_document, app.config().domain()),
+        ),
+    ))
+}
+
+/// Delete Document.
+///
+/// Delete a document from a paste.
+///
+/// When it is the last document of the paste, the paste is deleted alongside it
+/// if enabled, otherwise the request is rejected.
+///
+/// **Requires authentication.**
+///
+/// ## Path
+///
+/// - `paste_id` - The pastes ID.
+/// - `document_id` - The documents ID.
+///
+/// ## Errors
+/// Returns an error if the request failed.
+///
+/// ## Returns
+///
+/// - `400` - An ID within the path is not a valid snowflake, or it is the last document of the paste.
+/// - `401` - Invalid token and/or paste ID.
+/// - `404` - The paste or document was not found.
+/// - `410` - The paste has expired, or reached its maximum views.
+/// - `422` - The pastes limits are not met.
+/// - `204` - Successful deletion of the document.
+pub async fn delete_document(
+    State(app): State<App>,
+    Path(path): Path<DeleteDocumentPath>,
+    token: Token,
+) -> Result<StatusCode, RESTError> {
+    path.validate(app.config())?;
+
+    let paste = validate_paste(app.database(), path.paste_id(), Some(token)).await?;
+
+    let Some(document) =
+        Document::fetch_with_paste(app.database().pool(), paste.id(), path.document_id()).await?
+    else {
+        return Err(RESTError::not_found("Document not found."));
+    };
+
+    let mut transaction = app.database().pool().begin().await?;
+
+    // Concurrent deletes are counted one at a time, so they can never together remove the last document.
+    if !Paste::lock(transaction.as_mut(), paste.id()).await? {
+        return Err(RESTError::not_found("The paste was not found."));
+    }
+
+    let total_document_count =
+        Document::fetch_total_document_count(transaction.as_mut(), paste.id()).await?;
+
+    if total_document_count <= 1 {
+        if !app.config().delete_paste_on_last_document() {
+            return Err(RESTError::bad_request(
+                "A paste must have at least one document.",
+            ));
+        }
+
+        transaction.rollback().await?;
+
+        if !Paste::delete_cascade(app.database(), app.object_store(), paste.id()).await? {
+            return Err(RESTError::not_found("The paste was not found."));
+        }
+
+        app.handler().remove(paste.id()).await?;
+
+        return Ok(StatusCode::NO_CONTENT);
+    }
+
+    if !Document::delete(transaction.as_mut(), document.id()).await? {
+        return Err(RESTError::not_found("Document not found."));
+    }
+
+    enforce_minimums(&mut transaction, app.config(), paste.id()).await?;
+
+    transaction.commit().await?;
+
+    // The contents are only removed once the document is, so a failed commit never leaves the
+    // document without contents. A failed removal only leaves an orphaned object behind.
+    if let Err(err) = app.object_store().delete_document(&document).await {
+        tracing::warn!(
+            "Failed to delete the contents of document {}, leaving an orphaned object. Error: {err}",
+            document.id()
+        );
+    }
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Validate Document By Name.
+///
+/// Checks that the paste is valid (and the password is correct, if required),
+/// and that a document with the name exists within it.
+async fn validate_document_by_name(
+    app: &App,
+    paste_id: &Snowflake,
+    name: &str,
+    password: &PastePassword,
+) -> Result<(Paste, Document), RESTError> {
+    let paste = validate_paste(app.database(), paste_id, None).await?;
+
+    validate_paste_password(&paste, password)?;
+
+    let document = Document::fetch_by_name(
+        app.database().pool(),
+        paste_id,
+        name,
+        app.config().document_name_case(),
+    )
+    .await?
+    .ok_or_else(|| RESTError::not_found("Document not found."))?;
+
+    Ok((paste, document))
+}
+
+/// Acquire Write.
+///
+/// Acquire a permit to write to the documents of a paste, which must be held until the write is complete.
+///
+/// ## Errors
+///
+/// - [`RESTError::TooManyRequests`] - Too many documents are being written to the paste at once.
+async fn acquire_write(app: &App, paste_id: &Snowflake) -> Result<WritePermit, RESTError> {
+    app.write_limiter().acquire(paste_id).await.ok_or_else(|| {
+        RESTError::too_many_requests(
+            "Too many documents are being written to this paste, try again later.",
+        )
+    })
+}
+
+/// Validate Document.
+///
+/// Checks that the paste is valid (and the password is correct, if required),
+/// and that the document exists within it.
+async fn validate_document(
+    app: &App,
+    paste_id: &Snowflake,
+    document_id: &Snowflake,
+    password: &PastePassword,
+) -> Result<(Paste, Document), RESTError> {
+    let paste = validate_paste(app.database(), paste_id, None).await?;
+
+    validate_paste_password(&paste, password)?;
+
+    let document = Document::fetch(app.database().pool(), document_id)
+        .await?
+        .ok_or_else(|| RESTError::not_found("Document not found."))?;
+
+    if document.paste_id() != paste_id {
+        return Err(RESTError::bad_request(
+            "The document ID does not belong to that paste.".to_string(),
+        ));
+    }
+
+    Ok((paste, document))
+}
+
+/// Diff Content.
+///
+/// Fetch the contents of a document, checking that it is text and small enough to diff.
+async fn diff_content(app: &App, document: &Document) -> Result<String, RESTError> {
+    if !document
+        .doc_type()
+        .parse()
+        .is_ok_and(|mime| is_text_mime(&mime))
+    {
+        return Err(RESTError::bad_request(format!(
+            "The document {} is not a text document.",
+            document.id()
+        )));
+    }
+
+    if document.size() > app.config().size_limits().maximum_diff_document_size() {
+        return Err(RESTError::bad_request(format!(
+            "The document {} is too large to diff.",
+            document.id()
+        )));
+    }
+
+    let content = app
+        .object_store()
+        .fetch_document(document)
+        .await?
+        .ok_or_else(|| RESTError::not_found("Document not found."))?;
+
+    String::from_utf8(content.to_vec()).map_err(|_| {
+        RESTError::bad_request(format!(
+            "The document {} is not a text document.",
+            document.id()
+        ))
+    })
+}
+
+/// Satisfiable Range.
+///
+/// Resolve the first range requested against the size of the document.
+///
+/// ## Returns
+///
+/// The inclusive start and end of the range, or [`None`] if it cannot be satisfied.
+fn satisfiable_range(range: &Range, size: u64) -> Option<(u64, u64)> {
+    let (start, end) = range.satisfiable_ranges(size).next()?;
+
+    let Bound::Included(start) = start else {
+        return None;
+    };
+
+    let end = match end {
+        Bound::Included(end) => end.min(size.checked_sub(1)?),
+        Bound::Excluded(end) => end.checked_sub(1)?.min(size.checked_sub(1)?),
+        Bound::Unbounded => size.checked_sub(1)?,
+    };
+
+    if start >= size || start > end {
+        return None;
+    }
+
+    Some((start, end))
+}
+
+/// Document Headers.
+///
+/// The headers describing the contents of a document.
+///
+/// The contents are marked as an attachment if `download` is set, otherwise they are displayed inline.
+///
+/// Errors if a `checksum` is provided that does not match the documents checksum.
+fn document_headers(
+    document: &Document,
+    query: &GetDocumentRawQuery,
+) -> Result<HeaderMap, RESTError> {
+    let mut headers = HeaderMap::new();
+
+    headers.insert(CACHE_CONTROL, document_cache_control(document, query)?);
+
+    headers.insert(ACCEPT_RANGES, HeaderValue::from_static("bytes"));
+
+    headers.insert(
+        CONTENT_TYPE,
+        HeaderValue::from_str(document.doc_type()).map_err(RESTError::internal_server)?,
+    );
+    headers.insert(CONTENT_LENGTH, HeaderValue::from(document.size()));
+    headers.insert(
+        ETAG,
+        HeaderValue::from_str(&document.generate_etag()).map_err(RESTError::internal_server)?,
+    );
+
+    if let Some(checksum) = document.checksum() {
+        headers.insert(
+            HeaderName::from_static(CONTENT_SHA256_HEADER),
+            HeaderValue::from_str(checksum).map_err(RESTError::internal_server)?,
+        );
+    }
+
+    let disposition = if query.download() {
+        "attachment"
+    } else {
+        "inline"
+    };
+    headers.typed_insert(ContentDisposition::new(
+        disposition,
+        Some(document.name().to_string()),
+    ));
+
+    Ok(headers)
+}
+
+/// Document Cache Control.
+///
+/// The `Cache-Control` directive for the contents of a document.
+///
+/// Contents addressed by their checksum can never change, so they are cached indefinitely,
+/// whereas contents addressed only by the document ID must always be revalidated.
+fn document_cache_control(
+    document: &Document,
+    query: &GetDocumentRawQuery,
+) -> Result<HeaderValue, RESTError> {
+    let Some(checksum) = query.checksum() else {
+        return Ok(HeaderValue::from_static("no-cache"));
+    };
+
+    if document
+        .checksum()
+        .is_none_or(|expected| !expected.eq_ignore_ascii_case(checksum))
+    {
+        return Err(RESTError::not_found(
+            "The document does not match the checksum provided.",
+        ));
+    }
+
+    Ok(HeaderValue::from_static(
+        "public, max-age=31536000, immutable",
+    ))
+}
+
+/// ## RFC 5987 Attribute Characters
+///
+/// The characters that do not need to be percent encoded within an extended parameter value.
+const ATTR_CHAR: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'!')
+    .remove(b'#')
+    .remove(b'$')
+    .remove(b'&')
+    .remove(b'+')
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'^')
+    .remove(b'_')
+    .remove(b'`')
+    .remove(b'|')
+    .remove(b'~');
+
+/// ## Content Disposition
+///
+/// Custom content disposition header, with filename parser.
+#[derive(Debug, Clone)]
+pub struct ContentDisposition {
+    disposition: String,
+    filename: Option<String>,
+}
+
+impl ContentDisposition {
+    /// New.
+    ///
+    /// Create a new [`ContentDisposition`] object.
+    pub fn new(disposition: impl Into<String>, filename: Option<String>) -> Self {
+        Self {
+            disposition: disposition.into(),
+            filename,
+        }
+    }
+
+    /// The contents disposition type.
+    pub fn disposition(&self) -> &str {
+        &self.disposition
+    }
+
+    /// The content dispositions filename (if found).
+    pub fn filename(&self) -> Option<&str> {
+        self.filename.as_deref()
+    }
+}
+
+impl Header for ContentDisposition {
+    fn name() -> &'static HeaderName {
+        &axum::http::header::CONTENT_DISPOSITION
+    }
+
+    fn decode<'i, I>(values: &mut I) -> Result<Self, headers::Error>
+    where
+        I: Iterator<Item = &'i HeaderValue>,
+    {
+        let value = values.next().ok_or_else(headers::Error::invalid)?;
+
+        let s = value.to_str().map_err(|_| headers::Error::invalid())?;
+
+        let mut disposition = String::new();
+        let mut filename = None;
+        let mut extended_filename = None;
+
+        for (i, part) in s.split(';').enumerate() {
+            let part = part.trim();
+            if i == 0 {
+                disposition = part.to_string();
+            } else if let Some(rest) = part.strip_prefix("filename*=") {
+                extended_filename = rest
+                    .strip_prefix("UTF-8''")
+                    .and_then(|v| percent_decode_str(v).decode_utf8().ok())
+                    .map(|v| v.to_string());
+            } else if let Some(rest) = part.strip_prefix("filename=") {
+                filename = Some(rest.trim_matches('"').to_string());
+            }
+        }
+
+        Ok(Self {
+            disposition,
+            filename: extended_filename.or(filename),
+        })
+    }
+
+    fn encode<E>(&self, values: &mut E)
+    where
+        E: Extend<HeaderValue>,
+    {
+        let mut parts = vec![self.disposition.clone()];
+
+        if let Some(filename) = &self.filename {
+            let fallback: String = filename
+                .chars()
+                .map(|c| {
+                    if c.is_ascii() && !c.is_ascii_control() && c != '"' && c != '\\' {
+                        c
+                    } else {
+                        '_'
+                    }
+                })
+                .collect();
+
+            parts.push(format!("filename=\"{fallback}\""));
+
+            // Names that cannot be represented as is, are also provided in the RFC 5987 form.
+            if fallback != *filename {
+                parts.push(format!(
+                    "filename*=UTF-8''{}",
+                    utf8_percent_encode(filename, ATTR_CHAR)
+                ));
+            }
+        }
+
+        let full = parts.join("; ");
+
+        if let Ok(header_value) = HeaderValue::from_str(&full) {
+            values.extend(std::iter::once(header_value));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use sqlx::PgPool;
+
+    use crate::app::config::{Config, DocumentNameCase, SizeLimitConfig};
+    use crate::rest::generate_router as main_generate_router;
+
+    use axum_test::TestServer;
+    use bytes::Bytes;
+    use http::StatusCode;
+    use rstest::rstest;
+    use secrecy::SecretString;
+
+    use crate::{
+        app::{
+            application::ApplicationState,
+            object_store::{MemoryObjectStore, ObjectStoreExt as _},
+        },
+        models::{
+            authentication::hash_password,
+            document::{Document, generate_checksum},
+            errors::RESTErrorResponse,
+            paste::Paste,
+            payload::{document::ResponseDocument, paste::ResponsePaste},
+            snowflake::Snowflake,
+        },
+    };
+
+    mod v1 {
+        use super::*;
+
+        mod get_documents {
+            use super::*;
+
+            #[sqlx::test(fixtures(path = "../../tests/fixtures", scripts("pastes", "documents")))]
+            async fn test_filter_by_tag(pool: PgPool) {
+                let config = Config::test_builder()
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let paste_id = Snowflake::new(517_815_304_354_284_605);
+                let tagged_id = Snowflake::new(517_815_304_354_284_790);
+
+                Document::new(
+                    tagged_id,
+                    paste_id,
+                    "text/plain",
+                    "tagged.txt",
+                    4,
+                    None,
+                    &[String::from("rust"), String::from("examples")],
+                )
+                .insert(&pool)
+                .await
+                .expect("Failed to insert document.");
+
+                let response = server
+                    .get(&format!("/v1/pastes/{paste_id}/documents"))
+                    .await;
+
+                response.assert_status(StatusCode::OK);
+
+                let body: Vec<Document> = response.json();
+
+                let documents = Document::fetch_all(&pool, &paste_id)
+                    .await
+                    .expect("Failed to make DB request");
+
+                assert_eq!(body.len(), documents.len(), "Document counts do not match.");
+
+                let response = server
+                    .get(&format!("/v1/pastes/{paste_id}/documents?tag=rust"))
+                    .await;
+
+                response.assert_status(StatusCode::OK);
+
+                let body: Vec<Document> = response.json();
+
+                assert_eq!(body.len(), 1, "Expected only the tagged document.");
+
+                assert_eq!(body[0].id(), &tagged_id, "Document ID's do not match.");
+
+                assert_eq!(
+                    body[0].tags(),
+                    &[String::from("rust"), String::from("examples")],
+                    "Document tags do not match."
+                );
+
+                let response = server
+                    .get(&format!("/v1/pastes/{paste_id}/documents?tag=missing"))
+                    .await;
+
+                response.assert_status(StatusCode::OK);
+
+                let body: Vec<Document> = response.json();
+
+                assert!(body.is_empty(), "Expected no documents.");
+            }
+
+            #[sqlx::test(fixtures(path = "../../tests/fixtures", scripts("pastes", "documents")))]
+            async fn test_missing_paste(pool: PgPool) {
+                let config = Config::test_builder()
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let response = server
+                    .get("/v1/pastes/517815304354284999/documents?tag=rust")
+                    .await;
+
+                response.assert_status(StatusCode::NOT_FOUND);
+            }
+        }
+
+        mod get_document {
+            use super::*;
+
+            #[sqlx::test(fixtures(path = "../../tests/fixtures", scripts("pastes", "documents")))]
+            async fn test_url(pool: PgPool) {
+                let config = Config::test_builder()
+                    .domain(String::from("https://paste.example.com"))
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let paste_id = Snowflake::new(517_815_304_354_284_605);
+                let document_id = Snowflake::new(517_815_304_354_284_708);
+
+                let document = Document::fetch_with_paste(&pool, &paste_id, &document_id)
+                    .await
+                    .expect("Failed to make DB request")
+                    .expect("Document does not exist.");
+
+                let response = server
+                    .get(&format!("/v1/pastes/{paste_id}/documents/{document_id}"))
+                    .await;
+
+                response.assert_status(StatusCode::OK);
+
+                let body: ResponseDocument = response.json();
+
+                assert_eq!(
+                    body.url(),
+                    document.generate_url(config.domain()),
+                    "Document URL does not match."
+                );
+
+                let response = server.get(&format!("/v1/pastes/{paste_id}")).await;
+
+                response.assert_status(StatusCode::OK);
+
+                let body: ResponsePaste = response.json();
+
+                let paste_document = body
+                    .documents()
+                    .iter()
+                    .find(|d| d.id() == &document_id)
+                    .expect("Document was not found in the paste.");
+
+                assert_eq!(
+                    paste_document.url(),
+                    document.generate_url(config.domain()),
+                    "Paste document URL does not match."
+                );
+            }
+
+            #[sqlx::test(fixtures(path = "../../tests/fixtures", scripts("pastes", "documents")))]
+            async fn test_existing(pool: PgPool) {
+                let config = Config::test_builder()
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let paste_id = Snowflake::new(517_815_304_354_284_605);
+                let document_id = Snowflake::new(517_815_304_354_284_708);
+
+                let views = Paste::fetch(&pool, &paste_id)
+                    .await
+                    .expect("Failed to make DB request")
+                    .expect("Failed to find paste.")
+                    .views();
+
+                let response = server
+                    .get(&format!("/v1/pastes/{paste_id}/documents/{document_id}"))
+                    .await;
+
+                response.assert_status(StatusCode::OK);
+
+                response.assert_header("Content-Type", "application/json");
+
+                let body: Document = response.json();
+
+                let document = Document::fetch_with_paste(&pool, &paste_id, &document_id)
+                    .await
+                    .expect("Failed to make DB request")
+                    .expect("Document does not exist.");
+
+                assert_eq!(body.paste_id(), &paste_id, "Paste ID's do not match.");
+
+                assert_eq!(body.id(), &document_id, "Document ID's do not match.");
+
+                assert_eq!(
+                    body.name(),
+                    document.name(),
+                    "Document name's do not match."
+                );
+
+                assert_eq!(
+                    body.doc_type(),
+                    document.doc_type(),
+                    "Document name's do not match."
+                );
+
+                assert_eq!(
+                    body.size(),
+                    document.size(),
+                    "Document name's do not match."
+                );
+
+                let updated_views = Paste::fetch(&pool, &paste_id)
+                    .await
+                    .expect("Failed to make DB request")
+                    .expect("Failed to find paste.")
+                    .views();
+
+                assert_eq!(views + 1, updated_views, "Views was not updated.");
+            }
+
+            #[rstest]
+            #[case(Snowflake::new(517_815_304_354_284_605), "Document not found.")]
+            #[case(
+                Snowflake::new(1_234_567_890),
+                "The paste requested could not be found"
+            )]
+            #[sqlx::test(fixtures(path = "../../tests/fixtures", scripts("pastes", "documents")))]
+            async fn test_missing(
+                #[ignore] pool: PgPool,
+                #[case] paste_id: Snowflake,
+                #[case] message: &str,
+            ) {
+                let config = Config::test_builder()
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let document_id = Snowflake::new(1_234_567_890);
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let response = server
+                    .get(&format!("/v1/pastes/{paste_id}/documents/{document_id}"))
+                    .await;
+
+                response.assert_status(StatusCode::NOT_FOUND);
+
+                response.assert_header("Content-Type", "application/json");
+
+                let body: RESTErrorResponse = response.json();
+
+                assert_eq!(body.reason(), "Not Found", "Reason does not match.");
+
+                assert_eq!(body.message(), message, "Trace does not match.");
+            }
+        }
+
+        mod get_document_by_name {
+            use super::*;
+
+            #[sqlx::test(fixtures(path = "../../tests/fixtures", scripts("pastes", "documents")))]
+            async fn test_existing(pool: PgPool) {
+                let config = Config::test_builder()
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let paste_id = Snowflake::new(517_815_304_354_284_605);
+                let document_id = Snowflake::new(517_815_304_354_284_709);
+
+                let document = Document::fetch_with_paste(&pool, &paste_id, &document_id)
+                    .await
+                    .expect("Failed to make DB request")
+                    .expect("Document does not exist.");
+
+                let response = server
+                    .get(&format!(
+                        "/v1/pastes/{paste_id}/documents/by-name/random.json"
+                    ))
+                    .await;
+
+                response.assert_status(StatusCode::OK);
+
+                let body: Document = response.json();
+
+                assert_eq!(body.id(), &document_id, "Document ID's do not match.");
+
+                assert_eq!(
+                    body.name(),
+                    document.name(),
+                    "Document name's do not match."
+                );
+
+                let content = Bytes::from("a".repeat(document.size()));
+                object_store
+                    .create_document(&document, content.clone())
+                    .await
+                    .expect("Failed to create document contents.");
+
+                let response = server
+                    .get(&format!(
+                        "/v1/pastes/{paste_id}/documents/by-name/random.json/raw"
+                    ))
+                    .await;
+
+                response.assert_status(StatusCode::OK);
+
+                assert_eq!(
+                    response.as_bytes(),
+                    &content,
+                    "Document contents do not match."
+                );
+            }
+
+            #[rstest]
+            #[case(DocumentNameCase::Sensitive, StatusCode::NOT_FOUND)]
+            #[case(DocumentNameCase::Insensitive, StatusCode::OK)]
+            #[sqlx::test(fixtures(path = "../../tests/fixtures", scripts("pastes", "documents")))]
+            async fn test_name_case(
+                #[ignore] pool: PgPool,
+                #[case] document_name_case: DocumentNameCase,
+                #[case] expected_status: StatusCode,
+            ) {
+                let config = Config::test_builder()
+                    .document_name_case(document_name_case)
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let paste_id = Snowflake::new(517_815_304_354_284_605);
+
+                let response = server
+                    .get(&format!(
+                        "/v1/pastes/{paste_id}/documents/by-name/Random.JSON"
+                    ))
+                    .await;
+
+                response.assert_status(expected_status);
+            }
+
+            #[sqlx::test(fixtures(path = "../../tests/fixtures", scripts("pastes", "documents")))]
+            async fn test_missing(pool: PgPool) {
+                let config = Config::test_builder()
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let paste_id = Snowflake::new(517_815_304_354_284_605);
+
+                for path in ["missing.txt", "missing.txt/raw"] {
+                    let response = server
+                        .get(&format!("/v1/pastes/{paste_id}/documents/by-name/{path}"))
+                        .await;
+
+                    response.assert_status(StatusCode::NOT_FOUND);
+
+                    let body: RESTErrorResponse = response.json();
+
+                    assert_eq!(
+                        body.message(),
+                        "Document not found.",
+                        "Message does not match."
+                    );
+                }
+            }
+
+            #[sqlx::test(fixtures(path = "../../tests/fixtures", scripts("pastes", "documents")))]
+            async fn test_expired(pool: PgPool) {
+                let config = Config::test_builder()
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let paste_id = Snowflake::new(517_815_304_354_284_601);
+
+                let response = server
+                    .get(&format!("/v1/pastes/{paste_id}/documents/by-name/cool.txt"))
+                    .await;
+
+                response.assert_status(StatusCode::GONE);
+
+                let body: RESTErrorResponse = response.json();
+
+                assert_eq!(
+                    body.message(),
+                    "The paste requested has expired",
+                    "Message does not match."
+                );
+            }
+        }
+
+        mod get_document_raw {
+            use super::*;
+
+            #[sqlx::test(fixtures(path = "../../tests/fixtures", scripts("pastes", "documents")))]
+            async fn test_existing(pool: PgPool) {
+                let config = Config::test_builder()
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let paste_id = Snowflake::new(517_815_304_354_284_605);
+                let document_id = Snowflake::new(517_815_304_354_284_708);
+
+                let document = Document::fetch_with_paste(&pool, &paste_id, &document_id)
+                    .await
+                    .expect("Failed to make DB request")
+                    .expect("Document does not exist.");
+
+                let content = Bytes::from("a".repeat(document.size()));
+                object_store
+                    .create_document(&document, content.clone())
+                    .await
+                    .expect("Failed to create document contents.");
+
+                let views = Paste::fetch(&pool, &paste_id)
+                    .await
+                    .expect("Failed to make DB request")
+                    .expect("Failed to find paste.")
+                    .views();
+
+                let response = server
+                    .get(&format!(
+                        "/v1/pastes/{paste_id}/documents/{document_id}/raw"
+                    ))
+                    .await;
+
+                response.assert_status(StatusCode::OK);
+
+                response.assert_header("Content-Type", document.doc_type());
+
+                response.assert_header("Content-Length", document.size().to_string());
+
+                response.assert_header("ETag", document.generate_etag());
+
+                assert_eq!(response.as_bytes(), &content, "Contents do not match.");
+
+                let updated_views = Paste::fetch(&pool, &paste_id)
+                    .await
+                    .expect("Failed to make DB request")
+                    .expect("Failed to find paste.")
+                    .views();
+
+                assert_eq!(views + 1, updated_views, "Views was not updated.");
+            }
+
+            #[sqlx::test(fixtures(path = "../../tests/fixtures", scripts("pastes", "documents")))]
+            async fn test_checksum_header(pool: PgPool) {
+                let config = Config::test_builder()
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let paste_id = Snowflake::new(517_815_304_354_284_605);
+                let document_id = Snowflake::new(517_815_304_354_284_720);
+                let checksum = generate_checksum(b"test");
+
+                let document = Document::new(
+                    document_id,
+                    paste_id,
+                    "text/plain",
+                    "checksum.txt",
+                    4,
+                    Some(&checksum),
+                    &[],
+                );
+
+                document
+                    .insert(&pool)
+                    .await
+                    .expect("Failed to insert document.");
+
+                object_store
+                    .create_document(&document, Bytes::from("test"))
+                    .await
+                    .expect("Failed to create document contents.");
+
+                let response = server
+                    .get(&format!(
+                        "/v1/pastes/{paste_id}/documents/{document_id}/raw"
+                    ))
+                    .await;
+
+                response.assert_status(StatusCode::OK);
+
+                response.assert_header(
+                    "X-Content-SHA256",
+                    "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08",
+                );
+            }
+
+            #[sqlx::test(fixtures(path = "../../tests/fixtures", scripts("pastes", "documents")))]
+            async fn test_cache_control(pool: PgPool) {
+                let config = Config::test_builder()
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let paste_id = Snowflake::new(517_815_304_354_284_605);
+                let document_id = Snowflake::new(517_815_304_354_284_720);
+                let checksum = generate_checksum(b"test");
+
+                let document = Document::new(
+                    document_id,
+                    paste_id,
+                    "text/plain",
+                    "checksum.txt",
+                    4,
+                    Some(&checksum),
+                    &[],
+                );
+
+                document
+                    .insert(&pool)
+                    .await
+                    .expect("Failed to insert document.");
+
+                object_store
+                    .create_document(&document, Bytes::from("test"))
+                    .await
+                    .expect("Failed to create document contents.");
+
+                let response = server
+                    .get(&format!(
+                        "/v1/pastes/{paste_id}/documents/{document_id}/raw"
+                    ))
+                    .await;
+
+                response.assert_status(StatusCode::OK);
+
+                response.assert_header("Cache-Control", "no-cache");
+
+                let response = server
+                    .get(&format!(
+                        "/v1/pastes/{paste_id}/documents/{document_id}/raw?checksum={checksum}"
+                    ))
+                    .await;
+
+                response.assert_status(StatusCode::OK);
+
+                response.assert_header("Cache-Control", "public, max-age=31536000, immutable");
+
+                let response = server
+                    .head(&format!(
+                        "/v1/pastes/{paste_id}/documents/{document_id}/raw?checksum={checksum}"
+                    ))
+                    .await;
+
+                response.assert_status(StatusCode::OK);
+
+                response.assert_header("Cache-Control", "public, max-age=31536000, immutable");
+            }
+
+            #[sqlx::test(fixtures(path = "../../tests/fixtures", scripts("pastes", "documents")))]
+            async fn test_mismatched_checksum(pool: PgPool) {
+                let config = Config::test_builder()
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let paste_id = Snowflake::new(517_815_304_354_284_605);
+                let document_id = Snowflake::new(517_815_304_354_284_720);
+
+                let document = Document::new(
+                    document_id,
+                    paste_id,
+                    "text/plain",
+                    "checksum.txt",
+                    4,
+                    Some(&generate_checksum(b"test")),
+                    &[],
+                );
+
+                document
+                    .insert(&pool)
+                    .await
+                    .expect("Failed to insert document.");
+
+                object_store
+                    .create_document(&document, Bytes::from("test"))
+                    .await
+                    .expect("Failed to create document contents.");
+
+                let response = server
+                    .get(&format!(
+                        "/v1/pastes/{paste_id}/documents/{document_id}/raw?checksum={}",
+                        generate_checksum(b"other")
+                    ))
+                    .await;
+
+                response.assert_status(StatusCode::NOT_FOUND);
+
+                assert!(
+                    !response.headers().contains_key("Cache-Control"),
+                    "Cache-Control header should not be set."
+                );
+            }
+
+            #[sqlx::test(fixtures(path = "../../tests/fixtures", scripts("pastes", "documents")))]
+            async fn test_missing_contents(pool: PgPool) {
+                let config = Config::test_builder()
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let paste_id = Snowflake::new(517_815_304_354_284_605);
+                let document_id = Snowflake::new(517_815_304_354_284_708);
+
+                let response = server
+                    .get(&format!(
+                        "/v1/pastes/{paste_id}/documents/{document_id}/raw"
+                    ))
+                    .await;
+
+                response.assert_status(StatusCode::NOT_FOUND);
+
+                let body: RESTErrorResponse = response.json();
+
+                assert_eq!(
+                    body.message(),
+                    "Document not found.",
+                    "Trace does not match."
+                );
+            }
+
+            #[rstest]
+            #[case("", "inline")]
+            #[case("?download=false", "inline")]
+            #[case("?download=true", "attachment")]
+            #[sqlx::test(fixtures(path = "../../tests/fixtures", scripts("pastes", "documents")))]
+            async fn test_content_disposition(
+                #[ignore] pool: PgPool,
+                #[case] query: &str,
+                #[case] disposition: &str,
+            ) {
+                let config = Config::test_builder()
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let paste_id = Snowflake::new(517_815_304_354_284_605);
+                let document_id = Snowflake::new(517_815_304_354_284_708);
+
+                let document = Document::fetch_with_paste(&pool, &paste_id, &document_id)
+                    .await
+                    .expect("Failed to make DB request")
+                    .expect("Document does not exist.");
+
+                object_store
+                    .create_document(&document, Bytes::from("a".repeat(document.size())))
+                    .await
+                    .expect("Failed to create document contents.");
+
+                let response = server
+                    .get(&format!(
+                        "/v1/pastes/{paste_id}/documents/{document_id}/raw{query}"
+                    ))
+                    .await;
+
+                response.assert_status(StatusCode::OK);
+
+                response.assert_header(
+                    "Content-Disposition",
+                    format!("{disposition}; filename=\"test.txt\""),
+                );
+            }
+        }
+
+        mod get_document_raw_range {
+            use super::*;
+
+            #[rstest]
+            #[case("bytes=10-19", 10, 19)]
+            #[case("bytes=400-", 400, 439)]
+            #[case("bytes=-40", 400, 439)]
+            #[case("bytes=430-1000", 430, 439)]
+            #[sqlx::test(fixtures(path = "../../tests/fixtures", scripts("pastes", "documents")))]
+            async fn test_successful(
+                #[ignore] pool: PgPool,
+                #[case] range: &str,
+                #[case] start: usize,
+                #[case] end: usize,
+            ) {
+                let config = Config::test_builder()
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let paste_id = Snowflake::new(517_815_304_354_284_605);
+                let document_id = Snowflake::new(517_815_304_354_284_708);
+
+                let document = Document::fetch_with_paste(&pool, &paste_id, &document_id)
+                    .await
+                    .expect("Failed to make DB request")
+                    .expect("Document does not exist.");
+
+                let content: String = (0..document.size())
+                    .map(|i| char::from(b'a' + (i % 26) as u8))
+                    .collect();
+                object_store
+                    .create_document(&document, Bytes::from(content.clone()))
+                    .await
+                    .expect("Failed to create document contents.");
+
+                let response = server
+                    .get(&format!(
+                        "/v1/pastes/{paste_id}/documents/{document_id}/raw"
+                    ))
+                    .add_header("Range", range)
+                    .await;
+
+                response.assert_status(StatusCode::PARTIAL_CONTENT);
+
+                response.assert_header(
+                    "Content-Range",
+                    format!("bytes {start}-{end}/{}", document.size()),
+                );
+
+                response.assert_header("Content-Length", (end - start + 1).to_string());
+
+                assert_eq!(
+                    response.as_bytes(),
+                    &content.as_bytes()[start..=end],
+                    "Contents do not match."
+                );
+            }
+
+            #[rstest]
+            #[case("bytes=440-")]
+            #[case("bytes=500-600")]
+            #[case("bytes=20-10")]
+            #[sqlx::test(fixtures(path = "../../tests/fixtures", scripts("pastes", "documents")))]
+            async fn test_unsatisfiable(#[ignore] pool: PgPool, #[case] range: &str) {
+                let config = Config::test_builder()
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let paste_id = Snowflake::new(517_815_304_354_284_605);
+                let document_id = Snowflake::new(517_815_304_354_284_708);
+
+                let response = server
+                    .get(&format!(
+                        "/v1/pastes/{paste_id}/documents/{document_id}/raw"
+                    ))
+                    .add_header("Range", range)
+                    .await;
+
+                response.assert_status(StatusCode::RANGE_NOT_SATISFIABLE);
+
+                response.assert_header("Content-Range", "bytes */440");
+
+                let body: RESTErrorResponse = response.json();
+
+                assert_eq!(
+                    body.reason(),
+                    "Range Not Satisfiable",
+                    "Reason does not match."
+                );
+            }
+        }
+
+        mod head_document_raw {
+            use super::*;
+
+            #[sqlx::test(fixtures(path = "../../tests/fixtures", scripts("pastes", "documents")))]
+            async fn test_existing(pool: PgPool) {
+                let config = Config::test_builder()
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let paste_id = Snowflake::new(517_815_304_354_284_605);
+                let document_id = Snowflake::new(517_815_304_354_284_708);
+
+                let document = Document::fetch_with_paste(&pool, &paste_id, &document_id)
+                    .await
+                    .expect("Failed to make DB request")
+                    .expect("Document does not exist.");
+
+                let views = Paste::fetch(&pool, &paste_id)
+                    .await
+                    .expect("Failed to make DB request")
+                    .expect("Failed to find paste.")
+                    .views();
+
+                let response = server
+                    .method(
+                        http::Method::HEAD,
+                        &format!("/v1/pastes/{paste_id}/documents/{document_id}/raw"),
+                    )
+                    .await;
+
+                response.assert_status(StatusCode::OK);
+
+                response.assert_header("Content-Type", document.doc_type());
+
+                response.assert_header("Content-Length", document.size().to_string());
+
+                response.assert_header("ETag", document.generate_etag());
+
+                assert!(response.as_bytes().is_empty(), "A body was returned.");
+
+                let updated_views = Paste::fetch(&pool, &paste_id)
+                    .await
+                    .expect("Failed to make DB request")
+                    .expect("Failed to find paste.")
+                    .views();
+
+                assert_eq!(views, updated_views, "Views was updated.");
+            }
+
+            #[sqlx::test(fixtures(path = "../../tests/fixtures", scripts("pastes", "documents")))]
+            async fn test_missing(pool: PgPool) {
+                let config = Config::test_builder()
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let paste_id = Snowflake::new(517_815_304_354_284_605);
+                let document_id = Snowflake::new(1_234_567_890);
+
+                let response = server
+                    .method(
+                        http::Method::HEAD,
+                        &format!("/v1/pastes/{paste_id}/documents/{document_id}/raw"),
+                    )
+                    .await;
+
+                response.assert_status(StatusCode::NOT_FOUND);
+            }
+
+            #[sqlx::test(fixtures(path = "../../tests/fixtures", scripts("pastes", "documents")))]
+            async fn test_by_name(pool: PgPool) {
+                let config = Config::test_builder()
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let paste_id = Snowflake::new(517_815_304_354_284_605);
+                let document_id = Snowflake::new(517_815_304_354_284_709);
+
+                let document = Document::fetch_with_paste(&pool, &paste_id, &document_id)
+                    .await
+                    .expect("Failed to make DB request")
+                    .expect("Document does not exist.");
+
+                object_store
+                    .create_document(&document, Bytes::from("a".repeat(document.size())))
+                    .await
+                    .expect("Failed to create document contents.");
+
+                let views = Paste::fetch(&pool, &paste_id)
+                    .await
+                    .expect("Failed to make DB request")
+                    .expect("Failed to find paste.")
+                    .views();
+
+                let response = server
+                    .method(
+                        http::Method::HEAD,
+                        &format!("/v1/pastes/{paste_id}/documents/by-name/random.json/raw"),
+                    )
+                    .await;
+
+                response.assert_status(StatusCode::OK);
+
+                response.assert_header("Content-Type", document.doc_type());
+
+                response.assert_header("Content-Length", document.size().to_string());
+
+                assert!(response.as_bytes().is_empty(), "A body was returned.");
+
+                let updated_views = Paste::fetch(&pool, &paste_id)
+                    .await
+                    .expect("Failed to make DB request")
+                    .expect("Failed to find paste.")
+                    .views();
+
+                assert_eq!(views, updated_views, "Views was updated.");
+            }
+        }
+
+        mod get_document_diff {
+            use super::*;
+
+            /// Give the documents a text type, as the fixtures are typed `plain/text`.
+            async fn set_text_type(pool: &PgPool, document_ids: &[Snowflake]) {
+                let document_ids: Vec<i64> = document_ids.iter().map(|&id| id.into()).collect();
+
+                sqlx::query("UPDATE documents SET type = 'text/plain' WHERE id = ANY($1)")
+                    .bind(document_ids)
+                    .execute(pool)
+                    .await
+                    .expect("Failed to set document types.");
+            }
+
+            #[sqlx::test(fixtures(path = "../../tests/fixtures", scripts("pastes", "documents")))]
+            async fn test_successful(pool: PgPool) {
+                let config = Config::test_builder()
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let paste_id = Snowflake::new(517_815_304_354_284_605);
+                let document_id = Snowflake::new(517_815_304_354_284_708);
+                let other_paste_id = Snowflake::new(517_815_304_354_284_602);
+                let other_document_id = Snowflake::new(517_815_304_354_284_702);
+
+                set_text_type(&pool, &[document_id, other_document_id]).await;
+
+                let document = Document::fetch(&pool, &document_id)
+                    .await
+                    .expect("Failed to make DB request")
+                    .expect("Document does not exist.");
+                let other_document = Document::fetch(&pool, &other_document_id)
+                    .await
+                    .expect("Failed to make DB request")
+                    .expect("Document does not exist.");
+
+                object_store
+                    .create_document(&document, Bytes::from("first\nsecond\nthird\n"))
+                    .await
+                    .expect("Failed to create document contents.");
+                object_store
+                    .create_document(&other_document, Bytes::from("first\nchanged\nthird\n"))
+                    .await
+                    .expect("Failed to create document contents.");
+
+                let response = server
+                    .get(&format!(
+                        "/v1/pastes/{paste_id}/documents/{document_id}/diff/{other_paste_id}/{other_document_id}"
+                    ))
+                    .await;
+
+                response.assert_status(StatusCode::OK);
+
+                response.assert_header("Content-Type", "text/plain; charset=utf-8");
+
+                let body = response.text();
+
+                assert!(
+                    body.starts_with("--- a/test.txt\n+++ b/document.txt\n"),
+                    "File headers do not match."
+                );
+
+                assert!(body.contains("@@ -1,3 +1,3 @@\n"), "Hunk header not found.");
+
+                assert!(body.contains("\n-second\n"), "Removed line not found.");
+
+                assert!(body.contains("\n+changed\n"), "Added line not found.");
+            }
+
+            #[rstest]
+            #[case("image/png", "image.png")]
+            #[case("application/octet-stream", "data.bin")]
+            #[sqlx::test(fixtures(path = "../../tests/fixtures", scripts("pastes", "documents")))]
+            async fn test_binary(
+                #[ignore] pool: PgPool,
+                #[case] doc_type: &str,
+                #[case] name: &str,
+            ) {
+                let config = Config::test_builder()
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let paste_id = Snowflake::new(517_815_304_354_284_605);
+                let document_id = Snowflake::new(517_815_304_354_284_708);
+                let image_document_id = Snowflake::new(517_815_304_354_284_710);
+
+                set_text_type(&pool, &[document_id]).await;
+
+                let document = Document::fetch(&pool, &document_id)
+                    .await
+                    .expect("Failed to make DB request")
+                    .expect("Document does not exist.");
+                let image_document =
+                    Document::new(image_document_id, paste_id, doc_type, name, 4, None, &[]);
+
+                image_document
+                    .insert(&pool)
+                    .await
+                    .expect("Failed to insert document.");
+
+                object_store
+                    .create_document(&document, Bytes::from("text"))
+                    .await
+                    .expect("Failed to create document contents.");
+                object_store
+                    .create_document(&image_document, Bytes::from(vec![137, 80, 78, 71]))
+                    .await
+                    .expect("Failed to create document contents.");
+
+                let response = server
+                    .get(&format!(
+                        "/v1/pastes/{paste_id}/documents/{document_id}/diff/{paste_id}/{image_document_id}"
+                    ))
+                    .await;
+
+                response.assert_status(StatusCode::BAD_REQUEST);
+
+                let body: RESTErrorResponse = response.json();
+
+                assert_eq!(
+                    body.message(),
+                    format!("The document {image_document_id} is not a text document."),
+                    "Message does not match."
+                );
+            }
+
+            #[sqlx::test(fixtures(path = "../../tests/fixtures", scripts("pastes", "documents")))]
+            async fn test_too_large(pool: PgPool) {
+                let config = Config::test_builder()
+                    .size_limits(
+                        SizeLimitConfig::test_builder()
+                            .maximum_diff_document_size(100)
+                            .build()
+                            .expect("Failed to build size limit config."),
+                    )
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let paste_id = Snowflake::new(517_815_304_354_284_605);
+                let document_id = Snowflake::new(517_815_304_354_284_708);
+                let other_document_id = Snowflake::new(517_815_304_354_284_709);
+
+                set_text_type(&pool, &[document_id, other_document_id]).await;
+
+                let response = server
+                    .get(&format!(
+                        "/v1/pastes/{paste_id}/documents/{document_id}/diff/{paste_id}/{other_document_id}"
+                    ))
+                    .await;
+
+                response.assert_status(StatusCode::BAD_REQUEST);
+
+                let body: RESTErrorResponse = response.json();
+
+                assert_eq!(
+                    body.message(),
+                    format!("The document {document_id} is too large to diff."),
+                    "Message does not match."
+                );
+            }
+
+            #[rstest]
+            #[case(Some("hunter2"), None, StatusCode::UNAUTHORIZED)]
+            #[case(Some("hunter3"), None, StatusCode::UNAUTHORIZED)]
+            #[case(Some("hunter2"), Some("hunter2"), StatusCode::UNAUTHORIZED)]
+            #[case(None, Some("hunter3"), StatusCode::UNAUTHORIZED)]
+            #[case(Some("hunter2"), Some("hunter3"), StatusCode::OK)]
+            #[sqlx::test(fixtures(path = "../../tests/fixtures", scripts("pastes", "documents")))]
+            async fn test_separate_passwords(
+                #[ignore] pool: PgPool,
+                #[case] password: Option<&str>,
+                #[case] other_password: Option<&str>,
+                #[case] expected_status: StatusCode,
+            ) {
+                let config = Config::test_builder()
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let paste_id = Snowflake::new(517_815_304_354_284_605);
+                let document_id = Snowflake::new(517_815_304_354_284_708);
+                let other_paste_id = Snowflake::new(517_815_304_354_284_602);
+                let other_document_id = Snowflake::new(517_815_304_354_284_702);
+
+                set_text_type(&pool, &[document_id, other_document_id]).await;
+
+                for (id, paste_password) in [(paste_id, "hunter2"), (other_paste_id, "hunter3")] {
+                    let password_hash = hash_password(&SecretString::from(paste_password))
+                        .expect("Failed to hash password.");
+
+                    sqlx::query("UPDATE pastes SET password_hash = $1 WHERE id = $2")
+                        .bind(password_hash)
+                        .bind(i64::from(id))
+                        .execute(&pool)
+                        .await
+                        .expect("Failed to set paste password.");
+                }
+
+                let document = Document::fetch(&pool, &document_id)
+                    .await
+                    .expect("Failed to make DB request")
+                    .expect("Document does not exist.");
+                let other_document = Document::fetch(&pool, &other_document_id)
+                    .await
+                    .expect("Failed to make DB request")
+                    .expect("Document does not exist.");
+
+                object_store
+                    .create_document(&document, Bytes::from("first\nsecond\n"))
+                    .await
+                    .expect("Failed to create document contents.");
+                object_store
+                    .create_document(&other_document, Bytes::from("first\nchanged\n"))
+                    .await
+                    .expect("Failed to create document contents.");
+
+                let mut request = server.get(&format!(
+                    "/v1/pastes/{paste_id}/documents/{document_id}/diff/{other_paste_id}/{other_document_id}"
+                ));
+
+                if let Some(password) = password {
+                    request = request.add_header("X-Paste-Password", password);
+                }
+
+                if let Some(other_password) = other_password {
+                    request = request.add_header("X-Other-Paste-Password", other_password);
+                }
+
+                let response = request.await;
+
+                response.assert_status(expected_status);
+            }
+        }
+
+        mod post_document_move {
+            use super::*;
+
+            #[sqlx::test(fixtures(
+                path = "../../tests/fixtures",
+                scripts("pastes", "documents", "tokens")
+            ))]
+            async fn test_successful(pool: PgPool) {
+                let config = Config::test_builder()
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let paste_id = Snowflake::new(517_815_304_354_284_605);
+                let target_paste_id = Snowflake::new(517_815_304_354_284_602);
+                let document_id = Snowflake::new(517_815_304_354_284_708);
+
+                let document = Document::fetch_with_paste(&pool, &paste_id, &document_id)
+                    .await
+                    .expect("Failed to make DB request")
+                    .expect("Document does not exist.");
+
+                let content = Bytes::from("a".repeat(document.size()));
+                object_store
+                    .create_document(&document, content.clone())
+                    .await
+                    .expect("Failed to create document contents.");
+
+                let response = server
+                    .post(&format!(
+                        "/v1/pastes/{paste_id}/documents/{document_id}/move"
+                    ))
+                    .add_header(
+                        "Authorization",
+                        "Bearer NTE3ODE1MzA0MzU0Mjg0NjA1.MTc3MDQzODc5Mw==.ozlKKwEEZpoGVuNzPDCyOMRGv",
+                    )
+                    .json(&serde_json::json!({
+                        "target_paste_id": target_paste_id,
+                        "target_token": "NTE3ODE1MzA0MzU0Mjg0NjAy.MTc0NzgxNjA3NA==.dniyyCHW-xKQcYvjgawVExLCh"
+                    }))
+                    .await;
+
+                response.assert_status(StatusCode::OK);
+
+                let body: Document = response.json();
+
+                assert_eq!(
+                    body.paste_id(),
+                    &target_paste_id,
+                    "Paste ID does not match."
+                );
+
+                let source_documents = Document::fetch_all(&pool, &paste_id)
+                    .await
+                    .expect("Failed to make DB request");
+
+                assert!(
+                    source_documents.iter().all(|d| d.id() != &document_id),
+                    "Document still exists within the source paste."
+                );
+
+                let moved_document =
+                    Document::fetch_with_paste(&pool, &target_paste_id, &document_id)
+                        .await
+                        .expect("Failed to make DB request")
+                        .expect("Document was not moved to the target paste.");
+
+                assert_eq!(
+                    object_store
+                        .fetch_document(&moved_document)
+                        .await
+                        .expect("Failed to fetch document contents."),
+                    Some(content),
+                    "Contents do not match."
+                );
+
+                assert_eq!(
+                    object_store
+                        .fetch_document(&document)
+                        .await
+                        .expect("Failed to fetch document contents."),
+                    None,
+                    "Contents still exist at the source path."
+                );
+            }
+
+            #[rstest]
+            #[case(517_815_304_354_284_605)]
+            #[case(517_815_304_354_284_602)]
+            #[sqlx::test(fixtures(
+                path = "../../tests/fixtures",
+                scripts("pastes", "documents", "tokens")
+            ))]
+            async fn test_max_concurrent_paste_writes(
+                #[ignore] pool: PgPool,
+                #[case] held_paste_id: u64,
+            ) {
+                let config = Config::test_builder()
+                    .max_concurrent_paste_writes(Some(1))
+                    .paste_write_wait_ms(0)
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state.clone());
+                let server = TestServer::new(app);
+
+                let paste_id = Snowflake::new(517_815_304_354_284_605);
+                let target_paste_id = Snowflake::new(517_815_304_354_284_602);
+                let document_id = Snowflake::new(517_815_304_354_284_708);
+
+                // A write already in progress, holding the only permit of either paste.
+                let _permit = state
+                    .write_limiter()
+                    .acquire(&Snowflake::new(held_paste_id))
+                    .await
+                    .expect("Failed to acquire permit.");
+
+                let response = server
+                    .post(&format!(
+                        "/v1/pastes/{paste_id}/documents/{document_id}/move"
+                    ))
+                    .add_header(
+                        "Authorization",
+                        "Bearer NTE3ODE1MzA0MzU0Mjg0NjA1.MTc3MDQzODc5Mw==.ozlKKwEEZpoGVuNzPDCyOMRGv",
+                    )
+                    .json(&serde_json::json!({
+                        "target_paste_id": target_paste_id,
+                        "target_token": "NTE3ODE1MzA0MzU0Mjg0NjAy.MTc0NzgxNjA3NA==.dniyyCHW-xKQcYvjgawVExLCh"
+                    }))
+                    .await;
+
+                response.assert_status(StatusCode::TOO_MANY_REQUESTS);
+
+                Document::fetch_with_paste(&pool, &paste_id, &document_id)
+                    .await
+                    .expect("Failed to make DB request")
+                    .expect("Document was moved.");
+            }
+
+            #[sqlx::test(fixtures(
+                path = "../../tests/fixtures",
+                scripts("pastes", "documents", "tokens")
+            ))]
+            async fn test_invalid_target_token(pool: PgPool) {
+                let config = Config::test_builder()
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let paste_id = Snowflake::new(517_815_304_354_284_605);
+                let target_paste_id = Snowflake::new(517_815_304_354_284_602);
+                let document_id = Snowflake::new(517_815_304_354_284_708);
+
+                let response = server
+                    .post(&format!(
+                        "/v1/pastes/{paste_id}/documents/{document_id}/move"
+                    ))
+                    .add_header(
+                        "Authorization",
+                        "Bearer NTE3ODE1MzA0MzU0Mjg0NjA1.MTc3MDQzODc5Mw==.ozlKKwEEZpoGVuNzPDCyOMRGv",
+                    )
+                    .json(&serde_json::json!({
+                        "target_paste_id": target_paste_id,
+                        "target_token": "NTE3ODE1MzA0MzU0Mjg0NjA1.MTc3MDQzODc5Mw==.ozlKKwEEZpoGVuNzPDCyOMRGv"
+                    }))
+                    .await;
+
+                response.assert_status(StatusCode::UNAUTHORIZED);
+
+                let document = Document::fetch_with_paste(&pool, &paste_id, &document_id)
+                    .await
+                    .expect("Failed to make DB request");
+
+                assert!(document.is_some(), "Document was moved.");
+            }
+        }
+
+        mod put_document {
+            use super::*;
+
+            #[rstest]
+            #[case(None, StatusCode::CREATED)]
+            #[case(Some(3), StatusCode::CREATED)]
+            #[case(Some(2), StatusCode::BAD_REQUEST)]
+            #[sqlx::test(fixtures(
+                path = "../../tests/fixtures",
+                scripts("pastes", "documents", "tokens")
+            ))]
+            async fn test_max_documents_per_owner(
+                #[ignore] pool: PgPool,
+                #[case] max_documents_per_owner: Option<usize>,
+                #[case] expected_status: StatusCode,
+            ) {
+                let config = Config::test_builder()
+                    .max_documents_per_owner(max_documents_per_owner)
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let paste_id = Snowflake::new(517_815_304_354_284_605);
+                let document_id = Snowflake::new(517_815_304_354_284_730);
+
+                let response = server
+                    .put(&format!(
+                        "/v1/pastes/{paste_id}/documents/{document_id}?name=created.txt"
+                    ))
+                    .add_header(
+                        "Authorization",
+                        "Bearer NTE3ODE1MzA0MzU0Mjg0NjA1.MTc3MDQzODc5Mw==.ozlKKwEEZpoGVuNzPDCyOMRGv",
+                    )
+                    .content_type("text/plain")
+                    .bytes(Bytes::from("created"))
+                    .await;
+
+                response.assert_status(expected_status);
+
+                let document = Document::fetch_with_paste(&pool, &paste_id, &document_id)
+                    .await
+                    .expect("Failed to make DB request");
+
+                assert_eq!(
+                    document.is_some(),
+                    expected_status == StatusCode::CREATED,
+                    "Document creation does not match the expected status."
+                );
+            }
+
+            #[sqlx::test(fixtures(
+                path = "../../tests/fixtures",
+                scripts("pastes", "documents", "tokens")
+            ))]
+            async fn test_max_concurrent_paste_writes(pool: PgPool) {
+                use std::future::IntoFuture as _;
+
+                let config = Config::test_builder()
+                    .max_concurrent_paste_writes(Some(2))
+                    .paste_write_wait_ms(0)
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state.clone());
+                let server = TestServer::new(app);
+
+                let paste_id = Snowflake::new(517_815_304_354_284_605);
+
+                let put = |document_id: u64| {
+                    server
+                        .put(&format!(
+                            "/v1/pastes/{paste_id}/documents/{document_id}?name={document_id}.txt"
+                        ))
+                        .add_header(
+                            "Authorization",
+                            "Bearer NTE3ODE1MzA0MzU0Mjg0NjA1.MTc3MDQzODc5Mw==.ozlKKwEEZpoGVuNzPDCyOMRGv",
+                        )
+                        .content_type("text/plain")
+                        .bytes(Bytes::from("created"))
+                        .into_future()
+                };
+
+                // Writes already in progress, holding every permit of the paste.
+                let first_permit = state
+                    .write_limiter()
+                    .acquire(&paste_id)
+                    .await
+                    .expect("Failed to acquire permit.");
+                let second_permit = state
+                    .write_limiter()
+                    .acquire(&paste_id)
+                    .await
+                    .expect("Failed to acquire permit.");
+
+                let (first, second, third) = tokio::join!(
+                    put(517_815_304_354_284_730),
+                    put(517_815_304_354_284_731),
+                    put(517_815_304_354_284_732),
+                );
+
+                for response in [first, second, third] {
+                    response.assert_status(StatusCode::TOO_MANY_REQUESTS);
+                }
+
+                let documents = Document::fetch_all(&pool, &paste_id)
+                    .await
+                    .expect("Failed to make DB request");
+
+                assert_eq!(documents.len(), 2, "Throttled writes created documents.");
+
+                drop(first_permit);
+
+                let response = put(517_815_304_354_284_733).await;
+
+                response.assert_status(StatusCode::CREATED);
+
+                drop(second_permit);
+
+                let documents = Document::fetch_all(&pool, &paste_id)
+                    .await
+                    .expect("Failed to make DB request");
+
+                assert_eq!(documents.len(), 3, "Document was not created.");
+            }
+
+            #[sqlx::test(fixtures(
+                path = "../../tests/fixtures",
+                scripts("pastes", "documents", "tokens")
+            ))]
+            async fn test_create(pool: PgPool) {
+                let config = Config::test_builder()
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let paste_id = Snowflake::new(517_815_304_354_284_605);
+                let document_id = Snowflake::new(517_815_304_354_284_730);
+
+                let response = server
+                    .put(&format!(
+                        "/v1/pastes/{paste_id}/documents/{document_id}?name=created.txt"
+                    ))
+                    .add_header(
+                        "Authorization",
+                        "Bearer NTE3ODE1MzA0MzU0Mjg0NjA1.MTc3MDQzODc5Mw==.ozlKKwEEZpoGVuNzPDCyOMRGv",
+                    )
+                    .content_type("text/plain")
+                    .bytes(Bytes::from("created"))
+                    .await;
+
+                response.assert_status(StatusCode::CREATED);
+
+                let body: Document = response.json();
+
+                assert_eq!(body.id(), &document_id, "ID does not match.");
+                assert_eq!(body.name(), "created.txt", "Name does not match.");
+                assert_eq!(body.size(), 7, "Size does not match.");
+
+                let document = Document::fetch_with_paste(&pool, &paste_id, &document_id)
+                    .await
+                    .expect("Failed to make DB request")
+                    .expect("Document was not created.");
+
+                assert_eq!(
+                    document.checksum(),
+                    Some(generate_checksum(b"created").as_str()),
+                    "Checksum does not match."
+                );
+
+                assert_eq!(
+                    object_store
+                        .fetch_document(&document)
+                        .await
+                        .expect("Failed to fetch document contents."),
+                    Some(Bytes::from("created")),
+                    "Contents do not match."
+                );
+            }
+
+            #[sqlx::test(fixtures(
+                path = "../../tests/fixtures",
+                scripts("pastes", "documents", "tokens")
+            ))]
+            async fn test_replace(pool: PgPool) {
+                let config = Config::test_builder()
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let paste_id = Snowflake::new(517_815_304_354_284_605);
+                let document_id = Snowflake::new(517_815_304_354_284_708);
+
+                let document = Document::fetch_with_paste(&pool, &paste_id, &document_id)
+                    .await
+                    .expect("Failed to make DB request")
+                    .expect("Document does not exist.");
+
+                object_store
+                    .create_document(&document, Bytes::from("a".repeat(document.size())))
+                    .await
+                    .expect("Failed to create document contents.");
+
+                for _ in 0..2 {
+                    let response = server
+                        .put(&format!(
+                            "/v1/pastes/{paste_id}/documents/{document_id}"
+                        ))
+                        .add_header(
+                            "Authorization",
+                            "Bearer NTE3ODE1MzA0MzU0Mjg0NjA1.MTc3MDQzODc5Mw==.ozlKKwEEZpoGVuNzPDCyOMRGv",
+                        )
+                        .content_type("text/plain")
+                        .bytes(Bytes::from("replaced"))
+                        .await;
+
+                    response.assert_status(StatusCode::OK);
+
+                    let body: Document = response.json();
 
-    if document.paste_id() != path.paste_id() {
-        return Err(RESTError::bad_request(
-            "The document ID does not belong to that paste.".to_string(),
-        ));
-    }
+                    assert_eq!(body.name(), document.name(), "Name does not match.");
+                    assert_eq!(body.size(), 8, "Size does not match.");
+                }
 
-    paste.add_view(app.database().pool()).await?;
+                let document = Document::fetch_with_paste(&pool, &paste_id, &document_id)
+                    .await
+                    .expect("Failed to make DB request")
+                    .expect("Document does not exist.");
 
-    Ok((StatusCode::OK, Json(document)))
-}
+                assert_eq!(document.size(), 8, "Size does not match.");
 
-/// ## Content Disposition
-///
-/// Custom content disposition header, with filename parser.
-#[derive(Debug, Clone)]
-pub struct ContentDisposition {
-    disposition: String,
-    filename: Option<String>,
-}
+                assert_eq!(
+                    object_store
+                        .fetch_document(&document)
+                        .await
+                        .expect("Failed to fetch document contents."),
+                    Some(Bytes::from("replaced")),
+                    "Contents do not match."
+                );
+            }
 
-impl ContentDisposition {
-    /// The contents disposition type.
-    pub fn disposition(&self) -> &str {
-        &self.disposition
-    }
+            #[sqlx::test(fixtures(
+                path = "../../tests/fixtures",
+                scripts("pastes", "documents", "tokens")
+            ))]
+            async fn test_replace_exceeds_total_size(pool: PgPool) {
+                let config = Config::test_builder()
+                    .size_limits(
+                        SizeLimitConfig::test_builder()
+                            .maximum_total_document_size(500)
+                            .build()
+                            .expect("Failed to build size limit config."),
+                    )
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
 
-    /// The content dispositions filename (if found).
-    pub fn filename(&self) -> Option<&str> {
-        self.filename.as_deref()
-    }
-}
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
 
-impl Header for ContentDisposition {
-    fn name() -> &'static HeaderName {
-        &axum::http::header::CONTENT_DISPOSITION
-    }
+                let paste_id = Snowflake::new(517_815_304_354_284_605);
+                let document_id = Snowflake::new(517_815_304_354_284_708);
 
-    fn decode<'i, I>(values: &mut I) -> Result<Self, headers::Error>
-    where
-        I: Iterator<Item = &'i HeaderValue>,
-    {
-        let value = values.next().ok_or_else(headers::Error::invalid)?;
+                let document = Document::fetch_with_paste(&pool, &paste_id, &document_id)
+                    .await
+                    .expect("Failed to make DB request")
+                    .expect("Document does not exist.");
 
-        let s = value.to_str().map_err(|_| headers::Error::invalid())?;
+                let original = Bytes::from("a".repeat(document.size()));
 
-        let mut disposition = String::new();
-        let mut filename = None;
+                object_store
+                    .create_document(&document, original.clone())
+                    .await
+                    .expect("Failed to create document contents.");
 
-        for (i, part) in s.split(';').enumerate() {
-            let part = part.trim();
-            if i == 0 {
-                disposition = part.to_string();
-            } else if let Some(rest) = part.strip_prefix("filename=") {
-                filename = Some(rest.trim_matches('"').to_string());
+                for url in [
+                    format!("/v1/pastes/{paste_id}/documents/{document_id}"),
+                    format!("/v1/pastes/{paste_id}/documents/{document_id}?name=renamed.txt"),
+                ] {
+                    let response = server
+                        .put(&url)
+                        .add_header(
+                            "Authorization",
+                            "Bearer NTE3ODE1MzA0MzU0Mjg0NjA1.MTc3MDQzODc5Mw==.ozlKKwEEZpoGVuNzPDCyOMRGv",
+                        )
+                        .content_type("text/plain")
+                        .bytes(Bytes::from("b".repeat(500)))
+                        .await;
+
+                    response.assert_status(StatusCode::UNPROCESSABLE_ENTITY);
+                }
+
+                let current = Document::fetch_with_paste(&pool, &paste_id, &document_id)
+                    .await
+                    .expect("Failed to make DB request")
+                    .expect("Document does not exist.");
+
+                assert_eq!(current.name(), document.name(), "Name does not match.");
+                assert_eq!(current.size(), document.size(), "Size does not match.");
+                assert_eq!(current.version(), document.version(), "Version was bumped.");
+
+                assert_eq!(
+                    object_store
+                        .fetch_document(&current)
+                        .await
+                        .expect("Failed to fetch document contents."),
+                    Some(original),
+                    "Contents do not match."
+                );
             }
-        }
 
-        Ok(Self {
-            disposition,
-            filename,
-        })
-    }
+            #[sqlx::test(fixtures(
+                path = "../../tests/fixtures",
+                scripts("pastes", "documents", "tokens")
+            ))]
+            async fn test_replace_renamed(pool: PgPool) {
+                let config = Config::test_builder()
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
 
-    fn encode<E>(&self, values: &mut E)
-    where
-        E: Extend<HeaderValue>,
-    {
-        let mut parts = vec![self.disposition.clone()];
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
 
-        if let Some(filename) = &self.filename {
-            parts.push(format!("filename=\"{filename}\""));
-        }
+                let paste_id = Snowflake::new(517_815_304_354_284_605);
+                let document_id = Snowflake::new(517_815_304_354_284_708);
 
-        let full = parts.join("; ");
+                let document = Document::fetch_with_paste(&pool, &paste_id, &document_id)
+                    .await
+                    .expect("Failed to make DB request")
+                    .expect("Document does not exist.");
 
-        if let Ok(header_value) = HeaderValue::from_str(&full) {
-            values.extend(std::iter::once(header_value));
-        }
-    }
-}
+                object_store
+                    .create_document(&document, Bytes::from("a".repeat(document.size())))
+                    .await
+                    .expect("Failed to create document contents.");
 
-#[cfg(test)]
-mod test {
-    use sqlx::PgPool;
+                let response = server
+                    .put(&format!(
+                        "/v1/pastes/{paste_id}/documents/{document_id}?name=renamed.txt"
+                    ))
+                    .add_header(
+                        "Authorization",
+                        "Bearer NTE3ODE1MzA0MzU0Mjg0NjA1.MTc3MDQzODc5Mw==.ozlKKwEEZpoGVuNzPDCyOMRGv",
+                    )
+                    .content_type("text/plain")
+                    .bytes(Bytes::from("replaced"))
+                    .await;
 
-    use crate::app::config::Config;
-    use crate::rest::generate_router as main_generate_router;
+                response.assert_status(StatusCode::OK);
 
-    use axum_test::TestServer;
-    use http::StatusCode;
-    use rstest::rstest;
+                let current = Document::fetch_with_paste(&pool, &paste_id, &document_id)
+                    .await
+                    .expect("Failed to make DB request")
+                    .expect("Document does not exist.");
 
-    use crate::{
-        app::{application::ApplicationState, object_store::TestObjectStore},
-        models::{
-            document::Document, errors::RESTErrorResponse, paste::Paste, snowflake::Snowflake,
-        },
-    };
+                assert_eq!(current.name(), "renamed.txt", "Name does not match.");
 
-    mod v1 {
-        use super::*;
+                assert_eq!(
+                    object_store
+                        .fetch_document(&current)
+                        .await
+                        .expect("Failed to fetch document contents."),
+                    Some(Bytes::from("replaced")),
+                    "Contents do not match."
+                );
 
-        mod get_document {
-            use super::*;
+                assert_eq!(
+                    object_store
+                        .fetch_document(&document)
+                        .await
+                        .expect("Failed to fetch document contents."),
+                    None,
+                    "Previous contents were not removed."
+                );
+            }
 
-            #[sqlx::test(fixtures(path = "../../tests/fixtures", scripts("pastes", "documents")))]
-            async fn test_existing(pool: PgPool) {
+            #[sqlx::test(fixtures(
+                path = "../../tests/fixtures",
+                scripts("pastes", "documents", "tokens")
+            ))]
+            async fn test_if_match(pool: PgPool) {
                 let config = Config::test_builder()
                     .build()
                     .expect("Failed to build config.");
-                let object_store = TestObjectStore::new();
+                let object_store = MemoryObjectStore::new();
                 let state =
                     ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
                         .await
@@ -181,98 +3196,263 @@ mod test {
                 let paste_id = Snowflake::new(517_815_304_354_284_605);
                 let document_id = Snowflake::new(517_815_304_354_284_708);
 
-                let views = Paste::fetch(&pool, &paste_id)
+                let document = Document::fetch_with_paste(&pool, &paste_id, &document_id)
                     .await
                     .expect("Failed to make DB request")
-                    .expect("Failed to find paste.")
-                    .views();
+                    .expect("Document does not exist.");
+
+                object_store
+                    .create_document(&document, Bytes::from("a".repeat(document.size())))
+                    .await
+                    .expect("Failed to create document contents.");
 
                 let response = server
-                    .get(&format!("/v1/pastes/{paste_id}/documents/{document_id}"))
+                    .put(&format!("/v1/pastes/{paste_id}/documents/{document_id}"))
+                    .add_header(
+                        "Authorization",
+                        "Bearer NTE3ODE1MzA0MzU0Mjg0NjA1.MTc3MDQzODc5Mw==.ozlKKwEEZpoGVuNzPDCyOMRGv",
+                    )
+                    .add_header("If-Match", "\"0\"")
+                    .content_type("text/plain")
+                    .bytes(Bytes::from("first"))
                     .await;
 
                 response.assert_status(StatusCode::OK);
 
-                response.assert_header("Content-Type", "application/json");
-
                 let body: Document = response.json();
 
+                assert_eq!(body.version(), 1, "Version was not bumped.");
+
+                let response = server
+                    .put(&format!("/v1/pastes/{paste_id}/documents/{document_id}"))
+                    .add_header(
+                        "Authorization",
+                        "Bearer NTE3ODE1MzA0MzU0Mjg0NjA1.MTc3MDQzODc5Mw==.ozlKKwEEZpoGVuNzPDCyOMRGv",
+                    )
+                    .add_header("If-Match", "\"0\"")
+                    .content_type("text/plain")
+                    .bytes(Bytes::from("second"))
+                    .await;
+
+                response.assert_status(StatusCode::PRECONDITION_FAILED);
+
                 let document = Document::fetch_with_paste(&pool, &paste_id, &document_id)
                     .await
                     .expect("Failed to make DB request")
                     .expect("Document does not exist.");
 
-                assert_eq!(body.paste_id(), &paste_id, "Paste ID's do not match.");
+                assert_eq!(document.version(), 1, "Version was bumped.");
+                assert_eq!(document.size(), 5, "Size does not match.");
+            }
 
-                assert_eq!(body.id(), &document_id, "Document ID's do not match.");
+            #[sqlx::test(fixtures(
+                path = "../../tests/fixtures",
+                scripts("pastes", "documents", "tokens")
+            ))]
+            async fn test_missing_name(pool: PgPool) {
+                let config = Config::test_builder()
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
 
-                assert_eq!(
-                    body.name(),
-                    document.name(),
-                    "Document name's do not match."
-                );
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
 
-                assert_eq!(
-                    body.doc_type(),
-                    document.doc_type(),
-                    "Document name's do not match."
-                );
+                let paste_id = Snowflake::new(517_815_304_354_284_605);
+                let document_id = Snowflake::new(517_815_304_354_284_730);
+
+                let response = server
+                    .put(&format!(
+                        "/v1/pastes/{paste_id}/documents/{document_id}"
+                    ))
+                    .add_header(
+                        "Authorization",
+                        "Bearer NTE3ODE1MzA0MzU0Mjg0NjA1.MTc3MDQzODc5Mw==.ozlKKwEEZpoGVuNzPDCyOMRGv",
+                    )
+                    .bytes(Bytes::from("created"))
+                    .await;
+
+                response.assert_status(StatusCode::BAD_REQUEST);
+
+                let body: RESTErrorResponse = response.json();
 
                 assert_eq!(
-                    body.size(),
-                    document.size(),
-                    "Document name's do not match."
+                    body.message(),
+                    "A name is required when creating a document.",
+                    "Message does not match."
                 );
+            }
+        }
 
-                let updated_views = Paste::fetch(&pool, &paste_id)
-                    .await
-                    .expect("Failed to make DB request")
-                    .expect("Failed to find paste.")
-                    .views();
+        mod delete_document {
+            use super::*;
 
-                assert_eq!(views + 1, updated_views, "Views was not updated.");
+            #[sqlx::test(fixtures(
+                path = "../../tests/fixtures",
+                scripts("pastes", "documents", "tokens")
+            ))]
+            async fn test_successful(pool: PgPool) {
+                let config = Config::test_builder()
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let paste_id = Snowflake::new(517_815_304_354_284_605);
+                let document_id = Snowflake::new(517_815_304_354_284_708);
+
+                let response = server
+                    .delete(&format!(
+                        "/v1/pastes/{paste_id}/documents/{document_id}"
+                    ))
+                    .add_header(
+                        "Authorization",
+                        "Bearer NTE3ODE1MzA0MzU0Mjg0NjA1.MTc3MDQzODc5Mw==.ozlKKwEEZpoGVuNzPDCyOMRGv",
+                    )
+                    .await;
+
+                response.assert_status(StatusCode::NO_CONTENT);
+
+                assert!(
+                    Document::fetch(&pool, &document_id)
+                        .await
+                        .expect("Failed to make DB request")
+                        .is_none(),
+                    "Document was not deleted."
+                );
+
+                assert!(
+                    Paste::fetch(&pool, &paste_id)
+                        .await
+                        .expect("Failed to make DB request")
+                        .is_some(),
+                    "Paste was deleted."
+                );
             }
 
             #[rstest]
-            #[case(Snowflake::new(517_815_304_354_284_605), "Document not found.")]
-            #[case(
-                Snowflake::new(1_234_567_890),
-                "The paste requested could not be found"
-            )]
-            #[sqlx::test(fixtures(path = "../../tests/fixtures", scripts("pastes", "documents")))]
-            async fn test_missing(
+            #[case(false)]
+            #[case(true)]
+            #[sqlx::test(fixtures(
+                path = "../../tests/fixtures",
+                scripts("pastes", "documents", "tokens")
+            ))]
+            async fn test_last_document(
                 #[ignore] pool: PgPool,
-                #[case] paste_id: Snowflake,
-                #[case] message: &str,
+                #[case] delete_paste_on_last_document: bool,
             ) {
                 let config = Config::test_builder()
+                    .delete_paste_on_last_document(delete_paste_on_last_document)
                     .build()
                     .expect("Failed to build config.");
-                let object_store = TestObjectStore::new();
+                let object_store = MemoryObjectStore::new();
                 let state =
                     ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
                         .await
                         .expect("Failed to build application state.");
 
-                let document_id = Snowflake::new(1_234_567_890);
-
                 let app = main_generate_router(state);
                 let server = TestServer::new(app);
 
+                let paste_id = Snowflake::new(517_815_304_354_284_604);
+                let document_id = Snowflake::new(517_815_304_354_284_707);
+
                 let response = server
-                    .get(&format!("/v1/pastes/{paste_id}/documents/{document_id}"))
+                    .delete(&format!(
+                        "/v1/pastes/{paste_id}/documents/{document_id}"
+                    ))
+                    .add_header(
+                        "Authorization",
+                        "Bearer NTE3ODE1MzA0MzU0Mjg0NjA0.MTc0NzgxNjE4OQ==.FDP-mNTjfuOKovulMFbaSkoeq",
+                    )
                     .await;
 
-                response.assert_status(StatusCode::NOT_FOUND);
+                let paste = Paste::fetch(&pool, &paste_id)
+                    .await
+                    .expect("Failed to make DB request");
 
-                response.assert_header("Content-Type", "application/json");
+                let document = Document::fetch(&pool, &document_id)
+                    .await
+                    .expect("Failed to make DB request");
 
-                let body: RESTErrorResponse = response.json();
+                if delete_paste_on_last_document {
+                    response.assert_status(StatusCode::NO_CONTENT);
 
-                assert_eq!(body.reason(), "Not Found", "Reason does not match.");
+                    assert!(paste.is_none(), "Paste was not deleted.");
 
-                assert_eq!(body.message(), message, "Trace does not match.");
+                    assert!(document.is_none(), "Document was not deleted.");
+                } else {
+                    response.assert_status(StatusCode::BAD_REQUEST);
+
+                    let body: RESTErrorResponse = response.json();
+
+                    assert_eq!(
+                        body.message(),
+                        "A paste must have at least one document.",
+                        "Message does not match."
+                    );
+
+                    assert!(paste.is_some(), "Paste was deleted.");
+
+                    assert!(document.is_some(), "Document was deleted.");
+                }
             }
         }
     }
+
+    mod content_disposition {
+        use axum_extra::headers::HeaderMapExt as _;
+        use http::HeaderMap;
+
+        use crate::rest::document::ContentDisposition;
+
+        #[test]
+        fn test_encode_ascii() {
+            let mut headers = HeaderMap::new();
+            headers.typed_insert(ContentDisposition::new(
+                "attachment",
+                Some("my file.txt".to_string()),
+            ));
+
+            assert_eq!(
+                headers
+                    .get("Content-Disposition")
+                    .expect("Missing content disposition."),
+                "attachment; filename=\"my file.txt\""
+            );
+        }
+
+        #[test]
+        fn test_encode_unicode() {
+            let mut headers = HeaderMap::new();
+            headers.typed_insert(ContentDisposition::new(
+                "attachment",
+                Some("my résumé.txt".to_string()),
+            ));
+
+            assert_eq!(
+                headers
+                    .get("Content-Disposition")
+                    .expect("Missing content disposition."),
+                "attachment; filename=\"my r_sum_.txt\"; filename*=UTF-8''my%20r%C3%A9sum%C3%A9.txt"
+            );
+
+            let disposition: ContentDisposition = headers
+                .typed_get()
+                .expect("Failed to decode content disposition.");
+
+            assert_eq!(disposition.disposition(), "attachment");
+            assert_eq!(disposition.filename(), Some("my résumé.txt"));
+        }
+    }
 }
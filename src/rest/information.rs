@@ -2,18 +2,23 @@
 
 use axum::{
     Json, Router,
+    body::{Body, HttpBody as _},
     extract::{DefaultBodyLimit, State},
     response::{IntoResponse as _, Response},
     routing::get,
 };
-use http::StatusCode;
+use http::{StatusCode, header::CONTENT_LENGTH};
 
 use crate::{
     app::{application::App, config::Config},
     models::{
         errors::RESTError,
-        payload::information::{ResponseConfig, ResponseInformation, ResponseStatus},
+        payload::{
+            information::{ResponseConfig, ResponseInformation, ResponseStatus},
+            paste::PostPasteBodyInner,
+        },
     },
+    rest::negotiated::{Negotiated, ResponseFormat},
 };
 
 /// ## Generate Router
@@ -26,9 +31,13 @@ pub fn generate_router(config: &Config) -> Router<App> {
     Router::new()
         .route("/information", get(get_information))
         .route("/information/status", get(get_status))
-        .route("/information/configuration", get(get_configuration))
+        .route(
+            "/information/configuration",
+            get(get_configuration).head(head_configuration),
+        )
+        .route("/schema/paste", get(get_paste_schema))
         .layer(DefaultBodyLimit::max(
-            config.size_limits().maximum_total_document_size(),
+            config.size_limits().maximum_request_body_size(),
         ))
 }
 
@@ -76,20 +85,67 @@ pub async fn get_information(State(_app): State<App>) -> Result<Response, RESTEr
 /// ## Returns
 ///
 /// - `200` - The [`ResponseConfig`] object.
-pub async fn get_configuration(State(app): State<App>) -> Result<Response, RESTError> {
+pub async fn get_configuration(
+    State(app): State<App>,
+    format: ResponseFormat,
+) -> Result<Response, RESTError> {
     let response_config = ResponseConfig::from_config(app.config());
 
-    Ok((StatusCode::OK, Json(response_config)).into_response())
+    Ok((StatusCode::OK, Negotiated(format, response_config)).into_response())
+}
+
+/// Head Configuration.
+///
+/// Get the headers of the servers current configuration information, without the body.
+///
+/// The headers match those of [`get_configuration`], so it can be used by monitoring tools.
+///
+/// ## Errors
+/// Returns an error if the request failed.
+///
+/// ## Returns
+///
+/// - `200` - The headers of the [`ResponseConfig`] object.
+pub async fn head_configuration(
+    State(app): State<App>,
+    format: ResponseFormat,
+) -> Result<Response, RESTError> {
+    let (mut parts, body) = get_configuration(State(app), format).await?.into_parts();
+
+    if let Some(length) = body.size_hint().exact() {
+        parts.headers.insert(CONTENT_LENGTH, length.into());
+    }
+
+    Ok(Response::from_parts(parts, Body::empty()))
+}
+
+/// Get Paste Schema.
+///
+/// Get the JSON Schema of the paste creation payload.
+///
+/// The constraints reflect the servers configured size limits.
+///
+/// ## Errors
+/// Returns an error if the request failed.
+///
+/// ## Returns
+///
+/// - `200` - The JSON Schema of the [`PostPasteBodyInner`] object.
+pub async fn get_paste_schema(State(app): State<App>) -> Result<Response, RESTError> {
+    let schema = PostPasteBodyInner::json_schema(app.config());
+
+    Ok((StatusCode::OK, Json(schema)).into_response())
 }
 
 #[cfg(test)]
 mod tests {
     use axum_test::TestServer;
     use http::StatusCode;
+    use rstest::rstest;
     use sqlx::PgPool;
 
     use crate::app::{
-        application::ApplicationState, config::Config, object_store::TestObjectStore,
+        application::ApplicationState, config::Config, object_store::MemoryObjectStore,
     };
 
     use crate::models::payload::information::{
@@ -109,7 +165,7 @@ mod tests {
                 let config = Config::test_builder()
                     .build()
                     .expect("Failed to build config.");
-                let object_store = TestObjectStore::new();
+                let object_store = MemoryObjectStore::new();
                 let state = ApplicationState::new_tests(config.clone(), pool, object_store.clone())
                     .await
                     .expect("Failed to build application state.");
@@ -140,7 +196,7 @@ mod tests {
                 let config = Config::test_builder()
                     .build()
                     .expect("Failed to build config.");
-                let object_store = TestObjectStore::new();
+                let object_store = MemoryObjectStore::new();
                 let state = ApplicationState::new_tests(config.clone(), pool, object_store.clone())
                     .await
                     .expect("Failed to build application state.");
@@ -175,7 +231,7 @@ mod tests {
                 let config = Config::test_builder()
                     .build()
                     .expect("Failed to build config.");
-                let object_store = TestObjectStore::new();
+                let object_store = MemoryObjectStore::new();
                 let state = ApplicationState::new_tests(config.clone(), pool, object_store.clone())
                     .await
                     .expect("Failed to build application state.");
@@ -195,6 +251,131 @@ mod tests {
                     .expect("Failed to build expected body.");
                 assert_eq!(body.to_vec(), expected_body, "Body does not match.");
             }
+
+            #[rstest]
+            #[case("application/json", "application/json")]
+            #[case("application/msgpack", "application/msgpack")]
+            #[case("application/json;q=0.5, application/msgpack", "application/msgpack")]
+            #[sqlx::test]
+            async fn test_negotiated(
+                #[ignore] pool: PgPool,
+                #[case] accept: &str,
+                #[case] content_type: &str,
+            ) {
+                let config = Config::test_builder()
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state = ApplicationState::new_tests(config.clone(), pool, object_store.clone())
+                    .await
+                    .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let response = server
+                    .get("/v1/information/configuration")
+                    .add_header("Accept", accept)
+                    .await;
+
+                response.assert_status(StatusCode::OK);
+
+                response.assert_header("Content-Type", content_type);
+
+                response.assert_header("Vary", "accept");
+
+                let body = response.as_bytes();
+
+                let expected = serde_json::to_value(ResponseConfig::from_config(&config))
+                    .expect("Failed to build expected body.");
+
+                let received: serde_json::Value = if content_type == "application/msgpack" {
+                    rmp_serde::from_slice(body).expect("Body is not valid MessagePack.")
+                } else {
+                    serde_json::from_slice(body).expect("Body is not valid JSON.")
+                };
+
+                assert_eq!(received, expected, "Body does not match.");
+            }
+
+            #[sqlx::test]
+            async fn test_head(pool: PgPool) {
+                let config = Config::test_builder()
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state = ApplicationState::new_tests(config.clone(), pool, object_store.clone())
+                    .await
+                    .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let expected_body = serde_json::to_vec(&ResponseConfig::from_config(&config))
+                    .expect("Failed to build expected body.");
+
+                let response = server.head("/v1/information/configuration").await;
+
+                response.assert_status(StatusCode::OK);
+
+                response.assert_header("Content-Type", "application/json");
+
+                response.assert_header("Content-Length", expected_body.len().to_string());
+
+                assert!(response.as_bytes().is_empty(), "A body was returned.");
+            }
+        }
+
+        mod get_paste_schema {
+
+            use crate::app::config::SizeLimitConfig;
+
+            use super::*;
+
+            #[sqlx::test]
+            async fn test_successful(pool: PgPool) {
+                let config = Config::test_builder()
+                    .size_limits(
+                        SizeLimitConfig::test_builder()
+                            .minimum_total_document_count(2)
+                            .maximum_total_document_count(7)
+                            .build()
+                            .expect("Failed to build size limit config."),
+                    )
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state = ApplicationState::new_tests(config.clone(), pool, object_store.clone())
+                    .await
+                    .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let response = server.get("/v1/schema/paste").await;
+
+                response.assert_status(StatusCode::OK);
+
+                response.assert_header("Content-Type", "application/json");
+
+                let body: serde_json::Value = response.json();
+
+                let documents = &body["properties"]["documents"];
+
+                assert_eq!(documents["type"], "array", "Documents type does not match.");
+                assert_eq!(documents["minItems"], 2, "Minimum items do not match.");
+                assert_eq!(documents["maxItems"], 7, "Maximum items do not match.");
+                assert_eq!(
+                    documents["items"]["properties"]["name"]["maxLength"],
+                    config.size_limits().maximum_document_name_size(),
+                    "Maximum document name length does not match."
+                );
+                assert_eq!(
+                    body["required"],
+                    serde_json::json!(["documents"]),
+                    "Required fields do not match."
+                );
+            }
         }
     }
 }
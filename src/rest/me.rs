@@ -0,0 +1,375 @@
+//! Owner related endpoints and router generator.
+
+use axum::{
+    Json, Router,
+    extract::{DefaultBodyLimit, State},
+    routing::get,
+};
+use http::StatusCode;
+use secrecy::ExposeSecret as _;
+
+use crate::{
+    app::{application::App, config::Config},
+    models::{
+        authentication::Token,
+        errors::RESTError,
+        paste::Paste,
+        payload::{me::ResponseUsage, paste::ResponsePasteSummary},
+    },
+};
+
+/// ## Generate Router
+///
+/// Generates the router for owner related endpoints.
+///
+/// ## Returns
+/// The router with all the owner related endpoints attached.
+pub fn generate_router(config: &Config) -> Router<App> {
+    Router::new()
+        .route("/me/usage", get(get_usage))
+        .route("/me/pastes", get(get_pastes))
+        .layer(DefaultBodyLimit::max(
+            config.size_limits().maximum_request_body_size(),
+        ))
+}
+
+/// Get Usage.
+///
+/// Get the storage usage of all pastes owned by the owner of the token.
+///
+/// ## Headers
+///
+/// - `Authorization` - The token of any paste owned by the owner.
+///
+/// ## Errors
+/// Returns an error if the request failed.
+///
+/// ## Returns
+///
+/// - `401` - Invalid or missing token.
+/// - `200` - The [`ResponseUsage`] object.
+pub async fn get_usage(
+    State(app): State<App>,
+    token: Token,
+) -> Result<(StatusCode, Json<ResponseUsage>), RESTError> {
+    let owner_id = Paste::fetch(app.database().pool(), token.paste_id())
+        .await?
+        .map_or_else(|| *token.paste_id(), |paste| *paste.owner_id());
+
+    let stats = Paste::fetch_owned_stats(app.database().pool(), &owner_id).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ResponseUsage::from_stats(owner_id, &stats)),
+    ))
+}
+
+/// Get Pastes.
+///
+/// Get all pastes owned by the owner of the token.
+///
+/// ## Headers
+///
+/// - `Authorization` - The token of any paste owned by the owner.
+///
+/// ## Errors
+/// Returns an error if the request failed.
+///
+/// ## Returns
+///
+/// - `401` - Invalid or missing token.
+/// - `200` - A list of [`ResponsePasteSummary`] objects, ordered by their ID.
+pub async fn get_pastes(
+    State(app): State<App>,
+    token: Token,
+) -> Result<(StatusCode, Json<Vec<ResponsePasteSummary>>), RESTError> {
+    let pastes = Paste::fetch_owned(app.database().pool(), token.token().expose_secret()).await?;
+
+    let summaries = pastes
+        .iter()
+        .map(ResponsePasteSummary::from_paste)
+        .collect();
+
+    Ok((StatusCode::OK, Json(summaries)))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum_test::{
+        TestServer,
+        multipart::{MultipartForm, Part},
+    };
+    use bytes::Bytes;
+    use http::StatusCode;
+    use serde_json::json;
+    use sqlx::PgPool;
+
+    use crate::app::{
+        application::ApplicationState, config::Config, object_store::MemoryObjectStore,
+    };
+    use crate::models::{
+        errors::RESTErrorResponse,
+        payload::{
+            me::ResponseUsage,
+            paste::{ResponsePaste, ResponsePasteSummary},
+        },
+        snowflake::Snowflake,
+    };
+    use crate::rest::generate_router as main_generate_router;
+
+    mod v1 {
+        use super::*;
+
+        mod get_usage {
+            use super::*;
+
+            #[sqlx::test(fixtures(
+                path = "../../tests/fixtures",
+                scripts("pastes", "documents", "tokens")
+            ))]
+            async fn test_successful(pool: PgPool) {
+                let config = Config::test_builder()
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let owner_id = Snowflake::new(517_815_304_354_284_605);
+                let token_string =
+                    "NTE3ODE1MzA0MzU0Mjg0NjA1.MTc3MDQzODc5Mw==.ozlKKwEEZpoGVuNzPDCyOMRGv";
+
+                let payload = serde_json::to_vec(&json!({
+                    "documents": [{"id": 0, "name": "test.txt"}]
+                }))
+                .expect("Failed to build request body.");
+
+                let form = MultipartForm::new()
+                    .add_part(
+                        "payload",
+                        Part::bytes(Bytes::from(payload))
+                            .add_header("Content-Type", "application/json"),
+                    )
+                    .add_part(
+                        "files[0]",
+                        Part::bytes(Bytes::from("test")).add_header("Content-Type", "text/plain"),
+                    );
+
+                let response = server
+                    .post("/v1/pastes")
+                    .add_header("Authorization", format!("Bearer {token_string}"))
+                    .multipart(form)
+                    .await;
+
+                response.assert_status(StatusCode::OK);
+
+                let body: ResponsePaste = response.json();
+
+                let owned_token_string = body.token().expect("Token was not returned.").to_string();
+
+                for token in [token_string, &owned_token_string] {
+                    let response = server
+                        .get("/v1/me/usage")
+                        .add_header("Authorization", format!("Bearer {token}"))
+                        .await;
+
+                    response.assert_status(StatusCode::OK);
+
+                    let body: ResponseUsage = response.json();
+
+                    assert_eq!(body.owner_id(), &owner_id, "Owner ID does not match.");
+
+                    assert_eq!(body.paste_count(), 2, "Paste count does not match.");
+
+                    assert_eq!(body.document_count(), 3, "Document count does not match.");
+
+                    assert_eq!(body.total_size(), 484, "Total size does not match.");
+                }
+            }
+
+            #[sqlx::test(fixtures(
+                path = "../../tests/fixtures",
+                scripts("pastes", "documents", "tokens")
+            ))]
+            async fn test_unowned(pool: PgPool) {
+                let config = Config::test_builder()
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let token_string =
+                    "NTE3ODE1MzA0MzU0Mjg0NjAy.MTc0NzgxNjA3NA==.dniyyCHW-xKQcYvjgawVExLCh";
+
+                let response = server
+                    .get("/v1/me/usage")
+                    .add_header("Authorization", format!("Bearer {token_string}"))
+                    .await;
+
+                response.assert_status(StatusCode::OK);
+
+                let body: ResponseUsage = response.json();
+
+                assert_eq!(
+                    body.owner_id(),
+                    &Snowflake::new(517_815_304_354_284_602),
+                    "Owner ID does not match."
+                );
+
+                assert_eq!(body.paste_count(), 1, "Paste count does not match.");
+            }
+
+            #[sqlx::test]
+            async fn test_missing_token(pool: PgPool) {
+                let config = Config::test_builder()
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let response = server.get("/v1/me/usage").await;
+
+                response.assert_status(StatusCode::UNAUTHORIZED);
+
+                let body: RESTErrorResponse = response.json();
+
+                assert_eq!(
+                    body.reason(),
+                    "Missing Credentials",
+                    "Reason does not match."
+                );
+            }
+        }
+
+        mod get_pastes {
+            use super::*;
+
+            #[sqlx::test(fixtures(
+                path = "../../tests/fixtures",
+                scripts("pastes", "documents", "tokens")
+            ))]
+            async fn test_successful(pool: PgPool) {
+                let config = Config::test_builder()
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let token_string =
+                    "NTE3ODE1MzA0MzU0Mjg0NjA1.MTc3MDQzODc5Mw==.ozlKKwEEZpoGVuNzPDCyOMRGv";
+
+                let mut paste_ids = Vec::new();
+                for authorization in [Some(token_string), None] {
+                    let payload = serde_json::to_vec(&json!({
+                        "documents": [{"id": 0, "name": "test.txt"}]
+                    }))
+                    .expect("Failed to build request body.");
+
+                    let form = MultipartForm::new()
+                        .add_part(
+                            "payload",
+                            Part::bytes(Bytes::from(payload))
+                                .add_header("Content-Type", "application/json"),
+                        )
+                        .add_part(
+                            "files[0]",
+                            Part::bytes(Bytes::from("test"))
+                                .add_header("Content-Type", "text/plain"),
+                        );
+
+                    let mut request = server.post("/v1/pastes").multipart(form);
+
+                    if let Some(token) = authorization {
+                        request = request.add_header("Authorization", format!("Bearer {token}"));
+                    }
+
+                    let response = request.await;
+
+                    response.assert_status(StatusCode::OK);
+
+                    let body: ResponsePaste = response.json();
+
+                    paste_ids.push((
+                        body.id(),
+                        body.token().expect("Token was not returned.").to_string(),
+                    ));
+                }
+
+                let (owned_id, owned_token_string) = &paste_ids[0];
+                let (unowned_id, unowned_token_string) = &paste_ids[1];
+
+                let expected = vec![Snowflake::new(517_815_304_354_284_605), *owned_id];
+
+                for token in [token_string, owned_token_string] {
+                    let response = server
+                        .get("/v1/me/pastes")
+                        .add_header("Authorization", format!("Bearer {token}"))
+                        .await;
+
+                    response.assert_status(StatusCode::OK);
+
+                    let body: Vec<ResponsePasteSummary> = response.json();
+
+                    let ids: Vec<Snowflake> = body.iter().map(ResponsePasteSummary::id).collect();
+
+                    assert_eq!(ids, expected, "Owned pastes do not match.");
+                }
+
+                let response = server
+                    .get("/v1/me/pastes")
+                    .add_header("Authorization", format!("Bearer {unowned_token_string}"))
+                    .await;
+
+                response.assert_status(StatusCode::OK);
+
+                let body: Vec<ResponsePasteSummary> = response.json();
+
+                let ids: Vec<Snowflake> = body.iter().map(ResponsePasteSummary::id).collect();
+
+                assert_eq!(ids, vec![*unowned_id], "Owned pastes do not match.");
+            }
+
+            #[sqlx::test]
+            async fn test_missing_token(pool: PgPool) {
+                let config = Config::test_builder()
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let response = server.get("/v1/me/pastes").await;
+
+                response.assert_status(StatusCode::UNAUTHORIZED);
+            }
+        }
+    }
+}
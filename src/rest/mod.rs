@@ -1,16 +1,48 @@
 //! REST related endpoints and router generators.
 
+pub mod admin;
+pub mod client_ip;
 pub mod document;
 pub mod information;
+pub mod me;
+pub mod negotiated;
 pub mod paste;
+pub mod upload;
 
 use std::time::Duration;
 
-use axum::Router;
-use http::{HeaderValue, Method, StatusCode, header};
-use tower_http::{cors::CorsLayer, timeout::TimeoutLayer, trace::TraceLayer};
+use axum::{
+    Router,
+    extract::{Request, State},
+    middleware::{self, Next},
+    response::{IntoResponse as _, Response},
+};
+use axum_extra::headers::{ETag, IfMatch, IfUnmodifiedSince};
+use http::{HeaderName, HeaderValue, Method, StatusCode, header};
+use tower_http::{
+    compression::{
+        CompressionLayer,
+        predicate::{NotForContentType, Predicate, SizeAbove},
+    },
+    cors::CorsLayer,
+    decompression::RequestDecompressionLayer,
+    normalize_path::NormalizePath,
+    timeout::TimeoutLayer,
+    trace::TraceLayer,
+};
 
-use crate::{app::application::App, models::errors::RESTError};
+use crate::{
+    app::{
+        application::App,
+        config::{Config, ReadOnlyStatus},
+    },
+    models::{
+        DtUtc,
+        authentication::{OTHER_PASTE_PASSWORD_HEADER, PASTE_PASSWORD_HEADER},
+        errors::RESTError,
+        upload::UPLOAD_OFFSET_HEADER,
+    },
+};
 
 /// ## Generate Router
 ///
@@ -20,6 +52,210 @@ use crate::{app::application::App, models::errors::RESTError};
 /// The router with all the application related endpoints attached.
 pub fn generate_router(state: App) -> Router<()> {
     let config = state.config().clone();
+    let cors = generate_cors(&config);
+
+    let router = Router::new()
+        .nest("/v1", information::generate_router(&config))
+        .nest("/v1", paste::generate_router(&config))
+        .nest("/v1", document::generate_router(&config))
+        .nest("/v1", me::generate_router(&config))
+        .nest("/v1", upload::generate_router(&config))
+        .layer(middleware::from_fn_with_state(
+            state.clone(),
+            maintenance_mode,
+        ))
+        .layer(middleware::from_fn_with_state(state.clone(), read_only))
+        .nest("/v1", admin::generate_router(&config))
+        // Each routers body limit is read as the body is extracted, so it applies to the decompressed body.
+        .layer(RequestDecompressionLayer::new().gzip(true).deflate(true))
+        .layer(TraceLayer::new_for_http().make_span_with(make_request_span))
+        .layer(TimeoutLayer::with_status_code(
+            StatusCode::GATEWAY_TIMEOUT,
+            Duration::from_secs(10),
+        )); // TODO: Not sure if gateway timeout makes sense for this.
+
+    let router = if config.response_compression() {
+        router
+            .layer(generate_compression(&config))
+            .layer(middleware::map_response(weaken_compressed_etag))
+    } else {
+        router
+    };
+
+    let router = router
+        .layer(cors)
+        .fallback(fallback)
+        .layer(middleware::from_fn(request_id))
+        .with_state(state);
+
+    // Routing happens before any layer of the router, so the path is normalized by an outer router.
+    if config.trim_trailing_slash() {
+        Router::new().fallback_service(NormalizePath::trim_trailing_slash(router))
+    } else {
+        router
+    }
+}
+
+/// The header containing the ID of a request.
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// The longest request ID accepted from a client.
+const MAXIMUM_REQUEST_ID_LENGTH: usize = 128;
+
+tokio::task_local! {
+    /// The ID of the request currently being handled.
+    static REQUEST_ID: String;
+}
+
+/// Current Request ID.
+///
+/// The ID of the request currently being handled.
+///
+/// ## Returns
+///
+/// - [`Option::Some`] - The ID of the request.
+/// - [`Option::None`] - Not called while handling a request.
+pub fn current_request_id() -> Option<String> {
+    REQUEST_ID.try_with(Clone::clone).ok()
+}
+
+/// Request ID.
+///
+/// Uses the `X-Request-Id` provided by the client, or generates one if it is missing or invalid.
+///
+/// The ID is attached to the tracing span of the request, included in any error body,
+/// and echoed in the response headers.
+async fn request_id(mut request: Request, next: Next) -> Result<Response, RESTError> {
+    let request_id = match request
+        .headers()
+        .get(REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .filter(|value| !value.is_empty() && value.len() <= MAXIMUM_REQUEST_ID_LENGTH)
+    {
+        Some(request_id) => request_id.to_string(),
+        None => generate_request_id()?,
+    };
+
+    let header = HeaderValue::from_str(&request_id).map_err(RESTError::internal_server)?;
+
+    // The ID is set on the request, so a generated ID is also found by the request span.
+    request
+        .headers_mut()
+        .insert(REQUEST_ID_HEADER, header.clone());
+
+    let mut response = REQUEST_ID.scope(request_id, next.run(request)).await;
+
+    response.headers_mut().insert(REQUEST_ID_HEADER, header);
+
+    Ok(response)
+}
+
+/// Make Request Span.
+///
+/// The tracing span of each request, including its ID.
+fn make_request_span(request: &Request) -> tracing::Span {
+    let request_id = request
+        .headers()
+        .get(REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default();
+
+    tracing::debug_span!(
+        "request",
+        method = %request.method(),
+        uri = %request.uri(),
+        version = ?request.version(),
+        request_id,
+    )
+}
+
+/// Generate Request ID.
+///
+/// Generate a random (version 4) UUID, to identify a request.
+///
+/// ## Errors
+///
+/// - [`RESTError`] - Random bytes could not be generated.
+fn generate_request_id() -> Result<String, RESTError> {
+    let mut bytes = [0u8; 16];
+    getrandom::fill(&mut bytes).map_err(RESTError::internal_server)?;
+
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+
+    let value = u128::from_be_bytes(bytes);
+
+    Ok(format!(
+        "{:08x}-{:04x}-{:04x}-{:04x}-{:012x}",
+        value >> 96,
+        (value >> 80) & 0xffff,
+        (value >> 64) & 0xffff,
+        (value >> 48) & 0xffff,
+        value & 0xffff_ffff_ffff
+    ))
+}
+
+/// ## Generate Compression
+///
+/// Generates the response compression layer from the configuration.
+///
+/// Responses below the minimum size, images, and event streams are not compressed.
+/// Partial (range) responses are never compressed.
+///
+/// ## Returns
+/// The [`CompressionLayer`] to apply to all endpoints.
+pub fn generate_compression(config: &Config) -> CompressionLayer<impl Predicate + use<>> {
+    CompressionLayer::new().gzip(true).br(true).compress_when(
+        SizeAbove::new(config.response_compression_minimum_size())
+            .and(NotForContentType::IMAGES)
+            .and(NotForContentType::SSE),
+    )
+}
+
+/// Weaken Compressed `ETag`.
+///
+/// A strong `ETag` describes the exact bytes of a response,
+/// so once a response has been compressed its `ETag` is marked as weak.
+async fn weaken_compressed_etag(mut response: Response) -> Response {
+    if !response.headers().contains_key(header::CONTENT_ENCODING) {
+        return response;
+    }
+
+    if let Some(etag) = response.headers().get(header::ETAG)
+        && !etag.as_bytes().starts_with(b"W/")
+    {
+        let mut weak = b"W/".to_vec();
+        weak.extend_from_slice(etag.as_bytes());
+
+        if let Ok(weak) = HeaderValue::from_bytes(&weak) {
+            response.headers_mut().insert(header::ETAG, weak);
+        }
+    }
+
+    response
+}
+
+/// ## Generate Cors
+///
+/// Generates the CORS layer from the configuration.
+///
+/// ## Panics
+/// Panics if the domain, or an exposed header cannot be parsed.
+///
+/// ## Returns
+/// The [`CorsLayer`] to apply to all endpoints.
+pub fn generate_cors(config: &Config) -> CorsLayer {
+    let expose_headers: Vec<HeaderName> = config
+        .cors()
+        .expose_headers()
+        .iter()
+        .map(|header| {
+            header
+                .parse()
+                .expect("Failed to parse CORS exposed header.")
+        })
+        .collect();
+
     let cors = CorsLayer::new()
         .allow_origin(
             config
@@ -35,22 +271,545 @@ pub fn generate_router(state: App) -> Router<()> {
             Method::DELETE,
             Method::OPTIONS,
         ])
-        .allow_headers([header::ACCEPT, header::CONTENT_TYPE, header::AUTHORIZATION]);
+        .allow_headers([
+            header::ACCEPT,
+            header::CONTENT_TYPE,
+            header::CONTENT_ENCODING,
+            header::AUTHORIZATION,
+            header::IF_MATCH,
+            HeaderName::from_static(PASTE_PASSWORD_HEADER),
+            HeaderName::from_static(OTHER_PASTE_PASSWORD_HEADER),
+            HeaderName::from_static(UPLOAD_OFFSET_HEADER),
+        ])
+        .allow_credentials(config.cors().allow_credentials())
+        .expose_headers(expose_headers);
 
-    Router::new()
-        .nest("/v1", information::generate_router(&config))
-        .nest("/v1", paste::generate_router(&config))
-        .nest("/v1", document::generate_router(&config))
-        .layer(TraceLayer::new_for_http())
-        .layer(TimeoutLayer::with_status_code(
-            StatusCode::GATEWAY_TIMEOUT,
-            Duration::from_secs(10),
-        )) // TODO: Not sure if gateway timeout makes sense for this.
-        .layer(cors)
-        .fallback(fallback)
-        .with_state(state)
+    match config.cors().max_age() {
+        Some(max_age) => cors.max_age(Duration::from_secs(max_age)),
+        None => cors,
+    }
+}
+
+/// Validate If Match.
+///
+/// Validate the `If-Match` precondition against the current entity tag of a resource.
+///
+/// ## Arguments
+///
+/// - `if_match` - The `If-Match` header provided (if any).
+/// - `etag` - The current quoted entity tag of the resource.
+///
+/// ## Errors
+///
+/// - [`RESTError::PreconditionFailed`] - The resource has been modified since the entity tag provided.
+pub fn validate_if_match(if_match: Option<&IfMatch>, etag: &str) -> Result<(), RESTError> {
+    let Some(if_match) = if_match else {
+        return Ok(());
+    };
+
+    let etag: ETag = etag.parse().map_err(RESTError::internal_server)?;
+
+    if !if_match.precondition_passes(&etag) {
+        return Err(RESTError::precondition_failed(
+            "The resource has been modified since the version provided.",
+        ));
+    }
+
+    Ok(())
+}
+
+/// Validate If Unmodified Since.
+///
+/// Validate the `If-Unmodified-Since` precondition against the last modified time of a resource.
+///
+/// ## Arguments
+///
+/// - `if_unmodified_since` - The `If-Unmodified-Since` header provided (if any).
+/// - `last_modified` - The time the resource was last modified.
+///
+/// ## Errors
+///
+/// - [`RESTError::PreconditionFailed`] - The resource has been modified since the time provided.
+pub fn validate_if_unmodified_since(
+    if_unmodified_since: Option<&IfUnmodifiedSince>,
+    last_modified: &DtUtc,
+) -> Result<(), RESTError> {
+    let Some(if_unmodified_since) = if_unmodified_since else {
+        return Ok(());
+    };
+
+    if !if_unmodified_since.precondition_passes((*last_modified).into()) {
+        return Err(RESTError::precondition_failed(
+            "The resource has been modified since the time provided.",
+        ));
+    }
+
+    Ok(())
+}
+
+/// Maintenance Mode.
+///
+/// Rejects write requests with a `Retry-After` while the server is in maintenance mode.
+///
+/// Reads continue as normal, and the admin endpoints are never affected.
+async fn maintenance_mode(State(app): State<App>, request: Request, next: Next) -> Response {
+    if !app.maintenance_mode()
+        || matches!(
+            *request.method(),
+            Method::GET | Method::HEAD | Method::OPTIONS
+        )
+    {
+        return next.run(request).await;
+    }
+
+    let mut response =
+        RESTError::service_unavailable("The server is in maintenance mode, try again later.")
+            .into_response();
+
+    response.headers_mut().insert(
+        header::RETRY_AFTER,
+        HeaderValue::from(app.config().maintenance_retry_after_seconds()),
+    );
+
+    response
+}
+
+/// Read Only.
+///
+/// Rejects write requests with the configured status while the server is read only.
+///
+/// Reads continue as normal, and the admin endpoints are never affected.
+async fn read_only(State(app): State<App>, request: Request, next: Next) -> Response {
+    if !app.config().read_only()
+        || matches!(
+            *request.method(),
+            Method::GET | Method::HEAD | Method::OPTIONS
+        )
+    {
+        return next.run(request).await;
+    }
+
+    let message = "The server is read only.";
+
+    match app.config().read_only_status() {
+        ReadOnlyStatus::MethodNotAllowed => {
+            let mut response = RESTError::method_not_allowed(message).into_response();
+
+            response.headers_mut().insert(
+                header::ALLOW,
+                HeaderValue::from_static("GET, HEAD, OPTIONS"),
+            );
+
+            response
+        }
+        ReadOnlyStatus::Forbidden => RESTError::forbidden(message).into_response(),
+    }
 }
 
 async fn fallback() -> RESTError {
     RESTError::not_found("This endpoint does not exist.")
 }
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use std::io::Read as _;
+
+    use axum_test::TestServer;
+    use flate2::read::GzDecoder;
+    use http::{Request, Response};
+    use rstest::rstest;
+    use sqlx::PgPool;
+    use tower::{ServiceBuilder, ServiceExt as _};
+
+    use crate::{
+        app::{application::ApplicationState, config::CorsConfig, object_store::MemoryObjectStore},
+        models::{
+            document::Document, errors::RESTErrorResponse, paste::Paste,
+            payload::paste::ResponsePaste, snowflake::Snowflake,
+        },
+    };
+
+    use super::*;
+
+    fn config() -> Config {
+        Config::test_builder()
+            .domain(String::from("https://example.com"))
+            .cors(
+                CorsConfig::test_builder()
+                    .allow_credentials(true)
+                    .expose_headers(vec![
+                        String::from("ETag"),
+                        String::from("X-RateLimit-Remaining"),
+                    ])
+                    .max_age(Some(600))
+                    .build()
+                    .expect("Failed to build cors config."),
+            )
+            .build()
+            .expect("Failed to build config.")
+    }
+
+    #[tokio::test]
+    async fn test_cors_expose_headers() {
+        let service = ServiceBuilder::new()
+            .layer(generate_cors(&config()))
+            .service_fn(|_: Request<Body>| async {
+                Ok::<_, Infallible>(Response::new(Body::empty()))
+            });
+
+        let request = Request::get("/")
+            .header(header::ORIGIN, "https://example.com")
+            .body(Body::empty())
+            .expect("Failed to build request.");
+
+        let response = service.oneshot(request).await.expect("Request failed.");
+
+        assert_eq!(
+            response
+                .headers()
+                .get(header::ACCESS_CONTROL_EXPOSE_HEADERS),
+            Some(&HeaderValue::from_static("etag,x-ratelimit-remaining")),
+            "Exposed headers do not match."
+        );
+        assert_eq!(
+            response
+                .headers()
+                .get(header::ACCESS_CONTROL_ALLOW_CREDENTIALS),
+            Some(&HeaderValue::from_static("true")),
+            "Allow credentials does not match."
+        );
+    }
+
+    #[tokio::test]
+    async fn test_cors_max_age() {
+        let service = ServiceBuilder::new()
+            .layer(generate_cors(&config()))
+            .service_fn(|_: Request<Body>| async {
+                Ok::<_, Infallible>(Response::new(Body::empty()))
+            });
+
+        let request = Request::options("/")
+            .header(header::ORIGIN, "https://example.com")
+            .header(header::ACCESS_CONTROL_REQUEST_METHOD, "POST")
+            .body(Body::empty())
+            .expect("Failed to build request.");
+
+        let response = service.oneshot(request).await.expect("Request failed.");
+
+        assert_eq!(
+            response.headers().get(header::ACCESS_CONTROL_MAX_AGE),
+            Some(&HeaderValue::from_static("600")),
+            "Max age does not match."
+        );
+    }
+
+    #[sqlx::test]
+    async fn test_not_found_body_shape(pool: PgPool) {
+        let config = Config::test_builder()
+            .build()
+            .expect("Failed to build config.");
+        let object_store = MemoryObjectStore::new();
+        let state = ApplicationState::new_tests(config.clone(), pool, object_store.clone())
+            .await
+            .expect("Failed to build application state.");
+
+        let server = TestServer::new(generate_router(state));
+
+        let paste_response = server.get("/v1/pastes/517815304354284999").await;
+        let unknown_response = server.get("/v1/this/does/not/exist").await;
+
+        paste_response.assert_status(StatusCode::NOT_FOUND);
+        unknown_response.assert_status(StatusCode::NOT_FOUND);
+
+        paste_response.assert_header("Content-Type", "application/json");
+        unknown_response.assert_header("Content-Type", "application/json");
+
+        let paste_body: serde_json::Value = paste_response.json();
+        let unknown_body: serde_json::Value = unknown_response.json();
+
+        let keys = |body: &serde_json::Value| -> Vec<String> {
+            body.as_object()
+                .expect("Body is not an object.")
+                .keys()
+                .cloned()
+                .collect()
+        };
+
+        assert_eq!(
+            keys(&paste_body),
+            keys(&unknown_body),
+            "Body shapes do not match."
+        );
+        assert_eq!(
+            paste_body["reason"], unknown_body["reason"],
+            "Reasons do not match."
+        );
+        assert_eq!(
+            unknown_body["message"], "This endpoint does not exist.",
+            "Message does not match."
+        );
+    }
+
+    #[sqlx::test(fixtures(path = "../../tests/fixtures", scripts("pastes", "documents")))]
+    async fn test_response_compression(pool: PgPool) {
+        let config = Config::test_builder()
+            .response_compression(true)
+            .response_compression_minimum_size(32)
+            .build()
+            .expect("Failed to build config.");
+        let object_store = MemoryObjectStore::new();
+        let state = ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+            .await
+            .expect("Failed to build application state.");
+
+        let server = TestServer::new(generate_router(state));
+
+        let paste_id = Snowflake::new(517_815_304_354_284_605);
+
+        let response = server
+            .get(&format!("/v1/pastes/{paste_id}"))
+            .add_header("Accept-Encoding", "gzip")
+            .await;
+
+        response.assert_status(StatusCode::OK);
+
+        response.assert_header("Content-Encoding", "gzip");
+
+        let mut body = String::new();
+        GzDecoder::new(response.as_bytes().as_ref())
+            .read_to_string(&mut body)
+            .expect("Failed to decompress body.");
+
+        let body: serde_json::Value =
+            serde_json::from_str(&body).expect("Failed to parse decompressed body.");
+
+        let paste = Paste::fetch(&pool, &paste_id)
+            .await
+            .expect("Failed to make DB request")
+            .expect("Failed to find paste.");
+
+        let documents = Document::fetch_all(&pool, &paste_id)
+            .await
+            .expect("Failed to make DB request");
+
+        let expected_body = serde_json::to_value(ResponsePaste::from_paste(
+            &paste,
+            None,
+            documents,
+            config.domain(),
+        ))
+        .expect("Failed to build expected body.");
+
+        assert_eq!(body, expected_body, "Body does not match.");
+    }
+
+    #[rstest]
+    #[case(false, 32)]
+    #[case(true, u16::MAX)]
+    #[sqlx::test(fixtures(path = "../../tests/fixtures", scripts("pastes", "documents")))]
+    async fn test_response_not_compressed(
+        #[ignore] pool: PgPool,
+        #[case] response_compression: bool,
+        #[case] response_compression_minimum_size: u16,
+    ) {
+        let config = Config::test_builder()
+            .response_compression(response_compression)
+            .response_compression_minimum_size(response_compression_minimum_size)
+            .build()
+            .expect("Failed to build config.");
+        let object_store = MemoryObjectStore::new();
+        let state = ApplicationState::new_tests(config.clone(), pool, object_store.clone())
+            .await
+            .expect("Failed to build application state.");
+
+        let server = TestServer::new(generate_router(state));
+
+        let response = server
+            .get("/v1/pastes/517815304354284605")
+            .add_header("Accept-Encoding", "gzip")
+            .await;
+
+        response.assert_status(StatusCode::OK);
+
+        assert!(
+            response.maybe_header("Content-Encoding").is_none(),
+            "Response should not be compressed."
+        );
+
+        let _: ResponsePaste = response.json();
+    }
+
+    #[rstest]
+    #[case(ReadOnlyStatus::MethodNotAllowed, StatusCode::METHOD_NOT_ALLOWED)]
+    #[case(ReadOnlyStatus::Forbidden, StatusCode::FORBIDDEN)]
+    #[sqlx::test(fixtures(path = "../../tests/fixtures", scripts("pastes", "documents")))]
+    async fn test_read_only(
+        #[ignore] pool: PgPool,
+        #[case] read_only_status: ReadOnlyStatus,
+        #[case] expected_status: StatusCode,
+    ) {
+        let config = Config::test_builder()
+            .read_only(true)
+            .read_only_status(read_only_status)
+            .build()
+            .expect("Failed to build config.");
+        let object_store = MemoryObjectStore::new();
+        let state = ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+            .await
+            .expect("Failed to build application state.");
+
+        let server = TestServer::new(generate_router(state));
+
+        let response = server.get("/v1/pastes/517815304354284605").await;
+
+        response.assert_status_ok();
+
+        let response = server
+            .post("/v1/pastes")
+            .json(&serde_json::json!({"documents": []}))
+            .await;
+
+        response.assert_status(expected_status);
+
+        let response = server
+            .delete("/v1/pastes/517815304354284605")
+            .add_header(
+                "Authorization",
+                "Bearer NTE3ODE1MzA0MzU0Mjg0NjA1.MTc3MDQzODc5Mw==.ozlKKwEEZpoGVuNzPDCyOMRGv",
+            )
+            .await;
+
+        response.assert_status(expected_status);
+
+        let paste = Paste::fetch(&pool, &Snowflake::new(517_815_304_354_284_605))
+            .await
+            .expect("Failed to make DB request");
+
+        assert!(paste.is_some(), "Paste was deleted.");
+    }
+
+    #[rstest]
+    #[case(true, StatusCode::OK)]
+    #[case(false, StatusCode::NOT_FOUND)]
+    #[sqlx::test]
+    async fn test_trim_trailing_slash(
+        #[ignore] pool: PgPool,
+        #[case] trim_trailing_slash: bool,
+        #[case] expected_status: StatusCode,
+    ) {
+        let config = Config::test_builder()
+            .trim_trailing_slash(trim_trailing_slash)
+            .build()
+            .expect("Failed to build config.");
+        let object_store = MemoryObjectStore::new();
+        let state = ApplicationState::new_tests(config.clone(), pool, object_store.clone())
+            .await
+            .expect("Failed to build application state.");
+
+        let server = TestServer::new(generate_router(state));
+
+        let response = server.get("/v1/information/configuration").await;
+
+        response.assert_status_ok();
+
+        let response = server.get("/v1/information/configuration/").await;
+
+        response.assert_status(expected_status);
+
+        if expected_status == StatusCode::NOT_FOUND {
+            let body: RESTErrorResponse = response.json();
+
+            assert_eq!(
+                body.message(),
+                "This endpoint does not exist.",
+                "Message does not match."
+            );
+        }
+    }
+
+    #[sqlx::test(fixtures(path = "../../tests/fixtures", scripts("pastes", "documents")))]
+    async fn test_request_id(pool: PgPool) {
+        let config = Config::test_builder()
+            .build()
+            .expect("Failed to build config.");
+        let object_store = MemoryObjectStore::new();
+        let state = ApplicationState::new_tests(config.clone(), pool, object_store.clone())
+            .await
+            .expect("Failed to build application state.");
+
+        let server = TestServer::new(generate_router(state));
+
+        let response = server
+            .get("/v1/pastes/517815304354284605")
+            .add_header("X-Request-Id", "test-request-id")
+            .await;
+
+        response.assert_status_ok();
+        response.assert_header("X-Request-Id", "test-request-id");
+
+        let response = server
+            .get("/v1/pastes/517815304354284999")
+            .add_header("X-Request-Id", "test-request-id")
+            .await;
+
+        response.assert_status(StatusCode::NOT_FOUND);
+        response.assert_header("X-Request-Id", "test-request-id");
+
+        let body: RESTErrorResponse = response.json();
+
+        assert_eq!(
+            body.request_id(),
+            Some("test-request-id"),
+            "Request ID does not match."
+        );
+
+        let response = server.get("/v1/this/does/not/exist").await;
+
+        response.assert_status(StatusCode::NOT_FOUND);
+
+        let request_id = response.header("X-Request-Id");
+        let request_id = request_id.to_str().expect("Failed to read request ID.");
+
+        assert_eq!(request_id.len(), 36, "Request ID is not a UUID.");
+
+        let body: RESTErrorResponse = response.json();
+
+        assert_eq!(
+            body.request_id(),
+            Some(request_id),
+            "Generated request ID does not match."
+        );
+    }
+
+    #[rstest]
+    #[case(Some("gzip"), "\"abc\"", "W/\"abc\"")]
+    #[case(Some("gzip"), "W/\"abc\"", "W/\"abc\"")]
+    #[case(None, "\"abc\"", "\"abc\"")]
+    #[tokio::test]
+    async fn test_weaken_compressed_etag(
+        #[case] encoding: Option<&'static str>,
+        #[case] etag: &'static str,
+        #[case] expected: &'static str,
+    ) {
+        let mut response = Response::new(Body::empty());
+        response
+            .headers_mut()
+            .insert(header::ETAG, HeaderValue::from_static(etag));
+
+        if let Some(encoding) = encoding {
+            response
+                .headers_mut()
+                .insert(header::CONTENT_ENCODING, HeaderValue::from_static(encoding));
+        }
+
+        let response = weaken_compressed_etag(response).await;
+
+        assert_eq!(
+            response.headers().get(header::ETAG),
+            Some(&HeaderValue::from_static(expected)),
+            "ETag does not match."
+        );
+    }
+}
@@ -0,0 +1,156 @@
+//! The negotiated response, used to serialize responses in the format the client accepts.
+
+use std::convert::Infallible;
+
+use axum::{
+    Json,
+    extract::FromRequestParts,
+    response::{IntoResponse, Response},
+};
+use http::{
+    HeaderMap, HeaderValue,
+    header::{ACCEPT, CONTENT_TYPE, VARY},
+    request::Parts,
+};
+use serde::Serialize;
+
+use crate::models::errors::RESTError;
+
+/// ## `MessagePack` Mime
+///
+/// The mime of responses serialized with `MessagePack`.
+pub const APPLICATION_MSGPACK: &str = "application/msgpack";
+
+/// ## Response Format
+///
+/// The format a response is serialized with, negotiated from the `Accept` header.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ResponseFormat {
+    /// Serialized as JSON.
+    #[default]
+    Json,
+    /// Serialized as `MessagePack`.
+    MessagePack,
+}
+
+impl<S: Send + Sync> FromRequestParts<S> for ResponseFormat {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _: &S) -> Result<Self, Self::Rejection> {
+        Ok(negotiate_format(&parts.headers))
+    }
+}
+
+/// Negotiate Format.
+///
+/// Find the format the client prefers, from the `Accept` header.
+///
+/// `MessagePack` is only used when it is preferred over JSON,
+/// with ties going to whichever is listed first.
+///
+/// ## Arguments
+///
+/// - `headers` - The headers of the request.
+///
+/// ## Returns
+///
+/// The [`ResponseFormat`] to use, defaulting to JSON.
+fn negotiate_format(headers: &HeaderMap) -> ResponseFormat {
+    let mut preferred: Option<(ResponseFormat, f32)> = None;
+
+    let media_ranges = headers
+        .get_all(ACCEPT)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','));
+
+    for media_range in media_ranges {
+        let mut parameters = media_range.split(';').map(str::trim);
+
+        let format = match parameters.next().map(str::to_ascii_lowercase).as_deref() {
+            Some("application/json") => ResponseFormat::Json,
+            Some(APPLICATION_MSGPACK | "application/x-msgpack") => ResponseFormat::MessagePack,
+            _ => continue,
+        };
+
+        let quality = parameters
+            .find_map(|parameter| parameter.strip_prefix("q="))
+            .and_then(|quality| quality.parse().ok())
+            .unwrap_or(1.0);
+
+        if quality > 0.0 && preferred.is_none_or(|(_, preferred)| quality > preferred) {
+            preferred = Some((format, quality));
+        }
+    }
+
+    preferred.map(|(format, _)| format).unwrap_or_default()
+}
+
+/// ## Negotiated
+///
+/// A response serialized with the format negotiated with the client.
+pub struct Negotiated<T>(pub ResponseFormat, pub T);
+
+impl<T: Serialize> IntoResponse for Negotiated<T> {
+    fn into_response(self) -> Response {
+        let Self(format, value) = self;
+
+        let mut response = match format {
+            ResponseFormat::Json => Json(value).into_response(),
+            ResponseFormat::MessagePack => match rmp_serde::to_vec_named(&value) {
+                Ok(bytes) => (
+                    [(CONTENT_TYPE, HeaderValue::from_static(APPLICATION_MSGPACK))],
+                    bytes,
+                )
+                    .into_response(),
+                Err(e) => return RESTError::internal_server(e).into_response(),
+            },
+        };
+
+        response
+            .headers_mut()
+            .append(VARY, HeaderValue::from_static("accept"));
+
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(None, ResponseFormat::Json)]
+    #[case(Some("application/json"), ResponseFormat::Json)]
+    #[case(Some("application/msgpack"), ResponseFormat::MessagePack)]
+    #[case(Some("application/x-msgpack"), ResponseFormat::MessagePack)]
+    #[case(Some("application/json, application/msgpack"), ResponseFormat::Json)]
+    #[case(
+        Some("application/msgpack, application/json"),
+        ResponseFormat::MessagePack
+    )]
+    #[case(
+        Some("application/json;q=0.5, application/msgpack"),
+        ResponseFormat::MessagePack
+    )]
+    #[case(Some("application/msgpack;q=0"), ResponseFormat::Json)]
+    #[case(Some("text/html, */*"), ResponseFormat::Json)]
+    fn test_negotiate_format(#[case] accept: Option<&str>, #[case] expected: ResponseFormat) {
+        let mut headers = HeaderMap::new();
+
+        if let Some(accept) = accept {
+            headers.insert(
+                ACCEPT,
+                HeaderValue::from_str(accept).expect("Failed to build header."),
+            );
+        }
+
+        assert_eq!(
+            negotiate_format(&headers),
+            expected,
+            "Format does not match."
+        );
+    }
+}
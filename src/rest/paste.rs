@@ -1,31 +1,65 @@
 //! Paste related endpoints and router generator.
 
+use std::collections::HashMap;
+
 use axum::{
     Json, Router,
-    extract::{DefaultBodyLimit, Path, State},
-    http::StatusCode,
+    extract::{DefaultBodyLimit, Path, Query, State},
+    http::{HeaderMap, HeaderName, HeaderValue, StatusCode},
     routing::{delete, get, patch, post},
 };
-use chrono::{TimeDelta, Timelike, Utc};
+use axum_extra::{
+    TypedHeader,
+    headers::{IfMatch, IfUnmodifiedSince},
+};
+use chrono::{SubsecRound as _, TimeDelta, Utc};
+use mime::Mime;
+use secrecy::ExposeSecret as _;
 
 use crate::{
-    app::{application::App, config::Config, object_store::ObjectStoreExt as _},
+    app::{
+        application::App,
+        config::{Config, TimestampPrecision},
+        object_store::ObjectStoreExt as _,
+        source::fetch_source,
+        webhook::{record_view, validate_webhook_url},
+    },
     models::{
         DtUtc,
-        authentication::{Token, generate_token},
-        document::{Document, DocumentUpdateParameters, total_document_limits},
-        errors::{AuthenticationError, RESTError},
-        paste::{Paste, PasteUpdateParameters, validate_paste},
+        authentication::{
+            PASTE_TOKEN_HEADER, PastePassword, Token, enforce_token_maximum, generate_token,
+            hash_password,
+        },
+        document::{
+            Document, DocumentUpdateParameters, document_limits, enforce_maximums,
+            enforce_minimums, enforce_owner_maximum, enforce_unique_names, generate_checksum,
+            is_text_mime, normalize_content, total_document_maximums, total_document_minimums,
+            validate_tags, validate_unique_names,
+        },
+        errors::{AuthenticationError, RESTError, ValidationErrors},
+        paste::{
+            APPLIED_DEFAULTS_HEADER, Paste, PasteUpdateParameters, expand_paste_name,
+            validate_paste, validate_paste_password, validate_unique_name,
+        },
         payload::{
             document::PostPasteDocumentBody,
             paste::{
-                DeletePastePath, GetPastePath, PatchPasteMultipartBody, PatchPastePath,
-                PostPasteMultipartBody, ResponsePaste,
+                DeletePastePath, ExportPastePath, GetPasteAnalyticsPath, GetPasteAnalyticsQuery,
+                GetPastePath, PasteBundle, PatchPasteMultipartBody, PatchPastePath, PostPasteBody,
+                PostPasteImportBody, PostPasteMultipartBody, PostPasteQuery,
+                PostPasteValidateQuery, PostPastesBatchBody, ResponsePaste, ResponsePasteAnalytics,
+                ResponsePasteSummary, ResponsePasteValidation, RestorePastePath,
+                RotatePasteTokenPath, TokenLocation,
             },
         },
         snowflake::Snowflake,
         undefined::{Undefined, UndefinedOption},
     },
+    rest::{
+        client_ip::ClientIp,
+        negotiated::{Negotiated, ResponseFormat},
+        validate_if_match, validate_if_unmodified_since,
+    },
 };
 
 /// ## Generate Router
@@ -38,10 +72,20 @@ pub fn generate_router(config: &Config) -> Router<App> {
     Router::new()
         .route("/pastes/{paste_id}", get(get_paste))
         .route("/pastes", post(post_paste))
+        .route("/pastes/batch", post(post_pastes_batch))
+        .route("/pastes/validate", post(post_paste_validate))
+        .route("/pastes/import", post(post_paste_import))
+        .route("/pastes/{paste_id}/export", get(get_paste_export))
+        .route("/pastes/{paste_id}/analytics", get(get_paste_analytics))
         .route("/pastes/{paste_id}", patch(patch_paste))
         .route("/pastes/{paste_id}", delete(delete_paste))
+        .route("/pastes/{paste_id}/restore", post(post_paste_restore))
+        .route(
+            "/pastes/{paste_id}/token/rotate",
+            post(post_paste_token_rotate),
+        )
         .layer(DefaultBodyLimit::max(
-            config.size_limits().maximum_total_document_size(),
+            config.size_limits().maximum_request_body_size(),
         ))
 }
 
@@ -53,137 +97,426 @@ pub fn generate_router(config: &Config) -> Router<App> {
 ///
 /// - `paste_id` - The pastes ID.
 ///
+/// ## Headers
+///
+/// - `X-Paste-Password` - The pastes password (required if the paste is password protected).
+/// - `Authorization` - The pastes token (optional), views from the owner are not counted unless enabled.
+///
+/// Repeated views from the same address are only counted once within the view dedup window.
+///
 /// ## Errors
 /// Returns an error if the request failed.
 ///
 /// ## Returns
 ///
+/// - `400` - The paste ID is not a valid snowflake.
+/// - `401` - The pastes password is missing or incorrect, or the token is invalid.
 /// - `404` - The paste was not found.
+/// - `410` - The paste has expired, or reached its maximum views.
 /// - `200` - The [`ResponsePaste`] object.
 pub async fn get_paste(
     State(app): State<App>,
+    format: ResponseFormat,
     Path(path): Path<GetPastePath>,
-) -> Result<(StatusCode, Json<ResponsePaste>), RESTError> {
+    ClientIp(client_ip): ClientIp,
+    token: Option<Token>,
+    password: PastePassword,
+) -> Result<(StatusCode, Negotiated<ResponsePaste>), RESTError> {
+    path.validate(app.config())?;
+
     let mut paste = validate_paste(app.database(), path.paste_id(), None).await?;
 
+    validate_paste_password(&paste, &password)?;
+
     let documents = Document::fetch_all(app.database().pool(), paste.id()).await?;
 
-    paste.add_view(app.database().pool()).await?;
+    let is_owner = token.is_some_and(|token| token.paste_id() == paste.id());
+
+    if app.config().count_owner_views() || !is_owner {
+        let is_counted = match client_ip {
+            Some(address) => app.view_tracker().record_view(paste.id(), address).await,
+            None => true,
+        };
+
+        if is_counted {
+            record_view(&app, &mut paste, client_ip).await?;
+        }
+    }
+
+    let paste_response = ResponsePaste::from_paste(&paste, None, documents, app.config().domain());
+
+    Ok((StatusCode::OK, Negotiated(format, paste_response)))
+}
+
+/// Get Paste Analytics.
+///
+/// Get the views of an existing paste over time.
+///
+/// Only views counted while view analytics are enabled are included.
+///
+/// **Requires authentication.**
+///
+/// ## Path
+///
+/// - `paste_id` - The pastes ID.
+///
+/// ## Query
+///
+/// - `interval` - The interval the views are bucketed by, either `hour` or `day` (defaults to `hour`).
+///
+/// ## Errors
+/// Returns an error if the request failed.
+///
+/// ## Returns
+///
+/// - `400` - The paste ID is not a valid snowflake, or the interval is invalid.
+/// - `401` - Invalid token and/or paste ID.
+/// - `404` - The paste was not found.
+/// - `410` - The paste has expired, or reached its maximum views.
+/// - `200` - The [`ResponsePasteAnalytics`] object.
+pub async fn get_paste_analytics(
+    State(app): State<App>,
+    format: ResponseFormat,
+    Path(path): Path<GetPasteAnalyticsPath>,
+    Query(query): Query<GetPasteAnalyticsQuery>,
+    token: Token,
+) -> Result<(StatusCode, Negotiated<ResponsePasteAnalytics>), RESTError> {
+    path.validate(app.config())?;
+
+    let paste = validate_paste(app.database(), path.paste_id(), Some(token)).await?;
+
+    let buckets =
+        Paste::fetch_view_buckets(app.database().pool(), paste.id(), query.interval()).await?;
+
+    Ok((
+        StatusCode::OK,
+        Negotiated(
+            format,
+            ResponsePasteAnalytics::new(query.interval(), buckets),
+        ),
+    ))
+}
 
-    let paste_response = ResponsePaste::from_paste(&paste, None, documents);
+/// Post Pastes Batch.
+///
+/// Get multiple existing pastes at once.
+///
+/// Pastes that do not exist, have expired, or are password protected are omitted.
+/// This does not count as a view.
+///
+/// ## Body
+///
+/// References: [`PostPastesBatchBody`]
+///
+/// - `ids` - The IDs of the pastes to fetch.
+///
+/// ## Errors
+/// Returns an error if the request failed.
+///
+/// ## Returns
+///
+/// - `400` - Too many pastes were requested.
+/// - `200` - A list of [`ResponsePasteSummary`] objects.
+pub async fn post_pastes_batch(
+    State(app): State<App>,
+    body: PostPastesBatchBody,
+) -> Result<(StatusCode, Json<Vec<ResponsePasteSummary>>), RESTError> {
+    let maximum_batch_paste_count = app.config().size_limits().maximum_batch_paste_count();
+
+    if body.ids().len() > maximum_batch_paste_count {
+        return Err(RESTError::bad_request(format!(
+            "Too many pastes were requested. Expected: {maximum_batch_paste_count}, Received: {}",
+            body.ids().len()
+        )));
+    }
 
-    Ok((StatusCode::OK, Json(paste_response)))
+    let now = Utc::now();
+
+    let mut pastes: HashMap<Snowflake, Paste> =
+        Paste::fetch_many(app.database().pool(), body.ids())
+            .await?
+            .into_iter()
+            .filter(|paste| {
+                paste.expiry().is_none_or(|expiry| *expiry >= now)
+                    && paste
+                        .max_views()
+                        .is_none_or(|max_views| paste.views() < max_views)
+                    && paste.password_hash().is_none()
+            })
+            .map(|paste| (*paste.id(), paste))
+            .collect();
+
+    let summaries = body
+        .ids()
+        .iter()
+        .filter_map(|id| pastes.remove(id))
+        .map(|paste| ResponsePasteSummary::from_paste(&paste))
+        .collect();
+
+    Ok((StatusCode::OK, Json(summaries)))
 }
 
 /// Post Paste.
 ///
 /// Create a new paste.
 ///
-/// The first object in the multipart must be the body object.
+/// The multipart must contain a `payload` field (the body object),
+/// and a `files[{id}]` field for each document listed in the payload.
+///
+/// The fields can be provided in any order.
 ///
-/// The following items will be the documents.
+/// A document can list a `source_url` instead of having a field,
+/// which the contents are fetched from (when the host is allowed).
 ///
 /// ## Body
 ///
 /// References: [`PostPasteBody`]
 ///
 /// - `expiry` - The expiry of the paste.
+/// - `password` - The password required to read the paste.
+/// - `webhook_url` - The URL view milestones of the paste are sent to (when the host is allowed).
+///
+/// ## Query
+///
+/// - `token_in` - Where the new pastes token is returned, either `body` (default) or `header`.
+/// - `validate` - Whether only the `first` (default) or `all` validation failures are returned.
+///
+/// ## Headers
+///
+/// - `Authorization` - The token of an existing paste, whose owner will also own the new paste (optional).
+///
+/// The fields that were omitted and had a default applied (such as `expiry,max_views`),
+/// are listed in the `X-Applied-Defaults` response header.
 ///
 /// ## Errors
 /// Returns an error if the request failed.
 ///
 /// ## Returns
 ///
-/// - `400` - The body and/or documents are invalid.
-/// - `200` - The [`ResponsePaste`] object.
+/// - `400` - The body and/or documents are malformed.
+/// - `401` - The token provided is invalid.
+/// - `409` - Another paste already has the name (when unique paste names are enabled).
+/// - `422` - The expiry and/or documents are outside of the limits, with every failure listed (when all are requested).
+/// - `429` - The address has created the maximum amount of pastes for the day.
+/// - `200` - The [`ResponsePaste`] object, with the token in the `X-Paste-Token` header instead of the body when requested.
 pub async fn post_paste(
     State(app): State<App>,
+    format: ResponseFormat,
+    Query(query): Query<PostPasteQuery>,
+    ClientIp(client_ip): ClientIp,
+    token: Option<Token>,
     body: PostPasteMultipartBody,
-) -> Result<(StatusCode, Json<ResponsePaste>), RESTError> {
-    let name = {
-        match body.payload.name() {
-            UndefinedOption::Undefined => app
-                .config()
-                .size_limits()
-                .default_paste_name()
-                .map(ToString::to_string),
-            UndefinedOption::Some(name) => {
-                let name = name.to_string();
-
-                if name.len() > app.config().size_limits().maximum_paste_name_size() {
-                    return Err(RESTError::bad_request("The pastes name is too long."));
-                }
+) -> Result<(StatusCode, HeaderMap, Negotiated<ResponsePaste>), RESTError> {
+    if let Some(address) = client_ip
+        && !app.creation_limiter().record_creation(address).await
+    {
+        return Err(RESTError::too_many_requests(
+            "The maximum amount of pastes for today has been created.",
+        ));
+    }
 
-                if name.len() < app.config().size_limits().minimum_paste_name_size() {
-                    return Err(RESTError::bad_request("The pastes name is too short."));
-                }
+    let PostPasteMultipartBody {
+        payload,
+        mut documents,
+        sources,
+        mut errors,
+    } = body;
 
-                Some(name)
+    let created = async {
+        fetch_sources(app.config(), sources, &mut documents, &mut errors).await?;
+
+        create_paste(&app, token, &payload, documents, errors).await
+    }
+    .await;
+
+    // A rejected paste does not count towards the clients daily maximum.
+    let (mut response, defaulted) = match created {
+        Ok(created) => created,
+        Err(e) => {
+            if let Some(address) = client_ip {
+                app.creation_limiter().release_creation(address).await;
             }
-            UndefinedOption::None => None,
+
+            return Err(e);
         }
     };
 
-    let expiry = validate_expiry(app.config(), body.payload.expiry())?;
+    let mut headers = HeaderMap::new();
 
-    let max_views = match body.payload.max_views() {
-        UndefinedOption::Some(views) => Some(views),
-        UndefinedOption::Undefined => app.config().size_limits().default_maximum_views(),
-        UndefinedOption::None => None,
-    };
+    if !defaulted.is_empty() {
+        headers.insert(
+            HeaderName::from_static(APPLIED_DEFAULTS_HEADER),
+            HeaderValue::from_str(&defaulted.join(",")).map_err(RESTError::internal_server)?,
+        );
+    }
 
-    let mut transaction = app.database().pool().begin().await?;
+    if query.token_in() == TokenLocation::Header
+        && let Some(paste_token) = response.take_token()
+    {
+        headers.insert(
+            HeaderName::from_static(PASTE_TOKEN_HEADER),
+            HeaderValue::from_str(&paste_token).map_err(RESTError::internal_server)?,
+        );
+    }
 
-    let paste = Paste::new(
-        Snowflake::generate()?,
-        name,
-        Utc::now().with_nanosecond(0).ok_or_else(|| {
-            RESTError::internal_server("Failed to strip nanosecond from date time object.")
-        })?,
+    Ok((StatusCode::OK, headers, Negotiated(format, response)))
+}
+
+/// Post Paste Validate.
+///
+/// Validate a new paste, without creating it.
+///
+/// Accepts the same multipart as [`post_paste`], and performs the same validation,
+/// without storing the paste or its documents.
+///
+/// ## Body
+///
+/// References: [`PostPasteBody`]
+///
+/// - `expiry` - The expiry of the paste.
+/// - `password` - The password required to read the paste.
+///
+/// ## Query
+///
+/// - `validate` - Whether only the `first` (default) or `all` validation failures are returned.
+///
+/// ## Errors
+/// Returns an error if the request failed.
+///
+/// ## Returns
+///
+/// - `400` - The body and/or documents are malformed.
+/// - `409` - Another paste already has the name (when unique paste names are enabled).
+/// - `422` - The expiry and/or documents are outside of the limits, with every failure listed (when all are requested).
+/// - `200` - The [`ResponsePasteValidation`] object.
+pub async fn post_paste_validate(
+    State(app): State<App>,
+    Query(_): Query<PostPasteValidateQuery>,
+    body: PostPasteMultipartBody,
+) -> Result<(StatusCode, Json<ResponsePasteValidation>), RESTError> {
+    let PostPasteMultipartBody {
+        payload,
+        mut documents,
+        sources,
+        mut errors,
+    } = body;
+
+    fetch_sources(app.config(), sources, &mut documents, &mut errors).await?;
+
+    let validated = validate_paste_creation(app.config(), &payload, &documents, errors)?;
+
+    validate_unique_name(
+        app.database().pool(),
+        app.config(),
+        validated.name.as_deref(),
         None,
-        expiry.into(),
-        0,
-        max_views,
+    )
+    .await?;
+
+    let response = ResponsePasteValidation::new(
+        validated.name,
+        validated.expiry.into(),
+        validated.max_views,
+        documents.len(),
+        documents.iter().map(|(_, content, _)| content.len()).sum(),
     );
 
-    paste.insert(transaction.as_mut()).await?;
+    Ok((StatusCode::OK, Json(response)))
+}
 
-    let mut response_documents = Vec::new();
-    for (body, content, mime) in body.documents {
-        let mime_string = mime.to_string();
+/// Get Paste Export.
+///
+/// Export an existing paste as a [`PasteBundle`], including the contents of its documents.
+///
+/// The pastes password is not included.
+///
+/// An export counts as exactly one view, claimed once all of the documents contents have been fetched.
+/// If the paste reached its maximum views in the meantime, nothing is returned.
+///
+/// **Requires authentication.**
+///
+/// ## Path
+///
+/// - `paste_id` - The pastes ID.
+///
+/// ## Errors
+/// Returns an error if the request failed.
+///
+/// ## Returns
+///
+/// - `400` - The paste ID is not a valid snowflake.
+/// - `401` - Invalid token and/or paste ID.
+/// - `404` - The paste, or a documents contents were not found.
+/// - `410` - The paste has expired, or reached its maximum views.
+/// - `200` - The [`PasteBundle`] object.
+pub async fn get_paste_export(
+    State(app): State<App>,
+    Path(path): Path<ExportPastePath>,
+    token: Token,
+) -> Result<(StatusCode, Json<PasteBundle>), RESTError> {
+    path.validate(app.config())?;
 
-        let document = Document::new(
-            Snowflake::generate()?,
-            *paste.id(),
-            &mime_string,
-            body.name(),
-            content.len(),
-        );
+    let mut paste = validate_paste(app.database(), path.paste_id(), Some(token)).await?;
 
-        app.object_store()
-            .create_document(&document, content)
-            .await?;
+    let documents = Document::fetch_all(app.database().pool(), paste.id()).await?;
 
-        document.insert(transaction.as_mut()).await?;
+    let mut bundle_documents = Vec::new();
+    for document in documents {
+        let content = app
+            .object_store()
+            .fetch_document(&document)
+            .await?
+            .ok_or_else(|| RESTError::not_found("Document not found."))?;
 
-        response_documents.push(document);
+        bundle_documents.push((document, content));
     }
 
-    total_document_limits(&mut transaction, app.config(), paste.id()).await?;
-
-    let paste_token = Token::new(*paste.id(), generate_token(*paste.id())?);
-
-    paste_token.insert(transaction.as_mut()).await?;
-
-    if let Some(expiry) = paste.expiry() {
-        app.handler().add(paste.id(), *expiry).await?;
+    if !paste.claim_view(app.database().pool()).await? {
+        return Err(RESTError::gone(
+            "The paste requested has reached its maximum views",
+        ));
     }
 
-    transaction.commit().await?;
+    Ok((
+        StatusCode::OK,
+        Json(PasteBundle::from_paste(&paste, bundle_documents)),
+    ))
+}
 
-    let response = ResponsePaste::from_paste(&paste, Some(paste_token), response_documents);
+/// Post Paste Import.
+///
+/// Create a new paste from a [`PasteBundle`].
+///
+/// The paste and its documents are given new IDs, and a new token.
+///
+/// ## Body
+///
+/// References: [`PasteBundle`]
+///
+/// ## Errors
+/// Returns an error if the request failed.
+///
+/// ## Returns
+///
+/// - `400` - The bundle and/or documents are invalid.
+/// - `409` - Another paste already has the name (when unique paste names are enabled).
+/// - `422` - The expiry and/or documents are outside of the limits.
+/// - `200` - The [`ResponsePaste`] object.
+pub async fn post_paste_import(
+    State(app): State<App>,
+    format: ResponseFormat,
+    body: PostPasteImportBody,
+) -> Result<(StatusCode, Negotiated<ResponsePaste>), RESTError> {
+    let (response, _) = create_paste(
+        &app,
+        None,
+        &body.payload,
+        body.documents,
+        ValidationErrors::new(false),
+    )
+    .await?;
 
-    Ok((StatusCode::OK, Json(response)))
+    Ok((StatusCode::OK, Negotiated(format, response)))
 }
 
 /// Patch Paste.
@@ -196,24 +529,41 @@ pub async fn post_paste(
 ///
 /// - `paste_id` - The paste ID to edit.
 ///
+/// ## Headers
+///
+/// - `If-Match` - The `ETag` of the pastes version being edited (optional).
+/// - `If-Unmodified-Since` - The time the paste must not have been edited since (optional).
+///
 /// ## Errors
 /// Returns an error if the request failed.
 ///
 /// ## Returns
 ///
 /// - `401` - Invalid token and/or paste ID.
-/// - `400` - The body is invalid.
+/// - `400` - The paste ID is not a valid snowflake, or the body is invalid.
+/// - `409` - Another paste already has the name (when unique paste names are enabled).
+/// - `412` - The paste has been edited since the version or time provided.
+/// - `422` - The expiry and/or documents are outside of the limits.
 /// - `200` - The [`ResponsePaste`] object.
 #[expect(clippy::too_many_lines)]
 pub async fn patch_paste(
     State(app): State<App>,
+    format: ResponseFormat,
     Path(path): Path<PatchPastePath>,
     token: Token,
+    if_match: Option<TypedHeader<IfMatch>>,
+    if_unmodified_since: Option<TypedHeader<IfUnmodifiedSince>>,
     body: PatchPasteMultipartBody,
-) -> Result<(StatusCode, Json<ResponsePaste>), RESTError> {
+) -> Result<(StatusCode, Negotiated<ResponsePaste>), RESTError> {
+    path.validate(app.config())?;
+
     let mut paste = validate_paste(app.database(), path.paste_id(), Some(token)).await?;
 
-    let expiry = validate_expiry(app.config(), body.payload.expiry())?;
+    validate_if_match(if_match.as_deref(), &paste.generate_etag())?;
+
+    validate_if_unmodified_since(if_unmodified_since.as_deref(), paste.last_modified())?;
+
+    let expiry = validate_expiry(app.config(), body.payload.expiry(), Some(paste.creation()))?;
 
     let mut documents = Document::fetch_all(app.database().pool(), path.paste_id()).await?;
 
@@ -237,9 +587,7 @@ pub async fn patch_paste(
     let max_views = match body.payload.max_views() {
         UndefinedOption::Some(max_views) => {
             if paste.views() >= max_views {
-                return Err(RESTError::bad_request(
-                    "You cannot set the maximum views to a value equal to or lower than the current view count.",
-                ));
+                return Err(RESTError::MaxViewsBelowCurrent(paste.views()));
             }
 
             UndefinedOption::Some(max_views)
@@ -249,6 +597,16 @@ pub async fn patch_paste(
 
     let mut transaction = app.database().pool().begin().await?;
 
+    if let UndefinedOption::Some(name) = &name {
+        validate_unique_name(
+            transaction.as_mut(),
+            app.config(),
+            Some(name),
+            Some(paste.id()),
+        )
+        .await?;
+    }
+
     paste
         .update(
             transaction.as_mut(),
@@ -302,9 +660,16 @@ pub async fn patch_paste(
         documents = new_documents;
     }
 
+    // The contents to store, and the previous versions of replaced documents, kept until the
+    // new contents are stored.
+    let mut contents = Vec::new();
+    let mut previous_documents = Vec::new();
+
     if let Undefined::Some(multipart_documents) = body.documents {
         for (body, content, mime) in multipart_documents {
             if let Some(document) = documents.iter_mut().find(|v| v.id() == body.id()) {
+                previous_documents.push(document.clone());
+
                 document
                     .update(
                         transaction.as_mut(),
@@ -312,45 +677,68 @@ pub async fn patch_paste(
                             Undefined::Some(mime.to_string()),
                             body.name().map(ToString::to_string),
                             Undefined::Some(content.len()),
+                            Undefined::Some(generate_checksum(content.as_bytes())),
+                            body.tags().map(<[String]>::to_vec),
                         ),
                     )
                     .await?;
 
-                app.object_store().delete_document(document).await?;
-                app.object_store()
-                    .create_document(document, content)
-                    .await?;
+                contents.push((document.clone(), content));
             } else {
                 let body: PostPasteDocumentBody = body.try_into()?;
 
                 let document = Document::new(
-                    Snowflake::generate()?,
+                    Snowflake::generate_unique(transaction.as_mut(), app.config()).await?,
                     *paste.id(),
                     mime.as_ref(),
                     body.name(),
                     content.len(),
+                    Some(&generate_checksum(content.as_bytes())),
+                    body.tags(),
                 );
 
                 document.insert(transaction.as_mut()).await?;
 
-                app.object_store()
-                    .create_document(&document, content)
-                    .await?;
+                contents.push((document.clone(), content));
 
                 documents.push(document);
             }
         }
     }
 
+    enforce_maximums(&mut transaction, app.config(), paste.id()).await?;
+
+    enforce_owner_maximum(&mut transaction, app.config(), paste.owner_id()).await?;
+
+    enforce_unique_names(&mut transaction, app.config(), paste.id()).await?;
+
+    // Every limit is checked before the object store is touched, and the new contents are stored
+    // before the previous contents are removed, so a failure never leaves a document without contents.
+    for (document, content) in contents {
+        app.object_store()
+            .create_document(&document, content)
+            .await?;
+    }
+
     if let Some(expiry) = paste.expiry() {
         app.handler().add(paste.id(), *expiry).await?;
     }
 
     transaction.commit().await?;
 
-    let paste_response = ResponsePaste::from_paste(&paste, None, documents);
+    // When renamed, the previous contents are stored under a different path.
+    let name_case = app.config().document_name_case();
+    for previous in previous_documents {
+        if let Some(document) = documents.iter().find(|v| v.id() == previous.id())
+            && previous.generate_path(name_case) != document.generate_path(name_case)
+        {
+            app.object_store().delete_document(&previous).await?;
+        }
+    }
+
+    let paste_response = ResponsePaste::from_paste(&paste, None, documents, app.config().domain());
 
-    Ok((StatusCode::OK, Json(paste_response)))
+    Ok((StatusCode::OK, Negotiated(format, paste_response)))
 }
 
 /// Delete Paste.
@@ -369,131 +757,633 @@ pub async fn patch_paste(
 ///
 /// - `expiry` - The expiry of the paste.
 ///
+/// ## Headers
+///
+/// - `If-Unmodified-Since` - The time the paste must not have been edited since (optional).
+///
 /// ## Errors
 /// Returns an error if the request failed.
 ///
 /// ## Returns
 ///
+/// - `400` - The paste ID is not a valid snowflake.
 /// - `401` - Invalid token and/or paste ID.
+/// - `404` - The paste was not found.
+/// - `412` - The paste has been edited since the time provided.
 /// - `204` - Successful deletion of the paste.
 pub async fn delete_paste(
     State(app): State<App>,
     Path(path): Path<DeletePastePath>,
     token: Token,
+    if_unmodified_since: Option<TypedHeader<IfUnmodifiedSince>>,
 ) -> Result<StatusCode, RESTError> {
+    path.validate(app.config())?;
+
     if token.paste_id() != path.paste_id() {
         return Err(RESTError::Authentication(
             AuthenticationError::InvalidCredentials,
         ));
     }
 
-    let mut transaction = app.database().pool().begin().await?;
+    if let Some(TypedHeader(if_unmodified_since)) = if_unmodified_since {
+        let paste = Paste::fetch(app.database().pool(), path.paste_id())
+            .await?
+            .ok_or_else(|| RESTError::not_found("The paste was not found."))?;
+
+        validate_if_unmodified_since(Some(&if_unmodified_since), paste.last_modified())?;
+    }
 
-    if !Paste::delete(transaction.as_mut(), path.paste_id()).await? {
+    let deleted = if app.config().deletion_grace_hours() == 0 {
+        Paste::delete_cascade(app.database(), app.object_store(), path.paste_id()).await?
+    } else {
+        Paste::soft_delete(app.database().pool(), path.paste_id()).await?
+    };
+
+    if !deleted {
         return Err(RESTError::not_found("The paste was not found."));
     }
 
     app.handler().remove(path.paste_id()).await?;
 
-    transaction.commit().await?;
-
     Ok(StatusCode::NO_CONTENT)
 }
 
-/// Validate Expiry.
+/// Post Paste Restore.
 ///
-/// Checks if the expiry time is valid (if provided)
-/// Otherwise, if not provided, returns the default, or None.
+/// Restore a deleted paste, within the deletion grace period.
 ///
-/// This will also strip the nanoseconds off the timestamp.
+/// **Requires authentication.**
 ///
-/// ## Arguments
+/// ## Path
 ///
-/// - `config` - The config values to use.
-/// - `expiry` - The expiry to validate (if provided).
+/// - `paste_id` - The pastes ID.
 ///
 /// ## Errors
-///
-/// - [`RESTError`] - The app error returned, if the provided expiry is invalid, or a timestamp was required.
+/// Returns an error if the request failed.
 ///
 /// ## Returns
 ///
-/// - [`UndefinedOption::Some`] - The [`OffsetDateTime`] that was extracted, or defaulted to.
-/// - [`UndefinedOption::Undefined`] - No default set, and it was undefined.
-/// - [`UndefinedOption::None`] - None was given, and no maximum expiry has been set.
-fn validate_expiry(
-    config: &Config,
-    expiry: UndefinedOption<DtUtc>,
-) -> Result<UndefinedOption<DtUtc>, RESTError> {
-    let size_limits = config.size_limits();
-    match expiry {
-        UndefinedOption::Some(expiry) => {
-            let expiry = expiry.with_nanosecond(0).ok_or_else(|| {
-                RESTError::internal_server("Failed to strip nanosecond from date time object.")
-            })?;
-            let now = Utc::now().with_nanosecond(0).ok_or_else(|| {
-                RESTError::internal_server("Failed to strip nanosecond from date time object.")
-            })?;
+/// - `400` - The paste ID is not a valid snowflake.
+/// - `401` - Invalid token and/or paste ID.
+/// - `404` - The paste was not found, or can no longer be restored.
+/// - `200` - The restored [`ResponsePaste`] object.
+pub async fn post_paste_restore(
+    State(app): State<App>,
+    format: ResponseFormat,
+    Path(path): Path<RestorePastePath>,
+    token: Token,
+) -> Result<(StatusCode, Negotiated<ResponsePaste>), RESTError> {
+    path.validate(app.config())?;
 
-            let difference = expiry - now;
+    if token.paste_id() != path.paste_id() {
+        return Err(RESTError::Authentication(
+            AuthenticationError::InvalidCredentials,
+        ));
+    }
 
-            if difference.num_seconds() <= 0 {
-                return Err(RESTError::bad_request(
-                    "The timestamp provided has already passed.",
-                ));
-            }
+    let Some(paste) = Paste::fetch_deleted(app.database().pool(), path.paste_id()).await? else {
+        return Err(RESTError::not_found("The paste was not found."));
+    };
 
-            if let Some(minimum_expiry_hours) = size_limits.minimum_expiry_hours()
-                && difference < TimeDelta::hours(minimum_expiry_hours as i64)
-            {
-                return Err(RESTError::bad_request(
-                    "The timestamp provided is below the minimum.",
-                ));
-            }
+    let grace_period = TimeDelta::hours(app.config().deletion_grace_hours() as i64);
 
-            if let Some(maximum_expiry_hours) = size_limits.maximum_expiry_hours()
-                && difference > TimeDelta::hours(maximum_expiry_hours as i64)
-            {
-                return Err(RESTError::bad_request(
-                    "The timestamp provided is above the maximum.",
-                ));
-            }
+    if let Some(deleted_at) = paste.deleted_at()
+        && *deleted_at + grace_period < Utc::now()
+    {
+        return Err(RESTError::not_found("The paste can no longer be restored."));
+    }
 
-            Ok(UndefinedOption::Some(expiry))
-        }
-        UndefinedOption::Undefined => {
-            if let Some(default_expiry_hours) = size_limits.default_expiry_hours() {
-                return Ok(UndefinedOption::Some(
-                    Utc::now().with_nanosecond(0).ok_or_else(|| {
-                        RESTError::internal_server(
-                            "Failed to strip nanosecond from date time object.",
-                        )
-                    })? + TimeDelta::hours(default_expiry_hours as i64),
+    let mut transaction = app.database().pool().begin().await?;
+
+    if !Paste::restore(transaction.as_mut(), paste.id()).await? {
+        return Err(RESTError::not_found("The paste was not found."));
+    }
+
+    if let Some(expiry) = paste.expiry() {
+        app.handler().add(paste.id(), *expiry).await?;
+    }
+
+    let documents = Document::fetch_all(transaction.as_mut(), paste.id()).await?;
+
+    transaction.commit().await?;
+
+    let paste_response = ResponsePaste::from_paste(&paste, None, documents, app.config().domain());
+
+    Ok((StatusCode::OK, Negotiated(format, paste_response)))
+}
+
+/// Post Paste Token Rotate.
+///
+/// Replace the pastes token with a freshly generated one.
+///
+/// The previous token will no longer be valid.
+///
+/// **Requires authentication.**
+///
+/// ## Path
+///
+/// - `paste_id` - The pastes ID.
+///
+/// ## Errors
+/// Returns an error if the request failed.
+///
+/// ## Returns
+///
+/// - `400` - The paste ID is not a valid snowflake.
+/// - `401` - Invalid token and/or paste ID.
+/// - `404` - The paste was not found.
+/// - `410` - The paste has expired, or reached its maximum views.
+/// - `200` - The [`ResponsePaste`] object, containing the new token.
+pub async fn post_paste_token_rotate(
+    State(app): State<App>,
+    format: ResponseFormat,
+    Path(path): Path<RotatePasteTokenPath>,
+    token: Token,
+) -> Result<(StatusCode, Negotiated<ResponsePaste>), RESTError> {
+    path.validate(app.config())?;
+
+    let paste = validate_paste(app.database(), path.paste_id(), Some(token.clone())).await?;
+
+    let mut transaction = app.database().pool().begin().await?;
+
+    let new_token = token.rotate(&mut transaction).await?;
+
+    let documents = Document::fetch_all(transaction.as_mut(), paste.id()).await?;
+
+    transaction.commit().await?;
+
+    let paste_response =
+        ResponsePaste::from_paste(&paste, Some(new_token), documents, app.config().domain());
+
+    Ok((StatusCode::OK, Negotiated(format, paste_response)))
+}
+
+/// Validate Expiry.
+///
+/// Checks if the expiry time is valid (if provided)
+/// Otherwise, if not provided, returns the default, or None.
+///
+/// This will also truncate the timestamp to the configured precision,
+/// and clamp it to the absolute maximum age (if set).
+///
+/// ## Arguments
+///
+/// - `config` - The config values to use.
+/// - `expiry` - The expiry to validate (if provided).
+/// - `creation` - The creation time of the existing paste, or none for a new paste.
+///
+/// ## Errors
+///
+/// - [`RESTError`] - The app error returned, if the provided expiry is invalid, or a timestamp was required.
+///
+/// ## Returns
+///
+/// - [`UndefinedOption::Some`] - The [`OffsetDateTime`] that was extracted, defaulted to, or clamped to.
+/// - [`UndefinedOption::Undefined`] - No default set, and it was undefined.
+/// - [`UndefinedOption::None`] - None was given, and no maximum expiry has been set.
+fn validate_expiry(
+    config: &Config,
+    expiry: UndefinedOption<DtUtc>,
+    creation: Option<&DtUtc>,
+) -> Result<UndefinedOption<DtUtc>, RESTError> {
+    let size_limits = config.size_limits();
+    let expiry = match expiry {
+        UndefinedOption::Some(expiry) => {
+            let expiry = truncate_timestamp(config, expiry);
+            let now = truncate_timestamp(config, Utc::now());
+
+            let difference = expiry - now;
+
+            if difference.num_seconds() <= 0 {
+                return Err(RESTError::unprocessable(
+                    "The timestamp provided has already passed.",
                 ));
             }
 
-            if size_limits.minimum_expiry_hours().is_some()
+            if let Some(minimum_expiry_hours) = size_limits.minimum_expiry_hours()
+                && difference < TimeDelta::hours(minimum_expiry_hours as i64)
+            {
+                return Err(RESTError::unprocessable(
+                    "The timestamp provided is below the minimum.",
+                ));
+            }
+
+            if let Some(maximum_expiry_hours) = size_limits.maximum_expiry_hours()
+                && difference > TimeDelta::hours(maximum_expiry_hours as i64)
+            {
+                return Err(RESTError::unprocessable(
+                    "The timestamp provided is above the maximum.",
+                ));
+            }
+
+            UndefinedOption::Some(expiry)
+        }
+        UndefinedOption::Undefined => {
+            if let Some(default_expiry_hours) = size_limits.default_expiry_hours() {
+                UndefinedOption::Some(
+                    truncate_timestamp(config, Utc::now())
+                        + TimeDelta::hours(default_expiry_hours as i64),
+                )
+            } else if size_limits.minimum_expiry_hours().is_some()
                 || size_limits.maximum_expiry_hours().is_some()
             {
-                return Err(RESTError::bad_request(
+                return Err(RESTError::unprocessable(
                     "The expiry timestamp parameter is required.",
                 ));
+            } else {
+                UndefinedOption::Undefined
             }
-
-            Ok(UndefinedOption::Undefined)
         }
         UndefinedOption::None => {
             if size_limits.minimum_expiry_hours().is_some()
                 || size_limits.maximum_expiry_hours().is_some()
             {
-                return Err(RESTError::bad_request(
+                return Err(RESTError::unprocessable(
                     "The expiry timestamp parameter cannot be none.",
                 ));
             }
 
-            Ok(UndefinedOption::None)
+            UndefinedOption::None
+        }
+    };
+
+    let Some(absolute_max_age_hours) = config.absolute_max_age_hours() else {
+        return Ok(expiry);
+    };
+
+    let maximum = creation.map_or_else(
+        || truncate_timestamp(config, Utc::now()),
+        |creation| truncate_timestamp(config, *creation),
+    ) + TimeDelta::hours(absolute_max_age_hours as i64);
+
+    // An existing paste keeps its (already clamped) expiry when it is left undefined.
+    Ok(match expiry {
+        UndefinedOption::Some(expiry) => UndefinedOption::Some(expiry.min(maximum)),
+        UndefinedOption::Undefined if creation.is_some() => UndefinedOption::Undefined,
+        UndefinedOption::Undefined | UndefinedOption::None => UndefinedOption::Some(maximum),
+    })
+}
+
+/// Truncate To Second.
+///
+/// Truncate a timestamp down to the second it falls within.
+///
+/// ## Arguments
+///
+/// - `dt` - The timestamp to truncate.
+///
+/// ## Returns
+///
+/// The truncated timestamp.
+fn truncate_to_second(dt: DtUtc) -> DtUtc {
+    dt.trunc_subsecs(0)
+}
+
+/// Truncate Timestamp.
+///
+/// Truncate a timestamp down to the configured precision.
+///
+/// ## Arguments
+///
+/// - `config` - The config values to use.
+/// - `dt` - The timestamp to truncate.
+///
+/// ## Returns
+///
+/// The truncated timestamp.
+fn truncate_timestamp(config: &Config, dt: DtUtc) -> DtUtc {
+    match config.timestamp_precision() {
+        TimestampPrecision::Second => truncate_to_second(dt),
+        TimestampPrecision::Millisecond => dt.trunc_subsecs(3),
+    }
+}
+
+/// ## Validated Paste
+///
+/// The normalized values of a new paste, once validated.
+struct ValidatedPaste {
+    /// The name of the paste.
+    name: Option<String>,
+    /// The expiry of the paste.
+    expiry: UndefinedOption<DtUtc>,
+    /// The maximum views of the paste.
+    max_views: Option<usize>,
+    /// The fields that were omitted, and had a default applied.
+    defaulted: Vec<&'static str>,
+}
+
+/// Validate Paste Creation.
+///
+/// Validate a new paste and its documents, without storing anything.
+///
+/// ## Arguments
+///
+/// - `config` - The config values to use.
+/// - `payload` - The pastes payload.
+/// - `documents` - The documents, alongside their contents and mime.
+/// - `errors` - The validation failures collected so far.
+///
+/// ## Errors
+///
+/// - [`RESTError`] - The payload and/or documents are invalid.
+///
+/// ## Returns
+///
+/// The [`ValidatedPaste`], with defaults applied.
+fn validate_paste_creation(
+    config: &Config,
+    payload: &PostPasteBody,
+    documents: &[(PostPasteDocumentBody, String, Mime)],
+    mut errors: ValidationErrors,
+) -> Result<ValidatedPaste, RESTError> {
+    if config.require_text_document() && !documents.iter().any(|(_, _, mime)| is_text_mime(mime)) {
+        errors.push(
+            "documents",
+            RESTError::bad_request("At least one document must be text."),
+        )?;
+    }
+
+    errors.check(
+        "documents",
+        validate_unique_names(
+            config.document_name_case(),
+            documents.iter().map(|(body, _, _)| body.name()),
+        ),
+    )?;
+
+    let name = match payload.name() {
+        UndefinedOption::Undefined => config
+            .size_limits()
+            .default_paste_name()
+            .map(ToString::to_string),
+        UndefinedOption::Some(name) => {
+            let name = name.to_string();
+
+            if name.len() > config.size_limits().maximum_paste_name_size() {
+                errors.push(
+                    "name",
+                    RESTError::bad_request("The pastes name is too long."),
+                )?;
+            } else if name.len() < config.size_limits().minimum_paste_name_size() {
+                errors.push(
+                    "name",
+                    RESTError::bad_request("The pastes name is too short."),
+                )?;
+            }
+
+            Some(name)
         }
+        UndefinedOption::None => None,
+    };
+
+    let expiry = errors
+        .check("expiry", validate_expiry(config, payload.expiry(), None))?
+        .unwrap_or_default();
+
+    // The default only applies when omitted, and never depends on the expiry.
+    let max_views = match payload.max_views() {
+        UndefinedOption::Some(views) => Some(views),
+        UndefinedOption::Undefined => config.size_limits().default_maximum_views(),
+        UndefinedOption::None => None,
+    };
+
+    if payload
+        .password()
+        .is_some_and(|password| password.expose_secret().is_empty())
+    {
+        errors.push(
+            "password",
+            RESTError::bad_request("The pastes password cannot be empty."),
+        )?;
+    }
+
+    let total_document_size = documents.iter().map(|(_, content, _)| content.len()).sum();
+
+    errors.check(
+        "documents",
+        total_document_maximums(config, documents.len(), total_document_size),
+    )?;
+
+    errors.check(
+        "documents",
+        total_document_minimums(config, documents.len(), total_document_size),
+    )?;
+
+    errors.finish()?;
+
+    let mut defaulted = Vec::new();
+
+    if payload.name().is_undefined() && name.is_some() {
+        defaulted.push("name");
+    }
+
+    if payload.expiry().is_undefined() && expiry.is_some() {
+        defaulted.push("expiry");
+    }
+
+    if payload.max_views().is_undefined() && max_views.is_some() {
+        defaulted.push("max_views");
+    }
+
+    Ok(ValidatedPaste {
+        name,
+        expiry,
+        max_views,
+        defaulted,
+    })
+}
+
+/// Fetch Sources.
+///
+/// Fetch the contents of the documents with a source URL,
+/// and validate them the same as the uploaded documents.
+///
+/// ## Arguments
+///
+/// - `config` - The config values to use.
+/// - `sources` - The documents with a source URL.
+/// - `documents` - The documents the fetched documents are added to.
+/// - `errors` - The validation failures collected so far.
+///
+/// ## Errors
+///
+/// - [`RESTError`] - A document could not be fetched, or is invalid.
+async fn fetch_sources(
+    config: &Config,
+    sources: Vec<PostPasteDocumentBody>,
+    documents: &mut Vec<(PostPasteDocumentBody, String, Mime)>,
+    errors: &mut ValidationErrors,
+) -> Result<(), RESTError> {
+    for document in sources {
+        let Some(source_url) = document.source_url() else {
+            continue;
+        };
+
+        let field = format!("files[{}]", document.id());
+
+        let fetched = fetch_source(config, &field, document.name(), source_url).await;
+
+        let Some((content, mime)) = errors.check(&field, fetched)? else {
+            continue;
+        };
+
+        errors.check(
+            &field,
+            document_limits(
+                config,
+                &field,
+                Undefined::Some(document.name()),
+                Undefined::Some(&content),
+                mime.as_ref(),
+            ),
+        )?;
+
+        errors.check(&field, validate_tags(&field, document.tags()))?;
+
+        documents.push((document, content, mime));
+    }
+
+    Ok(())
+}
+
+/// Create Paste.
+///
+/// Create a new paste, and its documents.
+///
+/// ## Arguments
+///
+/// - `app` - The application state.
+/// - `token` - The token of an existing paste, whose owner will also own the new paste (if provided).
+/// - `payload` - The pastes payload.
+/// - `documents` - The documents, alongside their contents and mime.
+/// - `errors` - The validation failures collected so far.
+///
+/// ## Errors
+///
+/// - [`RESTError`] - The payload and/or documents are invalid, or the paste failed to be created.
+///
+/// ## Returns
+///
+/// The created [`ResponsePaste`], alongside the fields that had a default applied.
+async fn create_paste(
+    app: &App,
+    token: Option<Token>,
+    payload: &PostPasteBody,
+    documents: Vec<(PostPasteDocumentBody, String, Mime)>,
+    errors: ValidationErrors,
+) -> Result<(ResponsePaste, Vec<&'static str>), RESTError> {
+    let documents: Vec<(PostPasteDocumentBody, String, Mime)> = documents
+        .into_iter()
+        .map(|(body, content, mime)| {
+            let content = normalize_content(app.config(), &mime, content);
+            (body, content, mime)
+        })
+        .collect();
+
+    let validated = validate_paste_creation(app.config(), payload, &documents, errors)?;
+
+    if let Some(webhook_url) = payload.webhook_url() {
+        validate_webhook_url(app.config(), webhook_url).await?;
     }
+
+    let password_hash = match payload.password() {
+        Some(password) => Some(hash_password(password)?),
+        None => None,
+    };
+
+    let owner_id = match token {
+        Some(token) => Some(
+            Paste::fetch(app.database().pool(), token.paste_id())
+                .await?
+                .map_or_else(|| *token.paste_id(), |paste| *paste.owner_id()),
+        ),
+        None => None,
+    };
+
+    let mut transaction = app.database().pool().begin().await?;
+
+    let paste_id = Snowflake::generate_unique(transaction.as_mut(), app.config()).await?;
+    let creation = truncate_timestamp(app.config(), Utc::now());
+
+    // A defaulted name is a template, which can only be expanded once the paste has an ID.
+    let name = if validated.defaulted.contains(&"name") {
+        validated
+            .name
+            .map(|template| expand_paste_name(&template, &paste_id, &creation))
+    } else {
+        validated.name
+    };
+
+    validate_unique_name(transaction.as_mut(), app.config(), name.as_deref(), None).await?;
+
+    let paste = Paste::new(
+        paste_id,
+        name,
+        creation,
+        None,
+        validated.expiry.into(),
+        0,
+        validated.max_views,
+        password_hash,
+        owner_id,
+    );
+
+    paste.insert(transaction.as_mut()).await?;
+
+    if let Some(webhook_url) = payload.webhook_url() {
+        Paste::set_webhook_url(transaction.as_mut(), paste.id(), Some(webhook_url)).await?;
+    }
+
+    let mut response_documents = Vec::new();
+    for (body, content, mime) in documents {
+        let mime_string = mime.to_string();
+
+        let document = Document::new(
+            Snowflake::generate_unique(transaction.as_mut(), app.config()).await?,
+            *paste.id(),
+            &mime_string,
+            body.name(),
+            content.len(),
+            Some(&generate_checksum(content.as_bytes())),
+            body.tags(),
+        );
+
+        app.object_store()
+            .create_document(&document, content)
+            .await?;
+
+        document.insert(transaction.as_mut()).await?;
+
+        response_documents.push(document);
+    }
+
+    enforce_maximums(&mut transaction, app.config(), paste.id()).await?;
+
+    enforce_minimums(&mut transaction, app.config(), paste.id()).await?;
+
+    enforce_owner_maximum(&mut transaction, app.config(), paste.owner_id()).await?;
+
+    enforce_token_maximum(&mut transaction, app.config(), paste.id()).await?;
+
+    let paste_token = Token::new(*paste.id(), generate_token(*paste.id())?);
+
+    paste_token.insert(transaction.as_mut()).await?;
+
+    if let Some(expiry) = paste.expiry() {
+        app.handler().add(paste.id(), *expiry).await?;
+    }
+
+    transaction.commit().await?;
+
+    Ok((
+        ResponsePaste::from_paste(
+            &paste,
+            Some(paste_token),
+            response_documents,
+            app.config().domain(),
+        ),
+        validated.defaulted,
+    ))
 }
 
 #[cfg(test)]
@@ -503,11 +1393,14 @@ mod tests {
     use crate::{
         app::{
             application::ApplicationState,
-            config::{Config, SizeLimitConfig},
-            object_store::TestObjectStore,
+            config::{
+                Config, DocumentNameCase, ObjectStoreConfig, SizeLimitConfig, TextNormalization,
+            },
+            object_store::{MemoryObjectStore, ObjectStore},
         },
         models::errors::{RESTError, RESTErrorResponse},
     };
+    use axum::{Extension, extract::ConnectInfo};
     use axum_test::{
         TestServer,
         multipart::{MultipartForm, Part},
@@ -517,6 +1410,7 @@ mod tests {
     use rstest::*;
     use serde_json::json;
     use sqlx::PgPool;
+    use std::net::SocketAddr;
 
     mod v1 {
         use super::*;
@@ -529,7 +1423,7 @@ mod tests {
                 let config = Config::test_builder()
                     .build()
                     .expect("Failed to build config.");
-                let object_store = TestObjectStore::new();
+                let object_store = MemoryObjectStore::new();
                 let state =
                     ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
                         .await
@@ -562,368 +1456,438 @@ mod tests {
                     .await
                     .expect("Failed to make DB request");
 
-                let expected_body =
-                    serde_json::to_vec(&ResponsePaste::from_paste(&paste, None, documents))
-                        .expect("Failed to build expected body.");
+                let expected_body = serde_json::to_vec(&ResponsePaste::from_paste(
+                    &paste,
+                    None,
+                    documents,
+                    config.domain(),
+                ))
+                .expect("Failed to build expected body.");
 
                 assert_eq!(body.to_vec(), expected_body, "Body does not match.");
 
                 assert_eq!(views + 1, paste.views(), "Views was not updated.");
             }
 
+            #[rstest]
+            #[case("application/json", "application/json")]
+            #[case("application/msgpack", "application/msgpack")]
             #[sqlx::test(fixtures(path = "../../tests/fixtures", scripts("pastes", "documents")))]
-            async fn test_missing(pool: PgPool) {
+            async fn test_negotiated(
+                #[ignore] pool: PgPool,
+                #[case] accept: &str,
+                #[case] content_type: &str,
+            ) {
                 let config = Config::test_builder()
                     .build()
                     .expect("Failed to build config.");
-                let object_store = TestObjectStore::new();
+                let object_store = MemoryObjectStore::new();
                 let state =
                     ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
                         .await
                         .expect("Failed to build application state.");
 
-                let paste_id = Snowflake::new(1_234_567_890);
+                let paste_id = Snowflake::new(517_815_304_354_284_605);
 
                 let app = main_generate_router(state);
                 let server = TestServer::new(app);
 
-                let response = server.get(&format!("/v1/pastes/{paste_id}")).await;
-
-                response.assert_status(StatusCode::NOT_FOUND);
+                let response = server
+                    .get(&format!("/v1/pastes/{paste_id}"))
+                    .add_header("Accept", accept)
+                    .await;
 
-                response.assert_header("Content-Type", "application/json");
+                response.assert_status(StatusCode::OK);
 
-                let body: RESTErrorResponse = response.json();
+                response.assert_header("Content-Type", content_type);
 
-                assert_eq!(body.reason(), "Not Found", "Reason does not match.");
+                let body = response.as_bytes();
 
-                assert_eq!(
-                    body.message(),
-                    "The paste requested could not be found",
-                    "Message does not match."
-                );
-            }
-        }
+                let paste = Paste::fetch(&pool, &paste_id)
+                    .await
+                    .expect("Failed to make DB request")
+                    .expect("Failed to find paste.");
+                let documents = Document::fetch_all(&pool, &paste_id)
+                    .await
+                    .expect("Failed to make DB request");
 
-        mod post_paste {
-            use super::*;
+                let expected = serde_json::to_value(ResponsePaste::from_paste(
+                    &paste,
+                    None,
+                    documents,
+                    config.domain(),
+                ))
+                .expect("Failed to build expected body.");
+
+                let received: serde_json::Value = if content_type == "application/msgpack" {
+                    rmp_serde::from_slice(body).expect("Body is not valid MessagePack.")
+                } else {
+                    serde_json::from_slice(body).expect("Body is not valid JSON.")
+                };
 
-            #[rstest]
-            #[case(true)]
-            #[case(false)]
-            #[sqlx::test]
-            async fn test_successful(#[ignore] pool: PgPool, #[case] switch_order: bool) {
+                assert_eq!(received, expected, "Body does not match.");
+            }
+
+            #[sqlx::test(fixtures(path = "../../tests/fixtures", scripts("pastes", "documents")))]
+            async fn test_timestamps_rfc3339(pool: PgPool) {
                 let config = Config::test_builder()
                     .build()
                     .expect("Failed to build config.");
-                let object_store = TestObjectStore::new();
+                let object_store = MemoryObjectStore::new();
                 let state =
                     ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
                         .await
                         .expect("Failed to build application state.");
 
-                let app = main_generate_router(state);
-                let server = TestServer::new(app);
+                let paste_id = Snowflake::new(517_815_304_354_284_605);
 
-                let payload_expiry = Utc::now() + TimeDelta::days(1);
+                let expiry = Utc::now() + TimeDelta::hours(1);
 
-                // TODO: I think I want to build these properly, as this seems like it could easily break without easy trace backs.
-                let body = json!({
-                    "name": "test paste",
-                    "expiry_timestamp": payload_expiry.to_rfc3339(),
-                    "max_views": 100,
-                    "documents": [
-                        {"id": 0, "name": "custom.json"},
-                        {"id": 1, "name": "random.txt"}
-                    ]
-                });
+                sqlx::query("UPDATE pastes SET expiry = $1 WHERE id = $2")
+                    .bind(expiry)
+                    .bind(i64::from(paste_id))
+                    .execute(&pool)
+                    .await
+                    .expect("Failed to set expiry.");
 
-                let payload = serde_json::to_string(&body).expect("Failed to build request body.");
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
 
-                let payload_part = Part::bytes(Bytes::from(payload))
-                    .add_header("Content-Type", "application/json");
+                let response = server.get(&format!("/v1/pastes/{paste_id}")).await;
 
-                let document_1_content = Bytes::from(r#"{"test": "test_value"}"#);
-                let document_1_part = Part::bytes(document_1_content.clone())
-                    .add_header("Content-Type", "application/json");
+                response.assert_status(StatusCode::OK);
 
-                let document_2_content = Bytes::from(r"Just some random text.");
-                let document_2_part = Part::bytes(document_2_content.clone())
-                    .add_header("Content-Type", "text/plain");
+                let body: serde_json::Value = response.json();
 
-                let form = {
-                    if switch_order {
-                        MultipartForm::new()
-                            .add_part("payload", payload_part)
-                            .add_part("files[0]", document_1_part)
-                            .add_part("files[1]", document_2_part)
-                    } else {
-                        MultipartForm::new()
-                            .add_part("files[1]", document_2_part)
-                            .add_part("files[0]", document_1_part)
-                            .add_part("payload", payload_part)
-                    }
+                let parse = |key: &str| {
+                    chrono::DateTime::parse_from_rfc3339(
+                        body[key].as_str().expect("Timestamp is not a string."),
+                    )
+                    .expect("Timestamp is not RFC3339.")
+                    .to_utc()
                 };
 
-                let response = server.post("/v1/pastes").multipart(form).await;
+                assert_eq!(
+                    parse("timestamp").timestamp(),
+                    777_600,
+                    "Creation does not match."
+                );
+                assert_eq!(
+                    parse("edited_timestamp").timestamp(),
+                    1_641_600,
+                    "Edited does not match."
+                );
+                assert_eq!(
+                    parse("expiry_timestamp").timestamp(),
+                    expiry.timestamp(),
+                    "Expiry does not match."
+                );
+            }
 
-                response.assert_status(StatusCode::OK);
+            #[rstest]
+            #[case(false, 0)]
+            #[case(true, 1)]
+            #[sqlx::test(fixtures(
+                path = "../../tests/fixtures",
+                scripts("pastes", "documents", "tokens")
+            ))]
+            async fn test_owner_views(
+                #[ignore] pool: PgPool,
+                #[case] count_owner_views: bool,
+                #[case] expected_added_views: usize,
+            ) {
+                let config = Config::test_builder()
+                    .count_owner_views(count_owner_views)
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
 
-                response.assert_header("Content-Type", "application/json");
+                let paste_id = Snowflake::new(517_815_304_354_284_605);
+                let token_string =
+                    "NTE3ODE1MzA0MzU0Mjg0NjA1.MTc3MDQzODc5Mw==.ozlKKwEEZpoGVuNzPDCyOMRGv";
 
-                let body: ResponsePaste = response.json();
+                let views = Paste::fetch(&pool, &paste_id)
+                    .await
+                    .expect("Failed to make DB request")
+                    .expect("Failed to find paste.")
+                    .views();
 
-                assert_eq!(
-                    body.name(),
-                    Some("test paste"),
-                    "Paste name does not match."
-                );
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
 
-                assert!(body.token().is_some(), "Token was not returned.");
+                let response = server
+                    .get(&format!("/v1/pastes/{paste_id}"))
+                    .add_header("Authorization", format!("Bearer {token_string}"))
+                    .await;
 
-                // TODO: Check that timestamp is recent? within 5~ seconds of  the call?
+                response.assert_status(StatusCode::OK);
 
-                assert!(body.edited().is_none(), "Edited was set.");
+                let updated_views = Paste::fetch(&pool, &paste_id)
+                    .await
+                    .expect("Failed to make DB request")
+                    .expect("Failed to find paste.")
+                    .views();
 
                 assert_eq!(
-                    body.expiry(),
-                    Some(
-                        &payload_expiry
-                            .with_nanosecond(0)
-                            .expect("Failed to strip nanoseconds")
-                    ),
-                    "Expiry does not match."
+                    views + expected_added_views,
+                    updated_views,
+                    "Views do not match."
                 );
+            }
 
-                assert_eq!(body.views(), 0, "Views does not match.");
+            #[sqlx::test(fixtures(path = "../../tests/fixtures", scripts("pastes", "documents")))]
+            async fn test_view_dedup(pool: PgPool) {
+                let config = Config::test_builder()
+                    .view_dedup_seconds(1)
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
 
-                assert_eq!(body.max_views(), Some(100), "Maximum views does not match.");
+                let paste_id = Snowflake::new(517_815_304_354_284_605);
 
-                let documents = body.documents();
-                assert_eq!(documents.len(), 2, "Document count does not match.");
+                let fetch_views = async || {
+                    Paste::fetch(&pool, &paste_id)
+                        .await
+                        .expect("Failed to make DB request")
+                        .expect("Failed to find paste.")
+                        .views()
+                };
 
-                let paste_id = body.id();
+                let views = fetch_views().await;
 
-                let Some(document_1) = documents.first() else {
-                    panic!("Document 1 could not be found.");
-                };
+                let app = main_generate_router(state).layer(Extension(ConnectInfo(
+                    SocketAddr::from(([127, 0, 0, 1], 8080)),
+                )));
+                let server = TestServer::new(app);
 
-                assert_eq!(
-                    document_1.paste_id(),
-                    &paste_id,
-                    "Document 1 paste ID does not match parent paste ID.",
-                );
+                for _ in 0..2 {
+                    server
+                        .get(&format!("/v1/pastes/{paste_id}"))
+                        .await
+                        .assert_status(StatusCode::OK);
+                }
 
-                assert_eq!(
-                    document_1.name(),
-                    "custom.json",
-                    "Document 1 name does not match.",
-                );
+                assert_eq!(views + 1, fetch_views().await, "Repeated view was counted.");
 
-                assert_eq!(
-                    document_1.doc_type(),
-                    "application/json",
-                    "Document 1 doc type does not match.",
-                );
+                tokio::time::sleep(std::time::Duration::from_millis(1100)).await;
 
-                let document_1_contents = object_store
-                    .fetch_document(document_1)
+                server
+                    .get(&format!("/v1/pastes/{paste_id}"))
                     .await
-                    .expect("Failed to find document_1's contents.");
+                    .assert_status(StatusCode::OK);
 
                 assert_eq!(
-                    document_1_contents,
-                    Some(document_1_content),
-                    "Document 1 contents do not match.",
+                    views + 2,
+                    fetch_views().await,
+                    "View after the window was not counted."
                 );
+            }
 
-                let Some(document_2) = documents.get(1) else {
-                    panic!("Document 2 could not be found.");
-                };
+            #[sqlx::test(fixtures(path = "../../tests/fixtures", scripts("pastes", "documents")))]
+            async fn test_document_totals(pool: PgPool) {
+                let paste_id = Snowflake::new(517_815_304_354_284_603);
 
-                assert_eq!(
-                    document_2.paste_id(),
-                    &paste_id,
-                    "Document 2 paste ID does not match parent paste ID.",
-                );
+                let paste = Paste::fetch(&pool, &paste_id)
+                    .await
+                    .expect("Failed to make DB request")
+                    .expect("Failed to find paste.");
+                let documents = Document::fetch_all(&pool, &paste_id)
+                    .await
+                    .expect("Failed to make DB request");
 
-                assert_eq!(
-                    document_2.name(),
-                    "random.txt",
-                    "Document 2 name does not match.",
-                );
+                let response =
+                    ResponsePaste::from_paste(&paste, None, documents, "https://example.com");
 
                 assert_eq!(
-                    document_2.doc_type(),
-                    "text/plain",
-                    "Document 2 doc type does not match.",
+                    response.document_count(),
+                    3,
+                    "Document count does not match."
                 );
+                assert_eq!(response.total_size(), 1024, "Total size does not match.");
+            }
 
-                let document_2_contents = object_store
-                    .fetch_document(document_2)
-                    .await
-                    .expect("Failed to find document_1's contents.");
+            #[rstest]
+            #[case(Snowflake::new(517_815_304_354_284_605), StatusCode::BAD_REQUEST)]
+            #[case(Snowflake::new(1_234_567_890), StatusCode::NOT_FOUND)]
+            #[sqlx::test(fixtures(path = "../../tests/fixtures", scripts("pastes", "documents")))]
+            async fn test_implausible_snowflake(
+                #[ignore] pool: PgPool,
+                #[case] paste_id: Snowflake,
+                #[case] expected_status: StatusCode,
+            ) {
+                let config = Config::test_builder()
+                    .snowflake_skew_seconds(Some(3600))
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
 
-                assert_eq!(
-                    document_2_contents,
-                    Some(document_2_content),
-                    "Document 2 contents do not match.",
-                );
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let response = server.get(&format!("/v1/pastes/{paste_id}")).await;
+
+                response.assert_status(expected_status);
+
+                let body: RESTErrorResponse = response.json();
+
+                if expected_status == StatusCode::BAD_REQUEST {
+                    assert_eq!(
+                        body.message(),
+                        format!("The ID `{paste_id}` is not a valid snowflake."),
+                        "Message does not match."
+                    );
+                }
             }
 
-            #[rstest]
-            #[case(
-                Config::test_builder()
+            #[sqlx::test(fixtures(path = "../../tests/fixtures", scripts("pastes", "documents")))]
+            async fn test_missing(pool: PgPool) {
+                let config = Config::test_builder()
                     .build()
-                    .expect("Failed to build config."),
-                json!({
-                    "name": "test paste",
-                    "expiry_timestamp": Utc::now() + TimeDelta::hours(5),
-                    "max_views": 100,
-                    "documents": [{"id": 0, "name": "test.txt"}]
-                }),
-                Some("test paste"),
-                Some((Utc::now() + TimeDelta::hours(5)).with_nanosecond(0).expect("Failed to strip nanoseconds")),
-                Some(100)
-            )]
-            #[case(
-                Config::test_builder()
-                    .build()
-                    .expect("Failed to build config."),
-                json!({
-                    "name": null,
-                    "expiry_timestamp": null,
-                    "max_views": null,
-                    "documents": [{"id": 0, "name": "test.txt"}]
-                }),
-                None,
-                None,
-                None,
-            )]
-            #[case(
-                Config::test_builder()
-                    .size_limits(
-                        SizeLimitConfig::test_builder()
-                            .default_paste_name(None)
-                            .build()
-                            .expect("Failed to build size limit config.")
-                    )
-                    .build()
-                    .expect("Failed to build config."),
-                json!({
-                    "expiry_timestamp": null,
-                    "max_views": null,
-                    "documents": [{"id": 0, "name": "test.txt"}]
-                }),
-                None,
-                None,
-                None,
-            )]
-            #[case(
-                Config::test_builder()
-                    .size_limits(
-                        SizeLimitConfig::test_builder()
-                            .default_paste_name(Some("default_name".to_string()))
-                            .build()
-                            .expect("Failed to build size limit config.")
-                    )
-                    .build()
-                    .expect("Failed to build config."),
-                json!({
-                    "expiry_timestamp": null,
-                    "max_views": null,
-                    "documents": [{"id": 0, "name": "test.txt"}]
-                }),
-                Some("default_name"),
-                None,
-                None,
-            )]
-            #[case(
-                Config::test_builder()
-                    .size_limits(
-                        SizeLimitConfig::test_builder()
-                            .default_expiry_hours(None)
-                            .build()
-                            .expect("Failed to build size limit config.")
-                    )
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let paste_id = Snowflake::new(1_234_567_890);
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let response = server.get(&format!("/v1/pastes/{paste_id}")).await;
+
+                response.assert_status(StatusCode::NOT_FOUND);
+
+                response.assert_header("Content-Type", "application/json");
+
+                let body: RESTErrorResponse = response.json();
+
+                assert_eq!(body.reason(), "Not Found", "Reason does not match.");
+
+                assert_eq!(
+                    body.message(),
+                    "The paste requested could not be found",
+                    "Message does not match."
+                );
+            }
+
+            #[sqlx::test(fixtures(path = "../../tests/fixtures", scripts("pastes", "documents")))]
+            async fn test_expired(pool: PgPool) {
+                let config = Config::test_builder()
                     .build()
-                    .expect("Failed to build config."),
-                json!({
-                    "name": null,
-                    "max_views": null,
-                    "documents": [{"id": 0, "name": "test.txt"}]
-                }),
-                None,
-                None,
-                None,
-            )]
-            #[case(
-                Config::test_builder()
-                    .size_limits(
-                        SizeLimitConfig::test_builder()
-                            .default_expiry_hours(Some(5))
-                            .build()
-                            .expect("Failed to build size limit config.")
-                    )
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let paste_id = Snowflake::new(517_815_304_354_284_601);
+
+                let response = server.get(&format!("/v1/pastes/{paste_id}")).await;
+
+                response.assert_status(StatusCode::GONE);
+
+                let body: RESTErrorResponse = response.json();
+
+                assert_eq!(body.reason(), "Gone", "Reason does not match.");
+
+                assert_eq!(
+                    body.message(),
+                    "The paste requested has expired",
+                    "Message does not match."
+                );
+
+                let response = server.get(&format!("/v1/pastes/{paste_id}")).await;
+
+                response.assert_status(StatusCode::NOT_FOUND);
+            }
+
+            #[sqlx::test]
+            async fn test_maximum_views(pool: PgPool) {
+                let config = Config::test_builder()
                     .build()
-                    .expect("Failed to build config."),
-                json!({
-                    "name": null,
-                    "max_views": null,
-                    "documents": [{"id": 0, "name": "test.txt"}]
-                }),
-                None,
-                Some((Utc::now() + TimeDelta::hours(5)).with_nanosecond(0).expect("Failed to strip nanoseconds")),
-                None,
-            )]
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let paste_id = Snowflake::new(517_815_304_354_284_610);
+
+                Paste::new(
+                    paste_id,
+                    None,
+                    Utc::now(),
+                    None,
+                    None,
+                    10,
+                    Some(10),
+                    None,
+                    None,
+                )
+                .insert(&pool)
+                .await
+                .expect("Failed to insert paste.");
+
+                let response = server.get(&format!("/v1/pastes/{paste_id}")).await;
+
+                response.assert_status(StatusCode::GONE);
+
+                let body: RESTErrorResponse = response.json();
+
+                assert_eq!(body.reason(), "Gone", "Reason does not match.");
+
+                assert_eq!(
+                    body.message(),
+                    "The paste requested has reached its maximum views",
+                    "Message does not match."
+                );
+            }
+
+            #[rstest]
+            #[case(Some("hunter2"), StatusCode::OK, None)]
             #[case(
-                Config::test_builder()
-                    .size_limits(
-                        SizeLimitConfig::test_builder()
-                            .default_maximum_views(None)
-                            .build()
-                            .expect("Failed to build size limit config.")
-                    )
-                    .build()
-                    .expect("Failed to build config."),
-                json!({
-                    "name": null,
-                    "expiry_timestamp": null,
-                    "documents": [{"id": 0, "name": "test.txt"}]
-                }),
-                None,
-                None,
-                None,
+                Some("wrong"),
+                StatusCode::UNAUTHORIZED,
+                Some(("Invalid Password", "The password provided is incorrect"))
             )]
             #[case(
-                Config::test_builder()
-                    .size_limits(
-                        SizeLimitConfig::test_builder()
-                            .default_maximum_views(Some(100))
-                            .build()
-                            .expect("Failed to build size limit config.")
-                    )
-                    .build()
-                    .expect("Failed to build config."),
-                json!({
-                    "name": null,
-                    "expiry_timestamp": null,
-                    "documents": [{"id": 0, "name": "test.txt"}]
-                }),
                 None,
-                None,
-                Some(100),
+                StatusCode::UNAUTHORIZED,
+                Some(("Missing Password", "The paste requires a password"))
             )]
             #[sqlx::test]
-            async fn test_defaults(
+            async fn test_password(
                 #[ignore] pool: PgPool,
-                #[case] config: Config,
-                #[case] payload: serde_json::Value,
-                #[case] expected_name: Option<&str>,
-                #[case] expected_expiry: Option<DtUtc>,
-                #[case] expected_maximum_views: Option<usize>,
+                #[case] password: Option<&str>,
+                #[case] expected_status: StatusCode,
+                #[case] expected_error: Option<(&str, &str)>,
             ) {
-                let object_store = TestObjectStore::new();
+                let config = Config::test_builder()
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
                 let state =
                     ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
                         .await
@@ -932,294 +1896,58 @@ mod tests {
                 let app = main_generate_router(state);
                 let server = TestServer::new(app);
 
-                let payload =
-                    serde_json::to_string(&payload).expect("Failed to build request body.");
-
-                let payload_part = Part::bytes(Bytes::from(payload))
-                    .add_header("Content-Type", "application/json");
-
-                let document_1_part =
-                    Part::bytes(Bytes::from("test")).add_header("Content-Type", "application/json");
+                let payload = serde_json::to_vec(&json!({
+                    "password": "hunter2",
+                    "documents": [{"id": 0, "name": "test.txt"}]
+                }))
+                .expect("Failed to build request body.");
 
                 let form = MultipartForm::new()
-                    .add_part("payload", payload_part)
-                    .add_part("files[0]", document_1_part);
+                    .add_part(
+                        "payload",
+                        Part::bytes(Bytes::from(payload))
+                            .add_header("Content-Type", "application/json"),
+                    )
+                    .add_part(
+                        "files[0]",
+                        Part::bytes(Bytes::from("test")).add_header("Content-Type", "text/plain"),
+                    );
 
                 let response = server.post("/v1/pastes").multipart(form).await;
 
                 response.assert_status(StatusCode::OK);
 
-                response.assert_header("Content-Type", "application/json");
+                let body: serde_json::Value = response.json();
 
-                let body: ResponsePaste = response.json();
+                assert!(body.get("password").is_none(), "Password was returned.");
 
-                assert_eq!(body.name(), expected_name, "Names do not match.");
+                let paste_id = body["id"].as_str().expect("Paste ID was not returned.");
 
-                assert_eq!(body.name(), expected_name, "Names do not match.");
+                let mut request = server.get(&format!("/v1/pastes/{paste_id}"));
 
-                assert_eq!(
-                    body.expiry(),
-                    expected_expiry.as_ref(),
-                    "Expiries do not match."
-                );
+                if let Some(password) = password {
+                    request = request.add_header("X-Paste-Password", password);
+                }
 
-                assert_eq!(
-                    body.max_views(),
-                    expected_maximum_views,
-                    "Maximum views do not match."
-                );
+                let response = request.await;
+
+                response.assert_status(expected_status);
+
+                if let Some((reason, message)) = expected_error {
+                    let body: RESTErrorResponse = response.json();
+
+                    assert_eq!(body.reason(), reason, "Reason does not match.");
+
+                    assert_eq!(body.message(), message, "Message does not match.");
+                }
             }
 
-            #[rstest]
-            #[case(
-                Config::test_builder()
-                    .build()
-                    .expect("Failed to build config."),
-                MultipartForm::new()
-                    .add_part("payload", Part::bytes(Bytes::from("{}")).add_header("Content-Type", "application/json")),
-                StatusCode::BAD_REQUEST,
-                RESTErrorResponse::new("Json Parse Error", "missing field `documents` at line 1 column 2"),
-            )]
-            #[case(
-                Config::test_builder()
-                    .build()
-                    .expect("Failed to build config."),
-                MultipartForm::new()
-                    .add_part("payload", Part::bytes(Bytes::from("{}")).add_header("Content-Type", "application/json"))
-                    .add_part("files[0]", Part::bytes(Bytes::from("test")).add_header("Content-Type", "text/plain")),
-                StatusCode::BAD_REQUEST,
-                RESTErrorResponse::new("Json Parse Error", "missing field `documents` at line 1 column 2"),
-            )]
-            #[case(
-                Config::test_builder()
-                    .build()
-                    .expect("Failed to build config."),
-                MultipartForm::new()
-                    .add_part("payload", Part::bytes(Bytes::from(serde_json::to_vec(&json!({
-                        "documents": []
-                    })).expect("Failed to build payload"))).add_header("Content-Type", "application/json")),
-                StatusCode::BAD_REQUEST,
-                RESTErrorResponse::new("Bad Request", "Not enough documents were provided. Expected: 1, Received: 0"),
-            )]
-            #[case(
-                Config::test_builder()
-                    .build()
-                    .expect("Failed to build config."),
-                MultipartForm::new()
-                    .add_part("payload", Part::bytes(Bytes::from(serde_json::to_vec(&json!({
-                        "documents": [
-                            {"id": 0, "name": "test.txt"}
-                        ]
-                    })).expect("Failed to build payload"))).add_header("Content-Type", "application/json")),
-                StatusCode::BAD_REQUEST,
-                RESTErrorResponse::new("Bad Request", "A document with the ID of 0 was not found"),
-            )]
-            #[case(
-                Config::test_builder()
-                    .build()
-                    .expect("Failed to build config."),
-                MultipartForm::new()
-                    .add_part("payload", Part::bytes(Bytes::from(serde_json::to_vec(&json!({
-                        "documents": []
-                    })).expect("Failed to build payload"))).add_header("Content-Type", "application/json"))
-                    .add_part("files[0]", Part::bytes(Bytes::from("test")).add_header("Content-Type", "text/plain")),
-                StatusCode::BAD_REQUEST,
-                RESTErrorResponse::new("Bad Request", "More files were provided, than listed inside the payload"),
-            )]
-            #[case(
-                Config::test_builder()
-                    .build()
-                    .expect("Failed to build config."),
-                MultipartForm::new()
-                    .add_part("payload", Part::bytes(Bytes::from("{}"))),
-                StatusCode::BAD_REQUEST,
-                RESTErrorResponse::new("Bad Request", "Payload must have a content type of application/json"),
-            )]
-            #[case(
-                Config::test_builder()
-                    .build()
-                    .expect("Failed to build config."),
-                MultipartForm::new()
-                    .add_part("payload", Part::bytes(Bytes::from(serde_json::to_vec(&json!({
-                        "documents": [
-                            {"id": 0, "name": "test.png"}
-                        ]
-                    })).expect("Failed to build payload"))).add_header("Content-Type", "application/json"))
-                    .add_part("files[0]", Part::bytes(Bytes::new()).add_header("Content-Type", "image/png")),
-                StatusCode::BAD_REQUEST,
-                RESTErrorResponse::new("Bad Request", "Invalid mime type: image/png received for the document: 0"),
-            )]
-            #[case(
-                Config::test_builder()
-                    .size_limits(
-                            SizeLimitConfig::test_builder()
-                                .minimum_document_name_size(10)
-                                .build()
-                                .expect("Failed to build size limit config.")
-                    )
-                    .build()
-                    .expect("Failed to build config."),
-                MultipartForm::new()
-                    .add_part("payload", Part::bytes(Bytes::from(serde_json::to_vec(&json!({
-                        "documents": [
-                            {"id": 0, "name": "test.txt"}
-                        ]
-                    })).expect("Failed to build payload"))).add_header("Content-Type", "application/json"))
-                    .add_part("files[0]", Part::bytes(Bytes::from("test")).add_header("Content-Type", "text/plain")),
-                StatusCode::BAD_REQUEST,
-                RESTErrorResponse::new("Bad Request", "Document `0`'s name: `test.txt` is too small."),
-            )]
-            #[case(
-                Config::test_builder()
-                    .size_limits(
-                            SizeLimitConfig::test_builder()
-                                .maximum_document_name_size(10)
-                                .build()
-                                .expect("Failed to build size limit config.")
-                    )
-                    .build()
-                    .expect("Failed to build config."),
-                MultipartForm::new()
-                    .add_part("payload", Part::bytes(Bytes::from(serde_json::to_vec(&json!({
-                        "documents": [
-                            {"id": 0, "name": "test_file.txt"}
-                        ]
-                    })).expect("Failed to build payload"))).add_header("Content-Type", "application/json"))
-                    .add_part("files[0]", Part::bytes(Bytes::from("test")).add_header("Content-Type", "text/plain")),
-                StatusCode::BAD_REQUEST,
-                RESTErrorResponse::new("Bad Request", "Document `0`'s name: `test_file.txt` is too large."),
-            )]
-            #[case(
-                Config::test_builder()
-                    .size_limits(
-                            SizeLimitConfig::test_builder()
-                                .minimum_document_size(100)
-                                .build()
-                                .expect("Failed to build size limit config.")
-                    )
-                    .build()
-                    .expect("Failed to build config."),
-                MultipartForm::new()
-                    .add_part("payload", Part::bytes(Bytes::from(serde_json::to_vec(&json!({
-                        "documents": [
-                            {"id": 0, "name": "test.txt"}
-                        ]
-                    })).expect("Failed to build payload"))).add_header("Content-Type", "application/json"))
-                    .add_part("files[0]", Part::bytes(Bytes::new()).add_header("Content-Type", "text/plain")),
-                StatusCode::BAD_REQUEST,
-                RESTErrorResponse::new("Bad Request", "Document `0` is too small."),
-            )]
-            #[case(
-                Config::test_builder()
-                    .size_limits(
-                            SizeLimitConfig::test_builder()
-                                .maximum_document_size(100)
-                                .build()
-                                .expect("Failed to build size limit config.")
-                    )
-                    .build()
-                    .expect("Failed to build config."),
-                MultipartForm::new()
-                    .add_part("payload", Part::bytes(Bytes::from(serde_json::to_vec(&json!({
-                        "documents": [
-                            {"id": 0, "name": "test.txt"}
-                        ]
-                    })).expect("Failed to build payload"))).add_header("Content-Type", "application/json"))
-                    .add_part("files[0]", Part::bytes(Bytes::from(vec![0; 110])).add_header("Content-Type", "text/plain")),
-                StatusCode::BAD_REQUEST,
-                RESTErrorResponse::new("Bad Request", "Document `0` is too large."),
-            )]
-            #[case(
-                Config::test_builder()
-                    .size_limits(
-                            SizeLimitConfig::test_builder()
-                                .minimum_total_document_count(2)
-                                .build()
-                                .expect("Failed to build size limit config.")
-                    )
-                    .build()
-                    .expect("Failed to build config."),
-                MultipartForm::new()
-                    .add_part("payload", Part::bytes(Bytes::from(serde_json::to_vec(&json!({
-                        "documents": [
-                            {"id": 0, "name": "test.txt"}
-                        ]
-                    })).expect("Failed to build payload"))).add_header("Content-Type", "application/json"))
-                    .add_part("files[0]", Part::bytes(Bytes::from("test")).add_header("Content-Type", "text/plain")),
-                StatusCode::BAD_REQUEST,
-                RESTErrorResponse::new("Bad Request", "Not enough documents were provided. Expected: 2, Received: 1"),
-            )]
-            #[case(
-                Config::test_builder()
-                    .size_limits(
-                            SizeLimitConfig::test_builder()
-                                .maximum_total_document_count(1)
-                                .build()
-                                .expect("Failed to build size limit config.")
-                    )
-                    .build()
-                    .expect("Failed to build config."),
-                MultipartForm::new()
-                    .add_part("payload", Part::bytes(Bytes::from(serde_json::to_vec(&json!({
-                        "documents": [
-                            {"id": 0, "name": "test.txt"},
-                            {"id": 1, "name": "test2.txt"}
-                        ]
-                    })).expect("Failed to build payload"))).add_header("Content-Type", "application/json"))
-                    .add_part("files[0]", Part::bytes(Bytes::from("test")).add_header("Content-Type", "text/plain"))
-                    .add_part("files[1]", Part::bytes(Bytes::from("test2")).add_header("Content-Type", "text/plain")),
-                StatusCode::BAD_REQUEST,
-                RESTErrorResponse::new("Bad Request", "Too many documents were provided. Expected: 1, Received: 2"),
-            )]
-            #[case(
-                Config::test_builder()
-                    .size_limits(
-                            SizeLimitConfig::test_builder()
-                                .minimum_expiry_hours(Some(1))
-                                .maximum_expiry_hours(Some(5))
-                                .build()
-                                .expect("Failed to build size limit config.")
-                    )
-                    .build()
-                    .expect("Failed to build config."),
-                MultipartForm::new()
-                    .add_part("payload", Part::bytes(Bytes::from(serde_json::to_vec(&json!({
-                        "expiry_timestamp": Utc::now().to_rfc3339(),
-                        "documents": [{"id": 0, "name": "test.txt"}]
-                    })).expect("Failed to build payload"))).add_header("Content-Type", "application/json"))
-                    .add_part("files[0]", Part::bytes(Bytes::from("test")).add_header("Content-Type", "text/plain")),
-                StatusCode::BAD_REQUEST,
-                RESTErrorResponse::new("Bad Request", "The timestamp provided has already passed."),
-            )]
-            #[case(
-                Config::test_builder()
-                    .size_limits(
-                            SizeLimitConfig::test_builder()
-                                .minimum_expiry_hours(Some(1))
-                                .maximum_expiry_hours(Some(5))
-                                .build()
-                                .expect("Failed to build size limit config.")
-                    )
+            #[sqlx::test(fixtures(path = "../../tests/fixtures", scripts("pastes", "documents")))]
+            async fn test_password_not_required(pool: PgPool) {
+                let config = Config::test_builder()
                     .build()
-                    .expect("Failed to build config."),
-                MultipartForm::new()
-                    .add_part("payload", Part::bytes(Bytes::from(serde_json::to_vec(&json!({
-                        "expiry_timestamp": (Utc::now() + TimeDelta::hours(6)).to_rfc3339(),
-                        "documents": [{"id": 0, "name": "test.txt"}]
-                    })).expect("Failed to build payload"))).add_header("Content-Type", "application/json"))
-                    .add_part("files[0]", Part::bytes(Bytes::from("test")).add_header("Content-Type", "text/plain")),
-                StatusCode::BAD_REQUEST,
-                RESTErrorResponse::new("Bad Request", "The timestamp provided is above the maximum."),
-            )]
-            #[sqlx::test]
-            async fn test_failures(
-                #[ignore] pool: PgPool,
-                #[case] config: Config,
-                #[case] form: MultipartForm,
-                #[case] expected_status: StatusCode,
-                #[case] expected_response: RESTErrorResponse,
-            ) {
-                let object_store = TestObjectStore::new();
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
                 let state =
                     ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
                         .await
@@ -1228,1040 +1956,4793 @@ mod tests {
                 let app = main_generate_router(state);
                 let server = TestServer::new(app);
 
-                let response = server.post("/v1/pastes").multipart(form).await;
-
-                response.assert_status(expected_status);
+                let paste_id = Snowflake::new(517_815_304_354_284_605);
 
-                response.assert_header("Content-Type", "application/json");
+                let response = server
+                    .get(&format!("/v1/pastes/{paste_id}"))
+                    .add_header("X-Paste-Password", "anything")
+                    .await;
 
-                let body: RESTErrorResponse = response.json();
+                response.assert_status(StatusCode::OK);
+            }
 
-                assert_eq!(
-                    body.reason(),
-                    expected_response.reason(),
-                    "Mismatched response reason."
+            #[rstest]
+            #[case(Some(10), Some(9))]
+            #[case(Some(1), Some(0))]
+            #[case(None, None)]
+            #[sqlx::test]
+            async fn test_remaining_views(
+                #[ignore] pool: PgPool,
+                #[case] max_views: Option<usize>,
+                #[case] expected: Option<usize>,
+            ) {
+                let config = Config::test_builder()
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let payload = serde_json::to_vec(&json!({
+                    "max_views": max_views,
+                    "documents": [{"id": 0, "name": "test.txt"}]
+                }))
+                .expect("Failed to build request body.");
+
+                let form = MultipartForm::new()
+                    .add_part(
+                        "payload",
+                        Part::bytes(Bytes::from(payload))
+                            .add_header("Content-Type", "application/json"),
+                    )
+                    .add_part(
+                        "files[0]",
+                        Part::bytes(Bytes::from("test")).add_header("Content-Type", "text/plain"),
+                    );
+
+                let response = server.post("/v1/pastes").multipart(form).await;
+
+                response.assert_status(StatusCode::OK);
+
+                let body: ResponsePaste = response.json();
+
+                assert_eq!(
+                    body.remaining_views(),
+                    max_views,
+                    "Remaining views do not match."
                 );
 
+                let response = server.get(&format!("/v1/pastes/{}", body.id())).await;
+
+                response.assert_status(StatusCode::OK);
+
+                let body: ResponsePaste = response.json();
+
                 assert_eq!(
-                    body.message(),
-                    expected_response.message(),
-                    "Mismatched response message."
+                    body.remaining_views(),
+                    expected,
+                    "Remaining views do not match."
                 );
             }
-        }
 
-        mod patch_paste {
-            use super::*;
+            #[rstest]
+            #[case(2, None, 2, "milestone")]
+            #[case(0, Some(3), 3, "max_views")]
+            #[sqlx::test]
+            async fn test_webhook_view_milestone(
+                #[ignore] pool: PgPool,
+                #[case] webhook_view_milestone: usize,
+                #[case] max_views: Option<usize>,
+                #[case] expected_views: usize,
+                #[case] expected_event: &str,
+            ) {
+                let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+                    .await
+                    .expect("Failed to bind listener.");
+                let address = listener.local_addr().expect("Failed to fetch address.");
 
-            mod json {
-                use super::*;
+                let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel();
 
-                #[rstest]
-                #[case(
-                    Config::test_builder()
-                        .build()
-                        .expect("Failed to build config."),
-                    json!({}),
-                    Some("Test 5"),
-                    None,
-                    Some(20000)
-                )]
-                #[case(
-                    Config::test_builder()
-                        .build()
-                        .expect("Failed to build config."),
-                    json!({
-                        "name": "beans"
+                let webhook_router = axum::Router::new().route(
+                    "/webhook",
+                    post(move |Json(body): Json<serde_json::Value>| async move {
+                        sender.send(body).expect("Failed to send webhook body.");
+                        StatusCode::NO_CONTENT
                     }),
-                    Some("beans"),
-                    None,
-                    Some(20000)
-                )]
-                #[case(
-                    Config::test_builder()
-                        .build()
-                        .expect("Failed to build config."),
-                    json!({
-                        "expiry_timestamp": Utc::now() + TimeDelta::hours(5),
-                    }),
-                    Some("Test 5"),
-                    Some((Utc::now() + TimeDelta::hours(5)).with_nanosecond(0).expect("Failed to strip nanoseconds")),
-                    Some(20000)
-                )]
-                #[case(
-                    Config::test_builder()
-                        .build()
-                        .expect("Failed to build config."),
-                    json!({
-                        "max_views": 5000
-                    }),
-                    Some("Test 5"),
-                    None,
-                    Some(5000)
-                )]
-                #[sqlx::test(fixtures(
-                    path = "../../tests/fixtures",
-                    scripts("pastes", "documents", "tokens")
-                ))]
-                async fn test_successful(
-                    #[ignore] pool: PgPool,
-                    #[case] config: Config,
-                    #[case] body: serde_json::Value,
-                    #[case] expected_name: Option<&str>,
-                    #[case] expected_expiry: Option<DtUtc>,
-                    #[case] expected_max_views: Option<usize>,
-                ) {
-                    let object_store = TestObjectStore::new();
-                    let state = ApplicationState::new_tests(
-                        config.clone(),
-                        pool.clone(),
-                        object_store.clone(),
-                    )
-                    .await
-                    .expect("Failed to build application state.");
+                );
 
-                    let app = main_generate_router(state);
-                    let server = TestServer::new(app);
+                tokio::spawn(async move { axum::serve(listener, webhook_router).await });
 
-                    let paste_id = Snowflake::new(517_815_304_354_284_605);
-                    let token_string =
-                        "NTE3ODE1MzA0MzU0Mjg0NjA1.MTc3MDQzODc5Mw==.ozlKKwEEZpoGVuNzPDCyOMRGv";
+                let config = Config::test_builder()
+                    .webhook_allowed_hosts(vec![String::from("127.0.0.1")])
+                    .webhook_view_milestone(webhook_view_milestone)
+                    .source_url_allowed_networks(vec![
+                        "127.0.0.0/8".parse().expect("Failed to parse network."),
+                    ])
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
 
-                    let response = server
-                        .patch(&format!("/v1/pastes/{paste_id}"))
-                        .add_header("Authorization", format!("Bearer {token_string}"))
-                        .json(&body)
-                        .await;
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
 
-                    response.assert_status(StatusCode::OK);
+                let payload = serde_json::to_vec(&json!({
+                    "max_views": max_views,
+                    "webhook_url": format!("http://{address}/webhook"),
+                    "documents": [{"id": 0, "name": "test.txt"}]
+                }))
+                .expect("Failed to build request body.");
 
-                    response.assert_header("Content-Type", "application/json");
+                let form = MultipartForm::new()
+                    .add_part(
+                        "payload",
+                        Part::bytes(Bytes::from(payload))
+                            .add_header("Content-Type", "application/json"),
+                    )
+                    .add_part(
+                        "files[0]",
+                        Part::bytes(Bytes::from("test")).add_header("Content-Type", "text/plain"),
+                    );
 
-                    let body: ResponsePaste = response.json();
+                let response = server.post("/v1/pastes").multipart(form).await;
 
-                    assert_eq!(body.name(), expected_name, "Names do not match.");
+                response.assert_status(StatusCode::OK);
 
-                    assert_eq!(
-                        body.expiry(),
-                        expected_expiry.as_ref(),
-                        "Expiry's do not match."
-                    );
+                let body: ResponsePaste = response.json();
 
-                    assert_eq!(
-                        body.max_views(),
-                        expected_max_views,
-                        "Max views do not match."
-                    );
+                for _ in 0..expected_views {
+                    server
+                        .get(&format!("/v1/pastes/{}", body.id()))
+                        .await
+                        .assert_status(StatusCode::OK);
                 }
 
-                #[sqlx::test(fixtures(
-                    path = "../../tests/fixtures",
-                    scripts("pastes", "documents", "tokens")
-                ))]
-                async fn test_successful_document_deletion(pool: PgPool) {
-                    let config = Config::test_builder()
-                        .build()
-                        .expect("Failed to build config.");
-                    let object_store = TestObjectStore::new();
-                    let state = ApplicationState::new_tests(
-                        config.clone(),
-                        pool.clone(),
-                        object_store.clone(),
-                    )
-                    .await
-                    .expect("Failed to build application state.");
-
-                    let app = main_generate_router(state);
-                    let server = TestServer::new(app);
+                let webhook_body =
+                    tokio::time::timeout(std::time::Duration::from_secs(5), receiver.recv())
+                        .await
+                        .expect("The webhook was not sent.")
+                        .expect("The webhook channel closed.");
 
-                    let paste_id = Snowflake::new(517_815_304_354_284_605);
-                    let token_string =
-                        "NTE3ODE1MzA0MzU0Mjg0NjA1.MTc3MDQzODc5Mw==.ozlKKwEEZpoGVuNzPDCyOMRGv";
+                assert_eq!(
+                    webhook_body,
+                    json!({
+                        "event": expected_event,
+                        "paste_id": body.id().to_string(),
+                        "views": expected_views,
+                        "max_views": max_views
+                    }),
+                    "Webhook body does not match."
+                );
 
-                    let deleted_document_id = Snowflake::new(517_815_304_354_284_709);
+                assert!(
+                    receiver.try_recv().is_err(),
+                    "More than one webhook was sent."
+                );
+            }
 
-                    let documents = Document::fetch_all(&pool, &paste_id)
+            #[sqlx::test]
+            async fn test_webhook_private(pool: PgPool) {
+                let config = Config::test_builder()
+                    .webhook_allowed_hosts(vec![String::from("*")])
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
                         .await
-                        .expect("Failed to make DB request");
+                        .expect("Failed to build application state.");
 
-                    assert!(
-                        documents
-                            .iter()
-                            .map(|v| *v.id())
-                            .any(|x| x == deleted_document_id),
-                        "Document ID's does not contain the to be deleted ID."
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let payload = serde_json::to_vec(&json!({
+                    "webhook_url": "http://10.0.0.1/webhook",
+                    "documents": [{"id": 0, "name": "test.txt"}]
+                }))
+                .expect("Failed to build request body.");
+
+                let form = MultipartForm::new()
+                    .add_part(
+                        "payload",
+                        Part::bytes(Bytes::from(payload))
+                            .add_header("Content-Type", "application/json"),
+                    )
+                    .add_part(
+                        "files[0]",
+                        Part::bytes(Bytes::from("test")).add_header("Content-Type", "text/plain"),
                     );
 
-                    let body = json!({
-                        "documents": [
-                            {"id": "517815304354284708"}
-                        ]
-                    });
+                let response = server.post("/v1/pastes").multipart(form).await;
 
-                    let response = server
-                        .patch(&format!("/v1/pastes/{paste_id}"))
-                        .add_header("Authorization", format!("Bearer {token_string}"))
-                        .json(&body)
-                        .await;
+                response.assert_status(StatusCode::BAD_REQUEST);
 
-                    response.assert_status(StatusCode::OK);
+                let body: RESTErrorResponse = response.json();
 
-                    response.assert_header("Content-Type", "application/json");
+                assert_eq!(
+                    body.message(),
+                    "The pastes webhook URL resolves to a private address.",
+                    "Message does not match."
+                );
+            }
+        }
 
-                    let body: ResponsePaste = response.json();
+        mod get_paste_analytics {
+            use super::*;
 
-                    assert!(
-                        !body
-                            .documents()
-                            .iter()
-                            .map(|v| *v.id())
-                            .any(|x| x == deleted_document_id),
-                        "Body Document ID's still contains the deleted document."
-                    );
+            use chrono::DateTime;
 
-                    let updated_documents = Document::fetch_all(&pool, &paste_id)
+            use crate::models::payload::paste::ResponsePasteAnalytics;
+
+            #[rstest]
+            #[case(true, 2)]
+            #[case(false, 0)]
+            #[sqlx::test(fixtures(
+                path = "../../tests/fixtures",
+                scripts("pastes", "documents", "tokens")
+            ))]
+            async fn test_record_view_analytics(
+                #[ignore] pool: PgPool,
+                #[case] record_view_analytics: bool,
+                #[case] expected_views: i64,
+            ) {
+                let config = Config::test_builder()
+                    .record_view_analytics(record_view_analytics)
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
                         .await
-                        .expect("Failed to make DB request");
+                        .expect("Failed to build application state.");
 
-                    assert!(
-                        !updated_documents
-                            .iter()
-                            .map(|v| *v.id())
-                            .any(|x| x == deleted_document_id),
-                        "Updated Database ID's still contains the deleted document."
+                let app = main_generate_router(state).layer(Extension(ConnectInfo(
+                    SocketAddr::from(([127, 0, 0, 1], 8080)),
+                )));
+                let server = TestServer::new(app);
+
+                let paste_id = Snowflake::new(517_815_304_354_284_605);
+
+                for _ in 0..2 {
+                    server
+                        .get(&format!("/v1/pastes/{paste_id}"))
+                        .await
+                        .assert_status(StatusCode::OK);
+                }
+
+                let (views, hashed_views): (i64, i64) = sqlx::query_as(
+                    "SELECT COUNT(*), COUNT(ip_hash) FROM paste_views WHERE paste_id = $1",
+                )
+                .bind(i64::from(paste_id))
+                .fetch_one(&pool)
+                .await
+                .expect("Failed to make DB request");
+
+                assert_eq!(views, expected_views, "Recorded views do not match.");
+                assert_eq!(
+                    hashed_views, expected_views,
+                    "Recorded views are missing their address hash."
+                );
+
+                let ip_hash: Option<String> = sqlx::query_scalar(
+                    "SELECT ip_hash FROM paste_views WHERE paste_id = $1 LIMIT 1",
+                )
+                .bind(i64::from(paste_id))
+                .fetch_optional(&pool)
+                .await
+                .expect("Failed to make DB request")
+                .flatten();
+
+                if let Some(ip_hash) = ip_hash {
+                    assert!(
+                        !ip_hash.contains("127.0.0.1"),
+                        "The address was stored unhashed."
                     );
                 }
 
-                #[sqlx::test(fixtures(
-                    path = "../../tests/fixtures",
-                    scripts("pastes", "documents", "tokens")
-                ))]
-                async fn test_successful_document_update(pool: PgPool) {
-                    let config = Config::test_builder()
-                        .build()
-                        .expect("Failed to build config.");
-                    let object_store = TestObjectStore::new();
-                    let state = ApplicationState::new_tests(
-                        config.clone(),
-                        pool.clone(),
-                        object_store.clone(),
+                let response = server
+                    .get(&format!("/v1/pastes/{paste_id}/analytics"))
+                    .add_header(
+                        "Authorization",
+                        "Bearer NTE3ODE1MzA0MzU0Mjg0NjA1.MTc3MDQzODc5Mw==.ozlKKwEEZpoGVuNzPDCyOMRGv",
                     )
-                    .await
-                    .expect("Failed to build application state.");
+                    .await;
 
-                    let app = main_generate_router(state);
-                    let server = TestServer::new(app);
+                response.assert_status(StatusCode::OK);
 
-                    let paste_id = Snowflake::new(517_815_304_354_284_605);
-                    let token_string =
-                        "NTE3ODE1MzA0MzU0Mjg0NjA1.MTc3MDQzODc5Mw==.ozlKKwEEZpoGVuNzPDCyOMRGv";
+                let body: ResponsePasteAnalytics = response.json();
 
-                    let updated_document_id = Snowflake::new(517_815_304_354_284_708);
+                assert_eq!(
+                    body.buckets().iter().map(|b| b.views()).sum::<usize>(),
+                    expected_views as usize,
+                    "Bucketed views do not match."
+                );
+            }
 
-                    let documents = Document::fetch_all(&pool, &paste_id)
+            #[rstest]
+            #[case("", vec![(10, 2), (11, 1), (34, 1)])]
+            #[case("?interval=hour", vec![(10, 2), (11, 1), (34, 1)])]
+            #[case("?interval=day", vec![(0, 3), (24, 1)])]
+            #[sqlx::test(fixtures(
+                path = "../../tests/fixtures",
+                scripts("pastes", "documents", "tokens")
+            ))]
+            async fn test_buckets(
+                #[ignore] pool: PgPool,
+                #[case] query: &str,
+                #[case] expected: Vec<(i64, usize)>,
+            ) {
+                let config = Config::test_builder()
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
                         .await
-                        .expect("Failed to make DB request");
-                    let document_ids: Vec<Snowflake> = documents.iter().map(|v| *v.id()).collect();
-
-                    assert!(
-                        document_ids.contains(&updated_document_id),
-                        "Document ID's does not contain the to be deleted ID."
-                    );
+                        .expect("Failed to build application state.");
 
-                    let body = json!({
-                        "documents": [
-                            {"id": "517815304354284708", "name": "updated.txt"},
-                            {"id": "517815304354284709"}
-                        ]
-                    });
+                let server = TestServer::new(main_generate_router(state));
 
-                    let response = server
-                        .patch(&format!("/v1/pastes/{paste_id}"))
-                        .add_header("Authorization", format!("Bearer {token_string}"))
-                        .json(&body)
-                        .await;
+                let paste_id = Snowflake::new(517_815_304_354_284_605);
+                let day = DateTime::from_timestamp(1_700_000_000, 0)
+                    .expect("Failed to build timestamp.")
+                    .with_hour(0)
+                    .and_then(|t| t.with_minute(0))
+                    .and_then(|t| t.with_second(0))
+                    .expect("Failed to build timestamp.");
+
+                for minutes in [10 * 60 + 15, 10 * 60 + 45, 11 * 60 + 5, 34 * 60 + 30] {
+                    Paste::insert_view(&pool, &paste_id, day + TimeDelta::minutes(minutes), None)
+                        .await
+                        .expect("Failed to insert view.");
+                }
 
-                    response.assert_status(StatusCode::OK);
+                let response = server
+                    .get(&format!("/v1/pastes/{paste_id}/analytics{query}"))
+                    .add_header(
+                        "Authorization",
+                        "Bearer NTE3ODE1MzA0MzU0Mjg0NjA1.MTc3MDQzODc5Mw==.ozlKKwEEZpoGVuNzPDCyOMRGv",
+                    )
+                    .await;
 
-                    response.assert_header("Content-Type", "application/json");
+                response.assert_status(StatusCode::OK);
 
-                    let body: ResponsePaste = response.json();
+                let body: ResponsePasteAnalytics = response.json();
 
-                    let mut body_document_ids: Vec<Snowflake> =
-                        body.documents().iter().map(|v| *v.id()).collect();
-                    body_document_ids.sort();
+                let buckets: Vec<(i64, usize)> = body
+                    .buckets()
+                    .iter()
+                    .map(|bucket| ((bucket.start() - day).num_hours(), bucket.views()))
+                    .collect();
 
-                    assert_eq!(
-                        document_ids, body_document_ids,
-                        "Body Document ID's were changed."
-                    );
+                assert_eq!(buckets, expected, "Buckets do not match.");
+            }
 
-                    let updated_documents = Document::fetch_all(&pool, &paste_id)
+            #[sqlx::test(fixtures(
+                path = "../../tests/fixtures",
+                scripts("pastes", "documents", "tokens")
+            ))]
+            async fn test_invalid_token(pool: PgPool) {
+                let config = Config::test_builder()
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
                         .await
-                        .expect("Failed to make DB request");
-                    let mut updated_document_ids: Vec<Snowflake> =
-                        updated_documents.iter().map(|v| *v.id()).collect();
-                    updated_document_ids.sort();
+                        .expect("Failed to build application state.");
 
-                    assert_eq!(
-                        document_ids, updated_document_ids,
-                        "Updated Database ID's were changed."
-                    );
+                let server = TestServer::new(main_generate_router(state));
 
-                    let target_document = body
-                        .documents()
-                        .iter()
-                        .find(|d| *d.id() == updated_document_id);
+                let response = server
+                    .get("/v1/pastes/517815304354284605/analytics")
+                    .add_header(
+                        "Authorization",
+                        "Bearer NTE3ODE1MzA0MzU0Mjg0NjAy.MTc0NzgxNjA3NA==.dniyyCHW-xKQcYvjgawVExLCh",
+                    )
+                    .await;
 
-                    let Some(target_document) = target_document else {
-                        panic!("Target document was not found.");
-                    };
+                response.assert_status(StatusCode::UNAUTHORIZED);
+            }
+        }
 
-                    assert_eq!(
-                        target_document.name(),
-                        "updated.txt",
-                        "Name was not updated."
-                    );
+        mod post_paste {
+            use std::io::Write as _;
 
-                    // TODO: Need to validate that the value was not updated in the database.
-                }
+            use flate2::{Compression, write::GzEncoder};
 
-                #[rstest]
-                #[case(
-                    Config::test_builder()
-                        .size_limits(
-                                SizeLimitConfig::test_builder()
-                                    .minimum_expiry_hours(Some(1))
-                                    .build()
-                                    .expect("Failed to build size limit config.")
-                        )
-                        .build()
-                        .expect("Failed to build config."),
-                    json!({
-                        "expiry_timestamp": null,
-                    }),
-                    StatusCode::BAD_REQUEST,
-                    RESTErrorResponse::new("Bad Request", "The expiry timestamp parameter cannot be none."),
-                )]
-                #[case(
-                    Config::test_builder()
-                        .size_limits(
-                                SizeLimitConfig::test_builder()
-                                    .minimum_expiry_hours(Some(1))
-                                    .maximum_expiry_hours(Some(5))
-                                    .build()
-                                    .expect("Failed to build size limit config.")
-                        )
-                        .build()
-                        .expect("Failed to build config."),
-                    json!({
-                        "expiry_timestamp": Utc::now().to_rfc3339(),
-                    }),
-                    StatusCode::BAD_REQUEST,
-                    RESTErrorResponse::new("Bad Request", "The timestamp provided has already passed."),
-                )]
-                #[case(
-                    Config::test_builder()
-                        .size_limits(
-                                SizeLimitConfig::test_builder()
-                                    .minimum_expiry_hours(Some(1))
-                                    .maximum_expiry_hours(Some(5))
-                                    .build()
-                                    .expect("Failed to build size limit config.")
-                        )
-                        .build()
-                        .expect("Failed to build config."),
-                    json!({
-                        "expiry_timestamp": (Utc::now() + TimeDelta::hours(6)).to_rfc3339(),
-                    }),
-                    StatusCode::BAD_REQUEST,
-                    RESTErrorResponse::new("Bad Request", "The timestamp provided is above the maximum."),
-                )]
-                #[sqlx::test(fixtures(
-                    path = "../../tests/fixtures",
-                    scripts("pastes", "documents", "tokens")
-                ))]
-                async fn test_bad(
-                    #[ignore] pool: PgPool,
-                    #[case] config: Config,
-                    #[case] body: serde_json::Value,
-                    #[case] expected_status: StatusCode,
-                    #[case] expected_response: RESTErrorResponse,
-                ) {
-                    let object_store = TestObjectStore::new();
-                    let state = ApplicationState::new_tests(
-                        config.clone(),
-                        pool.clone(),
-                        object_store.clone(),
-                    )
+            use super::*;
+
+            /// Build a multipart body by hand, and gzip it.
+            fn gzip_form(payload: &str, content: &str) -> Vec<u8> {
+                let form = format!(
+                    "--boundary\r\nContent-Disposition: form-data; name=\"payload\"\r\nContent-Type: application/json\r\n\r\n{payload}\r\n--boundary\r\nContent-Disposition: form-data; name=\"files[0]\"\r\nContent-Type: text/plain\r\n\r\n{content}\r\n--boundary--\r\n"
+                );
+
+                let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+                encoder
+                    .write_all(form.as_bytes())
+                    .expect("Failed to compress form.");
+                encoder.finish().expect("Failed to compress form.")
+            }
+
+            #[sqlx::test]
+            async fn test_memory_object_store(pool: PgPool) {
+                let config = Config::test_builder()
+                    .object_store(ObjectStoreConfig::Memory)
+                    .build()
+                    .expect("Failed to build config.");
+
+                let ObjectStore::Memory(object_store) =
+                    ObjectStore::from_config(config.object_store())
+                        .expect("Failed to build object store.")
+                else {
+                    panic!("The memory object store was not selected.");
+                };
+
+                let state = ApplicationState::new_tests(config.clone(), pool.clone(), object_store)
                     .await
                     .expect("Failed to build application state.");
 
-                    let app = main_generate_router(state);
-                    let server = TestServer::new(app);
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
 
-                    let paste_id = Snowflake::new(517_815_304_354_284_605);
-                    let token_string =
-                        "NTE3ODE1MzA0MzU0Mjg0NjA1.MTc3MDQzODc5Mw==.ozlKKwEEZpoGVuNzPDCyOMRGv";
+                let payload = serde_json::to_vec(&json!({
+                    "documents": [{"id": 0, "name": "test.txt"}]
+                }))
+                .expect("Failed to build request body.");
 
-                    let response = server
-                        .patch(&format!("/v1/pastes/{paste_id}"))
-                        .add_header("Authorization", format!("Bearer {token_string}"))
-                        .json(&body)
-                        .await;
+                let form = MultipartForm::new()
+                    .add_part(
+                        "payload",
+                        Part::bytes(Bytes::from(payload))
+                            .add_header("Content-Type", "application/json"),
+                    )
+                    .add_part(
+                        "files[0]",
+                        Part::bytes(Bytes::from("Just some random text."))
+                            .add_header("Content-Type", "text/plain"),
+                    );
 
-                    response.assert_status(expected_status);
+                let response = server.post("/v1/pastes").multipart(form).await;
 
-                    response.assert_header("Content-Type", "application/json");
+                response.assert_status(StatusCode::OK);
 
-                    let body: RESTErrorResponse = response.json();
+                let body: ResponsePaste = response.json();
 
-                    assert_eq!(
-                        body.reason(),
-                        expected_response.reason(),
-                        "Mismatched response reason."
-                    );
+                let Some(document) = body.documents().first() else {
+                    panic!("Document could not be found.");
+                };
 
-                    assert_eq!(
-                        body.message(),
-                        expected_response.message(),
-                        "Mismatched response message."
-                    );
-                }
+                let response = server
+                    .get(&format!(
+                        "/v1/pastes/{}/documents/{}/raw",
+                        body.id(),
+                        document.id()
+                    ))
+                    .await;
+
+                response.assert_status(StatusCode::OK);
+
+                response.assert_text("Just some random text.");
             }
 
-            mod multipart {
-                use super::*;
+            #[sqlx::test]
+            async fn test_trace_spans(pool: PgPool) {
+                use tracing_subscriber::layer::SubscriberExt as _;
 
-                #[rstest]
-                #[case(
-                    Config::test_builder()
-                        .build()
-                        .expect("Failed to build config."),
-                    json!({}),
-                    Some("Test 5"),
-                    None,
-                    Some(20000)
-                )]
-                #[case(
-                    Config::test_builder()
-                        .build()
-                        .expect("Failed to build config."),
-                    json!({
-                        "name": "beans"
-                    }),
-                    Some("beans"),
-                    None,
-                    Some(20000)
-                )]
-                #[case(
-                    Config::test_builder()
-                        .build()
-                        .expect("Failed to build config."),
-                    json!({
-                        "expiry_timestamp": Utc::now() + TimeDelta::hours(5),
-                    }),
-                    Some("Test 5"),
-                    Some((Utc::now() + TimeDelta::hours(5)).with_nanosecond(0).expect("Failed to strip nanoseconds")),
-                    Some(20000)
-                )]
-                #[case(
-                    Config::test_builder()
-                        .build()
-                        .expect("Failed to build config."),
-                    json!({
-                        "max_views": 5000
-                    }),
-                    Some("Test 5"),
-                    None,
-                    Some(5000)
-                )]
-                #[sqlx::test(fixtures(
-                    path = "../../tests/fixtures",
-                    scripts("pastes", "documents", "tokens")
-                ))]
-                async fn test_successful(
-                    #[ignore] pool: PgPool,
-                    #[case] config: Config,
-                    #[case] body: serde_json::Value,
-                    #[case] expected_name: Option<&str>,
-                    #[case] expected_expiry: Option<DtUtc>,
-                    #[case] expected_max_views: Option<usize>,
-                ) {
-                    let object_store = TestObjectStore::new();
-                    let state = ApplicationState::new_tests(
-                        config.clone(),
-                        pool.clone(),
-                        object_store.clone(),
-                    )
-                    .await
-                    .expect("Failed to build application state.");
+                let exporter = opentelemetry_sdk::trace::InMemorySpanExporter::default();
+                let provider = opentelemetry_sdk::trace::SdkTracerProvider::builder()
+                    .with_simple_exporter(exporter.clone())
+                    .build();
 
-                    let app = main_generate_router(state);
-                    let server = TestServer::new(app);
+                let subscriber =
+                    tracing_subscriber::registry().with(crate::app::telemetry::layer(&provider));
+                let _guard = tracing::subscriber::set_default(subscriber);
 
-                    let paste_id = Snowflake::new(517_815_304_354_284_605);
-                    let token_string =
-                        "NTE3ODE1MzA0MzU0Mjg0NjA1.MTc3MDQzODc5Mw==.ozlKKwEEZpoGVuNzPDCyOMRGv";
+                let config = Config::test_builder()
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
 
-                    let value =
-                        Bytes::from(serde_json::to_vec(&body).expect("Failed to build payload"));
-                    let form = MultipartForm::new().add_part(
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let payload = serde_json::to_string(&json!({
+                    "documents": [{"id": 0, "name": "test.txt"}]
+                }))
+                .expect("Failed to build request body.");
+
+                let form = MultipartForm::new()
+                    .add_part(
                         "payload",
-                        Part::bytes(value).add_header("Content-Type", "application/json"),
+                        Part::bytes(Bytes::from(payload))
+                            .add_header("Content-Type", "application/json"),
+                    )
+                    .add_part(
+                        "files[0]",
+                        Part::bytes(Bytes::from("test")).add_header("Content-Type", "text/plain"),
                     );
 
-                    let response = server
-                        .patch(&format!("/v1/pastes/{paste_id}"))
-                        .add_header("Authorization", format!("Bearer {token_string}"))
-                        .multipart(form)
-                        .await;
+                let response = server.post("/v1/pastes").multipart(form).await;
 
-                    response.assert_status(StatusCode::OK);
+                response.assert_status(StatusCode::OK);
 
-                    response.assert_header("Content-Type", "application/json");
+                provider.force_flush().expect("Failed to flush spans.");
 
-                    let body: ResponsePaste = response.json();
+                let spans = exporter
+                    .get_finished_spans()
+                    .expect("Failed to fetch finished spans.");
 
-                    assert_eq!(body.name(), expected_name, "Names do not match.");
+                let request_span = spans
+                    .iter()
+                    .find(|span| span.name == "request")
+                    .expect("The request span was not found.");
 
-                    assert_eq!(
-                        body.expiry(),
-                        expected_expiry.as_ref(),
-                        "Expiry's do not match."
-                    );
+                for name in [
+                    "Paste::insert",
+                    "Document::insert",
+                    "ObjectStore::create_document",
+                ] {
+                    let span = spans
+                        .iter()
+                        .find(|span| span.name == name)
+                        .unwrap_or_else(|| panic!("The {name} span was not found."));
 
                     assert_eq!(
-                        body.max_views(),
-                        expected_max_views,
-                        "Max views do not match."
+                        span.parent_span_id,
+                        request_span.span_context.span_id(),
+                        "The {name} span is not a child of the request span."
                     );
                 }
+            }
 
-                #[sqlx::test(fixtures(
-                    path = "../../tests/fixtures",
-                    scripts("pastes", "documents", "tokens")
-                ))]
-                async fn test_successful_document_update(pool: PgPool) {
-                    let config = Config::test_builder()
-                        .build()
-                        .expect("Failed to build config.");
-                    let object_store = TestObjectStore::new();
-                    let state = ApplicationState::new_tests(
-                        config.clone(),
-                        pool.clone(),
-                        object_store.clone(),
+            #[rstest]
+            #[case(json!({"documents": [{"id": 0, "name": "test.txt"}]}), None)]
+            #[case(
+                json!({"name": "explicit name", "documents": [{"id": 0, "name": "test.txt"}]}),
+                Some("explicit name")
+            )]
+            #[sqlx::test]
+            async fn test_default_paste_name_template(
+                #[ignore] pool: PgPool,
+                #[case] payload: serde_json::Value,
+                #[case] expected_name: Option<&str>,
+            ) {
+                let config = Config::test_builder()
+                    .size_limits(
+                        SizeLimitConfig::test_builder()
+                            .default_paste_name(Some("Paste {snowflake} ({date})".to_string()))
+                            .build()
+                            .expect("Failed to build size limit config."),
                     )
-                    .await
-                    .expect("Failed to build application state.");
-
-                    let app = main_generate_router(state);
-                    let server = TestServer::new(app);
-
-                    let paste_id = Snowflake::new(517_815_304_354_284_605);
-                    let token_string =
-                        "NTE3ODE1MzA0MzU0Mjg0NjA1.MTc3MDQzODc5Mw==.ozlKKwEEZpoGVuNzPDCyOMRGv";
-
-                    let updated_document_id = Snowflake::new(517_815_304_354_284_708);
-
-                    let documents = Document::fetch_all(&pool, &paste_id)
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
                         .await
-                        .expect("Failed to make DB request");
-                    let mut document_ids: Vec<Snowflake> =
-                        documents.iter().map(|v| *v.id()).collect();
-                    document_ids.sort();
+                        .expect("Failed to build application state.");
 
-                    assert!(
-                        document_ids.contains(&updated_document_id),
-                        "Document ID's does not contain the to be deleted ID."
-                    );
+                let server = TestServer::new(main_generate_router(state));
 
-                    let body = json!({
-                        "documents": [
-                            {"id": "517815304354284708"},
-                            {"id": "517815304354284709"}
-                        ]
-                    });
+                let payload =
+                    serde_json::to_string(&payload).expect("Failed to build request body.");
 
-                    let multipart = MultipartForm::new()
-                        .add_part(
-                            "payload",
-                            Part::bytes(
-                                serde_json::to_string(&body).expect("Failed to parse body."),
-                            )
+                let form = MultipartForm::new()
+                    .add_part(
+                        "payload",
+                        Part::bytes(Bytes::from(payload))
                             .add_header("Content-Type", "application/json"),
-                        )
-                        .add_part(
-                            "files[517815304354284708]",
-                            Part::bytes(Bytes::from("test"))
-                                .add_header("Content-Type", "text/plain"),
-                        );
+                    )
+                    .add_part(
+                        "files[0]",
+                        Part::bytes(Bytes::from("test")).add_header("Content-Type", "text/plain"),
+                    );
 
-                    let response = server
-                        .patch(&format!("/v1/pastes/{paste_id}"))
-                        .add_header("Authorization", format!("Bearer {token_string}"))
-                        .multipart(multipart)
-                        .await;
+                let response = server.post("/v1/pastes").multipart(form).await;
 
-                    response.assert_status(StatusCode::OK);
+                response.assert_status(StatusCode::OK);
 
-                    response.assert_header("Content-Type", "application/json");
+                let body: ResponsePaste = response.json();
 
-                    let body: ResponsePaste = response.json();
+                let paste = Paste::fetch(&pool, &body.id())
+                    .await
+                    .expect("Failed to make DB request")
+                    .expect("Failed to find paste.");
 
-                    let mut body_document_ids: Vec<Snowflake> =
-                        body.documents().iter().map(|v| *v.id()).collect();
-                    body_document_ids.sort();
+                let expected_name = expected_name.map_or_else(
+                    || {
+                        format!(
+                            "Paste {} ({})",
+                            body.id(),
+                            paste.creation().format("%Y-%m-%d")
+                        )
+                    },
+                    ToString::to_string,
+                );
 
-                    assert_eq!(
-                        document_ids, body_document_ids,
-                        "Body Document ID's were changed."
-                    );
+                assert_eq!(
+                    paste.name(),
+                    Some(expected_name.as_str()),
+                    "Stored name does not match."
+                );
+                assert_eq!(
+                    body.name(),
+                    Some(expected_name.as_str()),
+                    "Response name does not match."
+                );
+            }
 
-                    let updated_documents = Document::fetch_all(&pool, &paste_id)
+            #[rstest]
+            #[case(true, StatusCode::CONFLICT)]
+            #[case(false, StatusCode::OK)]
+            #[sqlx::test(fixtures(path = "../../tests/fixtures", scripts("pastes")))]
+            async fn test_unique_paste_names(
+                #[ignore] pool: PgPool,
+                #[case] unique_paste_names: bool,
+                #[case] expected_status: StatusCode,
+            ) {
+                let config = Config::test_builder()
+                    .unique_paste_names(unique_paste_names)
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
                         .await
-                        .expect("Failed to make DB request");
-                    let mut updated_document_ids: Vec<Snowflake> =
-                        updated_documents.iter().map(|v| *v.id()).collect();
-                    updated_document_ids.sort();
-
-                    assert_eq!(
-                        document_ids, updated_document_ids,
-                        "Updated Database ID's were changed."
-                    );
+                        .expect("Failed to build application state.");
 
-                    let target_document = body
-                        .documents()
-                        .iter()
-                        .find(|d| *d.id() == updated_document_id);
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
 
-                    let Some(target_document) = target_document else {
-                        panic!("Target document was not found.");
-                    };
+                let payload = serde_json::to_string(&json!({
+                    "name": "Test 5",
+                    "documents": [{"id": 0, "name": "test.txt"}]
+                }))
+                .expect("Failed to build request body.");
 
-                    assert_eq!(
-                        target_document.size(),
-                        4,
-                        "Size of document was not updated."
+                let form = MultipartForm::new()
+                    .add_part(
+                        "payload",
+                        Part::bytes(Bytes::from(payload))
+                            .add_header("Content-Type", "application/json"),
+                    )
+                    .add_part(
+                        "files[0]",
+                        Part::bytes(Bytes::from("test")).add_header("Content-Type", "text/plain"),
                     );
 
-                    let content = object_store
-                        .fetch_document(target_document)
+                let response = server.post("/v1/pastes").multipart(form).await;
+
+                response.assert_status(expected_status);
+
+                let paste_count: i64 =
+                    sqlx::query_scalar("SELECT COUNT(*) FROM pastes WHERE name = $1")
+                        .bind("Test 5")
+                        .fetch_one(&pool)
                         .await
-                        .expect("Failed to find updated document from paste.");
+                        .expect("Failed to make DB request");
 
-                    assert_eq!(
-                        content,
-                        Some(Bytes::from("test")),
-                        "Content does not match."
-                    );
-                }
+                assert_eq!(
+                    paste_count,
+                    if unique_paste_names { 1 } else { 2 },
+                    "Paste count does not match."
+                );
+            }
 
-                #[sqlx::test(fixtures(
-                    path = "../../tests/fixtures",
-                    scripts("pastes", "documents", "tokens")
-                ))]
-                async fn test_successful_document_create(pool: PgPool) {
-                    let config = Config::test_builder()
-                        .build()
-                        .expect("Failed to build config.");
-                    let object_store = TestObjectStore::new();
-                    let state = ApplicationState::new_tests(
-                        config.clone(),
-                        pool.clone(),
-                        object_store.clone(),
+            #[rstest]
+            #[case("/v1/pastes", false)]
+            #[case("/v1/pastes?token_in=body", false)]
+            #[case("/v1/pastes?token_in=header", true)]
+            #[sqlx::test]
+            async fn test_token_in(
+                #[ignore] pool: PgPool,
+                #[case] url: &str,
+                #[case] token_in_header: bool,
+            ) {
+                let config = Config::test_builder()
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let payload = serde_json::to_string(&json!({
+                    "documents": [{"id": 0, "name": "test.txt"}]
+                }))
+                .expect("Failed to build request body.");
+
+                let form = MultipartForm::new()
+                    .add_part(
+                        "payload",
+                        Part::bytes(Bytes::from(payload))
+                            .add_header("Content-Type", "application/json"),
                     )
-                    .await
-                    .expect("Failed to build application state.");
+                    .add_part(
+                        "files[0]",
+                        Part::bytes(Bytes::from("test")).add_header("Content-Type", "text/plain"),
+                    );
 
-                    let app = main_generate_router(state);
-                    let server = TestServer::new(app);
+                let response = server.post(url).multipart(form).await;
 
-                    let paste_id = Snowflake::new(517_815_304_354_284_605);
-                    let token_string =
-                        "NTE3ODE1MzA0MzU0Mjg0NjA1.MTc3MDQzODc5Mw==.ozlKKwEEZpoGVuNzPDCyOMRGv";
+                response.assert_status(StatusCode::OK);
 
-                    let documents = Document::fetch_all(&pool, &paste_id)
+                let header_token = response
+                    .maybe_header(PASTE_TOKEN_HEADER)
+                    .map(|v| v.to_str().expect("Token header is not valid.").to_string());
+
+                let body: ResponsePaste = response.json();
+
+                let token = if token_in_header {
+                    assert!(body.token().is_none(), "Token was returned in the body.");
+                    header_token.expect("Token was not returned in the header.")
+                } else {
+                    assert!(header_token.is_none(), "Token was returned in the header.");
+                    body.token()
+                        .expect("Token was not returned in the body.")
+                        .to_string()
+                };
+
+                let token = Token::fetch(&pool, &token)
+                    .await
+                    .expect("Failed to make DB request")
+                    .expect("Token does not exist.");
+
+                assert_eq!(
+                    token.paste_id(),
+                    &body.id(),
+                    "Token paste ID does not match."
+                );
+            }
+
+            #[rstest]
+            #[case(DocumentNameCase::Sensitive, StatusCode::OK)]
+            #[case(DocumentNameCase::Insensitive, StatusCode::BAD_REQUEST)]
+            #[sqlx::test]
+            async fn test_document_name_case(
+                #[ignore] pool: PgPool,
+                #[case] document_name_case: DocumentNameCase,
+                #[case] expected_status: StatusCode,
+            ) {
+                let config = Config::test_builder()
+                    .document_name_case(document_name_case)
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
                         .await
-                        .expect("Failed to make DB request");
+                        .expect("Failed to build application state.");
 
-                    assert_eq!(documents.len(), 2, "Original document count is incorrect.");
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
 
-                    let body = json!({
-                        "documents": [
-                            {"id": "517815304354284708"},
-                            {"id": "517815304354284709"},
-                            {"id": "0", "name": "new.txt"}
-                        ]
-                    });
+                let payload = serde_json::to_string(&json!({
+                    "documents": [
+                        {"id": 0, "name": "A.txt"},
+                        {"id": 1, "name": "a.txt"}
+                    ]
+                }))
+                .expect("Failed to build request body.");
 
-                    let multipart = MultipartForm::new()
-                        .add_part(
-                            "payload",
-                            Part::bytes(
-                                serde_json::to_string(&body).expect("Failed to parse body."),
-                            )
+                let form = MultipartForm::new()
+                    .add_part(
+                        "payload",
+                        Part::bytes(Bytes::from(payload))
                             .add_header("Content-Type", "application/json"),
-                        )
-                        .add_part(
-                            "files[0]",
-                            Part::bytes(Bytes::from("some cool text"))
-                                .add_header("Content-Type", "text/plain"),
-                        );
-
-                    let response = server
-                        .patch(&format!("/v1/pastes/{paste_id}"))
-                        .add_header("Authorization", format!("Bearer {token_string}"))
-                        .multipart(multipart)
-                        .await;
+                    )
+                    .add_part(
+                        "files[0]",
+                        Part::bytes(Bytes::from("upper")).add_header("Content-Type", "text/plain"),
+                    )
+                    .add_part(
+                        "files[1]",
+                        Part::bytes(Bytes::from("lower")).add_header("Content-Type", "text/plain"),
+                    );
 
-                    response.assert_status(StatusCode::OK);
+                let response = server.post("/v1/pastes").multipart(form).await;
 
-                    response.assert_header("Content-Type", "application/json");
+                response.assert_status(expected_status);
 
+                if expected_status == StatusCode::OK {
                     let body: ResponsePaste = response.json();
 
+                    assert_eq!(body.document_count(), 2, "Document count does not match.");
+                } else {
+                    let body: RESTErrorResponse = response.json();
+
                     assert_eq!(
-                        body.documents().len(),
-                        3,
-                        "Body document count was incorrect."
+                        body.message(),
+                        "More than one document has the name: `a.txt`.",
+                        "Message does not match."
                     );
+                }
+            }
 
-                    let updated_documents = Document::fetch_all(&pool, &paste_id)
+            #[sqlx::test]
+            async fn test_gzip_body(pool: PgPool) {
+                let config = Config::test_builder()
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
                         .await
-                        .expect("Failed to make DB request");
+                        .expect("Failed to build application state.");
 
-                    assert_eq!(
-                        updated_documents.len(),
-                        3,
-                        "DB document count was incorrect."
-                    );
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
 
-                    let target_document = body.documents().iter().find(|d| {
-                        ![517_815_304_354_284_708, 517_815_304_354_284_709].contains(&d.id().id())
-                    });
+                let payload = serde_json::to_string(&json!({
+                    "documents": [{"id": 0, "name": "test.txt"}]
+                }))
+                .expect("Failed to build request body.");
+                let content = "compressed ".repeat(100);
 
-                    let Some(target_document) = target_document else {
-                        panic!("Target document was not found.");
-                    };
+                let response = server
+                    .post("/v1/pastes")
+                    .content_type("multipart/form-data; boundary=boundary")
+                    .add_header("Content-Encoding", "gzip")
+                    .bytes(Bytes::from(gzip_form(&payload, &content)))
+                    .await;
 
-                    assert_eq!(
-                        target_document.paste_id(),
-                        &paste_id,
-                        "Size of document was not updated."
-                    );
+                response.assert_status(StatusCode::OK);
 
-                    assert_eq!(
-                        target_document.size(),
-                        14,
-                        "Size of document was not updated."
-                    );
+                let body: ResponsePaste = response.json();
 
-                    assert_eq!(
-                        target_document.name(),
-                        "new.txt",
-                        "Size of document was not updated."
-                    );
+                let documents = Document::fetch_all(&pool, &body.id())
+                    .await
+                    .expect("Failed to make DB request");
 
-                    assert_eq!(
-                        target_document.doc_type(),
-                        "text/plain",
-                        "Size of document was not updated."
+                let Some(document) = documents.first() else {
+                    panic!("Document could not be found.");
+                };
+
+                assert_eq!(document.size(), content.len(), "Size does not match.");
+
+                let document_contents = object_store
+                    .fetch_document(document)
+                    .await
+                    .expect("Failed to find document contents.");
+
+                assert_eq!(
+                    document_contents,
+                    Some(Bytes::from(content)),
+                    "Contents do not match."
+                );
+            }
+
+            #[rstest]
+            #[case(TextNormalization::Off, "first  \r\nsecond\t\r\n")]
+            #[case(TextNormalization::TrailingWhitespace, "first\r\nsecond\r\n")]
+            #[case(TextNormalization::CrlfToLf, "first  \nsecond\t\n")]
+            #[case(TextNormalization::Both, "first\nsecond\n")]
+            #[sqlx::test]
+            async fn test_normalize_text(
+                #[ignore] pool: PgPool,
+                #[case] normalize_text: TextNormalization,
+                #[case] expected: &str,
+            ) {
+                let config = Config::test_builder()
+                    .normalize_text(normalize_text)
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let content = "first  \r\nsecond\t\r\n";
+
+                let payload = serde_json::to_string(&json!({
+                    "documents": [
+                        {"id": 0, "name": "test.txt"},
+                        {"id": 1, "name": "test.yaml"}
+                    ]
+                }))
+                .expect("Failed to build request body.");
+
+                let form = MultipartForm::new()
+                    .add_part(
+                        "payload",
+                        Part::bytes(Bytes::from(payload))
+                            .add_header("Content-Type", "application/json"),
+                    )
+                    .add_part(
+                        "files[0]",
+                        Part::bytes(Bytes::from(content)).add_header("Content-Type", "text/plain"),
+                    )
+                    .add_part(
+                        "files[1]",
+                        Part::bytes(Bytes::from(content))
+                            .add_header("Content-Type", "application/x-yaml"),
                     );
 
-                    let content = object_store
-                        .fetch_document(target_document)
+                let response = server.post("/v1/pastes").multipart(form).await;
+
+                response.assert_status(StatusCode::OK);
+
+                let body: ResponsePaste = response.json();
+
+                let documents = Document::fetch_all(&pool, &body.id())
+                    .await
+                    .expect("Failed to make DB request");
+
+                for document in documents {
+                    // Only text documents are normalized.
+                    let expected = if document.name() == "test.txt" {
+                        expected
+                    } else {
+                        content
+                    };
+
+                    assert_eq!(document.size(), expected.len(), "Size does not match.");
+
+                    let document_contents = object_store
+                        .fetch_document(&document)
                         .await
-                        .expect("Failed to find updated document from paste.");
+                        .expect("Failed to find document contents.");
 
                     assert_eq!(
-                        content,
-                        Some(Bytes::from("some cool text")),
-                        "Content does not match."
+                        document_contents,
+                        Some(Bytes::from(expected.to_string())),
+                        "Contents do not match."
                     );
                 }
+            }
 
-                #[rstest]
-                #[case(
-                    Config::test_builder()
-                        .size_limits(
-                                SizeLimitConfig::test_builder()
-                                    .minimum_expiry_hours(Some(1))
-                                    .build()
-                                    .expect("Failed to build size limit config.")
-                        )
-                        .build()
-                        .expect("Failed to build config."),
-                    MultipartForm::new()
-                        .add_part("payload", Part::bytes(Bytes::from(serde_json::to_vec(&json!({
-                            "expiry_timestamp": null,
-                            "documents": [
-                                {"id": "517815304354284708"},
-                                {"id": "517815304354284709"}
-                            ]
-                        })).expect("Failed to build payload"))).add_header("Content-Type", "application/json")),
-                    StatusCode::BAD_REQUEST,
-                    RESTErrorResponse::new("Bad Request", "The expiry timestamp parameter cannot be none."),
-                )]
-                #[case(
-                    Config::test_builder()
-                        .size_limits(
-                                SizeLimitConfig::test_builder()
-                                    .minimum_expiry_hours(Some(1))
-                                    .maximum_expiry_hours(Some(5))
-                                    .build()
-                                    .expect("Failed to build size limit config.")
-                        )
-                        .build()
-                        .expect("Failed to build config."),
-                    MultipartForm::new()
-                        .add_part("payload", Part::bytes(Bytes::from(serde_json::to_vec(&json!({
-                            "expiry_timestamp": Utc::now().to_rfc3339(),
-                            "documents": [
-                                {"id": "517815304354284708"},
-                                {"id": "517815304354284709"}
-                            ]
-                        })).expect("Failed to build payload"))).add_header("Content-Type", "application/json")),
-                    StatusCode::BAD_REQUEST,
-                    RESTErrorResponse::new("Bad Request", "The timestamp provided has already passed."),
-                )]
-                #[case(
-                    Config::test_builder()
-                        .size_limits(
-                                SizeLimitConfig::test_builder()
-                                    .minimum_expiry_hours(Some(1))
-                                    .maximum_expiry_hours(Some(5))
-                                    .build()
-                                    .expect("Failed to build size limit config.")
-                        )
-                        .build()
-                        .expect("Failed to build config."),
-                    MultipartForm::new()
-                        .add_part("payload", Part::bytes(Bytes::from(serde_json::to_vec(&json!({
-                            "expiry_timestamp": (Utc::now() + TimeDelta::hours(6)).to_rfc3339(),
-                            "documents": [
-                                {"id": "517815304354284708"},
-                                {"id": "517815304354284709"}
-                            ]
-                        })).expect("Failed to build payload"))).add_header("Content-Type", "application/json")),
-                    StatusCode::BAD_REQUEST,
-                    RESTErrorResponse::new("Bad Request", "The timestamp provided is above the maximum."),
-                )]
-                #[case(
-                    Config::test_builder()
-                        .build()
-                        .expect("Failed to build config."),
-                    MultipartForm::new()
-                        .add_part("payload", Part::bytes(Bytes::from(serde_json::to_vec(&json!({
-                            "documents": [
-                                {"id": "517815304354284708"},
-                                {"id": "517815304354284709"},
-                                {"id": 0}
-                            ]
-                        })).expect("Failed to build payload"))).add_header("Content-Type", "application/json")),
-                    StatusCode::BAD_REQUEST,
-                    RESTErrorResponse::new("Bad Request", "Document(s) were provided that do not exist or do not have contents"),
-                )]
-                #[case(
-                    Config::test_builder()
-                        .build()
-                        .expect("Failed to build config."),
-                    MultipartForm::new()
-                        .add_part("payload", Part::bytes(Bytes::from(serde_json::to_vec(&json!({
-                            "documents": [
-                                {"id": "517815304354284708"},
-                                {"id": "517815304354284709"}
-                            ]
-                        })).expect("Failed to build payload"))).add_header("Content-Type", "application/json"))
-                        .add_part("files[0]", Part::bytes(Bytes::from("test")).add_header("Content-Type", "text/plain")),
-                    StatusCode::BAD_REQUEST,
-                    RESTErrorResponse::new("Bad Request", "A document with the ID of 0 was not found"),
-                )]
-                #[case(
-                    Config::test_builder()
-                        .build()
-                        .expect("Failed to build config."),
-                    MultipartForm::new()
-                        .add_part("payload", Part::bytes(Bytes::from(serde_json::to_vec(&json!({
-                            "documents": [
-                                {"id": "517815304354284708"},
-                                {"id": "517815304354284709"},
-                                {"id": 0}
-                            ]
-                        })).expect("Failed to build payload"))).add_header("Content-Type", "application/json"))
-                        .add_part("files[0]", Part::bytes(Bytes::from("test")).add_header("Content-Type", "text/plain")),
-                    StatusCode::BAD_REQUEST,
-                    RESTErrorResponse::new("Bad Request", "The new document 0 requires the `name` parameter."),
-                )]
-                #[case(
-                    Config::test_builder()
-                        .size_limits(
-                            SizeLimitConfig::test_builder()
-                                .minimum_document_name_size(10)
-                                .build()
-                                .expect("Failed to build size limit config.")
-                        )
-                        .build()
-                        .expect("Failed to build config."),
-                    MultipartForm::new()
-                        .add_part("payload", Part::bytes(Bytes::from(serde_json::to_vec(&json!({
-                            "documents": [
-                                {"id": "517815304354284708"},
-                                {"id": "517815304354284709"},
-                                {"id": 0, "name": "test.txt"}
-                            ]
-                        })).expect("Failed to build payload"))).add_header("Content-Type", "application/json"))
-                        .add_part("files[0]", Part::bytes(Bytes::from("test")).add_header("Content-Type", "text/plain")),
-                    StatusCode::BAD_REQUEST,
-                    RESTErrorResponse::new("Bad Request", "Document `0`'s name: `test.txt` is too small."),
-                )]
-                #[case(
-                    Config::test_builder()
-                        .size_limits(
-                            SizeLimitConfig::test_builder()
-                                .maximum_document_name_size(10)
-                                .build()
-                                .expect("Failed to build size limit config.")
-                        )
-                        .build()
-                        .expect("Failed to build config."),
-                    MultipartForm::new()
-                        .add_part("payload", Part::bytes(Bytes::from(serde_json::to_vec(&json!({
-                            "documents": [
-                                {"id": "517815304354284708"},
-                                {"id": "517815304354284709"},
-                                {"id": 0, "name": "test_file.txt"}
-                            ]
-                        })).expect("Failed to build payload"))).add_header("Content-Type", "application/json"))
-                        .add_part("files[0]", Part::bytes(Bytes::from("test")).add_header("Content-Type", "text/plain")),
-                    StatusCode::BAD_REQUEST,
-                    RESTErrorResponse::new("Bad Request", "Document `0`'s name: `test_file.txt` is too large."),
-                )]
-                #[case(
-                    Config::test_builder()
-                        .size_limits(
-                            SizeLimitConfig::test_builder()
-                                .minimum_document_size(10)
-                                .build()
-                                .expect("Failed to build size limit config.")
-                        )
-                        .build()
-                        .expect("Failed to build config."),
-                    MultipartForm::new()
-                        .add_part("payload", Part::bytes(Bytes::from(serde_json::to_vec(&json!({
-                            "documents": [
-                                {"id": "517815304354284708"},
-                                {"id": "517815304354284709"},
-                                {"id": 0, "name": "test.txt"}
-                            ]
-                        })).expect("Failed to build payload"))).add_header("Content-Type", "application/json"))
-                        .add_part("files[0]", Part::bytes(Bytes::from("test")).add_header("Content-Type", "text/plain")),
-                    StatusCode::BAD_REQUEST,
-                    RESTErrorResponse::new("Bad Request", "Document `0` is too small."),
-                )]
-                #[case(
-                    Config::test_builder()
-                        .size_limits(
-                            SizeLimitConfig::test_builder()
-                                .maximum_document_size(10)
-                                .build()
-                                .expect("Failed to build size limit config.")
-                        )
-                        .build()
-                        .expect("Failed to build config."),
-                    MultipartForm::new()
-                        .add_part("payload", Part::bytes(Bytes::from(serde_json::to_vec(&json!({
-                            "documents": [
-                                {"id": "517815304354284708"},
-                                {"id": "517815304354284709"},
-                                {"id": 0, "name": "test.txt"}
-                            ]
-                        })).expect("Failed to build payload"))).add_header("Content-Type", "application/json"))
-                        .add_part("files[0]", Part::bytes(Bytes::from("some random contents")).add_header("Content-Type", "text/plain")),
-                    StatusCode::BAD_REQUEST,
-                    RESTErrorResponse::new("Bad Request", "Document `0` is too large."),
-                )]
-                #[sqlx::test(fixtures(
-                    path = "../../tests/fixtures",
-                    scripts("pastes", "documents", "tokens")
-                ))]
-                async fn test_failures(
-                    #[ignore] pool: PgPool,
-                    #[case] config: Config,
-                    #[case] form: MultipartForm,
-                    #[case] expected_status: StatusCode,
-                    #[case] expected_response: RESTErrorResponse,
-                ) {
-                    let object_store = TestObjectStore::new();
-                    let state = ApplicationState::new_tests(
-                        config.clone(),
-                        pool.clone(),
-                        object_store.clone(),
+            #[sqlx::test]
+            async fn test_total_document_size_limit(pool: PgPool) {
+                let config = Config::test_builder()
+                    .size_limits(
+                        SizeLimitConfig::test_builder()
+                            .maximum_document_size(1000)
+                            .maximum_total_document_size(1000)
+                            .build()
+                            .expect("Failed to build size limit config."),
+                    )
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let payload = serde_json::to_string(&json!({
+                    "documents": [{"id": 0, "name": "test.txt"}]
+                }))
+                .expect("Failed to build request body.");
+
+                let form = MultipartForm::new()
+                    .add_part(
+                        "payload",
+                        Part::bytes(Bytes::from(payload))
+                            .add_header("Content-Type", "application/json"),
+                    )
+                    .add_part(
+                        "files[0]",
+                        Part::bytes(Bytes::from("a".repeat(1000)))
+                            .add_header("Content-Type", "text/plain"),
+                    );
+
+                let response = server.post("/v1/pastes").multipart(form).await;
+
+                response.assert_status(StatusCode::OK);
+
+                let body: ResponsePaste = response.json();
+
+                assert_eq!(body.total_size(), 1000, "Total size does not match.");
+            }
+
+            #[sqlx::test]
+            async fn test_gzip_body_too_large(pool: PgPool) {
+                let config = Config::test_builder()
+                    .size_limits(
+                        SizeLimitConfig::test_builder()
+                            .maximum_document_size(1000)
+                            .maximum_total_document_size(1000)
+                            .maximum_request_body_size(Some(1000))
+                            .build()
+                            .expect("Failed to build size limit config."),
                     )
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let payload = serde_json::to_string(&json!({
+                    "documents": [{"id": 0, "name": "test.txt"}]
+                }))
+                .expect("Failed to build request body.");
+
+                let body = gzip_form(&payload, &"a".repeat(5000));
+
+                assert!(body.len() < 1000, "The compressed body is too large.");
+
+                let response = server
+                    .post("/v1/pastes")
+                    .content_type("multipart/form-data; boundary=boundary")
+                    .add_header("Content-Encoding", "gzip")
+                    .bytes(Bytes::from(body))
+                    .await;
+
+                response.assert_status(StatusCode::PAYLOAD_TOO_LARGE);
+            }
+
+            #[sqlx::test]
+            async fn test_unsupported_content_encoding(pool: PgPool) {
+                let config = Config::test_builder()
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let response = server
+                    .post("/v1/pastes")
+                    .content_type("multipart/form-data; boundary=boundary")
+                    .add_header("Content-Encoding", "zstd")
+                    .bytes(Bytes::from("--boundary--\r\n"))
+                    .await;
+
+                response.assert_status(StatusCode::UNSUPPORTED_MEDIA_TYPE);
+            }
+
+            #[rstest]
+            #[case(
+                "{\"documents\": [{\"id\": 0, \"name\": 5}]}",
+                "line 1",
+                "(`documents[0].name`): invalid type: integer `5`, expected a string"
+            )]
+            #[case(
+                "{\n  \"documents\": [\n    {\"id\": 0, \"name\": }\n  ]\n}",
+                "line 3",
+                "(`documents[0].name`): expected value"
+            )]
+            #[case("{\"documents\": []} {}", "line 1", ": trailing characters")]
+            #[sqlx::test]
+            async fn test_malformed_payload(
+                #[ignore] pool: PgPool,
+                #[case] payload: &str,
+                #[case] expected_line: &str,
+                #[case] expected_message: &str,
+            ) {
+                let config = Config::test_builder()
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let form = MultipartForm::new()
+                    .add_part(
+                        "payload",
+                        Part::bytes(Bytes::from(payload.to_string()))
+                            .add_header("Content-Type", "application/json"),
+                    )
+                    .add_part(
+                        "files[0]",
+                        Part::bytes(Bytes::from("test")).add_header("Content-Type", "text/plain"),
+                    );
+
+                let response = server.post("/v1/pastes").multipart(form).await;
+
+                response.assert_status(StatusCode::BAD_REQUEST);
+
+                let body: RESTErrorResponse = response.json();
+
+                assert!(
+                    body.message()
+                        .starts_with(&format!("Invalid payload at {expected_line} column ")),
+                    "Location does not match: {}",
+                    body.message()
+                );
+
+                assert!(
+                    body.message().ends_with(expected_message),
+                    "Message does not match: {}",
+                    body.message()
+                );
+            }
+
+            #[sqlx::test]
+            async fn test_invalid_owner_token(pool: PgPool) {
+                let config = Config::test_builder()
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let payload = serde_json::to_vec(&json!({
+                    "documents": [{"id": 0, "name": "test.txt"}]
+                }))
+                .expect("Failed to build request body.");
+
+                let form = MultipartForm::new()
+                    .add_part(
+                        "payload",
+                        Part::bytes(Bytes::from(payload))
+                            .add_header("Content-Type", "application/json"),
+                    )
+                    .add_part(
+                        "files[0]",
+                        Part::bytes(Bytes::from("test")).add_header("Content-Type", "text/plain"),
+                    );
+
+                let response = server
+                    .post("/v1/pastes")
+                    .add_header("Authorization", "Bearer invalid")
+                    .multipart(form)
+                    .await;
+
+                response.assert_status(StatusCode::UNAUTHORIZED);
+
+                let body: RESTErrorResponse = response.json();
+
+                assert_eq!(
+                    body.reason(),
+                    "Invalid Credentials",
+                    "Reason does not match."
+                );
+            }
+
+            #[sqlx::test]
+            async fn test_untyped_document(pool: PgPool) {
+                let config = Config::test_builder()
+                    .size_limits(
+                        SizeLimitConfig::test_builder()
+                            .default_document_mime(String::from("text/markdown"))
+                            .build()
+                            .expect("Failed to build size limit config."),
+                    )
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let payload = serde_json::to_string(&json!({
+                    "documents": [{"id": 0, "name": "README.md"}]
+                }))
+                .expect("Failed to build request body.");
+
+                // Built by hand, as the test multipart form always sets a content type on each part.
+                let form = format!(
+                    "--boundary\r\nContent-Disposition: form-data; name=\"payload\"\r\nContent-Type: application/json\r\n\r\n{payload}\r\n--boundary\r\nContent-Disposition: form-data; name=\"files[0]\"\r\n\r\n# Untyped\r\n--boundary--\r\n"
+                );
+
+                let response = server
+                    .post("/v1/pastes")
+                    .content_type("multipart/form-data; boundary=boundary")
+                    .bytes(Bytes::from(form))
+                    .await;
+
+                response.assert_status(StatusCode::OK);
+
+                let body: ResponsePaste = response.json();
+
+                let documents = Document::fetch_all(&pool, &body.id())
+                    .await
+                    .expect("Failed to make DB request");
+
+                assert_eq!(documents.len(), 1, "Incorrect amount of documents found.");
+
+                assert_eq!(
+                    documents[0].doc_type(),
+                    "text/markdown",
+                    "Document type was not defaulted."
+                );
+            }
+
+            #[sqlx::test]
+            async fn test_source_url(pool: PgPool) {
+                let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+                    .await
+                    .expect("Failed to bind listener.");
+                let address = listener.local_addr().expect("Failed to fetch address.");
+
+                let source_router =
+                    axum::Router::new().route("/remote.txt", get(|| async { "remote contents" }));
+
+                tokio::spawn(async move { axum::serve(listener, source_router).await });
+
+                let config = Config::test_builder()
+                    .source_url_allowed_hosts(vec![String::from("127.0.0.1")])
+                    .source_url_allowed_networks(vec![
+                        "127.0.0.0/8".parse().expect("Failed to parse network."),
+                    ])
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let payload = serde_json::to_vec(&json!({
+                    "documents": [
+                        {
+                            "id": 0,
+                            "name": "remote.txt",
+                            "source_url": format!("http://{address}/remote.txt")
+                        }
+                    ]
+                }))
+                .expect("Failed to build request body.");
+
+                let form = MultipartForm::new().add_part(
+                    "payload",
+                    Part::bytes(Bytes::from(payload))
+                        .add_header("Content-Type", "application/json"),
+                );
+
+                let response = server.post("/v1/pastes").multipart(form).await;
+
+                response.assert_status(StatusCode::OK);
+
+                let body: ResponsePaste = response.json();
+
+                let documents = Document::fetch_all(&pool, &body.id())
+                    .await
+                    .expect("Failed to make DB request");
+
+                assert_eq!(documents.len(), 1, "Incorrect amount of documents found.");
+
+                assert_eq!(
+                    object_store
+                        .fetch_document(&documents[0])
+                        .await
+                        .expect("Failed to fetch document contents."),
+                    Some(Bytes::from("remote contents")),
+                    "Contents do not match."
+                );
+            }
+
+            #[rstest]
+            #[case("http://localhost:8080/remote.txt")]
+            #[case("http://127.0.0.1/remote.txt")]
+            #[case("http://10.0.0.1/remote.txt")]
+            #[case("http://[::1]/remote.txt")]
+            #[sqlx::test]
+            async fn test_source_url_private(#[ignore] pool: PgPool, #[case] source_url: &str) {
+                let config = Config::test_builder()
+                    .source_url_allowed_hosts(vec![String::from("*")])
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let payload = serde_json::to_vec(&json!({
+                    "documents": [
+                        {"id": 0, "name": "remote.txt", "source_url": source_url}
+                    ]
+                }))
+                .expect("Failed to build request body.");
+
+                let form = MultipartForm::new().add_part(
+                    "payload",
+                    Part::bytes(Bytes::from(payload))
+                        .add_header("Content-Type", "application/json"),
+                );
+
+                let response = server.post("/v1/pastes").multipart(form).await;
+
+                response.assert_status(StatusCode::BAD_REQUEST);
+
+                let body: RESTErrorResponse = response.json();
+
+                assert_eq!(
+                    body.message(),
+                    "Document `files[0]`'s source URL resolves to a private address.",
+                    "Message does not match."
+                );
+
+                assert_eq!(
+                    Paste::fetch_count(&pool)
+                        .await
+                        .expect("Failed to make DB request"),
+                    0,
+                    "A paste was created."
+                );
+            }
+
+            #[sqlx::test]
+            async fn test_unknown_field(pool: PgPool) {
+                let config = Config::test_builder()
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                // The body of the unknown field is never terminated, so reading it would fail differently.
+                let form = format!(
+                    "--boundary\r\nContent-Disposition: form-data; name=\"garbage\"\r\n\r\n{}",
+                    "a".repeat(1024)
+                );
+
+                let response = server
+                    .post("/v1/pastes")
+                    .content_type("multipart/form-data; boundary=boundary")
+                    .bytes(Bytes::from(form))
+                    .await;
+
+                response.assert_status(StatusCode::BAD_REQUEST);
+
+                let body: RESTErrorResponse = response.json();
+
+                assert_eq!(
+                    body.message(),
+                    "An unknown multipart item was received: garbage",
+                    "Message does not match."
+                );
+            }
+
+            #[rstest]
+            #[case(true, &["text/plain"], StatusCode::OK)]
+            #[case(true, &["application/octet-stream", "application/json"], StatusCode::OK)]
+            #[case(
+                true,
+                &["application/octet-stream", "application/octet-stream"],
+                StatusCode::BAD_REQUEST
+            )]
+            #[case(false, &["application/octet-stream"], StatusCode::OK)]
+            #[sqlx::test]
+            async fn test_require_text_document(
+                #[ignore] pool: PgPool,
+                #[case] require_text_document: bool,
+                #[case] mimes: &[&str],
+                #[case] expected_status: StatusCode,
+            ) {
+                let config = Config::test_builder()
+                    .require_text_document(require_text_document)
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let documents: Vec<serde_json::Value> = (0..mimes.len())
+                    .map(|i| json!({"id": i, "name": format!("document-{i}")}))
+                    .collect();
+                let payload = serde_json::to_string(&json!({ "documents": documents }))
+                    .expect("Failed to build request body.");
+
+                let mut form = MultipartForm::new().add_part(
+                    "payload",
+                    Part::bytes(Bytes::from(payload))
+                        .add_header("Content-Type", "application/json"),
+                );
+                for (i, mime) in mimes.iter().enumerate() {
+                    form = form.add_part(
+                        format!("files[{i}]"),
+                        Part::bytes(Bytes::from("{}")).add_header("Content-Type", *mime),
+                    );
+                }
+
+                let response = server.post("/v1/pastes").multipart(form).await;
+
+                response.assert_status(expected_status);
+
+                if expected_status == StatusCode::BAD_REQUEST {
+                    let body: RESTErrorResponse = response.json();
+
+                    assert_eq!(
+                        body.message(),
+                        "At least one document must be text.",
+                        "Message does not match."
+                    );
+                }
+            }
+
+            #[sqlx::test]
+            async fn test_max_pastes_per_ip_per_day(pool: PgPool) {
+                let config = Config::test_builder()
+                    .max_pastes_per_ip_per_day(Some(2))
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let build_form = || {
+                    let payload = serde_json::to_string(&json!({
+                        "documents": [{"id": 0, "name": "test.txt"}]
+                    }))
+                    .expect("Failed to build request body.");
+
+                    MultipartForm::new()
+                        .add_part(
+                            "payload",
+                            Part::bytes(Bytes::from(payload))
+                                .add_header("Content-Type", "application/json"),
+                        )
+                        .add_part(
+                            "files[0]",
+                            Part::bytes(Bytes::from("test"))
+                                .add_header("Content-Type", "text/plain"),
+                        )
+                };
+
+                let server = TestServer::new(main_generate_router(state.clone()).layer(Extension(
+                    ConnectInfo(SocketAddr::from(([127, 0, 0, 1], 8080))),
+                )));
+                let other_server = TestServer::new(main_generate_router(state).layer(Extension(
+                    ConnectInfo(SocketAddr::from(([127, 0, 0, 2], 8080))),
+                )));
+
+                for _ in 0..2 {
+                    server
+                        .post("/v1/pastes")
+                        .multipart(build_form())
+                        .await
+                        .assert_status(StatusCode::OK);
+                }
+
+                let response = server.post("/v1/pastes").multipart(build_form()).await;
+
+                response.assert_status(StatusCode::TOO_MANY_REQUESTS);
+
+                let body: RESTErrorResponse = response.json();
+
+                assert_eq!(
+                    body.message(),
+                    "The maximum amount of pastes for today has been created.",
+                    "Message does not match."
+                );
+
+                other_server
+                    .post("/v1/pastes")
+                    .multipart(build_form())
+                    .await
+                    .assert_status(StatusCode::OK);
+            }
+
+            #[sqlx::test]
+            async fn test_max_pastes_per_ip_per_day_rejected(pool: PgPool) {
+                let config = Config::test_builder()
+                    .max_pastes_per_ip_per_day(Some(1))
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let build_form = |payload: serde_json::Value| {
+                    let payload =
+                        serde_json::to_string(&payload).expect("Failed to build request body.");
+
+                    MultipartForm::new()
+                        .add_part(
+                            "payload",
+                            Part::bytes(Bytes::from(payload))
+                                .add_header("Content-Type", "application/json"),
+                        )
+                        .add_part(
+                            "files[0]",
+                            Part::bytes(Bytes::from("test"))
+                                .add_header("Content-Type", "text/plain"),
+                        )
+                };
+
+                let server = TestServer::new(main_generate_router(state).layer(Extension(
+                    ConnectInfo(SocketAddr::from(([127, 0, 0, 1], 8080))),
+                )));
+
+                for _ in 0..2 {
+                    server
+                        .post("/v1/pastes")
+                        .multipart(build_form(json!({
+                            "expiry_timestamp": (Utc::now() - TimeDelta::hours(1)).to_rfc3339(),
+                            "documents": [{"id": 0, "name": "test.txt"}]
+                        })))
+                        .await
+                        .assert_status(StatusCode::UNPROCESSABLE_ENTITY);
+                }
+
+                server
+                    .post("/v1/pastes")
+                    .multipart(build_form(json!({
+                        "documents": [{"id": 0, "name": "test.txt"}]
+                    })))
+                    .await
+                    .assert_status(StatusCode::OK);
+
+                server
+                    .post("/v1/pastes")
+                    .multipart(build_form(json!({
+                        "documents": [{"id": 0, "name": "test.txt"}]
+                    })))
+                    .await
+                    .assert_status(StatusCode::TOO_MANY_REQUESTS);
+            }
+
+            #[rstest]
+            #[case(Some(1), false)]
+            #[case(Some(2), true)]
+            #[case(None, true)]
+            #[sqlx::test]
+            async fn test_max_tokens_per_paste(
+                #[ignore] pool: PgPool,
+                #[case] max_tokens_per_paste: Option<usize>,
+                #[case] expected_allowed: bool,
+            ) {
+                let config = Config::test_builder()
+                    .max_tokens_per_paste(max_tokens_per_paste)
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let payload = serde_json::to_vec(&json!({
+                    "documents": [{"id": 0, "name": "test.txt"}]
+                }))
+                .expect("Failed to build request body.");
+
+                let form = MultipartForm::new()
+                    .add_part(
+                        "payload",
+                        Part::bytes(Bytes::from(payload))
+                            .add_header("Content-Type", "application/json"),
+                    )
+                    .add_part(
+                        "files[0]",
+                        Part::bytes(Bytes::from("test")).add_header("Content-Type", "text/plain"),
+                    );
+
+                // Minting the pastes first token, up to the maximum.
+                let response = server.post("/v1/pastes").multipart(form).await;
+
+                response.assert_status(StatusCode::OK);
+
+                let body: ResponsePaste = response.json();
+
+                let paste_id = body.id();
+
+                assert_eq!(
+                    Token::count_for_paste(&pool, &paste_id)
+                        .await
+                        .expect("Failed to make DB request"),
+                    1,
+                    "Token count does not match."
+                );
+
+                // Minting the next token, beyond the maximum.
+                let mut transaction = pool.begin().await.expect("Failed to begin transaction.");
+
+                let result = enforce_token_maximum(&mut transaction, &config, &paste_id).await;
+
+                assert_eq!(result.is_ok(), expected_allowed, "Result does not match.");
+            }
+
+            #[rstest]
+            #[case(json!({}), Some(5), None, Some("expiry"))]
+            #[case(json!({}), Some(5), Some(50), Some("expiry,max_views"))]
+            #[case(json!({"max_views": 10}), Some(5), Some(50), Some("expiry"))]
+            #[case(json!({"expiry_timestamp": null}), Some(5), None, None)]
+            #[case(json!({"expiry_timestamp": (Utc::now() + TimeDelta::hours(5)).to_rfc3339()}), Some(5), None, None)]
+            #[case(json!({}), None, None, None)]
+            #[sqlx::test]
+            async fn test_applied_defaults(
+                #[ignore] pool: PgPool,
+                #[case] mut payload: serde_json::Value,
+                #[case] default_expiry_hours: Option<usize>,
+                #[case] default_maximum_views: Option<usize>,
+                #[case] expected: Option<&str>,
+            ) {
+                let config = Config::test_builder()
+                    .size_limits(
+                        SizeLimitConfig::test_builder()
+                            .default_expiry_hours(default_expiry_hours)
+                            .default_maximum_views(default_maximum_views)
+                            .build()
+                            .expect("Failed to build size limit config."),
+                    )
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                payload["documents"] = json!([{"id": 0, "name": "test.txt"}]);
+
+                let form = MultipartForm::new()
+                    .add_part(
+                        "payload",
+                        Part::bytes(Bytes::from(
+                            serde_json::to_vec(&payload).expect("Failed to build request body."),
+                        ))
+                        .add_header("Content-Type", "application/json"),
+                    )
+                    .add_part(
+                        "files[0]",
+                        Part::bytes(Bytes::from("test")).add_header("Content-Type", "text/plain"),
+                    );
+
+                let response = server.post("/v1/pastes").multipart(form).await;
+
+                response.assert_status(StatusCode::OK);
+
+                let applied_defaults = response
+                    .maybe_header(APPLIED_DEFAULTS_HEADER)
+                    .map(|v| v.to_str().expect("Header is not valid.").to_string());
+
+                assert_eq!(
+                    applied_defaults.as_deref(),
+                    expected,
+                    "Applied defaults do not match."
+                );
+            }
+
+            #[rstest]
+            #[case(json!({}), Some(50))]
+            #[case(json!({"max_views": null}), None)]
+            #[case(json!({"max_views": 10}), Some(10))]
+            #[case(json!({"expiry_timestamp": null}), Some(50))]
+            #[case(json!({"expiry_timestamp": null, "max_views": null}), None)]
+            #[case(json!({"expiry_timestamp": null, "max_views": 10}), Some(10))]
+            #[case(json!({"expiry_timestamp": (Utc::now() + TimeDelta::hours(5)).to_rfc3339()}), Some(50))]
+            #[case(json!({"expiry_timestamp": (Utc::now() + TimeDelta::hours(5)).to_rfc3339(), "max_views": null}), None)]
+            #[case(json!({"expiry_timestamp": (Utc::now() + TimeDelta::hours(5)).to_rfc3339(), "max_views": 10}), Some(10))]
+            #[sqlx::test]
+            async fn test_default_maximum_views(
+                #[ignore] pool: PgPool,
+                #[case] mut payload: serde_json::Value,
+                #[case] expected_max_views: Option<usize>,
+            ) {
+                let config = Config::test_builder()
+                    .size_limits(
+                        SizeLimitConfig::test_builder()
+                            .default_maximum_views(Some(50))
+                            .build()
+                            .expect("Failed to build size limit config."),
+                    )
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                payload["documents"] = json!([{"id": 0, "name": "test.txt"}]);
+
+                let form = MultipartForm::new()
+                    .add_part(
+                        "payload",
+                        Part::bytes(Bytes::from(
+                            serde_json::to_vec(&payload).expect("Failed to build request body."),
+                        ))
+                        .add_header("Content-Type", "application/json"),
+                    )
+                    .add_part(
+                        "files[0]",
+                        Part::bytes(Bytes::from("test")).add_header("Content-Type", "text/plain"),
+                    );
+
+                let response = server.post("/v1/pastes").multipart(form).await;
+
+                response.assert_status(StatusCode::OK);
+
+                let body: ResponsePaste = response.json();
+
+                assert_eq!(
+                    body.max_views(),
+                    expected_max_views,
+                    "Returned max views do not match."
+                );
+
+                let paste = Paste::fetch(&pool, &body.id())
+                    .await
+                    .expect("Failed to make DB request")
+                    .expect("Failed to find paste.");
+
+                assert_eq!(
+                    paste.max_views(),
+                    expected_max_views,
+                    "Stored max views do not match."
+                );
+            }
+
+            #[sqlx::test]
+            async fn test_oversized_document_identified(pool: PgPool) {
+                let config = Config::test_builder()
+                    .size_limits(
+                        SizeLimitConfig::test_builder()
+                            .maximum_document_size(100)
+                            .build()
+                            .expect("Failed to build size limit config."),
+                    )
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let payload = serde_json::to_vec(&json!({
+                    "documents": [
+                        {"id": 1, "name": "small.txt"},
+                        {"id": 2, "name": "big.log"},
+                        {"id": 3, "name": "other.txt"}
+                    ]
+                }))
+                .expect("Failed to build request body.");
+
+                let form = MultipartForm::new()
+                    .add_part(
+                        "payload",
+                        Part::bytes(Bytes::from(payload))
+                            .add_header("Content-Type", "application/json"),
+                    )
+                    .add_part(
+                        "files[1]",
+                        Part::bytes(Bytes::from("test")).add_header("Content-Type", "text/plain"),
+                    )
+                    .add_part(
+                        "files[2]",
+                        Part::bytes(Bytes::from("a".repeat(110)))
+                            .add_header("Content-Type", "text/plain"),
+                    )
+                    .add_part(
+                        "files[3]",
+                        Part::bytes(Bytes::from("test")).add_header("Content-Type", "text/plain"),
+                    );
+
+                let response = server.post("/v1/pastes").multipart(form).await;
+
+                response.assert_status(StatusCode::UNPROCESSABLE_ENTITY);
+
+                let body: RESTErrorResponse = response.json();
+
+                assert_eq!(
+                    body.code(),
+                    Some("DOCUMENT_TOO_LARGE"),
+                    "Code does not match."
+                );
+
+                assert_eq!(
+                    body.message(),
+                    "Document `files[2]` (big.log) is too large.",
+                    "Message does not match."
+                );
+            }
+
+            #[rstest]
+            #[case(true)]
+            #[case(false)]
+            #[sqlx::test]
+            async fn test_successful(#[ignore] pool: PgPool, #[case] switch_order: bool) {
+                let config = Config::test_builder()
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let payload_expiry = Utc::now() + TimeDelta::days(1);
+
+                // TODO: I think I want to build these properly, as this seems like it could easily break without easy trace backs.
+                let body = json!({
+                    "name": "test paste",
+                    "expiry_timestamp": payload_expiry.to_rfc3339(),
+                    "max_views": 100,
+                    "documents": [
+                        {"id": 0, "name": "custom.json"},
+                        {"id": 1, "name": "random.txt"}
+                    ]
+                });
+
+                let payload = serde_json::to_string(&body).expect("Failed to build request body.");
+
+                let payload_part = Part::bytes(Bytes::from(payload))
+                    .add_header("Content-Type", "application/json");
+
+                let document_1_content = Bytes::from(r#"{"test": "test_value"}"#);
+                let document_1_part = Part::bytes(document_1_content.clone())
+                    .add_header("Content-Type", "application/json");
+
+                let document_2_content = Bytes::from(r"Just some random text.");
+                let document_2_part = Part::bytes(document_2_content.clone())
+                    .add_header("Content-Type", "text/plain");
+
+                let form = {
+                    if switch_order {
+                        MultipartForm::new()
+                            .add_part("payload", payload_part)
+                            .add_part("files[0]", document_1_part)
+                            .add_part("files[1]", document_2_part)
+                    } else {
+                        MultipartForm::new()
+                            .add_part("files[1]", document_2_part)
+                            .add_part("files[0]", document_1_part)
+                            .add_part("payload", payload_part)
+                    }
+                };
+
+                let response = server.post("/v1/pastes").multipart(form).await;
+
+                response.assert_status(StatusCode::OK);
+
+                response.assert_header("Content-Type", "application/json");
+
+                let body: ResponsePaste = response.json();
+
+                assert_eq!(
+                    body.name(),
+                    Some("test paste"),
+                    "Paste name does not match."
+                );
+
+                assert!(body.token().is_some(), "Token was not returned.");
+
+                // TODO: Check that timestamp is recent? within 5~ seconds of  the call?
+
+                assert!(body.edited().is_none(), "Edited was set.");
+
+                assert_eq!(
+                    body.expiry(),
+                    Some(
+                        &payload_expiry
+                            .with_nanosecond(0)
+                            .expect("Failed to strip nanoseconds")
+                    ),
+                    "Expiry does not match."
+                );
+
+                assert_eq!(body.views(), 0, "Views does not match.");
+
+                assert_eq!(body.max_views(), Some(100), "Maximum views does not match.");
+
+                let documents = body.documents();
+                assert_eq!(documents.len(), 2, "Document count does not match.");
+
+                let paste_id = body.id();
+
+                let Some(document_1) = documents.first() else {
+                    panic!("Document 1 could not be found.");
+                };
+
+                assert_eq!(
+                    document_1.paste_id(),
+                    &paste_id,
+                    "Document 1 paste ID does not match parent paste ID.",
+                );
+
+                assert_eq!(
+                    document_1.name(),
+                    "custom.json",
+                    "Document 1 name does not match.",
+                );
+
+                assert_eq!(
+                    document_1.doc_type(),
+                    "application/json",
+                    "Document 1 doc type does not match.",
+                );
+
+                let document_1_contents = object_store
+                    .fetch_document(document_1)
+                    .await
+                    .expect("Failed to find document_1's contents.");
+
+                assert_eq!(
+                    document_1_contents,
+                    Some(document_1_content),
+                    "Document 1 contents do not match.",
+                );
+
+                let Some(document_2) = documents.get(1) else {
+                    panic!("Document 2 could not be found.");
+                };
+
+                assert_eq!(
+                    document_2.paste_id(),
+                    &paste_id,
+                    "Document 2 paste ID does not match parent paste ID.",
+                );
+
+                assert_eq!(
+                    document_2.name(),
+                    "random.txt",
+                    "Document 2 name does not match.",
+                );
+
+                assert_eq!(
+                    document_2.doc_type(),
+                    "text/plain",
+                    "Document 2 doc type does not match.",
+                );
+
+                let document_2_contents = object_store
+                    .fetch_document(document_2)
+                    .await
+                    .expect("Failed to find document_1's contents.");
+
+                assert_eq!(
+                    document_2_contents,
+                    Some(document_2_content),
+                    "Document 2 contents do not match.",
+                );
+            }
+
+            #[rstest]
+            #[case(
+                Config::test_builder()
+                    .build()
+                    .expect("Failed to build config."),
+                json!({
+                    "name": "test paste",
+                    "expiry_timestamp": Utc::now() + TimeDelta::hours(5),
+                    "max_views": 100,
+                    "documents": [{"id": 0, "name": "test.txt"}]
+                }),
+                Some("test paste"),
+                Some((Utc::now() + TimeDelta::hours(5)).with_nanosecond(0).expect("Failed to strip nanoseconds")),
+                Some(100)
+            )]
+            #[case(
+                Config::test_builder()
+                    .build()
+                    .expect("Failed to build config."),
+                json!({
+                    "name": null,
+                    "expiry_timestamp": null,
+                    "max_views": null,
+                    "documents": [{"id": 0, "name": "test.txt"}]
+                }),
+                None,
+                None,
+                None,
+            )]
+            #[case(
+                Config::test_builder()
+                    .size_limits(
+                        SizeLimitConfig::test_builder()
+                            .default_paste_name(None)
+                            .build()
+                            .expect("Failed to build size limit config.")
+                    )
+                    .build()
+                    .expect("Failed to build config."),
+                json!({
+                    "expiry_timestamp": null,
+                    "max_views": null,
+                    "documents": [{"id": 0, "name": "test.txt"}]
+                }),
+                None,
+                None,
+                None,
+            )]
+            #[case(
+                Config::test_builder()
+                    .size_limits(
+                        SizeLimitConfig::test_builder()
+                            .default_paste_name(Some("default_name".to_string()))
+                            .build()
+                            .expect("Failed to build size limit config.")
+                    )
+                    .build()
+                    .expect("Failed to build config."),
+                json!({
+                    "expiry_timestamp": null,
+                    "max_views": null,
+                    "documents": [{"id": 0, "name": "test.txt"}]
+                }),
+                Some("default_name"),
+                None,
+                None,
+            )]
+            #[case(
+                Config::test_builder()
+                    .size_limits(
+                        SizeLimitConfig::test_builder()
+                            .default_expiry_hours(None)
+                            .build()
+                            .expect("Failed to build size limit config.")
+                    )
+                    .build()
+                    .expect("Failed to build config."),
+                json!({
+                    "name": null,
+                    "max_views": null,
+                    "documents": [{"id": 0, "name": "test.txt"}]
+                }),
+                None,
+                None,
+                None,
+            )]
+            #[case(
+                Config::test_builder()
+                    .size_limits(
+                        SizeLimitConfig::test_builder()
+                            .default_expiry_hours(Some(5))
+                            .build()
+                            .expect("Failed to build size limit config.")
+                    )
+                    .build()
+                    .expect("Failed to build config."),
+                json!({
+                    "name": null,
+                    "max_views": null,
+                    "documents": [{"id": 0, "name": "test.txt"}]
+                }),
+                None,
+                Some((Utc::now() + TimeDelta::hours(5)).with_nanosecond(0).expect("Failed to strip nanoseconds")),
+                None,
+            )]
+            #[case(
+                Config::test_builder()
+                    .size_limits(
+                        SizeLimitConfig::test_builder()
+                            .default_maximum_views(None)
+                            .build()
+                            .expect("Failed to build size limit config.")
+                    )
+                    .build()
+                    .expect("Failed to build config."),
+                json!({
+                    "name": null,
+                    "expiry_timestamp": null,
+                    "documents": [{"id": 0, "name": "test.txt"}]
+                }),
+                None,
+                None,
+                None,
+            )]
+            #[case(
+                Config::test_builder()
+                    .size_limits(
+                        SizeLimitConfig::test_builder()
+                            .default_maximum_views(Some(100))
+                            .build()
+                            .expect("Failed to build size limit config.")
+                    )
+                    .build()
+                    .expect("Failed to build config."),
+                json!({
+                    "name": null,
+                    "expiry_timestamp": null,
+                    "documents": [{"id": 0, "name": "test.txt"}]
+                }),
+                None,
+                None,
+                Some(100),
+            )]
+            #[sqlx::test]
+            async fn test_defaults(
+                #[ignore] pool: PgPool,
+                #[case] config: Config,
+                #[case] payload: serde_json::Value,
+                #[case] expected_name: Option<&str>,
+                #[case] expected_expiry: Option<DtUtc>,
+                #[case] expected_maximum_views: Option<usize>,
+            ) {
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let payload =
+                    serde_json::to_string(&payload).expect("Failed to build request body.");
+
+                let payload_part = Part::bytes(Bytes::from(payload))
+                    .add_header("Content-Type", "application/json");
+
+                let document_1_part =
+                    Part::bytes(Bytes::from("test")).add_header("Content-Type", "application/json");
+
+                let form = MultipartForm::new()
+                    .add_part("payload", payload_part)
+                    .add_part("files[0]", document_1_part);
+
+                let response = server.post("/v1/pastes").multipart(form).await;
+
+                response.assert_status(StatusCode::OK);
+
+                response.assert_header("Content-Type", "application/json");
+
+                let body: ResponsePaste = response.json();
+
+                assert_eq!(body.name(), expected_name, "Names do not match.");
+
+                assert_eq!(body.name(), expected_name, "Names do not match.");
+
+                assert_eq!(
+                    body.expiry(),
+                    expected_expiry.as_ref(),
+                    "Expiries do not match."
+                );
+
+                assert_eq!(
+                    body.max_views(),
+                    expected_maximum_views,
+                    "Maximum views do not match."
+                );
+            }
+
+            #[rstest]
+            #[case(
+                Config::test_builder()
+                    .build()
+                    .expect("Failed to build config."),
+                MultipartForm::new()
+                    .add_part("payload", Part::bytes(Bytes::from("{}")).add_header("Content-Type", "application/json")),
+                StatusCode::BAD_REQUEST,
+                RESTErrorResponse::new("Json Parse Error", "missing field `documents` at line 1 column 2"),
+            )]
+            #[case(
+                Config::test_builder()
+                    .build()
+                    .expect("Failed to build config."),
+                MultipartForm::new()
+                    .add_part("payload", Part::bytes(Bytes::from("{")).add_header("Content-Type", "application/json"))
+                    .add_part("files[0]", Part::bytes(Bytes::from("test")).add_header("Content-Type", "text/plain")),
+                StatusCode::BAD_REQUEST,
+                RESTErrorResponse::new("Json Parse Error", "EOF while parsing an object at line 1 column 1"),
+            )]
+            #[case(
+                Config::test_builder()
+                    .build()
+                    .expect("Failed to build config."),
+                MultipartForm::new()
+                    .add_part("payload", Part::bytes(Bytes::from(serde_json::to_vec(&json!({
+                        "expiry_timestamp": (Utc::now() - TimeDelta::hours(1)).to_rfc3339(),
+                        "documents": [{"id": 0, "name": "test.txt"}]
+                    })).expect("Failed to build payload"))).add_header("Content-Type", "application/json"))
+                    .add_part("files[0]", Part::bytes(Bytes::from("test")).add_header("Content-Type", "text/plain")),
+                StatusCode::UNPROCESSABLE_ENTITY,
+                RESTErrorResponse::new("Unprocessable Entity", "The timestamp provided has already passed."),
+            )]
+            #[case(
+                Config::test_builder()
+                    .build()
+                    .expect("Failed to build config."),
+                MultipartForm::new()
+                    .add_part("payload", Part::bytes(Bytes::from("{}")).add_header("Content-Type", "application/json"))
+                    .add_part("files[0]", Part::bytes(Bytes::from("test")).add_header("Content-Type", "text/plain")),
+                StatusCode::BAD_REQUEST,
+                RESTErrorResponse::new("Json Parse Error", "missing field `documents` at line 1 column 2"),
+            )]
+            #[case(
+                Config::test_builder()
+                    .build()
+                    .expect("Failed to build config."),
+                MultipartForm::new()
+                    .add_part("payload", Part::bytes(Bytes::from(serde_json::to_vec(&json!({
+                        "documents": []
+                    })).expect("Failed to build payload"))).add_header("Content-Type", "application/json")),
+                StatusCode::UNPROCESSABLE_ENTITY,
+                RESTErrorResponse::new("Unprocessable Entity", "Not enough documents were provided. Expected: 1, Received: 0"),
+            )]
+            #[case(
+                Config::test_builder()
+                    .build()
+                    .expect("Failed to build config."),
+                MultipartForm::new()
+                    .add_part("payload", Part::bytes(Bytes::from(serde_json::to_vec(&json!({
+                        "documents": [
+                            {"id": 0, "name": "test.txt"}
+                        ]
+                    })).expect("Failed to build payload"))).add_header("Content-Type", "application/json")),
+                StatusCode::BAD_REQUEST,
+                RESTErrorResponse::new("Bad Request", "A document with the ID of 0 was not found"),
+            )]
+            #[case(
+                Config::test_builder()
+                    .build()
+                    .expect("Failed to build config."),
+                MultipartForm::new()
+                    .add_part("payload", Part::bytes(Bytes::from(serde_json::to_vec(&json!({
+                        "documents": []
+                    })).expect("Failed to build payload"))).add_header("Content-Type", "application/json"))
+                    .add_part("files[0]", Part::bytes(Bytes::from("test")).add_header("Content-Type", "text/plain")),
+                StatusCode::BAD_REQUEST,
+                RESTErrorResponse::new("Bad Request", "More files were provided, than listed inside the payload"),
+            )]
+            #[case(
+                Config::test_builder()
+                    .build()
+                    .expect("Failed to build config."),
+                MultipartForm::new()
+                    .add_part("payload", Part::bytes(Bytes::from("{}"))),
+                StatusCode::BAD_REQUEST,
+                RESTErrorResponse::new("Bad Request", "Payload must have a content type of application/json"),
+            )]
+            #[case(
+                Config::test_builder()
+                    .build()
+                    .expect("Failed to build config."),
+                MultipartForm::new()
+                    .add_part("payload", Part::bytes(Bytes::from(serde_json::to_vec(&json!({
+                        "documents": [
+                            {"id": 0, "name": "test.png"}
+                        ]
+                    })).expect("Failed to build payload"))).add_header("Content-Type", "application/json"))
+                    .add_part("files[0]", Part::bytes(Bytes::new()).add_header("Content-Type", "image/png")),
+                StatusCode::BAD_REQUEST,
+                RESTErrorResponse::new("Bad Request", "Invalid mime type: image/png received for the document: 0"),
+            )]
+            #[case(
+                Config::test_builder()
+                    .size_limits(
+                            SizeLimitConfig::test_builder()
+                                .minimum_document_name_size(10)
+                                .build()
+                                .expect("Failed to build size limit config.")
+                    )
+                    .build()
+                    .expect("Failed to build config."),
+                MultipartForm::new()
+                    .add_part("payload", Part::bytes(Bytes::from(serde_json::to_vec(&json!({
+                        "documents": [
+                            {"id": 0, "name": "test.txt"}
+                        ]
+                    })).expect("Failed to build payload"))).add_header("Content-Type", "application/json"))
+                    .add_part("files[0]", Part::bytes(Bytes::from("test")).add_header("Content-Type", "text/plain")),
+                StatusCode::UNPROCESSABLE_ENTITY,
+                RESTErrorResponse::new("Unprocessable Entity", "Document `files[0]`'s name: `test.txt` is too small."),
+            )]
+            #[case(
+                Config::test_builder()
+                    .size_limits(
+                            SizeLimitConfig::test_builder()
+                                .maximum_document_name_size(10)
+                                .build()
+                                .expect("Failed to build size limit config.")
+                    )
+                    .build()
+                    .expect("Failed to build config."),
+                MultipartForm::new()
+                    .add_part("payload", Part::bytes(Bytes::from(serde_json::to_vec(&json!({
+                        "documents": [
+                            {"id": 0, "name": "test_file.txt"}
+                        ]
+                    })).expect("Failed to build payload"))).add_header("Content-Type", "application/json"))
+                    .add_part("files[0]", Part::bytes(Bytes::from("test")).add_header("Content-Type", "text/plain")),
+                StatusCode::UNPROCESSABLE_ENTITY,
+                RESTErrorResponse::new("Unprocessable Entity", "Document `files[0]`'s name: `test_file.txt` is too large."),
+            )]
+            #[case(
+                Config::test_builder()
+                    .size_limits(
+                            SizeLimitConfig::test_builder()
+                                .minimum_document_size(100)
+                                .build()
+                                .expect("Failed to build size limit config.")
+                    )
+                    .build()
+                    .expect("Failed to build config."),
+                MultipartForm::new()
+                    .add_part("payload", Part::bytes(Bytes::from(serde_json::to_vec(&json!({
+                        "documents": [
+                            {"id": 0, "name": "test.txt"}
+                        ]
+                    })).expect("Failed to build payload"))).add_header("Content-Type", "application/json"))
+                    .add_part("files[0]", Part::bytes(Bytes::new()).add_header("Content-Type", "text/plain")),
+                StatusCode::UNPROCESSABLE_ENTITY,
+                RESTErrorResponse::new("Unprocessable Entity", "Document `files[0]` (test.txt) is too small."),
+            )]
+            #[case(
+                Config::test_builder()
+                    .size_limits(
+                            SizeLimitConfig::test_builder()
+                                .maximum_document_size(100)
+                                .build()
+                                .expect("Failed to build size limit config.")
+                    )
+                    .build()
+                    .expect("Failed to build config."),
+                MultipartForm::new()
+                    .add_part("payload", Part::bytes(Bytes::from(serde_json::to_vec(&json!({
+                        "documents": [
+                            {"id": 0, "name": "test.txt"}
+                        ]
+                    })).expect("Failed to build payload"))).add_header("Content-Type", "application/json"))
+                    .add_part("files[0]", Part::bytes(Bytes::from(vec![0; 110])).add_header("Content-Type", "text/plain")),
+                StatusCode::UNPROCESSABLE_ENTITY,
+                RESTErrorResponse::new("Unprocessable Entity", "Document `files[0]` (test.txt) is too large."),
+            )]
+            #[case(
+                Config::test_builder()
+                    .size_limits(
+                            SizeLimitConfig::test_builder()
+                                .minimum_total_document_count(2)
+                                .build()
+                                .expect("Failed to build size limit config.")
+                    )
+                    .build()
+                    .expect("Failed to build config."),
+                MultipartForm::new()
+                    .add_part("payload", Part::bytes(Bytes::from(serde_json::to_vec(&json!({
+                        "documents": [
+                            {"id": 0, "name": "test.txt"}
+                        ]
+                    })).expect("Failed to build payload"))).add_header("Content-Type", "application/json"))
+                    .add_part("files[0]", Part::bytes(Bytes::from("test")).add_header("Content-Type", "text/plain")),
+                StatusCode::UNPROCESSABLE_ENTITY,
+                RESTErrorResponse::new("Unprocessable Entity", "Not enough documents were provided. Expected: 2, Received: 1"),
+            )]
+            #[case(
+                Config::test_builder()
+                    .size_limits(
+                            SizeLimitConfig::test_builder()
+                                .maximum_total_document_count(1)
+                                .build()
+                                .expect("Failed to build size limit config.")
+                    )
+                    .build()
+                    .expect("Failed to build config."),
+                MultipartForm::new()
+                    .add_part("payload", Part::bytes(Bytes::from(serde_json::to_vec(&json!({
+                        "documents": [
+                            {"id": 0, "name": "test.txt"},
+                            {"id": 1, "name": "test2.txt"}
+                        ]
+                    })).expect("Failed to build payload"))).add_header("Content-Type", "application/json"))
+                    .add_part("files[0]", Part::bytes(Bytes::from("test")).add_header("Content-Type", "text/plain"))
+                    .add_part("files[1]", Part::bytes(Bytes::from("test2")).add_header("Content-Type", "text/plain")),
+                StatusCode::UNPROCESSABLE_ENTITY,
+                RESTErrorResponse::new("Unprocessable Entity", "Too many documents were provided. Expected: 1, Received: 2"),
+            )]
+            #[case(
+                Config::test_builder()
+                    .size_limits(
+                            SizeLimitConfig::test_builder()
+                                .minimum_expiry_hours(Some(1))
+                                .maximum_expiry_hours(Some(5))
+                                .build()
+                                .expect("Failed to build size limit config.")
+                    )
+                    .build()
+                    .expect("Failed to build config."),
+                MultipartForm::new()
+                    .add_part("payload", Part::bytes(Bytes::from(serde_json::to_vec(&json!({
+                        "expiry_timestamp": Utc::now().to_rfc3339(),
+                        "documents": [{"id": 0, "name": "test.txt"}]
+                    })).expect("Failed to build payload"))).add_header("Content-Type", "application/json"))
+                    .add_part("files[0]", Part::bytes(Bytes::from("test")).add_header("Content-Type", "text/plain")),
+                StatusCode::UNPROCESSABLE_ENTITY,
+                RESTErrorResponse::new("Unprocessable Entity", "The timestamp provided has already passed."),
+            )]
+            #[case(
+                Config::test_builder()
+                    .size_limits(
+                            SizeLimitConfig::test_builder()
+                                .minimum_expiry_hours(Some(1))
+                                .maximum_expiry_hours(Some(5))
+                                .build()
+                                .expect("Failed to build size limit config.")
+                    )
+                    .build()
+                    .expect("Failed to build config."),
+                MultipartForm::new()
+                    .add_part("payload", Part::bytes(Bytes::from(serde_json::to_vec(&json!({
+                        "expiry_timestamp": (Utc::now() + TimeDelta::hours(6)).to_rfc3339(),
+                        "documents": [{"id": 0, "name": "test.txt"}]
+                    })).expect("Failed to build payload"))).add_header("Content-Type", "application/json"))
+                    .add_part("files[0]", Part::bytes(Bytes::from("test")).add_header("Content-Type", "text/plain")),
+                StatusCode::UNPROCESSABLE_ENTITY,
+                RESTErrorResponse::new("Unprocessable Entity", "The timestamp provided is above the maximum."),
+            )]
+            #[case(
+                Config::test_builder()
+                    .build()
+                    .expect("Failed to build config."),
+                MultipartForm::new()
+                    .add_part("files[0]", Part::bytes(Bytes::from("test")).add_header("Content-Type", "text/plain")),
+                StatusCode::BAD_REQUEST,
+                RESTErrorResponse::new("Bad Request", "The `payload` field was not found in the form data."),
+            )]
+            #[case(
+                Config::test_builder()
+                    .size_limits(
+                            SizeLimitConfig::test_builder()
+                                .maximum_total_document_count(2)
+                                .build()
+                                .expect("Failed to build size limit config.")
+                    )
+                    .build()
+                    .expect("Failed to build config."),
+                MultipartForm::new()
+                    .add_part("files[0]", Part::bytes(Bytes::from("test")).add_header("Content-Type", "text/plain"))
+                    .add_part("files[1]", Part::bytes(Bytes::from("test")).add_header("Content-Type", "text/plain"))
+                    .add_part("files[2]", Part::bytes(Bytes::from("test")).add_header("Content-Type", "text/plain"))
+                    // Never reached, as the extractor rejects the request at the third file.
+                    .add_part("unknown", Part::bytes(Bytes::from("test")).add_header("Content-Type", "text/plain"))
+                    .add_part("payload", Part::bytes(Bytes::from(serde_json::to_vec(&json!({
+                        "documents": [
+                            {"id": 0, "name": "test.txt"},
+                            {"id": 1, "name": "test1.txt"},
+                            {"id": 2, "name": "test2.txt"}
+                        ]
+                    })).expect("Failed to build payload"))).add_header("Content-Type", "application/json")),
+                StatusCode::UNPROCESSABLE_ENTITY,
+                RESTErrorResponse::new("Unprocessable Entity", "Too many documents were provided. Expected: 2, Received: 3"),
+            )]
+            #[case(
+                Config::test_builder()
+                    .size_limits(
+                            SizeLimitConfig::test_builder()
+                                .maximum_total_document_count(2)
+                                .build()
+                                .expect("Failed to build size limit config.")
+                    )
+                    .build()
+                    .expect("Failed to build config."),
+                MultipartForm::new()
+                    .add_part("payload", Part::bytes(Bytes::from(serde_json::to_vec(&json!({
+                        "documents": [
+                            {"id": 0, "name": "test.txt"},
+                            {"id": 1, "name": "test1.txt"},
+                            {"id": 2, "name": "test2.txt"}
+                        ]
+                    })).expect("Failed to build payload"))).add_header("Content-Type", "application/json"))
+                    // Never reached, as the extractor rejects the request at the payload.
+                    .add_part("unknown", Part::bytes(Bytes::from("test")).add_header("Content-Type", "text/plain"))
+                    .add_part("files[0]", Part::bytes(Bytes::from("test")).add_header("Content-Type", "text/plain")),
+                StatusCode::UNPROCESSABLE_ENTITY,
+                RESTErrorResponse::new("Unprocessable Entity", "Too many documents were provided. Expected: 2, Received: 3"),
+            )]
+            #[case(
+                Config::test_builder()
+                    .build()
+                    .expect("Failed to build config."),
+                MultipartForm::new()
+                    .add_part("files[0]", Part::bytes(Bytes::from("test")).add_header("Content-Type", "text/plain"))
+                    .add_part("files[1]", Part::bytes(Bytes::from("test2")).add_header("Content-Type", "text/plain"))
+                    .add_part("payload", Part::bytes(Bytes::from(serde_json::to_vec(&json!({
+                        "documents": [{"id": 0, "name": "test.txt"}]
+                    })).expect("Failed to build payload"))).add_header("Content-Type", "application/json")),
+                StatusCode::BAD_REQUEST,
+                RESTErrorResponse::new("Bad Request", "More files were provided, than listed inside the payload"),
+            )]
+            #[case(
+                Config::test_builder()
+                    .build()
+                    .expect("Failed to build config."),
+                MultipartForm::new()
+                    .add_part("payload", Part::bytes(Bytes::from(serde_json::to_vec(&json!({
+                        "documents": [{"id": 0, "name": "test.txt"}]
+                    })).expect("Failed to build payload"))).add_header("Content-Type", "application/json")),
+                StatusCode::BAD_REQUEST,
+                RESTErrorResponse::new("Bad Request", "A document with the ID of 0 was not found"),
+            )]
+            #[case(
+                Config::test_builder()
+                    .build()
+                    .expect("Failed to build config."),
+                MultipartForm::new()
+                    .add_part("payload", Part::bytes(Bytes::from(serde_json::to_vec(&json!({
+                        "documents": []
+                    })).expect("Failed to build payload"))).add_header("Content-Type", "application/json")),
+                StatusCode::UNPROCESSABLE_ENTITY,
+                RESTErrorResponse::new("Unprocessable Entity", "Not enough documents were provided. Expected: 1, Received: 0"),
+            )]
+            #[sqlx::test]
+            async fn test_failures(
+                #[ignore] pool: PgPool,
+                #[case] config: Config,
+                #[case] form: MultipartForm,
+                #[case] expected_status: StatusCode,
+                #[case] expected_response: RESTErrorResponse,
+            ) {
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let response = server.post("/v1/pastes").multipart(form).await;
+
+                response.assert_status(expected_status);
+
+                response.assert_header("Content-Type", "application/json");
+
+                let body: RESTErrorResponse = response.json();
+
+                assert_eq!(
+                    body.reason(),
+                    expected_response.reason(),
+                    "Mismatched response reason."
+                );
+
+                assert_eq!(
+                    body.message(),
+                    expected_response.message(),
+                    "Mismatched response message."
+                );
+            }
+        }
+
+        mod post_paste_validate {
+            use super::*;
+
+            fn build_form(content: &str) -> MultipartForm {
+                let payload = serde_json::to_vec(&json!({
+                    "name": "validated",
+                    "documents": [
+                        {"id": 0, "name": "test.txt"}
+                    ]
+                }))
+                .expect("Failed to build request body.");
+
+                MultipartForm::new()
+                    .add_part(
+                        "payload",
+                        Part::bytes(Bytes::from(payload))
+                            .add_header("Content-Type", "application/json"),
+                    )
+                    .add_part(
+                        "files[0]",
+                        Part::bytes(Bytes::from(content.to_string()))
+                            .add_header("Content-Type", "text/plain"),
+                    )
+            }
+
+            #[sqlx::test]
+            async fn test_successful(pool: PgPool) {
+                let config = Config::test_builder()
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let response = server
+                    .post("/v1/pastes/validate")
+                    .multipart(build_form("test content"))
+                    .await;
+
+                response.assert_status(StatusCode::OK);
+
+                let body: ResponsePasteValidation = response.json();
+
+                assert_eq!(body.name(), Some("validated"), "Name does not match.");
+                assert_eq!(body.document_count(), 1, "Document count does not match.");
+                assert_eq!(body.total_size(), 12, "Total size does not match.");
+
+                assert_eq!(
+                    Paste::fetch_count(&pool)
+                        .await
+                        .expect("Failed to make DB request"),
+                    0,
+                    "A paste was created."
+                );
+
+                let (document_count, _) = Document::fetch_totals(&pool)
+                    .await
+                    .expect("Failed to make DB request");
+
+                assert_eq!(document_count, 0, "A document was created.");
+            }
+
+            #[sqlx::test]
+            async fn test_oversized_document(pool: PgPool) {
+                let config = Config::test_builder()
+                    .size_limits(
+                        SizeLimitConfig::test_builder()
+                            .maximum_document_size(5)
+                            .build()
+                            .expect("Failed to build size limit config."),
+                    )
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let validate_response = server
+                    .post("/v1/pastes/validate")
+                    .multipart(build_form("test content"))
+                    .await;
+
+                let create_response = server
+                    .post("/v1/pastes")
+                    .multipart(build_form("test content"))
+                    .await;
+
+                validate_response.assert_status(StatusCode::UNPROCESSABLE_ENTITY);
+                create_response.assert_status(StatusCode::UNPROCESSABLE_ENTITY);
+
+                let validate_body: RESTErrorResponse = validate_response.json();
+                let create_body: RESTErrorResponse = create_response.json();
+
+                assert_eq!(
+                    validate_body.code(),
+                    create_body.code(),
+                    "Code does not match."
+                );
+                assert_eq!(
+                    validate_body.message(),
+                    create_body.message(),
+                    "Message does not match."
+                );
+            }
+
+            #[rstest]
+            #[case("/v1/pastes/validate?validate=all")]
+            #[case("/v1/pastes?validate=all")]
+            #[sqlx::test]
+            async fn test_validate_all(#[ignore] pool: PgPool, #[case] url: &str) {
+                let config = Config::test_builder()
+                    .size_limits(
+                        SizeLimitConfig::test_builder()
+                            .maximum_document_size(5)
+                            .maximum_paste_name_size(3)
+                            .build()
+                            .expect("Failed to build size limit config."),
+                    )
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let response = server.post(url).multipart(build_form("test content")).await;
+
+                response.assert_status(StatusCode::UNPROCESSABLE_ENTITY);
+
+                let body: RESTErrorResponse = response.json();
+
+                let errors: Vec<(&str, &str, &str)> = body
+                    .errors()
+                    .iter()
+                    .map(|error| (error.field(), error.code(), error.message()))
+                    .collect();
+
+                assert_eq!(
+                    errors,
+                    vec![
+                        (
+                            "files[0]",
+                            "DOCUMENT_TOO_LARGE",
+                            "Document `files[0]` (test.txt) is too large."
+                        ),
+                        ("name", "INVALID", "The pastes name is too long."),
+                    ],
+                    "Errors do not match."
+                );
+
+                assert_eq!(
+                    Paste::fetch_count(&pool)
+                        .await
+                        .expect("Failed to make DB request"),
+                    0,
+                    "A paste was created."
+                );
+            }
+        }
+
+        mod patch_paste {
+            use super::*;
+
+            mod json {
+                use super::*;
+
+                #[rstest]
+                #[case(
+                    Config::test_builder()
+                        .build()
+                        .expect("Failed to build config."),
+                    json!({}),
+                    Some("Test 5"),
+                    None,
+                    Some(20000)
+                )]
+                #[case(
+                    Config::test_builder()
+                        .build()
+                        .expect("Failed to build config."),
+                    json!({
+                        "name": "beans"
+                    }),
+                    Some("beans"),
+                    None,
+                    Some(20000)
+                )]
+                #[case(
+                    Config::test_builder()
+                        .build()
+                        .expect("Failed to build config."),
+                    json!({
+                        "expiry_timestamp": Utc::now() + TimeDelta::hours(5),
+                    }),
+                    Some("Test 5"),
+                    Some((Utc::now() + TimeDelta::hours(5)).with_nanosecond(0).expect("Failed to strip nanoseconds")),
+                    Some(20000)
+                )]
+                #[case(
+                    Config::test_builder()
+                        .build()
+                        .expect("Failed to build config."),
+                    json!({
+                        "max_views": 5000
+                    }),
+                    Some("Test 5"),
+                    None,
+                    Some(5000)
+                )]
+                #[sqlx::test(fixtures(
+                    path = "../../tests/fixtures",
+                    scripts("pastes", "documents", "tokens")
+                ))]
+                async fn test_successful(
+                    #[ignore] pool: PgPool,
+                    #[case] config: Config,
+                    #[case] body: serde_json::Value,
+                    #[case] expected_name: Option<&str>,
+                    #[case] expected_expiry: Option<DtUtc>,
+                    #[case] expected_max_views: Option<usize>,
+                ) {
+                    let object_store = MemoryObjectStore::new();
+                    let state = ApplicationState::new_tests(
+                        config.clone(),
+                        pool.clone(),
+                        object_store.clone(),
+                    )
+                    .await
+                    .expect("Failed to build application state.");
+
+                    let app = main_generate_router(state);
+                    let server = TestServer::new(app);
+
+                    let paste_id = Snowflake::new(517_815_304_354_284_605);
+                    let token_string =
+                        "NTE3ODE1MzA0MzU0Mjg0NjA1.MTc3MDQzODc5Mw==.ozlKKwEEZpoGVuNzPDCyOMRGv";
+
+                    let response = server
+                        .patch(&format!("/v1/pastes/{paste_id}"))
+                        .add_header("Authorization", format!("Bearer {token_string}"))
+                        .json(&body)
+                        .await;
+
+                    response.assert_status(StatusCode::OK);
+
+                    response.assert_header("Content-Type", "application/json");
+
+                    let body: ResponsePaste = response.json();
+
+                    assert_eq!(body.name(), expected_name, "Names do not match.");
+
+                    assert_eq!(
+                        body.expiry(),
+                        expected_expiry.as_ref(),
+                        "Expiry's do not match."
+                    );
+
+                    assert_eq!(
+                        body.max_views(),
+                        expected_max_views,
+                        "Max views do not match."
+                    );
+                }
+
+                #[rstest]
+                #[case(true, "Test 1", StatusCode::CONFLICT)]
+                #[case(true, "Test 5", StatusCode::OK)]
+                #[case(false, "Test 1", StatusCode::OK)]
+                #[sqlx::test(fixtures(
+                    path = "../../tests/fixtures",
+                    scripts("pastes", "documents", "tokens")
+                ))]
+                async fn test_unique_paste_names(
+                    #[ignore] pool: PgPool,
+                    #[case] unique_paste_names: bool,
+                    #[case] name: &str,
+                    #[case] expected_status: StatusCode,
+                ) {
+                    let config = Config::test_builder()
+                        .unique_paste_names(unique_paste_names)
+                        .build()
+                        .expect("Failed to build config.");
+                    let object_store = MemoryObjectStore::new();
+                    let state = ApplicationState::new_tests(
+                        config.clone(),
+                        pool.clone(),
+                        object_store.clone(),
+                    )
+                    .await
+                    .expect("Failed to build application state.");
+
+                    let app = main_generate_router(state);
+                    let server = TestServer::new(app);
+
+                    let paste_id = Snowflake::new(517_815_304_354_284_605);
+                    let token_string =
+                        "NTE3ODE1MzA0MzU0Mjg0NjA1.MTc3MDQzODc5Mw==.ozlKKwEEZpoGVuNzPDCyOMRGv";
+
+                    let response = server
+                        .patch(&format!("/v1/pastes/{paste_id}"))
+                        .add_header("Authorization", format!("Bearer {token_string}"))
+                        .json(&json!({
+                            "name": name
+                        }))
+                        .await;
+
+                    response.assert_status(expected_status);
+
+                    let paste = Paste::fetch(&pool, &paste_id)
+                        .await
+                        .expect("Failed to make DB request")
+                        .expect("Failed to find paste.");
+
+                    let expected_name = if expected_status == StatusCode::OK {
+                        name
+                    } else {
+                        "Test 5"
+                    };
+
+                    assert_eq!(paste.name(), Some(expected_name), "Names do not match.");
+                }
+
+                #[rstest]
+                #[case(2322)]
+                #[case(100)]
+                #[sqlx::test(fixtures(
+                    path = "../../tests/fixtures",
+                    scripts("pastes", "documents", "tokens")
+                ))]
+                async fn test_max_views_below_current(
+                    #[ignore] pool: PgPool,
+                    #[case] max_views: usize,
+                ) {
+                    let config = Config::test_builder()
+                        .build()
+                        .expect("Failed to build config.");
+                    let object_store = MemoryObjectStore::new();
+                    let state = ApplicationState::new_tests(
+                        config.clone(),
+                        pool.clone(),
+                        object_store.clone(),
+                    )
+                    .await
+                    .expect("Failed to build application state.");
+
+                    let app = main_generate_router(state);
+                    let server = TestServer::new(app);
+
+                    let paste_id = Snowflake::new(517_815_304_354_284_605);
+                    let token_string =
+                        "NTE3ODE1MzA0MzU0Mjg0NjA1.MTc3MDQzODc5Mw==.ozlKKwEEZpoGVuNzPDCyOMRGv";
+
+                    let response = server
+                        .patch(&format!("/v1/pastes/{paste_id}"))
+                        .add_header("Authorization", format!("Bearer {token_string}"))
+                        .json(&json!({
+                            "max_views": max_views
+                        }))
+                        .await;
+
+                    response.assert_status(StatusCode::BAD_REQUEST);
+
+                    let body: RESTErrorResponse = response.json();
+
+                    assert_eq!(
+                        body.code(),
+                        Some("MAX_VIEWS_BELOW_CURRENT"),
+                        "Code does not match."
+                    );
+
+                    assert_eq!(
+                        body.message(),
+                        "The maximum views must be greater than the current view count of 2322.",
+                        "Message does not match."
+                    );
+
+                    let paste = Paste::fetch(&pool, &paste_id)
+                        .await
+                        .expect("Failed to make DB request")
+                        .expect("Failed to find paste.");
+
+                    assert_eq!(paste.max_views(), Some(20000), "Max views was updated.");
+                }
+
+                #[sqlx::test(fixtures(
+                    path = "../../tests/fixtures",
+                    scripts("pastes", "documents", "tokens")
+                ))]
+                async fn test_current_if_match(pool: PgPool) {
+                    let config = Config::test_builder()
+                        .build()
+                        .expect("Failed to build config.");
+                    let object_store = MemoryObjectStore::new();
+                    let state = ApplicationState::new_tests(
+                        config.clone(),
+                        pool.clone(),
+                        object_store.clone(),
+                    )
+                    .await
+                    .expect("Failed to build application state.");
+
+                    let app = main_generate_router(state);
+                    let server = TestServer::new(app);
+
+                    let paste_id = Snowflake::new(517_815_304_354_284_605);
+                    let token_string =
+                        "NTE3ODE1MzA0MzU0Mjg0NjA1.MTc3MDQzODc5Mw==.ozlKKwEEZpoGVuNzPDCyOMRGv";
+
+                    let response = server
+                        .patch(&format!("/v1/pastes/{paste_id}"))
+                        .add_header("Authorization", format!("Bearer {token_string}"))
+                        .add_header("If-Match", "\"0\"")
+                        .json(&json!({
+                            "name": "beans"
+                        }))
+                        .await;
+
+                    response.assert_status(StatusCode::OK);
+
+                    let body: ResponsePaste = response.json();
+
+                    assert_eq!(body.version(), 1, "Version was not bumped.");
+
+                    let paste = Paste::fetch(&pool, &paste_id)
+                        .await
+                        .expect("Failed to make DB request")
+                        .expect("Failed to find paste.");
+
+                    assert_eq!(paste.version(), 1, "Stored version was not bumped.");
+                    assert_eq!(paste.name(), Some("beans"), "Name was not updated.");
+                }
+
+                #[sqlx::test(fixtures(
+                    path = "../../tests/fixtures",
+                    scripts("pastes", "documents", "tokens")
+                ))]
+                async fn test_stale_if_match(pool: PgPool) {
+                    let config = Config::test_builder()
+                        .build()
+                        .expect("Failed to build config.");
+                    let object_store = MemoryObjectStore::new();
+                    let state = ApplicationState::new_tests(
+                        config.clone(),
+                        pool.clone(),
+                        object_store.clone(),
+                    )
+                    .await
+                    .expect("Failed to build application state.");
+
+                    let app = main_generate_router(state);
+                    let server = TestServer::new(app);
+
+                    let paste_id = Snowflake::new(517_815_304_354_284_605);
+                    let token_string =
+                        "NTE3ODE1MzA0MzU0Mjg0NjA1.MTc3MDQzODc5Mw==.ozlKKwEEZpoGVuNzPDCyOMRGv";
+
+                    let response = server
+                        .patch(&format!("/v1/pastes/{paste_id}"))
+                        .add_header("Authorization", format!("Bearer {token_string}"))
+                        .add_header("If-Match", "\"0\"")
+                        .json(&json!({
+                            "name": "first"
+                        }))
+                        .await;
+
+                    response.assert_status(StatusCode::OK);
+
+                    let response = server
+                        .patch(&format!("/v1/pastes/{paste_id}"))
+                        .add_header("Authorization", format!("Bearer {token_string}"))
+                        .add_header("If-Match", "\"0\"")
+                        .json(&json!({
+                            "name": "second"
+                        }))
+                        .await;
+
+                    response.assert_status(StatusCode::PRECONDITION_FAILED);
+
+                    let body: RESTErrorResponse = response.json();
+
+                    assert_eq!(
+                        body.message(),
+                        "The resource has been modified since the version provided.",
+                        "Message does not match."
+                    );
+
+                    let paste = Paste::fetch(&pool, &paste_id)
+                        .await
+                        .expect("Failed to make DB request")
+                        .expect("Failed to find paste.");
+
+                    assert_eq!(paste.version(), 1, "Version was bumped.");
+                    assert_eq!(paste.name(), Some("first"), "Name was updated.");
+                }
+
+                #[sqlx::test(fixtures(
+                    path = "../../tests/fixtures",
+                    scripts("pastes", "documents", "tokens")
+                ))]
+                async fn test_successful_document_deletion(pool: PgPool) {
+                    let config = Config::test_builder()
+                        .build()
+                        .expect("Failed to build config.");
+                    let object_store = MemoryObjectStore::new();
+                    let state = ApplicationState::new_tests(
+                        config.clone(),
+                        pool.clone(),
+                        object_store.clone(),
+                    )
+                    .await
+                    .expect("Failed to build application state.");
+
+                    let app = main_generate_router(state);
+                    let server = TestServer::new(app);
+
+                    let paste_id = Snowflake::new(517_815_304_354_284_605);
+                    let token_string =
+                        "NTE3ODE1MzA0MzU0Mjg0NjA1.MTc3MDQzODc5Mw==.ozlKKwEEZpoGVuNzPDCyOMRGv";
+
+                    let deleted_document_id = Snowflake::new(517_815_304_354_284_709);
+
+                    let documents = Document::fetch_all(&pool, &paste_id)
+                        .await
+                        .expect("Failed to make DB request");
+
+                    assert!(
+                        documents
+                            .iter()
+                            .map(|v| *v.id())
+                            .any(|x| x == deleted_document_id),
+                        "Document ID's does not contain the to be deleted ID."
+                    );
+
+                    let body = json!({
+                        "documents": [
+                            {"id": "517815304354284708"}
+                        ]
+                    });
+
+                    let response = server
+                        .patch(&format!("/v1/pastes/{paste_id}"))
+                        .add_header("Authorization", format!("Bearer {token_string}"))
+                        .json(&body)
+                        .await;
+
+                    response.assert_status(StatusCode::OK);
+
+                    response.assert_header("Content-Type", "application/json");
+
+                    let body: ResponsePaste = response.json();
+
+                    assert!(
+                        !body
+                            .documents()
+                            .iter()
+                            .map(|v| *v.id())
+                            .any(|x| x == deleted_document_id),
+                        "Body Document ID's still contains the deleted document."
+                    );
+
+                    let updated_documents = Document::fetch_all(&pool, &paste_id)
+                        .await
+                        .expect("Failed to make DB request");
+
+                    assert!(
+                        !updated_documents
+                            .iter()
+                            .map(|v| *v.id())
+                            .any(|x| x == deleted_document_id),
+                        "Updated Database ID's still contains the deleted document."
+                    );
+                }
+
+                #[sqlx::test(fixtures(
+                    path = "../../tests/fixtures",
+                    scripts("pastes", "documents", "tokens")
+                ))]
+                async fn test_successful_document_update(pool: PgPool) {
+                    let config = Config::test_builder()
+                        .build()
+                        .expect("Failed to build config.");
+                    let object_store = MemoryObjectStore::new();
+                    let state = ApplicationState::new_tests(
+                        config.clone(),
+                        pool.clone(),
+                        object_store.clone(),
+                    )
+                    .await
+                    .expect("Failed to build application state.");
+
+                    let app = main_generate_router(state);
+                    let server = TestServer::new(app);
+
+                    let paste_id = Snowflake::new(517_815_304_354_284_605);
+                    let token_string =
+                        "NTE3ODE1MzA0MzU0Mjg0NjA1.MTc3MDQzODc5Mw==.ozlKKwEEZpoGVuNzPDCyOMRGv";
+
+                    let updated_document_id = Snowflake::new(517_815_304_354_284_708);
+
+                    let documents = Document::fetch_all(&pool, &paste_id)
+                        .await
+                        .expect("Failed to make DB request");
+                    let document_ids: Vec<Snowflake> = documents.iter().map(|v| *v.id()).collect();
+
+                    assert!(
+                        document_ids.contains(&updated_document_id),
+                        "Document ID's does not contain the to be deleted ID."
+                    );
+
+                    let body = json!({
+                        "documents": [
+                            {"id": "517815304354284708", "name": "updated.txt"},
+                            {"id": "517815304354284709"}
+                        ]
+                    });
+
+                    let response = server
+                        .patch(&format!("/v1/pastes/{paste_id}"))
+                        .add_header("Authorization", format!("Bearer {token_string}"))
+                        .json(&body)
+                        .await;
+
+                    response.assert_status(StatusCode::OK);
+
+                    response.assert_header("Content-Type", "application/json");
+
+                    let body: ResponsePaste = response.json();
+
+                    let mut body_document_ids: Vec<Snowflake> =
+                        body.documents().iter().map(|v| *v.id()).collect();
+                    body_document_ids.sort();
+
+                    assert_eq!(
+                        document_ids, body_document_ids,
+                        "Body Document ID's were changed."
+                    );
+
+                    let updated_documents = Document::fetch_all(&pool, &paste_id)
+                        .await
+                        .expect("Failed to make DB request");
+                    let mut updated_document_ids: Vec<Snowflake> =
+                        updated_documents.iter().map(|v| *v.id()).collect();
+                    updated_document_ids.sort();
+
+                    assert_eq!(
+                        document_ids, updated_document_ids,
+                        "Updated Database ID's were changed."
+                    );
+
+                    let target_document = body
+                        .documents()
+                        .iter()
+                        .find(|d| *d.id() == updated_document_id);
+
+                    let Some(target_document) = target_document else {
+                        panic!("Target document was not found.");
+                    };
+
+                    assert_eq!(
+                        target_document.name(),
+                        "updated.txt",
+                        "Name was not updated."
+                    );
+
+                    // TODO: Need to validate that the value was not updated in the database.
+                }
+
+                #[rstest]
+                #[case(
+                    Config::test_builder()
+                        .size_limits(
+                                SizeLimitConfig::test_builder()
+                                    .minimum_expiry_hours(Some(1))
+                                    .build()
+                                    .expect("Failed to build size limit config.")
+                        )
+                        .build()
+                        .expect("Failed to build config."),
+                    json!({
+                        "expiry_timestamp": null,
+                    }),
+                    StatusCode::UNPROCESSABLE_ENTITY,
+                    RESTErrorResponse::new("Unprocessable Entity", "The expiry timestamp parameter cannot be none."),
+                )]
+                #[case(
+                    Config::test_builder()
+                        .size_limits(
+                                SizeLimitConfig::test_builder()
+                                    .minimum_expiry_hours(Some(1))
+                                    .maximum_expiry_hours(Some(5))
+                                    .build()
+                                    .expect("Failed to build size limit config.")
+                        )
+                        .build()
+                        .expect("Failed to build config."),
+                    json!({
+                        "expiry_timestamp": Utc::now().to_rfc3339(),
+                    }),
+                    StatusCode::UNPROCESSABLE_ENTITY,
+                    RESTErrorResponse::new("Unprocessable Entity", "The timestamp provided has already passed."),
+                )]
+                #[case(
+                    Config::test_builder()
+                        .size_limits(
+                                SizeLimitConfig::test_builder()
+                                    .minimum_expiry_hours(Some(1))
+                                    .maximum_expiry_hours(Some(5))
+                                    .build()
+                                    .expect("Failed to build size limit config.")
+                        )
+                        .build()
+                        .expect("Failed to build config."),
+                    json!({
+                        "expiry_timestamp": (Utc::now() + TimeDelta::hours(6)).to_rfc3339(),
+                    }),
+                    StatusCode::UNPROCESSABLE_ENTITY,
+                    RESTErrorResponse::new("Unprocessable Entity", "The timestamp provided is above the maximum."),
+                )]
+                #[sqlx::test(fixtures(
+                    path = "../../tests/fixtures",
+                    scripts("pastes", "documents", "tokens")
+                ))]
+                async fn test_bad(
+                    #[ignore] pool: PgPool,
+                    #[case] config: Config,
+                    #[case] body: serde_json::Value,
+                    #[case] expected_status: StatusCode,
+                    #[case] expected_response: RESTErrorResponse,
+                ) {
+                    let object_store = MemoryObjectStore::new();
+                    let state = ApplicationState::new_tests(
+                        config.clone(),
+                        pool.clone(),
+                        object_store.clone(),
+                    )
+                    .await
+                    .expect("Failed to build application state.");
+
+                    let app = main_generate_router(state);
+                    let server = TestServer::new(app);
+
+                    let paste_id = Snowflake::new(517_815_304_354_284_605);
+                    let token_string =
+                        "NTE3ODE1MzA0MzU0Mjg0NjA1.MTc3MDQzODc5Mw==.ozlKKwEEZpoGVuNzPDCyOMRGv";
+
+                    let response = server
+                        .patch(&format!("/v1/pastes/{paste_id}"))
+                        .add_header("Authorization", format!("Bearer {token_string}"))
+                        .json(&body)
+                        .await;
+
+                    response.assert_status(expected_status);
+
+                    response.assert_header("Content-Type", "application/json");
+
+                    let body: RESTErrorResponse = response.json();
+
+                    assert_eq!(
+                        body.reason(),
+                        expected_response.reason(),
+                        "Mismatched response reason."
+                    );
+
+                    assert_eq!(
+                        body.message(),
+                        expected_response.message(),
+                        "Mismatched response message."
+                    );
+                }
+            }
+
+            mod multipart {
+                use super::*;
+
+                #[rstest]
+                #[case(
+                    Config::test_builder()
+                        .build()
+                        .expect("Failed to build config."),
+                    json!({}),
+                    Some("Test 5"),
+                    None,
+                    Some(20000)
+                )]
+                #[case(
+                    Config::test_builder()
+                        .build()
+                        .expect("Failed to build config."),
+                    json!({
+                        "name": "beans"
+                    }),
+                    Some("beans"),
+                    None,
+                    Some(20000)
+                )]
+                #[case(
+                    Config::test_builder()
+                        .build()
+                        .expect("Failed to build config."),
+                    json!({
+                        "expiry_timestamp": Utc::now() + TimeDelta::hours(5),
+                    }),
+                    Some("Test 5"),
+                    Some((Utc::now() + TimeDelta::hours(5)).with_nanosecond(0).expect("Failed to strip nanoseconds")),
+                    Some(20000)
+                )]
+                #[case(
+                    Config::test_builder()
+                        .build()
+                        .expect("Failed to build config."),
+                    json!({
+                        "max_views": 5000
+                    }),
+                    Some("Test 5"),
+                    None,
+                    Some(5000)
+                )]
+                #[sqlx::test(fixtures(
+                    path = "../../tests/fixtures",
+                    scripts("pastes", "documents", "tokens")
+                ))]
+                async fn test_successful(
+                    #[ignore] pool: PgPool,
+                    #[case] config: Config,
+                    #[case] body: serde_json::Value,
+                    #[case] expected_name: Option<&str>,
+                    #[case] expected_expiry: Option<DtUtc>,
+                    #[case] expected_max_views: Option<usize>,
+                ) {
+                    let object_store = MemoryObjectStore::new();
+                    let state = ApplicationState::new_tests(
+                        config.clone(),
+                        pool.clone(),
+                        object_store.clone(),
+                    )
+                    .await
+                    .expect("Failed to build application state.");
+
+                    let app = main_generate_router(state);
+                    let server = TestServer::new(app);
+
+                    let paste_id = Snowflake::new(517_815_304_354_284_605);
+                    let token_string =
+                        "NTE3ODE1MzA0MzU0Mjg0NjA1.MTc3MDQzODc5Mw==.ozlKKwEEZpoGVuNzPDCyOMRGv";
+
+                    let value =
+                        Bytes::from(serde_json::to_vec(&body).expect("Failed to build payload"));
+                    let form = MultipartForm::new().add_part(
+                        "payload",
+                        Part::bytes(value).add_header("Content-Type", "application/json"),
+                    );
+
+                    let response = server
+                        .patch(&format!("/v1/pastes/{paste_id}"))
+                        .add_header("Authorization", format!("Bearer {token_string}"))
+                        .multipart(form)
+                        .await;
+
+                    response.assert_status(StatusCode::OK);
+
+                    response.assert_header("Content-Type", "application/json");
+
+                    let body: ResponsePaste = response.json();
+
+                    assert_eq!(body.name(), expected_name, "Names do not match.");
+
+                    assert_eq!(
+                        body.expiry(),
+                        expected_expiry.as_ref(),
+                        "Expiry's do not match."
+                    );
+
+                    assert_eq!(
+                        body.max_views(),
+                        expected_max_views,
+                        "Max views do not match."
+                    );
+                }
+
+                #[sqlx::test(fixtures(
+                    path = "../../tests/fixtures",
+                    scripts("pastes", "documents", "tokens")
+                ))]
+                async fn test_successful_document_update(pool: PgPool) {
+                    let config = Config::test_builder()
+                        .build()
+                        .expect("Failed to build config.");
+                    let object_store = MemoryObjectStore::new();
+                    let state = ApplicationState::new_tests(
+                        config.clone(),
+                        pool.clone(),
+                        object_store.clone(),
+                    )
+                    .await
+                    .expect("Failed to build application state.");
+
+                    let app = main_generate_router(state);
+                    let server = TestServer::new(app);
+
+                    let paste_id = Snowflake::new(517_815_304_354_284_605);
+                    let token_string =
+                        "NTE3ODE1MzA0MzU0Mjg0NjA1.MTc3MDQzODc5Mw==.ozlKKwEEZpoGVuNzPDCyOMRGv";
+
+                    let updated_document_id = Snowflake::new(517_815_304_354_284_708);
+
+                    let documents = Document::fetch_all(&pool, &paste_id)
+                        .await
+                        .expect("Failed to make DB request");
+                    let mut document_ids: Vec<Snowflake> =
+                        documents.iter().map(|v| *v.id()).collect();
+                    document_ids.sort();
+
+                    assert!(
+                        document_ids.contains(&updated_document_id),
+                        "Document ID's does not contain the to be deleted ID."
+                    );
+
+                    let body = json!({
+                        "documents": [
+                            {"id": "517815304354284708"},
+                            {"id": "517815304354284709"}
+                        ]
+                    });
+
+                    let multipart = MultipartForm::new()
+                        .add_part(
+                            "payload",
+                            Part::bytes(
+                                serde_json::to_string(&body).expect("Failed to parse body."),
+                            )
+                            .add_header("Content-Type", "application/json"),
+                        )
+                        .add_part(
+                            "files[517815304354284708]",
+                            Part::bytes(Bytes::from("test"))
+                                .add_header("Content-Type", "text/plain"),
+                        );
+
+                    let response = server
+                        .patch(&format!("/v1/pastes/{paste_id}"))
+                        .add_header("Authorization", format!("Bearer {token_string}"))
+                        .multipart(multipart)
+                        .await;
+
+                    response.assert_status(StatusCode::OK);
+
+                    response.assert_header("Content-Type", "application/json");
+
+                    let body: ResponsePaste = response.json();
+
+                    let mut body_document_ids: Vec<Snowflake> =
+                        body.documents().iter().map(|v| *v.id()).collect();
+                    body_document_ids.sort();
+
+                    assert_eq!(
+                        document_ids, body_document_ids,
+                        "Body Document ID's were changed."
+                    );
+
+                    let updated_documents = Document::fetch_all(&pool, &paste_id)
+                        .await
+                        .expect("Failed to make DB request");
+                    let mut updated_document_ids: Vec<Snowflake> =
+                        updated_documents.iter().map(|v| *v.id()).collect();
+                    updated_document_ids.sort();
+
+                    assert_eq!(
+                        document_ids, updated_document_ids,
+                        "Updated Database ID's were changed."
+                    );
+
+                    let target_document = body
+                        .documents()
+                        .iter()
+                        .find(|d| *d.id() == updated_document_id);
+
+                    let Some(target_document) = target_document else {
+                        panic!("Target document was not found.");
+                    };
+
+                    assert_eq!(
+                        target_document.size(),
+                        4,
+                        "Size of document was not updated."
+                    );
+
+                    let content = object_store
+                        .fetch_document(target_document)
+                        .await
+                        .expect("Failed to find updated document from paste.");
+
+                    assert_eq!(
+                        content,
+                        Some(Bytes::from("test")),
+                        "Content does not match."
+                    );
+
+                    assert_eq!(
+                        target_document.checksum(),
+                        Some("9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08"),
+                        "Checksum of document was not updated."
+                    );
+
+                    let stored_document =
+                        Document::fetch_with_paste(&pool, &paste_id, &updated_document_id)
+                            .await
+                            .expect("Failed to make DB request")
+                            .expect("Updated document was not found.");
+
+                    assert_eq!(
+                        stored_document.checksum(),
+                        target_document.checksum(),
+                        "Stored checksum does not match."
+                    );
+                }
+
+                #[sqlx::test(fixtures(
+                    path = "../../tests/fixtures",
+                    scripts("pastes", "documents", "tokens")
+                ))]
+                async fn test_exceeds_total_size(pool: PgPool) {
+                    let config = Config::test_builder()
+                        .size_limits(
+                            SizeLimitConfig::test_builder()
+                                .maximum_total_document_size(500)
+                                .build()
+                                .expect("Failed to build size limit config."),
+                        )
+                        .build()
+                        .expect("Failed to build config.");
+                    let object_store = MemoryObjectStore::new();
+                    let state = ApplicationState::new_tests(
+                        config.clone(),
+                        pool.clone(),
+                        object_store.clone(),
+                    )
+                    .await
+                    .expect("Failed to build application state.");
+
+                    let app = main_generate_router(state);
+                    let server = TestServer::new(app);
+
+                    let paste_id = Snowflake::new(517_815_304_354_284_605);
+                    let token_string =
+                        "NTE3ODE1MzA0MzU0Mjg0NjA1.MTc3MDQzODc5Mw==.ozlKKwEEZpoGVuNzPDCyOMRGv";
+
+                    let document_id = Snowflake::new(517_815_304_354_284_708);
+
+                    let document = Document::fetch_with_paste(&pool, &paste_id, &document_id)
+                        .await
+                        .expect("Failed to make DB request")
+                        .expect("Document does not exist.");
+
+                    let original = Bytes::from("a".repeat(document.size()));
+
+                    object_store
+                        .create_document(&document, original.clone())
+                        .await
+                        .expect("Failed to create document contents.");
+
+                    let body = json!({
+                        "documents": [
+                            {"id": "517815304354284708"},
+                            {"id": "517815304354284709"}
+                        ]
+                    });
+
+                    let multipart = MultipartForm::new()
+                        .add_part(
+                            "payload",
+                            Part::bytes(
+                                serde_json::to_string(&body).expect("Failed to parse body."),
+                            )
+                            .add_header("Content-Type", "application/json"),
+                        )
+                        .add_part(
+                            "files[517815304354284708]",
+                            Part::bytes(Bytes::from("b".repeat(500)))
+                                .add_header("Content-Type", "text/plain"),
+                        );
+
+                    let response = server
+                        .patch(&format!("/v1/pastes/{paste_id}"))
+                        .add_header("Authorization", format!("Bearer {token_string}"))
+                        .multipart(multipart)
+                        .await;
+
+                    response.assert_status(StatusCode::UNPROCESSABLE_ENTITY);
+
+                    let current = Document::fetch_with_paste(&pool, &paste_id, &document_id)
+                        .await
+                        .expect("Failed to make DB request")
+                        .expect("Document does not exist.");
+
+                    assert_eq!(current.size(), document.size(), "Size does not match.");
+
+                    assert_eq!(
+                        object_store
+                            .fetch_document(&current)
+                            .await
+                            .expect("Failed to fetch document contents."),
+                        Some(original),
+                        "Contents do not match."
+                    );
+                }
+
+                #[sqlx::test(fixtures(
+                    path = "../../tests/fixtures",
+                    scripts("pastes", "documents", "tokens")
+                ))]
+                async fn test_successful_document_create(pool: PgPool) {
+                    let config = Config::test_builder()
+                        .build()
+                        .expect("Failed to build config.");
+                    let object_store = MemoryObjectStore::new();
+                    let state = ApplicationState::new_tests(
+                        config.clone(),
+                        pool.clone(),
+                        object_store.clone(),
+                    )
+                    .await
+                    .expect("Failed to build application state.");
+
+                    let app = main_generate_router(state);
+                    let server = TestServer::new(app);
+
+                    let paste_id = Snowflake::new(517_815_304_354_284_605);
+                    let token_string =
+                        "NTE3ODE1MzA0MzU0Mjg0NjA1.MTc3MDQzODc5Mw==.ozlKKwEEZpoGVuNzPDCyOMRGv";
+
+                    let documents = Document::fetch_all(&pool, &paste_id)
+                        .await
+                        .expect("Failed to make DB request");
+
+                    assert_eq!(documents.len(), 2, "Original document count is incorrect.");
+
+                    let body = json!({
+                        "documents": [
+                            {"id": "517815304354284708"},
+                            {"id": "517815304354284709"},
+                            {"id": "0", "name": "new.txt"}
+                        ]
+                    });
+
+                    let multipart = MultipartForm::new()
+                        .add_part(
+                            "payload",
+                            Part::bytes(
+                                serde_json::to_string(&body).expect("Failed to parse body."),
+                            )
+                            .add_header("Content-Type", "application/json"),
+                        )
+                        .add_part(
+                            "files[0]",
+                            Part::bytes(Bytes::from("some cool text"))
+                                .add_header("Content-Type", "text/plain"),
+                        );
+
+                    let response = server
+                        .patch(&format!("/v1/pastes/{paste_id}"))
+                        .add_header("Authorization", format!("Bearer {token_string}"))
+                        .multipart(multipart)
+                        .await;
+
+                    response.assert_status(StatusCode::OK);
+
+                    response.assert_header("Content-Type", "application/json");
+
+                    let body: ResponsePaste = response.json();
+
+                    assert_eq!(
+                        body.documents().len(),
+                        3,
+                        "Body document count was incorrect."
+                    );
+
+                    let updated_documents = Document::fetch_all(&pool, &paste_id)
+                        .await
+                        .expect("Failed to make DB request");
+
+                    assert_eq!(
+                        updated_documents.len(),
+                        3,
+                        "DB document count was incorrect."
+                    );
+
+                    let target_document = body.documents().iter().find(|d| {
+                        ![517_815_304_354_284_708, 517_815_304_354_284_709].contains(&d.id().id())
+                    });
+
+                    let Some(target_document) = target_document else {
+                        panic!("Target document was not found.");
+                    };
+
+                    assert_eq!(
+                        target_document.paste_id(),
+                        &paste_id,
+                        "Size of document was not updated."
+                    );
+
+                    assert_eq!(
+                        target_document.size(),
+                        14,
+                        "Size of document was not updated."
+                    );
+
+                    assert_eq!(
+                        target_document.name(),
+                        "new.txt",
+                        "Size of document was not updated."
+                    );
+
+                    assert_eq!(
+                        target_document.doc_type(),
+                        "text/plain",
+                        "Size of document was not updated."
+                    );
+
+                    let content = object_store
+                        .fetch_document(target_document)
+                        .await
+                        .expect("Failed to find updated document from paste.");
+
+                    assert_eq!(
+                        content,
+                        Some(Bytes::from("some cool text")),
+                        "Content does not match."
+                    );
+                }
+
+                #[sqlx::test(fixtures(
+                    path = "../../tests/fixtures",
+                    scripts("pastes", "documents", "tokens")
+                ))]
+                async fn test_oversized_document_identified(pool: PgPool) {
+                    let config = Config::test_builder()
+                        .size_limits(
+                            SizeLimitConfig::test_builder()
+                                .maximum_document_size(100)
+                                .build()
+                                .expect("Failed to build size limit config."),
+                        )
+                        .build()
+                        .expect("Failed to build config.");
+                    let object_store = MemoryObjectStore::new();
+                    let state = ApplicationState::new_tests(
+                        config.clone(),
+                        pool.clone(),
+                        object_store.clone(),
+                    )
+                    .await
+                    .expect("Failed to build application state.");
+
+                    let app = main_generate_router(state);
+                    let server = TestServer::new(app);
+
+                    let paste_id = Snowflake::new(517_815_304_354_284_605);
+                    let token_string =
+                        "NTE3ODE1MzA0MzU0Mjg0NjA1.MTc3MDQzODc5Mw==.ozlKKwEEZpoGVuNzPDCyOMRGv";
+
+                    let payload = serde_json::to_vec(&json!({
+                        "documents": [
+                            {"id": "517815304354284708"},
+                            {"id": "517815304354284709"},
+                            {"id": 2, "name": "big.log"}
+                        ]
+                    }))
+                    .expect("Failed to build request body.");
+
+                    let form = MultipartForm::new()
+                        .add_part(
+                            "payload",
+                            Part::bytes(Bytes::from(payload))
+                                .add_header("Content-Type", "application/json"),
+                        )
+                        .add_part(
+                            "files[2]",
+                            Part::bytes(Bytes::from("a".repeat(110)))
+                                .add_header("Content-Type", "text/plain"),
+                        );
+
+                    let response = server
+                        .patch(&format!("/v1/pastes/{paste_id}"))
+                        .add_header("Authorization", format!("Bearer {token_string}"))
+                        .multipart(form)
+                        .await;
+
+                    response.assert_status(StatusCode::UNPROCESSABLE_ENTITY);
+
+                    let body: RESTErrorResponse = response.json();
+
+                    assert_eq!(
+                        body.code(),
+                        Some("DOCUMENT_TOO_LARGE"),
+                        "Code does not match."
+                    );
+
+                    assert_eq!(
+                        body.message(),
+                        "Document `files[2]` (big.log) is too large.",
+                        "Message does not match."
+                    );
+
+                    let documents = Document::fetch_all(&pool, &paste_id)
+                        .await
+                        .expect("Failed to make DB request");
+
+                    assert_eq!(documents.len(), 2, "A document was created.");
+                }
+
+                #[rstest]
+                #[case(
+                    Config::test_builder()
+                        .size_limits(
+                                SizeLimitConfig::test_builder()
+                                    .minimum_expiry_hours(Some(1))
+                                    .build()
+                                    .expect("Failed to build size limit config.")
+                        )
+                        .build()
+                        .expect("Failed to build config."),
+                    MultipartForm::new()
+                        .add_part("payload", Part::bytes(Bytes::from(serde_json::to_vec(&json!({
+                            "expiry_timestamp": null,
+                            "documents": [
+                                {"id": "517815304354284708"},
+                                {"id": "517815304354284709"}
+                            ]
+                        })).expect("Failed to build payload"))).add_header("Content-Type", "application/json")),
+                    StatusCode::UNPROCESSABLE_ENTITY,
+                    RESTErrorResponse::new("Unprocessable Entity", "The expiry timestamp parameter cannot be none."),
+                )]
+                #[case(
+                    Config::test_builder()
+                        .size_limits(
+                                SizeLimitConfig::test_builder()
+                                    .minimum_expiry_hours(Some(1))
+                                    .maximum_expiry_hours(Some(5))
+                                    .build()
+                                    .expect("Failed to build size limit config.")
+                        )
+                        .build()
+                        .expect("Failed to build config."),
+                    MultipartForm::new()
+                        .add_part("payload", Part::bytes(Bytes::from(serde_json::to_vec(&json!({
+                            "expiry_timestamp": Utc::now().to_rfc3339(),
+                            "documents": [
+                                {"id": "517815304354284708"},
+                                {"id": "517815304354284709"}
+                            ]
+                        })).expect("Failed to build payload"))).add_header("Content-Type", "application/json")),
+                    StatusCode::UNPROCESSABLE_ENTITY,
+                    RESTErrorResponse::new("Unprocessable Entity", "The timestamp provided has already passed."),
+                )]
+                #[case(
+                    Config::test_builder()
+                        .size_limits(
+                                SizeLimitConfig::test_builder()
+                                    .minimum_expiry_hours(Some(1))
+                                    .maximum_expiry_hours(Some(5))
+                                    .build()
+                                    .expect("Failed to build size limit config.")
+                        )
+                        .build()
+                        .expect("Failed to build config."),
+                    MultipartForm::new()
+                        .add_part("payload", Part::bytes(Bytes::from(serde_json::to_vec(&json!({
+                            "expiry_timestamp": (Utc::now() + TimeDelta::hours(6)).to_rfc3339(),
+                            "documents": [
+                                {"id": "517815304354284708"},
+                                {"id": "517815304354284709"}
+                            ]
+                        })).expect("Failed to build payload"))).add_header("Content-Type", "application/json")),
+                    StatusCode::UNPROCESSABLE_ENTITY,
+                    RESTErrorResponse::new("Unprocessable Entity", "The timestamp provided is above the maximum."),
+                )]
+                #[case(
+                    Config::test_builder()
+                        .build()
+                        .expect("Failed to build config."),
+                    MultipartForm::new()
+                        .add_part("payload", Part::bytes(Bytes::from(serde_json::to_vec(&json!({
+                            "documents": [
+                                {"id": "517815304354284708"},
+                                {"id": "517815304354284709"},
+                                {"id": 0}
+                            ]
+                        })).expect("Failed to build payload"))).add_header("Content-Type", "application/json")),
+                    StatusCode::BAD_REQUEST,
+                    RESTErrorResponse::new("Bad Request", "Document(s) were provided that do not exist or do not have contents"),
+                )]
+                #[case(
+                    Config::test_builder()
+                        .build()
+                        .expect("Failed to build config."),
+                    MultipartForm::new()
+                        .add_part("payload", Part::bytes(Bytes::from(serde_json::to_vec(&json!({
+                            "documents": [
+                                {"id": "517815304354284708"},
+                                {"id": "517815304354284709"}
+                            ]
+                        })).expect("Failed to build payload"))).add_header("Content-Type", "application/json"))
+                        .add_part("files[0]", Part::bytes(Bytes::from("test")).add_header("Content-Type", "text/plain")),
+                    StatusCode::BAD_REQUEST,
+                    RESTErrorResponse::new("Bad Request", "A document with the ID of 0 was not found"),
+                )]
+                #[case(
+                    Config::test_builder()
+                        .build()
+                        .expect("Failed to build config."),
+                    MultipartForm::new()
+                        .add_part("payload", Part::bytes(Bytes::from(serde_json::to_vec(&json!({
+                            "documents": [
+                                {"id": "517815304354284708"},
+                                {"id": "517815304354284709"},
+                                {"id": 0}
+                            ]
+                        })).expect("Failed to build payload"))).add_header("Content-Type", "application/json"))
+                        .add_part("files[0]", Part::bytes(Bytes::from("test")).add_header("Content-Type", "text/plain")),
+                    StatusCode::BAD_REQUEST,
+                    RESTErrorResponse::new("Bad Request", "The new document 0 requires the `name` parameter."),
+                )]
+                #[case(
+                    Config::test_builder()
+                        .size_limits(
+                            SizeLimitConfig::test_builder()
+                                .minimum_document_name_size(10)
+                                .build()
+                                .expect("Failed to build size limit config.")
+                        )
+                        .build()
+                        .expect("Failed to build config."),
+                    MultipartForm::new()
+                        .add_part("payload", Part::bytes(Bytes::from(serde_json::to_vec(&json!({
+                            "documents": [
+                                {"id": "517815304354284708"},
+                                {"id": "517815304354284709"},
+                                {"id": 0, "name": "test.txt"}
+                            ]
+                        })).expect("Failed to build payload"))).add_header("Content-Type", "application/json"))
+                        .add_part("files[0]", Part::bytes(Bytes::from("test")).add_header("Content-Type", "text/plain")),
+                    StatusCode::UNPROCESSABLE_ENTITY,
+                    RESTErrorResponse::new("Unprocessable Entity", "Document `files[0]`'s name: `test.txt` is too small."),
+                )]
+                #[case(
+                    Config::test_builder()
+                        .size_limits(
+                            SizeLimitConfig::test_builder()
+                                .maximum_document_name_size(10)
+                                .build()
+                                .expect("Failed to build size limit config.")
+                        )
+                        .build()
+                        .expect("Failed to build config."),
+                    MultipartForm::new()
+                        .add_part("payload", Part::bytes(Bytes::from(serde_json::to_vec(&json!({
+                            "documents": [
+                                {"id": "517815304354284708"},
+                                {"id": "517815304354284709"},
+                                {"id": 0, "name": "test_file.txt"}
+                            ]
+                        })).expect("Failed to build payload"))).add_header("Content-Type", "application/json"))
+                        .add_part("files[0]", Part::bytes(Bytes::from("test")).add_header("Content-Type", "text/plain")),
+                    StatusCode::UNPROCESSABLE_ENTITY,
+                    RESTErrorResponse::new("Unprocessable Entity", "Document `files[0]`'s name: `test_file.txt` is too large."),
+                )]
+                #[case(
+                    Config::test_builder()
+                        .size_limits(
+                            SizeLimitConfig::test_builder()
+                                .minimum_document_size(10)
+                                .build()
+                                .expect("Failed to build size limit config.")
+                        )
+                        .build()
+                        .expect("Failed to build config."),
+                    MultipartForm::new()
+                        .add_part("payload", Part::bytes(Bytes::from(serde_json::to_vec(&json!({
+                            "documents": [
+                                {"id": "517815304354284708"},
+                                {"id": "517815304354284709"},
+                                {"id": 0, "name": "test.txt"}
+                            ]
+                        })).expect("Failed to build payload"))).add_header("Content-Type", "application/json"))
+                        .add_part("files[0]", Part::bytes(Bytes::from("test")).add_header("Content-Type", "text/plain")),
+                    StatusCode::UNPROCESSABLE_ENTITY,
+                    RESTErrorResponse::new("Unprocessable Entity", "Document `files[0]` (test.txt) is too small."),
+                )]
+                #[case(
+                    Config::test_builder()
+                        .size_limits(
+                            SizeLimitConfig::test_builder()
+                                .maximum_document_size(10)
+                                .build()
+                                .expect("Failed to build size limit config.")
+                        )
+                        .build()
+                        .expect("Failed to build config."),
+                    MultipartForm::new()
+                        .add_part("payload", Part::bytes(Bytes::from(serde_json::to_vec(&json!({
+                            "documents": [
+                                {"id": "517815304354284708"},
+                                {"id": "517815304354284709"},
+                                {"id": 0, "name": "test.txt"}
+                            ]
+                        })).expect("Failed to build payload"))).add_header("Content-Type", "application/json"))
+                        .add_part("files[0]", Part::bytes(Bytes::from("some random contents")).add_header("Content-Type", "text/plain")),
+                    StatusCode::UNPROCESSABLE_ENTITY,
+                    RESTErrorResponse::new("Unprocessable Entity", "Document `files[0]` (test.txt) is too large."),
+                )]
+                #[sqlx::test(fixtures(
+                    path = "../../tests/fixtures",
+                    scripts("pastes", "documents", "tokens")
+                ))]
+                async fn test_failures(
+                    #[ignore] pool: PgPool,
+                    #[case] config: Config,
+                    #[case] form: MultipartForm,
+                    #[case] expected_status: StatusCode,
+                    #[case] expected_response: RESTErrorResponse,
+                ) {
+                    let object_store = MemoryObjectStore::new();
+                    let state = ApplicationState::new_tests(
+                        config.clone(),
+                        pool.clone(),
+                        object_store.clone(),
+                    )
+                    .await
+                    .expect("Failed to build application state.");
+
+                    let app = main_generate_router(state);
+                    let server = TestServer::new(app);
+
+                    let paste_id = Snowflake::new(517_815_304_354_284_605);
+                    let token_string =
+                        "NTE3ODE1MzA0MzU0Mjg0NjA1.MTc3MDQzODc5Mw==.ozlKKwEEZpoGVuNzPDCyOMRGv";
+
+                    let response = server
+                        .patch(&format!("/v1/pastes/{paste_id}"))
+                        .add_header("Authorization", format!("Bearer {token_string}"))
+                        .multipart(form)
+                        .await;
+
+                    response.assert_status(expected_status);
+
+                    response.assert_header("Content-Type", "application/json");
+
+                    let body: RESTErrorResponse = response.json();
+
+                    assert_eq!(
+                        body.reason(),
+                        expected_response.reason(),
+                        "Mismatched response reason."
+                    );
+
+                    assert_eq!(
+                        body.message(),
+                        expected_response.message(),
+                        "Mismatched response message."
+                    );
+                }
+            }
+
+            #[rstest]
+            #[case(
+                Snowflake::new(517_815_304_354_284_605),
+                Some("beans"),
+                "Invalid Credentials",
+                "Invalid Token and/or mismatched paste ID"
+            )]
+            #[case(
+                Snowflake::new(517_815_304_354_284_605),
+                None,
+                "Missing Credentials",
+                "Missing Token"
+            )]
+            #[sqlx::test(fixtures(
+                path = "../../tests/fixtures",
+                scripts("pastes", "documents", "tokens")
+            ))]
+            async fn test_authentication(
+                #[ignore] pool: PgPool,
+                #[case] paste_id: Snowflake,
+                #[case] authentication: Option<&str>,
+                #[case] reason: &str,
+                #[case] message: &str,
+            ) {
+                let config = Config::test_builder()
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let mut request = server.patch(&format!("/v1/pastes/{paste_id}"));
+
+                if let Some(authentication) = authentication {
+                    request =
+                        request.add_header("Authorization", format!("Bearer {authentication}"));
+                }
+
+                let response = request.await;
+
+                response.assert_status(StatusCode::UNAUTHORIZED);
+
+                response.assert_header("Content-Type", "application/json");
+
+                let body: RESTErrorResponse = response.json();
+
+                assert_eq!(body.reason(), reason, "Reason does not match.");
+
+                assert_eq!(body.message(), message, "Message does not match.");
+            }
+
+            #[sqlx::test(fixtures(
+                path = "../../tests/fixtures",
+                scripts("pastes", "documents", "tokens")
+            ))]
+            async fn test_unknown_body_type(pool: PgPool) {
+                let config = Config::test_builder()
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let paste_id = Snowflake::new(517_815_304_354_284_605);
+                let token_string =
+                    "NTE3ODE1MzA0MzU0Mjg0NjA1.MTc3MDQzODc5Mw==.ozlKKwEEZpoGVuNzPDCyOMRGv";
+
+                let request = server
+                    .patch(&format!("/v1/pastes/{paste_id}"))
+                    .add_header("Authorization", format!("Bearer {token_string}"))
+                    .add_header("Content-Type", "image/png")
+                    .bytes(Bytes::from("test"));
+
+                let response = request.await;
+
+                response.assert_status(StatusCode::BAD_REQUEST);
+
+                response.assert_header("Content-Type", "application/json");
+
+                let body: RESTErrorResponse = response.json();
+
+                assert_eq!(body.reason(), "Bad Request", "Reason does not match.");
+
+                assert_eq!(
+                    body.message(),
+                    "Expected application/json or multipart/form-data as content type.",
+                    "Trace does not match."
+                );
+            }
+        }
+
+        mod delete_paste {
+            use super::*;
+
+            #[rstest]
+            #[case(
+                Snowflake::new(1_234_567_890),
+                Some("NTE3ODE1MzA0MzU0Mjg0NjA1.MTc3MDQzODc5Mw==.ozlKKwEEZpoGVuNzPDCyOMRGv"),
+                "Invalid Credentials",
+                "Invalid Token and/or mismatched paste ID"
+            )]
+            #[case(
+                Snowflake::new(517_815_304_354_284_605),
+                Some("beans"),
+                "Invalid Credentials",
+                "Invalid Token and/or mismatched paste ID"
+            )]
+            #[case(
+                Snowflake::new(517_815_304_354_284_605),
+                None,
+                "Missing Credentials",
+                "Missing Token"
+            )]
+            #[sqlx::test(fixtures(
+                path = "../../tests/fixtures",
+                scripts("pastes", "documents", "tokens")
+            ))]
+            async fn test_authentication(
+                #[ignore] pool: PgPool,
+                #[case] paste_id: Snowflake,
+                #[case] authentication: Option<&str>,
+                #[case] reason: &str,
+                #[case] message: &str,
+            ) {
+                let config = Config::test_builder()
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let mut request = server.delete(&format!("/v1/pastes/{paste_id}"));
+
+                if let Some(authentication) = authentication {
+                    request =
+                        request.add_header("Authorization", format!("Bearer {authentication}"));
+                }
+
+                let response = request.await;
+
+                response.assert_status(StatusCode::UNAUTHORIZED);
+
+                response.assert_header("Content-Type", "application/json");
+
+                let body: RESTErrorResponse = response.json();
+
+                assert_eq!(body.reason(), reason, "Reason does not match.");
+
+                assert_eq!(body.message(), message, "Message does not match.");
+            }
+
+            #[sqlx::test(fixtures(
+                path = "../../tests/fixtures",
+                scripts("pastes", "documents", "tokens")
+            ))]
+            async fn test_successful(pool: PgPool) {
+                let config = Config::test_builder()
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let paste_id = Snowflake::new(517_815_304_354_284_605);
+                let token_string =
+                    "NTE3ODE1MzA0MzU0Mjg0NjA1.MTc3MDQzODc5Mw==.ozlKKwEEZpoGVuNzPDCyOMRGv";
+
+                let paste = Paste::fetch(&pool, &paste_id)
+                    .await
+                    .expect("Failed to make DB request");
+                let documents = Document::fetch_all(&pool, &paste_id)
+                    .await
+                    .expect("Failed to make DB request");
+                let token = Token::fetch(&pool, token_string)
+                    .await
+                    .expect("Failed to make DB request");
+
+                assert!(paste.is_some(), "Paste was not found");
+
+                assert_eq!(documents.len(), 2, "Incorrect amount of documents found");
+
+                assert!(token.is_some(), "Token was not found");
+
+                for document in &documents {
+                    object_store
+                        .create_document(document, Bytes::from("a".repeat(document.size())))
+                        .await
+                        .expect("Failed to create document contents.");
+                }
+
+                let response = server
+                    .delete(&format!("/v1/pastes/{paste_id}"))
+                    .add_header("Authorization", format!("Bearer {token_string}"))
+                    .await;
+
+                response.assert_status(StatusCode::NO_CONTENT);
+
+                for document in &documents {
+                    assert!(
+                        object_store
+                            .fetch_document(document)
+                            .await
+                            .expect("Failed to fetch document contents.")
+                            .is_none(),
+                        "Document contents were found"
+                    );
+                }
+
+                let paste = Paste::fetch(&pool, &paste_id)
+                    .await
+                    .expect("Failed to make DB request");
+                let documents = Document::fetch_all(&pool, &paste_id)
+                    .await
+                    .expect("Failed to make DB request");
+                let token = Token::fetch(&pool, token_string)
+                    .await
+                    .expect("Failed to make DB request");
+
+                assert!(paste.is_none(), "Paste was found");
+
+                assert!(documents.is_empty(), "one or more documents were found");
+
+                assert!(token.is_none(), "Token was found");
+            }
+
+            #[rstest]
+            #[case("Thu, 15 Jan 1970 00:00:00 GMT", StatusCode::PRECONDITION_FAILED)]
+            #[case("Tue, 20 Jan 1970 00:00:00 GMT", StatusCode::NO_CONTENT)]
+            #[case("Sat, 01 Jan 2000 00:00:00 GMT", StatusCode::NO_CONTENT)]
+            #[sqlx::test(fixtures(
+                path = "../../tests/fixtures",
+                scripts("pastes", "documents", "tokens")
+            ))]
+            async fn test_if_unmodified_since(
+                #[ignore] pool: PgPool,
+                #[case] if_unmodified_since: &str,
+                #[case] status: StatusCode,
+            ) {
+                let config = Config::test_builder()
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let paste_id = Snowflake::new(517_815_304_354_284_605);
+                let token_string =
+                    "NTE3ODE1MzA0MzU0Mjg0NjA1.MTc3MDQzODc5Mw==.ozlKKwEEZpoGVuNzPDCyOMRGv";
+
+                let response = server
+                    .delete(&format!("/v1/pastes/{paste_id}"))
+                    .add_header("Authorization", format!("Bearer {token_string}"))
+                    .add_header("If-Unmodified-Since", if_unmodified_since)
+                    .await;
+
+                response.assert_status(status);
+
+                let paste = Paste::fetch(&pool, &paste_id)
+                    .await
+                    .expect("Failed to make DB request");
+
+                if status == StatusCode::PRECONDITION_FAILED {
+                    let body: RESTErrorResponse = response.json();
+
+                    assert_eq!(
+                        body.message(),
+                        "The resource has been modified since the time provided.",
+                        "Message does not match."
+                    );
+
+                    assert!(paste.is_some(), "Paste was not found");
+                } else {
+                    assert!(paste.is_none(), "Paste was found");
+                }
+            }
+
+            #[sqlx::test(fixtures(
+                path = "../../tests/fixtures",
+                scripts("pastes", "documents", "tokens")
+            ))]
+            async fn test_soft_delete(pool: PgPool) {
+                let config = Config::test_builder()
+                    .deletion_grace_hours(24)
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let paste_id = Snowflake::new(517_815_304_354_284_605);
+                let token_string =
+                    "NTE3ODE1MzA0MzU0Mjg0NjA1.MTc3MDQzODc5Mw==.ozlKKwEEZpoGVuNzPDCyOMRGv";
+
+                let response = server
+                    .delete(&format!("/v1/pastes/{paste_id}"))
+                    .add_header("Authorization", format!("Bearer {token_string}"))
+                    .await;
+
+                response.assert_status(StatusCode::NO_CONTENT);
+
+                let response = server.get(&format!("/v1/pastes/{paste_id}")).await;
+
+                response.assert_status(StatusCode::NOT_FOUND);
+
+                let paste = Paste::fetch(&pool, &paste_id)
                     .await
-                    .expect("Failed to build application state.");
+                    .expect("Failed to make DB request");
+                let deleted_paste = Paste::fetch_deleted(&pool, &paste_id)
+                    .await
+                    .expect("Failed to make DB request");
+                let documents = Document::fetch_all(&pool, &paste_id)
+                    .await
+                    .expect("Failed to make DB request");
+                let token = Token::fetch(&pool, token_string)
+                    .await
+                    .expect("Failed to make DB request");
 
-                    let app = main_generate_router(state);
-                    let server = TestServer::new(app);
+                assert!(paste.is_none(), "Paste was found");
 
-                    let paste_id = Snowflake::new(517_815_304_354_284_605);
-                    let token_string =
-                        "NTE3ODE1MzA0MzU0Mjg0NjA1.MTc3MDQzODc5Mw==.ozlKKwEEZpoGVuNzPDCyOMRGv";
+                assert!(deleted_paste.is_some(), "Deleted paste was not found");
 
-                    let response = server
-                        .patch(&format!("/v1/pastes/{paste_id}"))
-                        .add_header("Authorization", format!("Bearer {token_string}"))
-                        .multipart(form)
-                        .await;
+                assert_eq!(documents.len(), 2, "Incorrect amount of documents found");
 
-                    response.assert_status(expected_status);
+                assert!(token.is_some(), "Token was not found");
+            }
+        }
 
-                    response.assert_header("Content-Type", "application/json");
+        mod paste_bundle {
+            use super::*;
 
-                    let body: RESTErrorResponse = response.json();
+            #[sqlx::test(fixtures(
+                path = "../../tests/fixtures",
+                scripts("pastes", "documents", "tokens")
+            ))]
+            async fn test_round_trip(pool: PgPool) {
+                let config = Config::test_builder()
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let paste_id = Snowflake::new(517_815_304_354_284_605);
+                let token_string =
+                    "NTE3ODE1MzA0MzU0Mjg0NjA1.MTc3MDQzODc5Mw==.ozlKKwEEZpoGVuNzPDCyOMRGv";
+
+                let documents = Document::fetch_all(&pool, &paste_id)
+                    .await
+                    .expect("Failed to make DB request");
+
+                for document in &documents {
+                    object_store
+                        .create_document(document, Bytes::from("a".repeat(document.size())))
+                        .await
+                        .expect("Failed to create document contents.");
+                }
+
+                let response = server
+                    .get(&format!("/v1/pastes/{paste_id}/export"))
+                    .add_header("Authorization", format!("Bearer {token_string}"))
+                    .await;
+
+                response.assert_status(StatusCode::OK);
+
+                let bundle: PasteBundle = response.json();
+
+                let response = server.post("/v1/pastes/import").json(&bundle).await;
+
+                response.assert_status(StatusCode::OK);
 
+                let body: ResponsePaste = response.json();
+
+                assert_ne!(body.id(), paste_id, "The paste ID was not regenerated.");
+                assert_eq!(body.name(), Some("Test 5"), "Name does not match.");
+                assert!(body.token().is_some(), "A new token was not returned.");
+
+                let mut imported_documents = Document::fetch_all(&pool, &body.id())
+                    .await
+                    .expect("Failed to make DB request");
+
+                assert_eq!(
+                    imported_documents.len(),
+                    documents.len(),
+                    "Document count does not match."
+                );
+
+                imported_documents.sort_by(|a, b| a.name().cmp(b.name()));
+                let mut documents = documents;
+                documents.sort_by(|a, b| a.name().cmp(b.name()));
+
+                for (document, imported_document) in documents.iter().zip(&imported_documents) {
+                    assert_ne!(
+                        document.id(),
+                        imported_document.id(),
+                        "The document ID was not regenerated."
+                    );
                     assert_eq!(
-                        body.reason(),
-                        expected_response.reason(),
-                        "Mismatched response reason."
+                        document.name(),
+                        imported_document.name(),
+                        "Name does not match."
+                    );
+                    assert_eq!(
+                        document.doc_type(),
+                        imported_document.doc_type(),
+                        "Type does not match."
                     );
 
+                    let imported_content = object_store
+                        .fetch_document(imported_document)
+                        .await
+                        .expect("Failed to retrieve document");
+
                     assert_eq!(
-                        body.message(),
-                        expected_response.message(),
-                        "Mismatched response message."
+                        imported_content,
+                        Some(Bytes::from("a".repeat(document.size()))),
+                        "Contents do not match."
                     );
                 }
             }
 
-            #[rstest]
-            #[case(
-                Snowflake::new(517_815_304_354_284_605),
-                Some("beans"),
-                "Invalid Credentials",
-                "Invalid Token and/or mismatched paste ID"
-            )]
-            #[case(
-                Snowflake::new(517_815_304_354_284_605),
-                None,
-                "Missing Credentials",
-                "Missing Token"
-            )]
+            #[sqlx::test(fixtures(path = "../../tests/fixtures", scripts("pastes", "documents")))]
+            async fn test_export_missing_token(pool: PgPool) {
+                let config = Config::test_builder()
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let paste_id = Snowflake::new(517_815_304_354_284_605);
+
+                let response = server.get(&format!("/v1/pastes/{paste_id}/export")).await;
+
+                response.assert_status(StatusCode::UNAUTHORIZED);
+            }
+
             #[sqlx::test(fixtures(
                 path = "../../tests/fixtures",
                 scripts("pastes", "documents", "tokens")
             ))]
-            async fn test_authentication(
-                #[ignore] pool: PgPool,
-                #[case] paste_id: Snowflake,
-                #[case] authentication: Option<&str>,
-                #[case] reason: &str,
-                #[case] message: &str,
-            ) {
+            async fn test_export_max_views(pool: PgPool) {
+                let config = Config::test_builder()
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let paste_id = Snowflake::new(517_815_304_354_284_605);
+                let token_string =
+                    "NTE3ODE1MzA0MzU0Mjg0NjA1.MTc3MDQzODc5Mw==.ozlKKwEEZpoGVuNzPDCyOMRGv";
+
+                sqlx::query("UPDATE pastes SET views = 0, max_views = 1 WHERE id = $1")
+                    .bind(i64::from(paste_id))
+                    .execute(&pool)
+                    .await
+                    .expect("Failed to make DB request");
+
+                let documents = Document::fetch_all(&pool, &paste_id)
+                    .await
+                    .expect("Failed to make DB request");
+
+                for document in &documents {
+                    object_store
+                        .create_document(document, Bytes::from("a".repeat(document.size())))
+                        .await
+                        .expect("Failed to create document contents.");
+                }
+
+                let response = server
+                    .get(&format!("/v1/pastes/{paste_id}/export"))
+                    .add_header("Authorization", format!("Bearer {token_string}"))
+                    .await;
+
+                response.assert_status(StatusCode::OK);
+
+                let paste = Paste::fetch(&pool, &paste_id)
+                    .await
+                    .expect("Failed to make DB request")
+                    .expect("Failed to find paste.");
+
+                assert_eq!(paste.views(), 1, "The export was not counted as one view.");
+
+                let response = server
+                    .get(&format!("/v1/pastes/{paste_id}/export"))
+                    .add_header("Authorization", format!("Bearer {token_string}"))
+                    .await;
+
+                response.assert_status(StatusCode::GONE);
+            }
+        }
+
+        mod post_paste_restore {
+            use super::*;
+
+            #[sqlx::test(fixtures(
+                path = "../../tests/fixtures",
+                scripts("pastes", "documents", "tokens")
+            ))]
+            async fn test_successful(pool: PgPool) {
+                let config = Config::test_builder()
+                    .deletion_grace_hours(24)
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let paste_id = Snowflake::new(517_815_304_354_284_605);
+                let token_string =
+                    "NTE3ODE1MzA0MzU0Mjg0NjA1.MTc3MDQzODc5Mw==.ozlKKwEEZpoGVuNzPDCyOMRGv";
+
+                let response = server
+                    .delete(&format!("/v1/pastes/{paste_id}"))
+                    .add_header("Authorization", format!("Bearer {token_string}"))
+                    .await;
+
+                response.assert_status(StatusCode::NO_CONTENT);
+
+                let response = server
+                    .post(&format!("/v1/pastes/{paste_id}/restore"))
+                    .add_header("Authorization", format!("Bearer {token_string}"))
+                    .await;
+
+                response.assert_status(StatusCode::OK);
+
+                response.assert_header("Content-Type", "application/json");
+
+                let body: ResponsePaste = response.json();
+
+                assert_eq!(body.id(), paste_id, "Paste ID does not match.");
+
+                assert_eq!(body.documents().len(), 2, "Incorrect amount of documents.");
+
+                let response = server.get(&format!("/v1/pastes/{paste_id}")).await;
+
+                response.assert_status(StatusCode::OK);
+
+                let deleted_paste = Paste::fetch_deleted(&pool, &paste_id)
+                    .await
+                    .expect("Failed to make DB request");
+
+                assert!(deleted_paste.is_none(), "Deleted paste was found");
+            }
+
+            #[sqlx::test(fixtures(
+                path = "../../tests/fixtures",
+                scripts("pastes", "documents", "tokens")
+            ))]
+            async fn test_grace_period_passed(pool: PgPool) {
                 let config = Config::test_builder()
+                    .deletion_grace_hours(24)
                     .build()
                     .expect("Failed to build config.");
-                let object_store = TestObjectStore::new();
+                let object_store = MemoryObjectStore::new();
                 let state =
                     ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
                         .await
@@ -2270,35 +6751,54 @@ mod tests {
                 let app = main_generate_router(state);
                 let server = TestServer::new(app);
 
-                let mut request = server.patch(&format!("/v1/pastes/{paste_id}"));
+                let paste_id = Snowflake::new(517_815_304_354_284_605);
+                let token_string =
+                    "NTE3ODE1MzA0MzU0Mjg0NjA1.MTc3MDQzODc5Mw==.ozlKKwEEZpoGVuNzPDCyOMRGv";
+
+                let response = server
+                    .delete(&format!("/v1/pastes/{paste_id}"))
+                    .add_header("Authorization", format!("Bearer {token_string}"))
+                    .await;
 
-                if let Some(authentication) = authentication {
-                    request =
-                        request.add_header("Authorization", format!("Bearer {authentication}"));
-                }
+                response.assert_status(StatusCode::NO_CONTENT);
 
-                let response = request.await;
+                sqlx::query("UPDATE pastes SET deleted_at = $1 WHERE id = $2")
+                    .bind(Utc::now() - TimeDelta::hours(25))
+                    .bind(i64::from(paste_id))
+                    .execute(&pool)
+                    .await
+                    .expect("Failed to make DB request");
 
-                response.assert_status(StatusCode::UNAUTHORIZED);
+                let response = server
+                    .post(&format!("/v1/pastes/{paste_id}/restore"))
+                    .add_header("Authorization", format!("Bearer {token_string}"))
+                    .await;
 
-                response.assert_header("Content-Type", "application/json");
+                response.assert_status(StatusCode::NOT_FOUND);
 
                 let body: RESTErrorResponse = response.json();
 
-                assert_eq!(body.reason(), reason, "Reason does not match.");
+                assert_eq!(
+                    body.message(),
+                    "The paste can no longer be restored.",
+                    "Message does not match."
+                );
 
-                assert_eq!(body.message(), message, "Message does not match.");
+                let response = server.get(&format!("/v1/pastes/{paste_id}")).await;
+
+                response.assert_status(StatusCode::NOT_FOUND);
             }
 
             #[sqlx::test(fixtures(
                 path = "../../tests/fixtures",
                 scripts("pastes", "documents", "tokens")
             ))]
-            async fn test_unknown_body_type(pool: PgPool) {
+            async fn test_not_deleted(pool: PgPool) {
                 let config = Config::test_builder()
+                    .deletion_grace_hours(24)
                     .build()
                     .expect("Failed to build config.");
-                let object_store = TestObjectStore::new();
+                let object_store = MemoryObjectStore::new();
                 let state =
                     ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
                         .await
@@ -2311,67 +6811,27 @@ mod tests {
                 let token_string =
                     "NTE3ODE1MzA0MzU0Mjg0NjA1.MTc3MDQzODc5Mw==.ozlKKwEEZpoGVuNzPDCyOMRGv";
 
-                let request = server
-                    .patch(&format!("/v1/pastes/{paste_id}"))
+                let response = server
+                    .post(&format!("/v1/pastes/{paste_id}/restore"))
                     .add_header("Authorization", format!("Bearer {token_string}"))
-                    .add_header("Content-Type", "image/png")
-                    .bytes(Bytes::from("test"));
-
-                let response = request.await;
-
-                response.assert_status(StatusCode::BAD_REQUEST);
-
-                response.assert_header("Content-Type", "application/json");
-
-                let body: RESTErrorResponse = response.json();
-
-                assert_eq!(body.reason(), "Bad Request", "Reason does not match.");
+                    .await;
 
-                assert_eq!(
-                    body.message(),
-                    "Expected application/json or multipart/form-data as content type.",
-                    "Trace does not match."
-                );
+                response.assert_status(StatusCode::NOT_FOUND);
             }
         }
 
-        mod delete_paste {
+        mod post_paste_token_rotate {
             use super::*;
 
-            #[rstest]
-            #[case(
-                Snowflake::new(1_234_567_890),
-                Some("NTE3ODE1MzA0MzU0Mjg0NjA1.MTc3MDQzODc5Mw==.ozlKKwEEZpoGVuNzPDCyOMRGv"),
-                "Invalid Credentials",
-                "Invalid Token and/or mismatched paste ID"
-            )]
-            #[case(
-                Snowflake::new(517_815_304_354_284_605),
-                Some("beans"),
-                "Invalid Credentials",
-                "Invalid Token and/or mismatched paste ID"
-            )]
-            #[case(
-                Snowflake::new(517_815_304_354_284_605),
-                None,
-                "Missing Credentials",
-                "Missing Token"
-            )]
             #[sqlx::test(fixtures(
                 path = "../../tests/fixtures",
                 scripts("pastes", "documents", "tokens")
             ))]
-            async fn test_authentication(
-                #[ignore] pool: PgPool,
-                #[case] paste_id: Snowflake,
-                #[case] authentication: Option<&str>,
-                #[case] reason: &str,
-                #[case] message: &str,
-            ) {
+            async fn test_successful(pool: PgPool) {
                 let config = Config::test_builder()
                     .build()
                     .expect("Failed to build config.");
-                let object_store = TestObjectStore::new();
+                let object_store = MemoryObjectStore::new();
                 let state =
                     ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
                         .await
@@ -2380,35 +6840,57 @@ mod tests {
                 let app = main_generate_router(state);
                 let server = TestServer::new(app);
 
-                let mut request = server.delete(&format!("/v1/pastes/{paste_id}"));
+                let paste_id = Snowflake::new(517_815_304_354_284_605);
+                let token_string =
+                    "NTE3ODE1MzA0MzU0Mjg0NjA1.MTc3MDQzODc5Mw==.ozlKKwEEZpoGVuNzPDCyOMRGv";
 
-                if let Some(authentication) = authentication {
-                    request =
-                        request.add_header("Authorization", format!("Bearer {authentication}"));
-                }
+                let response = server
+                    .post(&format!("/v1/pastes/{paste_id}/token/rotate"))
+                    .add_header("Authorization", format!("Bearer {token_string}"))
+                    .await;
 
-                let response = request.await;
+                response.assert_status(StatusCode::OK);
+
+                let body: ResponsePaste = response.json();
+
+                assert_eq!(body.id(), paste_id, "Paste ID does not match.");
+
+                let Some(new_token_string) = body.token() else {
+                    panic!("No token was returned.");
+                };
+
+                assert_ne!(new_token_string, token_string, "Token was not rotated.");
+
+                let response = server
+                    .patch(&format!("/v1/pastes/{paste_id}"))
+                    .add_header("Authorization", format!("Bearer {token_string}"))
+                    .json(&json!({"name": "rotated"}))
+                    .await;
 
                 response.assert_status(StatusCode::UNAUTHORIZED);
 
-                response.assert_header("Content-Type", "application/json");
+                let response = server
+                    .patch(&format!("/v1/pastes/{paste_id}"))
+                    .add_header("Authorization", format!("Bearer {new_token_string}"))
+                    .json(&json!({"name": "rotated"}))
+                    .await;
 
-                let body: RESTErrorResponse = response.json();
+                response.assert_status(StatusCode::OK);
 
-                assert_eq!(body.reason(), reason, "Reason does not match.");
+                let body: ResponsePaste = response.json();
 
-                assert_eq!(body.message(), message, "Message does not match.");
+                assert_eq!(body.name(), Some("rotated"), "Name was not updated.");
             }
 
             #[sqlx::test(fixtures(
                 path = "../../tests/fixtures",
                 scripts("pastes", "documents", "tokens")
             ))]
-            async fn test_successful(pool: PgPool) {
+            async fn test_mismatched_token(pool: PgPool) {
                 let config = Config::test_builder()
                     .build()
                     .expect("Failed to build config.");
-                let object_store = TestObjectStore::new();
+                let object_store = MemoryObjectStore::new();
                 let state =
                     ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
                         .await
@@ -2417,48 +6899,120 @@ mod tests {
                 let app = main_generate_router(state);
                 let server = TestServer::new(app);
 
-                let paste_id = Snowflake::new(517_815_304_354_284_605);
+                let paste_id = Snowflake::new(517_815_304_354_284_602);
                 let token_string =
                     "NTE3ODE1MzA0MzU0Mjg0NjA1.MTc3MDQzODc5Mw==.ozlKKwEEZpoGVuNzPDCyOMRGv";
 
-                let paste = Paste::fetch(&pool, &paste_id)
-                    .await
-                    .expect("Failed to make DB request");
-                let documents = Document::fetch_all(&pool, &paste_id)
-                    .await
-                    .expect("Failed to make DB request");
+                let response = server
+                    .post(&format!("/v1/pastes/{paste_id}/token/rotate"))
+                    .add_header("Authorization", format!("Bearer {token_string}"))
+                    .await;
+
+                response.assert_status(StatusCode::UNAUTHORIZED);
+
                 let token = Token::fetch(&pool, token_string)
                     .await
                     .expect("Failed to make DB request");
 
-                assert!(paste.is_some(), "Paste was not found");
+                assert!(token.is_some(), "Token was rotated.");
+            }
+        }
 
-                assert_eq!(documents.len(), 2, "Incorrect amount of documents found");
+        mod post_pastes_batch {
+            use super::*;
 
-                assert!(token.is_some(), "Token was not found");
+            #[sqlx::test(fixtures(path = "../../tests/fixtures", scripts("pastes", "documents")))]
+            async fn test_successful(pool: PgPool) {
+                let config = Config::test_builder()
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
+
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let existing_paste_id = Snowflake::new(517_815_304_354_284_605);
+                let other_existing_paste_id = Snowflake::new(517_815_304_354_284_602);
+                let expired_paste_id = Snowflake::new(517_815_304_354_284_601);
+                let missing_paste_id = Snowflake::new(1_234_567_890);
+
+                let views = Paste::fetch(&pool, &existing_paste_id)
+                    .await
+                    .expect("Failed to make DB request")
+                    .expect("Failed to find paste.")
+                    .views();
 
                 let response = server
-                    .delete(&format!("/v1/pastes/{paste_id}"))
-                    .add_header("Authorization", format!("Bearer {token_string}"))
+                    .post("/v1/pastes/batch")
+                    .json(&json!({
+                        "ids": [
+                            existing_paste_id,
+                            missing_paste_id,
+                            expired_paste_id,
+                            other_existing_paste_id
+                        ]
+                    }))
                     .await;
 
-                response.assert_status(StatusCode::NO_CONTENT);
+                response.assert_status(StatusCode::OK);
 
-                let paste = Paste::fetch(&pool, &paste_id)
-                    .await
-                    .expect("Failed to make DB request");
-                let documents = Document::fetch_all(&pool, &paste_id)
-                    .await
-                    .expect("Failed to make DB request");
-                let token = Token::fetch(&pool, token_string)
+                let body: Vec<ResponsePasteSummary> = response.json();
+
+                let ids: Vec<Snowflake> = body.iter().map(ResponsePasteSummary::id).collect();
+
+                assert_eq!(
+                    ids,
+                    vec![existing_paste_id, other_existing_paste_id],
+                    "Returned pastes do not match."
+                );
+
+                let updated_views = Paste::fetch(&pool, &existing_paste_id)
                     .await
-                    .expect("Failed to make DB request");
+                    .expect("Failed to make DB request")
+                    .expect("Failed to find paste.")
+                    .views();
 
-                assert!(paste.is_none(), "Paste was found");
+                assert_eq!(views, updated_views, "Views was updated.");
+            }
 
-                assert!(documents.is_empty(), "one or more documents were found");
+            #[sqlx::test]
+            async fn test_too_many(pool: PgPool) {
+                let config = Config::test_builder()
+                    .size_limits(
+                        SizeLimitConfig::test_builder()
+                            .maximum_batch_paste_count(1)
+                            .build()
+                            .expect("Failed to build size limit config."),
+                    )
+                    .build()
+                    .expect("Failed to build config.");
+                let object_store = MemoryObjectStore::new();
+                let state =
+                    ApplicationState::new_tests(config.clone(), pool.clone(), object_store.clone())
+                        .await
+                        .expect("Failed to build application state.");
 
-                assert!(token.is_none(), "Token was found");
+                let app = main_generate_router(state);
+                let server = TestServer::new(app);
+
+                let response = server
+                    .post("/v1/pastes/batch")
+                    .json(&json!({"ids": ["1", "2"]}))
+                    .await;
+
+                response.assert_status(StatusCode::BAD_REQUEST);
+
+                let body: RESTErrorResponse = response.json();
+
+                assert_eq!(
+                    body.message(),
+                    "Too many pastes were requested. Expected: 1, Received: 2",
+                    "Message does not match."
+                );
             }
         }
     }
@@ -2560,7 +7114,7 @@ mod tests {
         #[case] expected: UndefinedOption<DtUtc>,
     ) {
         let returned_expiry =
-            validate_expiry(&config, expiry).expect("Expected a undefined option.");
+            validate_expiry(&config, expiry, None).expect("Expected a undefined option.");
 
         assert_eq!(returned_expiry, expected, "Mismatched expiry.");
     }
@@ -2639,9 +7193,10 @@ mod tests {
         #[case] expiry: UndefinedOption<DtUtc>,
         #[case] expected: &str,
     ) {
-        let returned_expiry = validate_expiry(&config, expiry).expect_err("Expected an error.");
+        let returned_expiry =
+            validate_expiry(&config, expiry, None).expect_err("Expected an error.");
 
-        if let RESTError::BadRequest(response) = &returned_expiry {
+        if let RESTError::Unprocessable(response) = &returned_expiry {
             assert_eq!(response, expected, "Invalid response received.");
         } else {
             panic!(
@@ -2656,7 +7211,7 @@ mod tests {
     #[case(make_config(Some(10), None, Some(100)))]
     #[case(make_config(Some(10), Some(1), Some(100)))]
     fn test_validate_expiry_default(#[case] config: Config) {
-        let returned_expiry = validate_expiry(&config, UndefinedOption::Undefined)
+        let returned_expiry = validate_expiry(&config, UndefinedOption::Undefined, None)
             .expect("Expected a undefined option.");
 
         if let UndefinedOption::Some(returned_time) = returned_expiry {
@@ -2675,4 +7230,138 @@ mod tests {
             panic!("Expected a timestamp to be returned.");
         }
     }
+
+    #[rstest]
+    // New pastes always receive an expiry, clamped to the absolute maximum age.
+    #[case(
+        UndefinedOption::Undefined,
+        None,
+        UndefinedOption::Some(TimeDelta::hours(24))
+    )]
+    #[case(
+        UndefinedOption::None,
+        None,
+        UndefinedOption::Some(TimeDelta::hours(24))
+    )]
+    #[case(
+        UndefinedOption::Some(TimeDelta::hours(10)),
+        None,
+        UndefinedOption::Some(TimeDelta::hours(10))
+    )]
+    #[case(
+        UndefinedOption::Some(TimeDelta::hours(50)),
+        None,
+        UndefinedOption::Some(TimeDelta::hours(24))
+    )]
+    // Existing pastes are clamped relative to their creation.
+    #[case(UndefinedOption::Undefined, Some(TimeDelta::hours(-4)), UndefinedOption::Undefined)]
+    #[case(
+        UndefinedOption::None,
+        Some(TimeDelta::hours(-4)),
+        UndefinedOption::Some(TimeDelta::hours(20))
+    )]
+    #[case(
+        UndefinedOption::Some(TimeDelta::hours(50)),
+        Some(TimeDelta::hours(-4)),
+        UndefinedOption::Some(TimeDelta::hours(20))
+    )]
+    fn test_validate_expiry_absolute_max_age(
+        #[case] expiry: UndefinedOption<TimeDelta>,
+        #[case] creation: Option<TimeDelta>,
+        #[case] expected: UndefinedOption<TimeDelta>,
+    ) {
+        let config = Config::test_builder()
+            .absolute_max_age_hours(Some(24))
+            .build()
+            .expect("Failed to build config.");
+
+        let now = Utc::now()
+            .with_nanosecond(0)
+            .expect("Failed to build current time with reset nanosecond.");
+
+        let expiry = match expiry {
+            UndefinedOption::Some(offset) => UndefinedOption::Some(now + offset),
+            UndefinedOption::Undefined => UndefinedOption::Undefined,
+            UndefinedOption::None => UndefinedOption::None,
+        };
+        let creation = creation.map(|offset| now + offset);
+
+        let returned_expiry =
+            validate_expiry(&config, expiry, creation.as_ref()).expect("Expected a valid expiry.");
+
+        match (returned_expiry, expected) {
+            (UndefinedOption::Some(returned), UndefinedOption::Some(offset)) => {
+                // The current time may have moved on by a second.
+                let difference = returned - (now + offset);
+                assert!(
+                    difference >= TimeDelta::zero() && difference <= TimeDelta::seconds(1),
+                    "Mismatched expiry."
+                );
+            }
+            (UndefinedOption::Undefined, UndefinedOption::Undefined) => {}
+            (returned, expected) => {
+                panic!("Mismatched expiry.\nExpected - {expected:?}\nActual - {returned:?}")
+            }
+        }
+    }
+
+    #[rstest]
+    #[case(TimestampPrecision::Second, 0)]
+    #[case(TimestampPrecision::Millisecond, 123_000_000)]
+    fn test_truncate_timestamp(
+        #[case] timestamp_precision: TimestampPrecision,
+        #[case] expected_nanosecond: u32,
+    ) {
+        let config = Config::test_builder()
+            .timestamp_precision(timestamp_precision)
+            .build()
+            .expect("Failed to build config.");
+
+        let timestamp = Utc::now()
+            .with_nanosecond(123_456_789)
+            .expect("Failed to build current time with set nanosecond.");
+
+        let truncated = truncate_timestamp(&config, timestamp);
+
+        assert_eq!(
+            truncated.nanosecond(),
+            expected_nanosecond,
+            "Mismatching nanosecond."
+        );
+        assert_eq!(
+            truncated.timestamp(),
+            timestamp.timestamp(),
+            "Mismatching second."
+        );
+    }
+
+    #[rstest]
+    #[case(TimestampPrecision::Second, 0)]
+    #[case(TimestampPrecision::Millisecond, 250_000_000)]
+    fn test_validate_expiry_precision(
+        #[case] timestamp_precision: TimestampPrecision,
+        #[case] expected_nanosecond: u32,
+    ) {
+        let config = Config::test_builder()
+            .timestamp_precision(timestamp_precision)
+            .build()
+            .expect("Failed to build config.");
+
+        let expiry = (Utc::now() + TimeDelta::hours(50))
+            .with_nanosecond(250_250_250)
+            .expect("Failed to build expiry with set nanosecond.");
+
+        let UndefinedOption::Some(returned_expiry) =
+            validate_expiry(&config, UndefinedOption::Some(expiry), None)
+                .expect("Expected a valid expiry.")
+        else {
+            panic!("Expected a timestamp to be returned.");
+        };
+
+        assert_eq!(
+            returned_expiry.nanosecond(),
+            expected_nanosecond,
+            "Mismatching nanosecond."
+        );
+    }
 }
@@ -0,0 +1,748 @@
+//! Resumable upload related endpoints and router generator.
+
+use axum::{
+    Json, Router,
+    extract::{DefaultBodyLimit, Path, State},
+    response::{IntoResponse as _, Response},
+    routing::{head, post},
+};
+use bytes::Bytes;
+use chrono::Utc;
+use http::{
+    HeaderMap, HeaderName, HeaderValue, StatusCode,
+    header::{CACHE_CONTROL, LOCATION},
+};
+
+use crate::{
+    app::{application::App, config::Config, object_store::ObjectStoreExt as _},
+    models::{
+        authentication::Token,
+        document::{
+            Document, document_limits, enforce_maximums, enforce_minimums, enforce_owner_maximum,
+            enforce_unique_names, generate_checksum, is_mime_allowed,
+        },
+        errors::RESTError,
+        paste::{Paste, validate_paste},
+        payload::{
+            document::ResponseDocument,
+            upload::{
+                DeleteUploadPath, HeadUploadPath, PatchUploadPath, PostUploadBody, ResponseUpload,
+            },
+        },
+        snowflake::{Snowflake, validate_snowflakes},
+        undefined::Undefined,
+        upload::{UPLOAD_LENGTH_HEADER, UPLOAD_OFFSET_HEADER, Upload},
+    },
+};
+
+/// ## Generate Router
+///
+/// Generates the router for resumable upload related endpoints.
+///
+/// ## Returns
+/// The router with all the resumable upload related endpoints attached.
+pub fn generate_router(config: &Config) -> Router<App> {
+    Router::new()
+        .route("/uploads", post(post_upload))
+        .route(
+            "/uploads/{upload_id}",
+            head(head_upload).patch(patch_upload).delete(delete_upload),
+        )
+        .layer(DefaultBodyLimit::max(
+            config.size_limits().maximum_request_body_size(),
+        ))
+}
+
+/// Post Upload.
+///
+/// Create a resumable upload, which the contents of a document are appended to in chunks.
+///
+/// **Requires authentication.**
+///
+/// ## Body
+///
+/// References: [`PostUploadBody`]
+///
+/// - `paste_id` - The ID of the paste the document is uploaded to.
+/// - `name` - The name of the document.
+/// - `type` - The type of the document (defaults to the configured default document type).
+/// - `length` - The total size of the document (bytes).
+///
+/// ## Errors
+/// Returns an error if the request failed.
+///
+/// ## Returns
+///
+/// - `400` - The paste ID is not a valid snowflake.
+/// - `401` - Invalid token and/or paste ID.
+/// - `404` - The paste was not found.
+/// - `410` - The paste has expired, or reached its maximum views.
+/// - `422` - The limits are exceeded.
+/// - `201` - The [`ResponseUpload`] object, with the URL chunks are appended to in the `Location` header.
+pub async fn post_upload(
+    State(app): State<App>,
+    token: Token,
+    Json(body): Json<PostUploadBody>,
+) -> Result<(StatusCode, HeaderMap, Json<ResponseUpload>), RESTError> {
+    validate_snowflakes(app.config(), &[body.paste_id()])?;
+
+    let paste = validate_paste(app.database(), body.paste_id(), Some(token)).await?;
+
+    let mime = body.doc_type().map_or_else(
+        || {
+            app.config()
+                .size_limits()
+                .default_document_mime()
+                .to_string()
+        },
+        ToString::to_string,
+    );
+
+    if !is_mime_allowed(app.config(), &mime) {
+        return Err(RESTError::bad_request(format!(
+            "Invalid mime type: {mime} received for the document: {}",
+            body.name()
+        )));
+    }
+
+    document_limits(
+        app.config(),
+        "upload",
+        Undefined::Some(body.name()),
+        Undefined::Undefined,
+        &mime,
+    )?;
+
+    let size_limits = app.config().size_limits();
+
+    if body.length() == 0 || size_limits.minimum_document_size() > body.length() {
+        return Err(RESTError::unprocessable_with_code(
+            "DOCUMENT_TOO_SMALL",
+            format!("Document `upload` ({}) is too small.", body.name()),
+        ));
+    }
+
+    if size_limits.maximum_document_size_for(&mime) < body.length() {
+        return Err(RESTError::unprocessable_with_code(
+            "DOCUMENT_TOO_LARGE",
+            format!("Document `upload` ({}) is too large.", body.name()),
+        ));
+    }
+
+    let upload_id =
+        Snowflake::generate_unique(&mut *app.database().pool().acquire().await?, app.config())
+            .await?;
+
+    let document = Document::new(
+        upload_id,
+        *paste.id(),
+        &mime,
+        body.name(),
+        body.length(),
+        None,
+        &[],
+    );
+
+    let multipart_id = app.object_store().create_upload(&document).await?;
+
+    let upload = Upload::new(
+        upload_id,
+        *paste.id(),
+        &mime,
+        body.name(),
+        body.length(),
+        &multipart_id,
+        Utc::now(),
+    );
+
+    if let Err(e) = upload.insert(app.database().pool()).await {
+        app.object_store()
+            .abort_upload(&document, &multipart_id)
+            .await?;
+
+        return Err(e.into());
+    }
+
+    let url = format!("/v1/uploads/{upload_id}");
+
+    let mut headers = upload_headers(&upload);
+
+    headers.insert(
+        LOCATION,
+        HeaderValue::from_str(&url).map_err(RESTError::internal_server)?,
+    );
+
+    Ok((
+        StatusCode::CREATED,
+        headers,
+        Json(ResponseUpload::from_upload(&upload, url)),
+    ))
+}
+
+/// Head Upload.
+///
+/// Get the amount of bytes a resumable upload has received, so an interrupted upload can be resumed.
+///
+/// **Requires authentication.**
+///
+/// ## Path
+///
+/// - `upload_id` - The uploads ID.
+///
+/// ## Errors
+/// Returns an error if the request failed.
+///
+/// ## Returns
+///
+/// - `400` - The upload ID is not a valid snowflake.
+/// - `401` - Invalid token and/or paste ID.
+/// - `404` - The upload or its paste was not found.
+/// - `410` - The paste has expired, or reached its maximum views.
+/// - `200` - The `Upload-Offset` and `Upload-Length` headers.
+pub async fn head_upload(
+    State(app): State<App>,
+    Path(path): Path<HeadUploadPath>,
+    token: Token,
+) -> Result<(StatusCode, HeaderMap), RESTError> {
+    path.validate(app.config())?;
+
+    let (_, upload) = validate_upload(&app, path.upload_id(), token).await?;
+
+    Ok((StatusCode::OK, upload_headers(&upload)))
+}
+
+/// Patch Upload.
+///
+/// Append a chunk to a resumable upload.
+///
+/// Once every byte has been received, the upload is finalized into a document.
+/// Every chunk other than the last must be at least the configured minimum upload chunk size.
+///
+/// **Requires authentication.**
+///
+/// ## Path
+///
+/// - `upload_id` - The uploads ID.
+///
+/// ## Headers
+///
+/// - `Upload-Offset` - The offset the chunk starts at, which must match the bytes received so far.
+///
+/// ## Body
+///
+/// The chunk of the documents contents.
+///
+/// ## Errors
+/// Returns an error if the request failed.
+///
+/// ## Returns
+///
+/// - `400` - The upload ID is not a valid snowflake, the offset is missing, the chunk is too small or exceeds the length.
+/// - `401` - Invalid token and/or paste ID.
+/// - `404` - The upload or its paste was not found.
+/// - `409` - The offset does not match the bytes received so far.
+/// - `410` - The paste has expired, or reached its maximum views.
+/// - `422` - The limits are exceeded.
+/// - `201` - The finalized [`Document`] object.
+/// - `204` - The chunk was appended, with the new `Upload-Offset` header.
+pub async fn patch_upload(
+    State(app): State<App>,
+    Path(path): Path<PatchUploadPath>,
+    token: Token,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Result<Response, RESTError> {
+    path.validate(app.config())?;
+
+    let (paste, mut upload) = validate_upload(&app, path.upload_id(), token).await?;
+
+    let offset: usize = headers
+        .get(UPLOAD_OFFSET_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse().ok())
+        .ok_or_else(|| {
+            RESTError::bad_request("The `Upload-Offset` header is missing or invalid.")
+        })?;
+
+    if offset != upload.offset() {
+        return Err(RESTError::conflict(format!(
+            "The upload offset does not match. Expected: {}, Received: {offset}",
+            upload.offset()
+        )));
+    }
+
+    if body.is_empty() {
+        return Err(RESTError::bad_request("The chunk cannot be empty."));
+    }
+
+    let end = offset.saturating_add(body.len());
+
+    if end > upload.length() {
+        return Err(RESTError::bad_request(format!(
+            "The chunk exceeds the length of the upload. Expected: {}, Received: {end}",
+            upload.length()
+        )));
+    }
+
+    let minimum_upload_chunk_size = app.config().size_limits().minimum_upload_chunk_size();
+
+    if end < upload.length() && body.len() < minimum_upload_chunk_size {
+        return Err(RESTError::bad_request(format!(
+            "The chunk is too small. Expected at least: {minimum_upload_chunk_size}, Received: {}",
+            body.len()
+        )));
+    }
+
+    let size = body.len();
+
+    app.object_store()
+        .upload_part(
+            &upload.document(),
+            upload.multipart_id(),
+            upload.part_count() + 1,
+            body,
+        )
+        .await?;
+
+    if !upload.append(app.database().pool(), size).await? {
+        return Err(RESTError::conflict(
+            "Another chunk was appended to the upload first.",
+        ));
+    }
+
+    if !upload.is_complete() {
+        return Ok((StatusCode::NO_CONTENT, upload_headers(&upload)).into_response());
+    }
+
+    let document = match finalize_upload(&app, &paste, &upload).await {
+        Ok(document) => document,
+        Err(e) => {
+            app.object_store()
+                .delete_document(&upload.document())
+                .await?;
+
+            Upload::delete(app.database().pool(), upload.id()).await?;
+
+            return Err(e);
+        }
+    };
+
+    Ok((
+        StatusCode::CREATED,
+        upload_headers(&upload),
+        Json(ResponseDocument::from_document(
+            document,
+            app.config().domain(),
+        )),
+    )
+        .into_response())
+}
+
+/// Delete Upload.
+///
+/// Cancel a resumable upload, discarding every chunk received.
+///
+/// **Requires authentication.**
+///
+/// ## Path
+///
+/// - `upload_id` - The uploads ID.
+///
+/// ## Errors
+/// Returns an error if the request failed.
+///
+/// ## Returns
+///
+/// - `400` - The upload ID is not a valid snowflake.
+/// - `401` - Invalid token and/or paste ID.
+/// - `404` - The upload or its paste was not found.
+/// - `410` - The paste has expired, or reached its maximum views.
+/// - `204` - Successful cancellation of the upload.
+pub async fn delete_upload(
+    State(app): State<App>,
+    Path(path): Path<DeleteUploadPath>,
+    token: Token,
+) -> Result<StatusCode, RESTError> {
+    path.validate(app.config())?;
+
+    let (_, upload) = validate_upload(&app, path.upload_id(), token).await?;
+
+    app.object_store()
+        .abort_upload(&upload.document(), upload.multipart_id())
+        .await?;
+
+    Upload::delete(app.database().pool(), upload.id()).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Validate Upload.
+///
+/// Checks that the upload exists, and its paste is still valid for the token provided.
+async fn validate_upload(
+    app: &App,
+    upload_id: &Snowflake,
+    token: Token,
+) -> Result<(Paste, Upload), RESTError> {
+    let Some(upload) = Upload::fetch(app.database().pool(), upload_id).await? else {
+        return Err(RESTError::not_found("The upload was not found."));
+    };
+
+    let paste = validate_paste(app.database(), upload.paste_id(), Some(token)).await?;
+
+    Ok((paste, upload))
+}
+
+/// Finalize Upload.
+///
+/// Join the chunks of a complete upload, and create the document from its contents.
+async fn finalize_upload(app: &App, paste: &Paste, upload: &Upload) -> Result<Document, RESTError> {
+    app.object_store()
+        .complete_upload(&upload.document(), upload.multipart_id())
+        .await?;
+
+    let Some(content) = app
+        .object_store()
+        .fetch_document(&upload.document())
+        .await?
+    else {
+        return Err(RESTError::internal_server(
+            "The contents of the upload could not be found.",
+        ));
+    };
+
+    let content = String::from_utf8(content.to_vec())?;
+
+    document_limits(
+        app.config(),
+        "upload",
+        Undefined::Some(upload.name()),
+        Undefined::Some(&content),
+        upload.doc_type(),
+    )?;
+
+    let document = Document::new(
+        *upload.id(),
+        *upload.paste_id(),
+        upload.doc_type(),
+        upload.name(),
+        content.len(),
+        Some(&generate_checksum(content.as_bytes())),
+        &[],
+    );
+
+    let mut transaction = app.database().pool().begin().await?;
+
+    document.insert(transaction.as_mut()).await?;
+
+    enforce_maximums(&mut transaction, app.config(), paste.id()).await?;
+
+    enforce_minimums(&mut transaction, app.config(), paste.id()).await?;
+
+    enforce_owner_maximum(&mut transaction, app.config(), paste.owner_id()).await?;
+
+    enforce_unique_names(&mut transaction, app.config(), paste.id()).await?;
+
+    Upload::delete(transaction.as_mut(), upload.id()).await?;
+
+    transaction.commit().await?;
+
+    Ok(document)
+}
+
+/// Upload Headers.
+///
+/// The `Upload-Offset` and `Upload-Length` headers describing an upload.
+fn upload_headers(upload: &Upload) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+
+    headers.insert(
+        HeaderName::from_static(UPLOAD_OFFSET_HEADER),
+        HeaderValue::from(upload.offset()),
+    );
+
+    headers.insert(
+        HeaderName::from_static(UPLOAD_LENGTH_HEADER),
+        HeaderValue::from(upload.length()),
+    );
+
+    headers.insert(CACHE_CONTROL, HeaderValue::from_static("no-store"));
+
+    headers
+}
+
+#[cfg(test)]
+mod tests {
+    use axum_test::{TestResponse, TestServer};
+    use http::StatusCode;
+    use sqlx::PgPool;
+
+    use crate::app::{
+        application::ApplicationState,
+        config::{Config, SizeLimitConfig},
+        object_store::{MemoryObjectStore, ObjectStoreExt as _},
+    };
+    use crate::models::{
+        errors::RESTErrorResponse,
+        payload::{
+            document::ResponseDocument,
+            upload::{PostUploadBody, ResponseUpload},
+        },
+        snowflake::Snowflake,
+        upload::Upload,
+    };
+    use crate::rest::generate_router as main_generate_router;
+
+    const TOKEN: &str = "NTE3ODE1MzA0MzU0Mjg0NjA1.MTc3MDQzODc5Mw==.ozlKKwEEZpoGVuNzPDCyOMRGv";
+
+    /// Start a server, with chunks allowed to be as small as 4 bytes.
+    async fn start_server(pool: &PgPool, object_store: &MemoryObjectStore) -> TestServer {
+        let config = Config::test_builder()
+            .size_limits(
+                SizeLimitConfig::test_builder()
+                    .minimum_upload_chunk_size(4)
+                    .build()
+                    .expect("Failed to build size limit config."),
+            )
+            .build()
+            .expect("Failed to build config.");
+        let state = ApplicationState::new_tests(config, pool.clone(), object_store.clone())
+            .await
+            .expect("Failed to build application state.");
+
+        TestServer::new(main_generate_router(state))
+    }
+
+    /// Create an upload of `length` bytes, for the paste the token belongs to.
+    async fn create_upload(server: &TestServer, length: usize) -> ResponseUpload {
+        let response = server
+            .post("/v1/uploads")
+            .add_header("Authorization", format!("Bearer {TOKEN}"))
+            .json(&PostUploadBody::new(
+                Snowflake::new(517_815_304_354_284_605),
+                "upload.txt",
+                None,
+                length,
+            ))
+            .await;
+
+        response.assert_status(StatusCode::CREATED);
+
+        let body: ResponseUpload = response.json();
+
+        response.assert_header("Location", body.url());
+
+        body
+    }
+
+    async fn append_chunk(
+        server: &TestServer,
+        upload: &ResponseUpload,
+        offset: usize,
+        chunk: &'static str,
+    ) -> TestResponse {
+        server
+            .patch(upload.url())
+            .add_header("Authorization", format!("Bearer {TOKEN}"))
+            .add_header("Upload-Offset", offset.to_string())
+            .bytes(chunk.into())
+            .await
+    }
+
+    mod v1 {
+        use super::*;
+
+        mod patch_upload {
+            use super::*;
+
+            #[sqlx::test(fixtures(
+                path = "../../tests/fixtures",
+                scripts("pastes", "documents", "tokens")
+            ))]
+            async fn test_successful(pool: PgPool) {
+                let object_store = MemoryObjectStore::new();
+                let server = start_server(&pool, &object_store).await;
+
+                let upload = create_upload(&server, 10).await;
+
+                assert_eq!(upload.offset(), 0, "Offset does not match.");
+
+                let response = append_chunk(&server, &upload, 0, "hello").await;
+
+                response.assert_status(StatusCode::NO_CONTENT);
+
+                response.assert_header("Upload-Offset", "5");
+
+                let response = server
+                    .method(http::Method::HEAD, upload.url())
+                    .add_header("Authorization", format!("Bearer {TOKEN}"))
+                    .await;
+
+                response.assert_status_ok();
+
+                response.assert_header("Upload-Offset", "5");
+
+                response.assert_header("Upload-Length", "10");
+
+                let response = append_chunk(&server, &upload, 5, "world").await;
+
+                response.assert_status(StatusCode::CREATED);
+
+                let document: ResponseDocument = response.json();
+
+                assert_eq!(document.id(), upload.id(), "Document ID does not match.");
+
+                assert_eq!(document.size(), 10, "Document size does not match.");
+
+                assert_eq!(
+                    document.name(),
+                    "upload.txt",
+                    "Document name does not match."
+                );
+
+                let contents = object_store
+                    .fetch_document(&document)
+                    .await
+                    .expect("Failed to fetch document contents.");
+
+                assert_eq!(
+                    contents.as_deref(),
+                    Some(b"helloworld".as_slice()),
+                    "Document contents do not match."
+                );
+
+                let response = server
+                    .get(&format!(
+                        "/v1/pastes/{}/documents/{}/raw",
+                        document.paste_id(),
+                        document.id()
+                    ))
+                    .await;
+
+                response.assert_status_ok();
+
+                response.assert_text("helloworld");
+
+                let upload = Upload::fetch(&pool, upload.id())
+                    .await
+                    .expect("Failed to make DB request");
+
+                assert!(upload.is_none(), "Upload was found");
+            }
+
+            #[sqlx::test(fixtures(
+                path = "../../tests/fixtures",
+                scripts("pastes", "documents", "tokens")
+            ))]
+            async fn test_offset_gap(pool: PgPool) {
+                let object_store = MemoryObjectStore::new();
+                let server = start_server(&pool, &object_store).await;
+
+                let upload = create_upload(&server, 10).await;
+
+                let response = append_chunk(&server, &upload, 0, "hello").await;
+
+                response.assert_status(StatusCode::NO_CONTENT);
+
+                let response = append_chunk(&server, &upload, 7, "rld").await;
+
+                response.assert_status(StatusCode::CONFLICT);
+
+                let body: RESTErrorResponse = response.json();
+
+                assert_eq!(
+                    body.message(),
+                    "The upload offset does not match. Expected: 5, Received: 7",
+                    "Message does not match."
+                );
+
+                let fetched = Upload::fetch(&pool, upload.id())
+                    .await
+                    .expect("Failed to make DB request")
+                    .expect("Upload was not found");
+
+                assert_eq!(fetched.offset(), 5, "Offset does not match.");
+
+                let response = append_chunk(&server, &upload, 5, "world").await;
+
+                response.assert_status(StatusCode::CREATED);
+
+                let document: ResponseDocument = response.json();
+
+                let contents = object_store
+                    .fetch_document(&document)
+                    .await
+                    .expect("Failed to fetch document contents.");
+
+                assert_eq!(
+                    contents.as_deref(),
+                    Some(b"helloworld".as_slice()),
+                    "Document contents do not match."
+                );
+            }
+
+            #[sqlx::test(fixtures(
+                path = "../../tests/fixtures",
+                scripts("pastes", "documents", "tokens")
+            ))]
+            async fn test_chunk_limits(pool: PgPool) {
+                let object_store = MemoryObjectStore::new();
+                let server = start_server(&pool, &object_store).await;
+
+                let upload = create_upload(&server, 10).await;
+
+                let response = append_chunk(&server, &upload, 0, "abc").await;
+
+                response.assert_status(StatusCode::BAD_REQUEST);
+
+                let body: RESTErrorResponse = response.json();
+
+                assert_eq!(
+                    body.message(),
+                    "The chunk is too small. Expected at least: 4, Received: 3",
+                    "Message does not match."
+                );
+
+                let response = append_chunk(&server, &upload, 0, "hello world").await;
+
+                response.assert_status(StatusCode::BAD_REQUEST);
+
+                let body: RESTErrorResponse = response.json();
+
+                assert_eq!(
+                    body.message(),
+                    "The chunk exceeds the length of the upload. Expected: 10, Received: 11",
+                    "Message does not match."
+                );
+            }
+        }
+
+        mod delete_upload {
+            use super::*;
+
+            #[sqlx::test(fixtures(
+                path = "../../tests/fixtures",
+                scripts("pastes", "documents", "tokens")
+            ))]
+            async fn test_successful(pool: PgPool) {
+                let object_store = MemoryObjectStore::new();
+                let server = start_server(&pool, &object_store).await;
+
+                let upload = create_upload(&server, 10).await;
+
+                let response = server
+                    .delete(upload.url())
+                    .add_header("Authorization", format!("Bearer {TOKEN}"))
+                    .await;
+
+                response.assert_status(StatusCode::NO_CONTENT);
+
+                let upload = Upload::fetch(&pool, upload.id())
+                    .await
+                    .expect("Failed to make DB request");
+
+                assert!(upload.is_none(), "Upload was found");
+            }
+        }
+    }
+}
@@ -1,20 +1,21 @@
+-- Each token is stored as its SHA-256 digest, the plaintext tokens are used by the tests.
 INSERT INTO paste_tokens VALUES (
     517815304354284601,
-    'NTE3ODE1MzA0MzU0Mjg0NjAx.MTc0NzgxNjA1NA==.zYXUmCXIcnlvtAxJNJsUaDvRD'
+    'a6b8bdbcc1e09dbc12b429cfee8afad41ae88541644f4c4088c39e1e3b3941ee'
 );
 INSERT INTO paste_tokens VALUES (
     517815304354284602,
-    'NTE3ODE1MzA0MzU0Mjg0NjAy.MTc0NzgxNjA3NA==.dniyyCHW-xKQcYvjgawVExLCh'
+    'a3d05c4e8898208e8735f7ac9043e820171c0075c0b0997e9d69cea7248beb75'
 );
 INSERT INTO paste_tokens VALUES (
     517815304354284603,
-    'NTE3ODE1MzA0MzU0Mjg0NjAz.MTc0NzgxNjExNA==.cJeeeAPNidthlMtvkLNosiafy'
+    '8096881b782fb1c7e27a16375762ee1935620e4b4a7a2ce69949fc73806e93c7'
 );
 INSERT INTO paste_tokens VALUES (
     517815304354284604,
-    'NTE3ODE1MzA0MzU0Mjg0NjA0.MTc0NzgxNjE4OQ==.FDP-mNTjfuOKovulMFbaSkoeq'
+    '7e98ca19b1156d89f9bf7ba712cd8bc101996c660fb88dae9e7f42089e8a4cf5'
 );
 INSERT INTO paste_tokens VALUES (
     517815304354284605,
-    'NTE3ODE1MzA0MzU0Mjg0NjA1.MTc3MDQzODc5Mw==.ozlKKwEEZpoGVuNzPDCyOMRGv'
+    '8baa8631e879586951e3aed71c3703f7170ec23e9e970f33f1f49641d2b05423'
 );
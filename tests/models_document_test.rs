@@ -16,7 +16,7 @@ fn test_getters() {
     let name = "test.document";
     let size = 329;
 
-    let document = Document::new(document_id, paste_id, doc_type, name, size);
+    let document = Document::new(document_id, paste_id, doc_type, name, size, None);
 
     assert_eq!(document.id(), &document_id, "Mismatched document ID.");
 
@@ -191,8 +191,9 @@ fn test_insert(pool: PgPool) {
     let doc_type = "example/document";
     let name = "test.document";
     let size = 475;
+    let checksum = generate_checksum(b"test");
 
-    let document = Document::new(document_id, paste_id, doc_type, name, size);
+    let document = Document::new(document_id, paste_id, doc_type, name, size, Some(&checksum));
 
     document
         .insert(db.pool())
@@ -213,6 +214,12 @@ fn test_insert(pool: PgPool) {
     assert_eq!(result.name(), name, "Mismatched document type.");
 
     assert_eq!(result.size(), size);
+
+    assert_eq!(
+        result.checksum(),
+        Some("9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08"),
+        "Mismatched checksum."
+    );
 }
 
 #[rstest]
@@ -221,6 +228,7 @@ fn test_insert(pool: PgPool) {
         Undefined::Undefined,
         Undefined::Undefined,
         Undefined::Undefined,
+        Undefined::Undefined,
     ),
     "plain/text",
     "cool.txt",
@@ -232,6 +240,7 @@ fn test_insert(pool: PgPool) {
         Undefined::Some("text/plain".to_string()),
         Undefined::Undefined,
         Undefined::Undefined,
+        Undefined::Undefined,
     ),
     "text/plain",
     "cool.txt",
@@ -243,6 +252,7 @@ fn test_insert(pool: PgPool) {
         Undefined::Undefined,
         Undefined::Some("updated.txt".to_string()),
         Undefined::Undefined,
+        Undefined::Undefined,
     ),
     "plain/text",
     "updated.txt",
@@ -254,6 +264,7 @@ fn test_insert(pool: PgPool) {
         Undefined::Undefined,
         Undefined::Undefined,
         Undefined::Some(400),
+        Undefined::Undefined,
     ),
     "plain/text",
     "cool.txt",
@@ -265,6 +276,7 @@ fn test_insert(pool: PgPool) {
         Undefined::Some("text/plain".to_string()),
         Undefined::Some("updated.txt".to_string()),
         Undefined::Some(400),
+        Undefined::Undefined,
     ),
     "text/plain",
     "updated.txt",
@@ -5,6 +5,7 @@ use platy_paste::{
     app::database::Database,
     models::{
         DtUtc,
+        document::Document,
         paste::*,
         snowflake::Snowflake,
         undefined::{Undefined, UndefinedOption},
@@ -17,6 +18,7 @@ use sqlx::PgPool;
 #[test]
 fn test_getters() {
     let paste_id = Snowflake::new(123);
+    let owner_id = Snowflake::new(456);
     let creation = DateTime::from_timestamp(10, 0).expect("failed to generate timestamp.");
     let edited = DateTime::from_timestamp(15, 0).expect("failed to generate timestamp.");
     let expiry = DateTime::from_timestamp(20, 0).expect("failed to generate timestamp.");
@@ -29,6 +31,8 @@ fn test_getters() {
         Some(expiry),
         567,
         Some(1000),
+        Some("hash".to_string()),
+        Some(owner_id),
     );
 
     assert_eq!(paste.id(), &paste_id, "Mismatched paste ID.");
@@ -42,6 +46,14 @@ fn test_getters() {
     assert_eq!(paste.views(), 567, "Mismatched views.");
 
     assert_eq!(paste.max_views(), Some(1000), "Mismatched max views.");
+
+    assert_eq!(
+        paste.password_hash(),
+        Some("hash"),
+        "Mismatched password hash."
+    );
+
+    assert_eq!(paste.owner_id(), &owner_id, "Mismatched owner ID.");
 }
 
 #[sqlx::test(fixtures("pastes"))]
@@ -148,6 +160,8 @@ fn test_insert(pool: PgPool) {
         Some(expiry),
         53489,
         Some(100_000),
+        Some("hash".to_string()),
+        None,
     );
 
     paste
@@ -173,6 +187,14 @@ fn test_insert(pool: PgPool) {
     assert_eq!(paste.views(), 53489, "Mismatched views.");
 
     assert_eq!(paste.max_views(), Some(100_000), "Mismatched max views.");
+
+    assert_eq!(
+        result.password_hash(),
+        Some("hash"),
+        "Mismatched password hash."
+    );
+
+    assert_eq!(result.owner_id(), &paste_id, "Mismatched owner ID.");
 }
 
 #[rstest]
@@ -452,3 +474,65 @@ fn test_delete(pool: PgPool) {
 
     assert!(result.is_none(), "Found paste in db.");
 }
+
+#[sqlx::test(fixtures("pastes", "documents"))]
+fn test_fetch_owned_stats(pool: PgPool) {
+    let db = Database::from_pool(pool);
+
+    let owner_id = Snowflake::new(517_815_304_354_284_605);
+    let owned_paste_ids = [Snowflake::new(1), Snowflake::new(2)];
+
+    for paste_id in owned_paste_ids {
+        Paste::new(
+            paste_id,
+            None,
+            Utc::now(),
+            None,
+            None,
+            0,
+            None,
+            None,
+            Some(owner_id),
+        )
+        .insert(db.pool())
+        .await
+        .expect("Failed to insert paste");
+    }
+
+    Document::new(
+        Snowflake::new(3),
+        owned_paste_ids[0],
+        "text/plain",
+        "owned.txt",
+        100,
+        None,
+    )
+    .insert(db.pool())
+    .await
+    .expect("Failed to insert document");
+
+    let stats = Paste::fetch_owned_stats(db.pool(), &owner_id)
+        .await
+        .expect("Failed to fetch value from database.");
+
+    assert_eq!(stats.paste_count(), 3, "Mismatched paste count.");
+
+    assert_eq!(stats.document_count(), 3, "Mismatched document count.");
+
+    assert_eq!(stats.total_size(), 580, "Mismatched total size.");
+}
+
+#[sqlx::test]
+fn test_fetch_owned_stats_missing(pool: PgPool) {
+    let db = Database::from_pool(pool);
+
+    let stats = Paste::fetch_owned_stats(db.pool(), &Snowflake::new(123))
+        .await
+        .expect("Failed to fetch value from database.");
+
+    assert_eq!(
+        stats,
+        PasteOwnedStats::new(0, 0, 0),
+        "Mismatched owned stats."
+    );
+}
@@ -2,7 +2,7 @@
 
 use std::collections::HashSet;
 
-use platy_paste::models::snowflake::Snowflake;
+use platy_paste::models::snowflake::{MAXIMUM_WORKER_ID, Snowflake};
 
 #[test]
 fn test_uniqueness() {
@@ -27,3 +27,74 @@ fn test_uniqueness() {
         "Non-unique snowflake(s) found: {snowflakes:?}"
     );
 }
+
+#[test]
+fn test_plausible() {
+    let snowflake = Snowflake::generate().expect("Failed to generate snowflake.");
+
+    assert!(
+        snowflake.is_plausible(0),
+        "Generated snowflake was not plausible."
+    );
+
+    let ahead = Snowflake::new((snowflake.created_at() + 60) << 22);
+
+    assert!(
+        ahead.is_plausible(3600),
+        "Snowflake within the skew was not plausible."
+    );
+}
+
+#[test]
+fn test_implausible() {
+    let snowflake = Snowflake::new(517_815_304_354_284_605);
+
+    assert!(
+        !snowflake.is_plausible(3600),
+        "Far future snowflake was plausible."
+    );
+
+    let ahead = Snowflake::new(
+        (Snowflake::generate()
+            .expect("Failed to generate snowflake.")
+            .created_at()
+            + 7200)
+            << 22,
+    );
+
+    assert!(
+        !ahead.is_plausible(3600),
+        "Snowflake outside the skew was plausible."
+    );
+}
+
+#[test]
+fn test_worker_ids() {
+    let first = Snowflake::generate_with_worker(1).expect("Failed to generate snowflake.");
+    let second = Snowflake::generate_with_worker(2).expect("Failed to generate snowflake.");
+
+    assert_ne!(first, second, "Snowflakes from different workers match.");
+
+    let timestamp = first.created_at();
+    let first = Snowflake::new((timestamp << 22) | (first.id() & 0x003F_FFFF));
+    let second = Snowflake::new((timestamp << 22) | (second.id() & 0x003F_FFFF));
+
+    assert_ne!(
+        first, second,
+        "Snowflakes from different workers match for the same timestamp."
+    );
+}
+
+#[test]
+fn test_worker_id_round_trip() {
+    for worker_id in [0, 1, 512, MAXIMUM_WORKER_ID] {
+        let snowflake =
+            Snowflake::generate_with_worker(worker_id).expect("Failed to generate snowflake.");
+
+        assert_eq!(
+            snowflake.worker_id(),
+            worker_id,
+            "Worker ID does not match."
+        );
+    }
+}